@@ -4,6 +4,7 @@
 //! Error types for rusted-jetsons
 
 use std::fmt;
+use std::path::PathBuf;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -13,6 +14,74 @@ pub enum Error {
     HardwareNotFound(String),
     PermissionDenied(String),
     UnsupportedPlatform(String),
+    /// The TUI was started without an interactive terminal
+    NotATerminal,
+    /// A Jetson-only tool or interface is missing
+    NotJetson,
+    /// Reading a sysfs, procfs or configuration file failed
+    SysfsRead {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// Writing a sysfs file failed for a reason other than permissions
+    SysfsWrite {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// A file, or the output of a command, had unexpected contents
+    Parse {
+        path: PathBuf,
+        detail: String,
+    },
+    /// A value passed by the caller is out of range or malformed
+    InvalidArgument(String),
+    /// An external command exited unsuccessfully
+    CommandFailed {
+        cmd: String,
+        stderr: String,
+    },
+    /// Sending statistics to, or serving them for, a telemetry backend failed
+    Telemetry {
+        service: String,
+        detail: String,
+    },
+    /// An NVML call failed
+    #[cfg(feature = "nvml")]
+    Nvml(nvml_wrapper::error::NvmlError),
+}
+
+impl Error {
+    /// Error for a failed read of `path`
+    pub fn sysfs_read(path: impl Into<PathBuf>, source: std::io::Error) -> Self {
+        Error::SysfsRead {
+            path: path.into(),
+            source,
+        }
+    }
+
+    /// Error for a value that failed to parse, read from `path`
+    pub fn parse(path: impl Into<PathBuf>, detail: impl fmt::Display) -> Self {
+        Error::Parse {
+            path: path.into(),
+            detail: detail.to_string(),
+        }
+    }
+
+    /// Error for `cmd` exiting unsuccessfully with `stderr`
+    pub fn command_failed(cmd: &str, stderr: &[u8]) -> Self {
+        Error::CommandFailed {
+            cmd: cmd.to_string(),
+            stderr: String::from_utf8_lossy(stderr).trim().to_string(),
+        }
+    }
+
+    /// Error for a failure talking to the telemetry `service`
+    pub fn telemetry(service: &str, detail: impl fmt::Display) -> Self {
+        Error::Telemetry {
+            service: service.to_string(),
+            detail: detail.to_string(),
+        }
+    }
 }
 
 impl fmt::Display for Error {
@@ -22,6 +91,27 @@ impl fmt::Display for Error {
             Error::HardwareNotFound(s) => write!(f, "Hardware not found: {}", s),
             Error::PermissionDenied(s) => write!(f, "Permission denied: {}", s),
             Error::UnsupportedPlatform(s) => write!(f, "Unsupported platform: {}", s),
+            Error::NotATerminal => write!(
+                f,
+                "Not a terminal: the TUI needs an interactive terminal, use --stats for piped output"
+            ),
+            Error::NotJetson => write!(f, "Not running on a Jetson device"),
+            Error::SysfsRead { path, source } => {
+                write!(f, "Failed to read {}: {}", path.display(), source)
+            }
+            Error::SysfsWrite { path, source } => {
+                write!(f, "Failed to write {}: {}", path.display(), source)
+            }
+            Error::Parse { path, detail } => {
+                write!(f, "Failed to parse {}: {}", path.display(), detail)
+            }
+            Error::InvalidArgument(s) => write!(f, "Invalid argument: {}", s),
+            Error::CommandFailed { cmd, stderr } => {
+                write!(f, "{} command failed: {}", cmd, stderr)
+            }
+            Error::Telemetry { service, detail } => write!(f, "{}: {}", service, detail),
+            #[cfg(feature = "nvml")]
+            Error::Nvml(e) => write!(f, "NVML error: {}", e),
         }
     }
 }
@@ -30,6 +120,9 @@ impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Error::Io(e) => Some(e),
+            Error::SysfsRead { source, .. } | Error::SysfsWrite { source, .. } => Some(source),
+            #[cfg(feature = "nvml")]
+            Error::Nvml(e) => Some(e),
             _ => None,
         }
     }
@@ -41,6 +134,13 @@ impl From<std::io::Error> for Error {
     }
 }
 
+#[cfg(feature = "nvml")]
+impl From<nvml_wrapper::error::NvmlError> for Error {
+    fn from(e: nvml_wrapper::error::NvmlError) -> Self {
+        Error::Nvml(e)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -75,6 +175,86 @@ mod tests {
         assert_eq!(display, "Unsupported platform: x86_64");
     }
 
+    #[test]
+    fn test_error_display_not_a_terminal() {
+        let display = format!("{}", Error::NotATerminal);
+        assert!(display.starts_with("Not a terminal"));
+        assert!(display.contains("--stats"));
+    }
+
+    #[test]
+    fn test_error_display_not_jetson() {
+        assert_eq!(
+            format!("{}", Error::NotJetson),
+            "Not running on a Jetson device"
+        );
+    }
+
+    #[test]
+    fn test_error_display_sysfs_read() {
+        let err = Error::sysfs_read(
+            "/sys/class/thermal/thermal_zone0/temp",
+            std::io::Error::new(std::io::ErrorKind::NotFound, "No such file"),
+        );
+        assert_eq!(
+            format!("{}", err),
+            "Failed to read /sys/class/thermal/thermal_zone0/temp: No such file"
+        );
+        assert!(StdError::source(&err).is_some());
+    }
+
+    #[test]
+    fn test_error_display_sysfs_write() {
+        let err = Error::SysfsWrite {
+            path: PathBuf::from("/sys/class/hwmon/hwmon1/pwm1"),
+            source: std::io::Error::new(std::io::ErrorKind::InvalidInput, "Invalid argument"),
+        };
+        assert_eq!(
+            format!("{}", err),
+            "Failed to write /sys/class/hwmon/hwmon1/pwm1: Invalid argument"
+        );
+        assert!(StdError::source(&err).is_some());
+    }
+
+    #[test]
+    fn test_error_display_parse() {
+        let err = Error::parse("/etc/nvpmodel.conf", "missing POWER_MODEL");
+        assert!(matches!(&err, Error::Parse { detail, .. } if detail == "missing POWER_MODEL"));
+        assert_eq!(
+            format!("{}", err),
+            "Failed to parse /etc/nvpmodel.conf: missing POWER_MODEL"
+        );
+    }
+
+    #[test]
+    fn test_error_display_invalid_argument() {
+        let err = Error::InvalidArgument("Speed must be 0-100".to_string());
+        assert_eq!(format!("{}", err), "Invalid argument: Speed must be 0-100");
+    }
+
+    #[test]
+    fn test_error_display_command_failed() {
+        let err = Error::command_failed("nvpmodel", b"NVPM ERROR: invalid mode\n");
+        assert!(matches!(
+            &err,
+            Error::CommandFailed { cmd, stderr }
+                if cmd == "nvpmodel" && stderr == "NVPM ERROR: invalid mode"
+        ));
+        assert_eq!(
+            format!("{}", err),
+            "nvpmodel command failed: NVPM ERROR: invalid mode"
+        );
+    }
+
+    #[test]
+    fn test_error_display_telemetry() {
+        let err = Error::telemetry("OTLP", "endpoint responded with status 500");
+        assert_eq!(
+            format!("{}", err),
+            "OTLP: endpoint responded with status 500"
+        );
+    }
+
     #[test]
     fn test_error_source_io() {
         let io_err = std::io::Error::new(std::io::ErrorKind::NotFound, "file not found");
@@ -92,6 +272,10 @@ mod tests {
 
         let err = Error::UnsupportedPlatform("x86".to_string());
         assert!(StdError::source(&err).is_none());
+
+        assert!(StdError::source(&Error::NotATerminal).is_none());
+        assert!(StdError::source(&Error::NotJetson).is_none());
+        assert!(StdError::source(&Error::InvalidArgument("x".to_string())).is_none());
     }
 
     #[test]
@@ -109,6 +293,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::unnecessary_literal_unwrap)]
     fn test_result_type() {
         let ok_result: Result<i32> = Ok(42);
         assert_eq!(ok_result.unwrap(), 42);
@@ -10,7 +10,7 @@
 //! - Hardware detection and monitoring
 //! - CPU, GPU, Memory, Power, Temperature monitoring
 //! - Control of NVP model, jetson_clocks, fan
-//! - OpenTelemetry exports
+//! - OpenTelemetry and Prometheus exports
 //! - TUI with ratatui
 //!
 //! ## License
@@ -25,6 +25,9 @@
 pub mod error;
 pub mod modules;
 
+#[cfg(any(feature = "telemetry", feature = "prometheus", feature = "influxdb"))]
+pub mod telemetry;
+
 #[cfg(feature = "tui")]
 #[path = "../tui/mod.rs"]
 pub mod tui;
@@ -35,18 +38,49 @@ pub use tui::TuiApp;
 pub use error::{Error, Result};
 
 pub use modules::{
-    cpu::{CpuCore, CpuStats},
-    fan::{FanInfo, FanMode, FanStats},
-    gpu::{GpuProcess, GpuStats},
-    hardware::detect_board,
+    camel_case::CamelCase,
+    collector::{Collector, CollectorRegistry},
+    config::Config,
+    containers::{detect_containers, is_containerized, ContainerInfo},
+    cpu::{CpuCluster, CpuCore, CpuMonitor, CpuStats},
+    disk::{read_disks, DiskMonitor, DiskStats},
+    fan::{
+        detect_fan_backend, FanControlBackend, FanController, FanCurve, FanInfo, FanMode, FanStats,
+    },
+    gpu::{
+        gpu_arch_for_chip, gpu_name_for_chip, nominal_gpu_cores, GpuCores, GpuFunction, GpuMonitor,
+        GpuProcess, GpuStats, DEFAULT_GPU_CACHE_TTL,
+    },
     hardware::BoardInfo,
-    jetson_clocks::JetsonClocksStats,
+    hardware::{detect_board, detect_chip},
+    health::{health_summary, HealthStatus, HealthSummary, HealthThresholds},
+    history::History,
+    jetson_clocks::{JetsonClocksStats, JetsonClocksStatus},
+    jetson_stats::JetsonStats,
     memory::MemoryStats,
-    nvpmodel::{NVPModel, NVPModelStats},
-    power::{PowerRail, PowerStats},
-    temperature::{TemperatureStats, ThermalZone},
+    monitor::JetsonMonitor,
+    network::{NetInterface, NetMonitor},
+    nvpmodel::{NVPModel, NVPModelQuery, NVPModelStats},
+    power::{power_source, power_supply, PowerMonitor, PowerRail, PowerSource, PowerStats},
+    pressure::{read_pressure, Pressure, PressureStats},
+    report::{HardwareReport, StatsReport, StatsReporter, SCHEMA_VERSION},
+    swap::{swap_devices, SwapDevice, SwapKind},
+    temperature::{TemperatureAlert, TemperatureStats, TemperatureUnit, ThermalZone},
+    watchdog::{default_action, ThermalTrip, ThermalWatchdog, TripAction},
 };
 
+#[cfg(feature = "nvml")]
+pub use modules::gpu::NvmlContext;
+
+#[cfg(feature = "telemetry")]
+pub use telemetry::TelemetryExporter;
+
+#[cfg(feature = "prometheus")]
+pub use telemetry::PrometheusExporter;
+
+#[cfg(feature = "influxdb")]
+pub use telemetry::InfluxExporter;
+
 #[cfg(feature = "tui")]
 pub use tui::screens::{
     SimpleBoardInfo, SimpleCpuStats, SimpleFanStats, SimpleGpuStats, SimpleMemoryStats,
@@ -65,6 +99,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::double_comparisons)]
     fn test_memory_stats_public_api() {
         let stats = MemoryStats::get();
         // MemoryStats should be accessible
@@ -4,6 +4,7 @@
 //! rusted-jetsons CLI - rjtop
 
 use clap::Parser;
+use rusted_jetsons::TemperatureUnit;
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
@@ -22,14 +23,41 @@ struct Cli {
 
     #[arg(long)]
     jetson_clocks: bool,
+
+    /// TUI refresh interval in milliseconds (100-5000) [default: 250 or tick_ms from the config]
+    #[arg(
+        long,
+        value_name = "MS",
+        value_parser = clap::value_parser!(u64).range(
+            rusted_jetsons::tui::app::MIN_TICK_RATE_MS..=rusted_jetsons::tui::app::MAX_TICK_RATE_MS
+        )
+    )]
+    interval: Option<u64>,
+
+    /// Switch TUI screens by clicking their tabs (disables terminal copy/paste)
+    #[arg(long)]
+    mouse: bool,
+
+    /// Temperature unit for the TUI and --stats: celsius (c) or fahrenheit (f) [default: celsius or temp_unit from the config]
+    #[arg(long, value_name = "UNIT")]
+    temp_unit: Option<TemperatureUnit>,
 }
 
 fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
 
+    // Flags override the configuration file
+    let mut config = rusted_jetsons::Config::load()?;
+    if let Some(ms) = cli.interval {
+        config.tick_ms = ms;
+    }
+    if let Some(unit) = cli.temp_unit {
+        config.temp_unit = unit;
+    }
+
     // Handle stats output
     if cli.stats {
-        print_json_stats()?;
+        print_json_stats(config.temp_unit)?;
         return Ok(());
     }
 
@@ -58,100 +86,41 @@ fn main() -> anyhow::Result<()> {
     }
 
     // Run TUI
-    let mut app = rusted_jetsons::TuiApp::new()?;
+    let mut app = rusted_jetsons::TuiApp::with_config(&config)?;
+    if cli.mouse {
+        app.enable_mouse()?;
+    }
     app.run()?;
 
     Ok(())
 }
 
-fn print_json_stats() -> anyhow::Result<()> {
-    use rusted_jetsons::modules::{
-        cpu, engine, fan, gpu, hardware, memory, power, processes, temperature,
-    };
-
-    let is_jetson = hardware::is_jetson();
-    let board_info = hardware::detect_board();
-
-    let cpu_stats = cpu::CpuStats::get();
-    let gpu_stats = gpu::GpuStats::get();
-    let memory_stats = memory::MemoryStats::get();
-    let fan_stats = fan::FanStats::get();
-    let temperature_stats = temperature::TemperatureStats::get();
-    let power_stats = power::PowerStats::get();
-    let engine_stats = engine::EngineStats::get();
-    let process_stats = processes::ProcessStats::get();
-
-    let stats = serde_json::json!({
-        "hardware": {
-            "model": board_info.model,
-            "jetpack": board_info.jetpack,
-            "l4t": board_info.l4t,
-            "serial": board_info.serial,
-            "is_jetson": is_jetson,
-        },
-        "cpu": {
-            "usage": cpu_stats.usage,
-            "cores": cpu::get_core_count(),
-        },
-        "gpu": {
-            "usage": gpu_stats.usage,
-            "frequency": gpu_stats.frequency,
-            "temperature": gpu_stats.temperature,
-            "governor": gpu_stats.governor,
-        },
-        "memory": {
-            "ram_used": memory_stats.ram_used,
-            "ram_total": memory_stats.ram_total,
-            "ram_cached": memory_stats.ram_cached,
-            "swap_used": memory_stats.swap_used,
-            "swap_total": memory_stats.swap_total,
-            "swap_cached": memory_stats.swap_cached,
-            "iram_used": memory_stats.iram_used,
-            "iram_total": memory_stats.iram_total,
-            "iram_lfb": memory_stats.iram_lfb,
-        },
-        "fan": {
-            "speed": fan_stats.speed,
-            "rpm": fan_stats.rpm,
-            "mode": fan_stats.mode.to_string(),
-            "fans": fan_stats.fans,
-            "temperature": fan_stats.temperature,
-        },
-        "temperature": {
-            "cpu": temperature_stats.cpu,
-            "gpu": temperature_stats.gpu,
-            "board": temperature_stats.board,
-            "pmic": temperature_stats.pmic,
-            "thermal_zones": temperature_stats.thermal_zones,
-        },
-        "power": {
-            "total": power_stats.total,
-            "rails": power_stats.rails,
-        },
-        "engine": {
-            "ape": engine_stats.ape,
-            "dla0": engine_stats.dla0,
-            "dla1": engine_stats.dla1,
-            "nvdec": engine_stats.nvdec,
-            "nvenc": engine_stats.nvenc,
-            "nvjpg": engine_stats.nvjpg,
-        },
-        "processes": {
-            "total_processes": process_stats.total_processes,
-            "gpu_processes": process_stats.gpu_processes,
-        },
-    });
-
-    println!("{}", stats);
+fn print_json_stats(unit: TemperatureUnit) -> anyhow::Result<()> {
+    let report = rusted_jetsons::StatsReport::collect().to_unit(unit);
+    println!("{}", serde_json::to_string(&report)?);
     Ok(())
 }
 
+#[cfg(feature = "telemetry")]
 fn print_export_info(endpoint: &str) -> anyhow::Result<()> {
     println!("OTLP export to endpoint: {}", endpoint);
-    println!("Note: OpenTelemetry export not yet implemented");
+
+    let exporter = rusted_jetsons::TelemetryExporter::new(endpoint.to_string());
+    tokio::runtime::Runtime::new()?.block_on(async {
+        let stats = rusted_jetsons::JetsonStats::collect_async().await;
+        exporter.export(&stats).await
+    })?;
+
+    println!("Successfully exported to OTLP endpoint");
     Ok(())
 }
 
+#[cfg(not(feature = "telemetry"))]
+fn print_export_info(endpoint: &str) -> anyhow::Result<()> {
+    println!("OTLP export to endpoint: {}", endpoint);
+    anyhow::bail!("OTLP export requires the 'telemetry' feature (cargo build --features telemetry)")
+}
+
 fn control_fan(speed: u8) -> anyhow::Result<()> {
     if speed > 100 {
         anyhow::bail!("Fan speed must be between 0 and 100");
@@ -3,8 +3,13 @@
 
 //! rusted-jetsons CLI - rjtop-cli (no TUI)
 
+use std::io::{IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
 use anyhow::Result;
 use clap::Parser;
+use rusted_jetsons::Collector;
 
 #[derive(Parser)]
 #[command(
@@ -15,26 +20,83 @@ use clap::Parser;
     long_about = "rjtop-cli provides command-line interface for monitoring and controlling NVIDIA Jetson devices. Supports JSON export, OTLP export, fan control, NVP model switching, and jetson_clocks toggling.",
     after_help = "EXAMPLES:
   rjtop-cli --stats                    Display system statistics in JSON format
+  rjtop-cli --stats --camel-case       Same, with camelCase keys for web dashboards
+  rjtop-cli --stats --watch 2          Stream stats as NDJSON every 2 seconds
+  rjtop-cli --stats --watch 1 --format csv  Log a CSV row every second
+  rjtop-cli --stats --strict           Fail instead of degrading when not on a Jetson
+  rjtop-cli --snapshot stats.json       Write one snapshot to a file (.csv for CSV)
+  rjtop-cli --gpu-only --interval 0.5  Print a GPU summary line every 0.5s
+  rjtop-cli --board-only               Print the detected board model
+  rjtop-cli --model-id                 Print the current NVP model ID
+  rjtop-cli --health-check --crit-temp 90  Exit 0/1/2 for OK/WARNING/CRITICAL
+  rjtop-cli --alert-temp 90 --alert-cmd 'nvpmodel -m 1'  Throttle when a zone stays above 90°C
   rjtop-cli --fan 75                   Set fan speed to 75%
+  rjtop-cli --fan 75 --fan-index 1     Set only fan 1 to 75%
+  rjtop-cli --fan-auto                 Restore automatic fan control
+  rjtop-cli --fan-curve 40:20,60:50,80:100  Drive the fan from a temperature curve
   rjtop-cli --nvpmodel 0               Set NVP model to ID 0
+  rjtop-cli --governor performance     Set the CPU governor of every core
+  rjtop-cli --gpu-governor performance Set the GPU devfreq governor
   rjtop-cli --jetson-clocks            Toggle jetson_clocks
+  rjtop-cli --clocks-store             Save the clock settings before experimenting
+  rjtop-cli --clocks-restore           Roll the clock settings back
+  rjtop-cli --swap-create 4096         Enable 4 GB of zram swap
+  rjtop-cli --swaps                    List active swap devices
   rjtop-cli --export otlp              Export stats to OTLP endpoint
-  rjtop-cli --export otlp --endpoint http://localhost:4318  Export to specific OTLP endpoint"
+  rjtop-cli --export otlp --endpoint http://localhost:4318  Export to specific OTLP endpoint
+  rjtop-cli --export influxdb --token T  Write one point to InfluxDB at localhost:8086
+  rjtop-cli --export prometheus --endpoint 0.0.0.0:9101  Serve Prometheus metrics at /metrics"
 )]
 struct Cli {
     #[arg(
         long,
         short = 's',
         help = "Display system statistics in JSON format",
-        long_help = "Output comprehensive system statistics in JSON format including CPU, GPU, memory, temperature, fan, and power metrics."
+        long_help = "Output comprehensive system statistics in JSON format including CPU, GPU, memory, temperature, fan, power, pressure stall (PSI), disk usage and network throughput metrics."
     )]
     stats: bool,
 
+    #[arg(
+        long,
+        requires = "stats",
+        help = "Use camelCase keys in --stats JSON output",
+        long_help = "Emit --stats JSON with camelCase keys (ramUsed, swapTotal) for web dashboards instead of the default snake_case keys."
+    )]
+    camel_case: bool,
+
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        requires = "stats",
+        value_parser = parse_watch_interval,
+        help = "Stream --stats as one JSON object per line every SECONDS",
+        long_help = "Keep printing --stats snapshots as newline-delimited JSON (NDJSON), one compact object per line, every SECONDS until interrupted or --count is reached. CPU usage is computed from the delta between iterations. --watch 0 samples as fast as possible. Example: --stats --watch 2"
+    )]
+    watch: Option<f64>,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value = "json",
+        requires = "stats",
+        help = "Output format for --stats",
+        long_help = "Output format for --stats. 'json' (default) prints the full statistics. 'csv' prints a header row followed by one row per sample with timestamp (Unix seconds), cpu_usage, gpu_usage, gpu_temp, ram_used, ram_total, power_total and fan_speed columns. Combine with --watch to log to a spreadsheet-friendly file. Example: --stats --watch 1 --format csv"
+    )]
+    format: OutputFormat,
+
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Write one statistics snapshot to a file",
+        long_help = "Collect statistics once and write them to PATH as pretty JSON, or as a header plus one CSV row when PATH ends in .csv. The file is replaced atomically, so a concurrent reader never sees a partial write. Example: --snapshot /tmp/jetson.json"
+    )]
+    snapshot: Option<PathBuf>,
+
     #[arg(
         long,
         value_name = "TYPE",
         help = "Export statistics to external system",
-        long_help = "Export statistics to external monitoring systems. Currently supports 'otlp' for OpenTelemetry export.",
+        long_help = "Export statistics to external monitoring systems. Supports 'otlp' for a one-shot OpenTelemetry export, 'influxdb' to write one point in InfluxDB line protocol and 'prometheus' to serve a scrape endpoint at /metrics.",
         value_parser = parse_export_type
     )]
     export: Option<String>,
@@ -47,6 +109,34 @@ struct Cli {
     )]
     fan: Option<u8>,
 
+    #[arg(
+        long,
+        value_name = "INDEX",
+        requires = "fan",
+        help = "Apply --fan to a single fan",
+        long_help = "Set the speed of only the fan with this index, as listed in the fans array of --stats, leaving the other fans untouched. Boards such as AGX modules have several controllable fans. Example: --fan 75 --fan-index 1"
+    )]
+    fan_index: Option<usize>,
+
+    #[arg(
+        long,
+        conflicts_with = "fan",
+        help = "Restore automatic fan control",
+        long_help = "Hand fan control back to the thermal governor after --fan. Requires root/sudo privileges."
+    )]
+    fan_auto: bool,
+
+    #[arg(
+        long,
+        value_name = "CURVE",
+        value_parser = parse_fan_curve,
+        num_args = 0..=1,
+        conflicts_with_all = ["fan", "fan_auto"],
+        help = "Drive the fan from a temperature curve",
+        long_help = "Set the fan speed from the hottest thermal zone every --interval seconds, interpolating linearly between TEMP:SPEED points. Temperatures outside the curve use the nearest point. Without a value the fan_curve of the config file is used. Requires root/sudo privileges. Example: --fan-curve 40:20,60:50,80:100"
+    )]
+    fan_curve: Option<Option<rusted_jetsons::FanCurve>>,
+
     #[arg(
         long,
         value_name = "ID",
@@ -55,6 +145,22 @@ struct Cli {
     )]
     nvpmodel: Option<u8>,
 
+    #[arg(
+        long,
+        value_name = "GOVERNOR",
+        help = "Set the CPU frequency governor of every core",
+        long_help = "Set the cpufreq governor of every online CPU core, e.g. performance or schedutil. The governor must be listed in scaling_available_governors. Requires root/sudo privileges. Example: --governor performance"
+    )]
+    governor: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "GOVERNOR",
+        help = "Set the GPU devfreq governor",
+        long_help = "Set the devfreq governor of the GPU, e.g. performance or nvhost_podgov. The governor must be listed in the devfreq available_governors. Requires root/sudo privileges. Example: --gpu-governor performance"
+    )]
+    gpu_governor: Option<String>,
+
     #[arg(
         long,
         help = "Toggle jetson_clocks",
@@ -62,66 +168,529 @@ struct Cli {
     )]
     jetson_clocks: bool,
 
+    #[arg(
+        long,
+        value_name = "PATH",
+        num_args = 0..=1,
+        default_missing_value = rusted_jetsons::modules::jetson_clocks::DEFAULT_STORE_PATH,
+        conflicts_with = "clocks_restore",
+        help = "Save the current clock settings with jetson_clocks --store",
+        long_help = "Snapshot the current CPU, GPU and EMC clock settings with jetson_clocks --store, so they can be rolled back with --clocks-restore. Without PATH the settings go to /var/tmp/rusted_jetsons_clocks.conf. Requires root/sudo privileges. Example: --clocks-store"
+    )]
+    clocks_store: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "PATH",
+        num_args = 0..=1,
+        default_missing_value = rusted_jetsons::modules::jetson_clocks::DEFAULT_STORE_PATH,
+        help = "Restore clock settings saved by --clocks-store",
+        long_help = "Roll the clock settings back with jetson_clocks --restore from a file written by --clocks-store. Without PATH /var/tmp/rusted_jetsons_clocks.conf is used. Requires root/sudo privileges. Example: --clocks-restore"
+    )]
+    clocks_restore: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "MB",
+        value_parser = clap::value_parser!(u64).range(1..),
+        help = "Create and enable a zram swap device of MB megabytes",
+        long_help = "Create a compressed zram device of the given size in MB, format it with mkswap and enable it with swapon at priority 100, so it is used before any disk swap. The zram kernel module is loaded if needed. Requires root/sudo privileges. Example: --swap-create 4096"
+    )]
+    swap_create: Option<u64>,
+
+    #[arg(
+        long,
+        help = "List active swap devices in JSON format",
+        long_help = "Output the active swap devices from /proc/swaps as a JSON array with the name, type (zram, file or partition), size and used space in bytes, and priority of each."
+    )]
+    swaps: bool,
+
     #[arg(
         long,
         value_name = "URL",
-        help = "OTLP endpoint URL for export",
-        long_help = "Specify the OTLP (OpenTelemetry Protocol) endpoint URL for exporting metrics. Default: http://localhost:4318. Example: --endpoint http://localhost:4318"
+        help = "OTLP or InfluxDB endpoint URL, or Prometheus listen address",
+        long_help = "Specify the OTLP (OpenTelemetry Protocol) endpoint URL for exporting metrics. Default: http://localhost:4318. Example: --endpoint http://localhost:4318. With --export influxdb this is the InfluxDB URL with the org and bucket in the query. Default: http://localhost:8086. Example: --endpoint 'http://localhost:8086?org=acme&bucket=jetson'. With --export prometheus this is the address to listen on instead. Default: 0.0.0.0:9101"
     )]
     endpoint: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "TOKEN",
+        requires = "export",
+        help = "InfluxDB API token for --export influxdb"
+    )]
+    token: Option<String>,
+
+    #[arg(
+        long,
+        help = "Watch GPU only, one summary line per refresh",
+        long_help = "Lightweight GPU watch mode. Prints one line per refresh with GPU usage, clock, temperature, memory used/total and active functions, without collecting CPU, power or thermal data."
+    )]
+    gpu_only: bool,
+
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        default_value_t = 1.0,
+        value_parser = parse_interval,
+        help = "Refresh interval in seconds for watch modes",
+        long_help = "Refresh interval in seconds for watch modes such as --gpu-only. Fractions are allowed. Example: --interval 0.5"
+    )]
+    interval: f64,
+
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Stop watch modes after N refreshes",
+        long_help = "Number of refreshes before watch modes such as --watch and --gpu-only exit. Runs until interrupted when omitted."
+    )]
+    count: Option<u64>,
+
+    #[arg(
+        long,
+        help = "Print only the detected board model",
+        long_help = "Print the detected board model on a single line and exit. Exits with a nonzero status when not running on a Jetson device."
+    )]
+    board_only: bool,
+
+    #[arg(
+        long,
+        help = "Print only the current NVP model ID",
+        long_help = "Print the current NVP model ID on a single line and exit. Exits with a nonzero status when not running on a Jetson device or the model cannot be determined."
+    )]
+    model_id: bool,
+
+    #[arg(
+        long,
+        help = "Print a one-line health status and exit 0/1/2 for OK/WARNING/CRITICAL",
+        long_help = "Check thermal zones and RAM usage against the warning and critical thresholds. Prints a one-line reason and exits with 0 (OK), 1 (WARNING) or 2 (CRITICAL), following the Nagios plugin convention. Exits with 3 when the thresholds are inconsistent."
+    )]
+    health_check: bool,
+
+    #[arg(
+        long,
+        value_name = "CELSIUS",
+        help = "Warning temperature for --health-check (default 80 or warn_temp from the config)"
+    )]
+    warn_temp: Option<f32>,
+
+    #[arg(
+        long,
+        value_name = "CELSIUS",
+        help = "Critical temperature for --health-check (default 95 or crit_temp from the config)"
+    )]
+    crit_temp: Option<f32>,
+
+    #[arg(
+        long,
+        value_name = "CELSIUS",
+        requires = "alert_cmd",
+        help = "Run --alert-cmd when a thermal zone stays above this temperature",
+        long_help = "Monitor all thermal zones and run --alert-cmd once the hottest zone has stayed at or above this temperature for --alert-duration seconds. The command runs again only after the temperature drops below the threshold. Example: --alert-temp 90"
+    )]
+    alert_temp: Option<f32>,
+
+    #[arg(
+        long,
+        value_name = "CMD",
+        requires = "alert_temp",
+        help = "Shell command run when --alert-temp is exceeded",
+        long_help = "Shell command run through 'sh -c' when --alert-temp is exceeded. RJTOP_ALERT_ZONE and RJTOP_ALERT_TEMP are set to the triggering zone and temperature. Example: --alert-cmd 'shutdown -h now'"
+    )]
+    alert_cmd: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        default_value_t = 10.0,
+        value_parser = parse_interval,
+        help = "How long --alert-temp must be exceeded before firing",
+        long_help = "Number of seconds the temperature must stay at or above --alert-temp before --alert-cmd runs, so short spikes do not trigger it. Example: --alert-duration 30"
+    )]
+    alert_duration: f64,
+
+    #[arg(
+        long,
+        help = "Disable colored output",
+        long_help = "Disable ANSI colors in text output. Colors are also disabled when NO_COLOR is set or stdout is not a terminal."
+    )]
+    no_color: bool,
+
+    #[arg(
+        long,
+        help = "Exit with an error when not running on a Jetson",
+        long_help = "Refuse to run on boards that are not detected as a Jetson and exit with status 1. Without this flag rjtop-cli degrades gracefully and reports hardware.is_jetson as false in --stats output."
+    )]
+    strict: bool,
+}
+
+/// Output format of --stats
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum OutputFormat {
+    Json,
+    Csv,
 }
 
+/// Header row of the --format csv output
+const CSV_HEADER: &str =
+    "timestamp,cpu_usage,gpu_usage,gpu_temp,ram_used,ram_total,power_total,fan_speed";
+
 fn parse_export_type(s: &str) -> Result<String, String> {
     let s_lower = s.to_lowercase();
-    if s_lower == "otlp" {
+    if s_lower == "otlp" || s_lower == "prometheus" || s_lower == "influxdb" {
         Ok(s_lower)
     } else {
         Err(format!(
-            "Invalid export type '{}'. Supported types: otlp",
+            "Invalid export type '{}'. Supported types: otlp, prometheus, influxdb",
             s
         ))
     }
 }
 
-#[derive(serde::Serialize)]
-struct SystemStats {
-    cpu: rusted_jetsons::CpuStats,
-    gpu: rusted_jetsons::GpuStats,
-    memory: rusted_jetsons::MemoryStats,
-    temperature: rusted_jetsons::TemperatureStats,
-    fan: rusted_jetsons::FanStats,
-    power: rusted_jetsons::PowerStats,
-    hardware: rusted_jetsons::BoardInfo,
+fn parse_fan_curve(s: &str) -> Result<rusted_jetsons::FanCurve, String> {
+    s.parse()
+        .map_err(|e| format!("Invalid fan curve '{}': {}", s, e))
+}
+
+fn parse_interval(s: &str) -> Result<f64, String> {
+    match s.parse::<f64>() {
+        Ok(secs) if secs.is_finite() && secs > 0.0 => Ok(secs),
+        _ => Err(format!(
+            "Invalid interval '{}'. Expected a positive number of seconds",
+            s
+        )),
+    }
+}
+
+/// Parse a --watch interval, where 0 means no delay between samples
+fn parse_watch_interval(s: &str) -> Result<f64, String> {
+    match s.parse::<f64>() {
+        Ok(secs) if secs.is_finite() && secs >= 0.0 => Ok(secs),
+        _ => Err(format!(
+            "Invalid interval '{}'. Expected a non-negative number of seconds",
+            s
+        )),
+    }
+}
+
+/// Whether ANSI colors should be used for text output
+fn use_color(no_color: bool) -> bool {
+    !no_color && std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal()
+}
+
+/// Wrap `text` in an ANSI color code when colors are enabled
+fn paint(text: &str, code: &str, color: bool) -> String {
+    if color {
+        format!("\x1b[{}m{}\x1b[0m", code, text)
+    } else {
+        text.to_string()
+    }
+}
+
+/// Format a one-line GPU summary for `--gpu-only`
+fn format_gpu_line(stats: &rusted_jetsons::GpuStats, color: bool) -> String {
+    let usage_code = if stats.usage >= 90.0 {
+        "31"
+    } else if stats.usage >= 50.0 {
+        "33"
+    } else {
+        "32"
+    };
+    let (used, used_unit) = rusted_jetsons::modules::memory::format_memory_bytes(stats.memory_used);
+    let (total, total_unit) =
+        rusted_jetsons::modules::memory::format_memory_bytes(stats.memory_total);
+    let functions = if stats.active_functions.is_empty() {
+        "-".to_string()
+    } else {
+        stats.active_functions.join(",")
+    };
+
+    format!(
+        "{} {} | {} MHz | {:.1}°C | mem {:.1} {}/{:.1} {} | {}",
+        paint("GPU", "1", color),
+        paint(&format!("{:5.1}%", stats.usage), usage_code, color),
+        stats.frequency / 1_000_000,
+        stats.temperature,
+        used,
+        used_unit,
+        total,
+        total_unit,
+        functions
+    )
+}
+
+/// Apply a fan curve until `count` refreshes are done
+fn run_fan_curve(curve: rusted_jetsons::FanCurve, interval: f64, count: Option<u64>) -> Result<()> {
+    let mut controller =
+        rusted_jetsons::FanController::new(curve, Duration::from_secs_f64(interval));
+    let mut refreshes = 0u64;
+
+    loop {
+        match controller.tick() {
+            Ok(speed) => println!("Fan speed set to {}%", speed),
+            Err(e) => {
+                eprintln!("Error applying fan curve: {}", e);
+                eprintln!("Note: This operation requires root/sudo privileges.");
+                std::process::exit(1);
+            }
+        }
+
+        refreshes += 1;
+        if count.is_some_and(|n| refreshes >= n) {
+            return Ok(());
+        }
+
+        std::thread::sleep(controller.poll_interval);
+    }
 }
 
-impl SystemStats {
-    fn new() -> Self {
-        Self {
-            cpu: rusted_jetsons::CpuStats::get(),
-            gpu: rusted_jetsons::GpuStats::get(),
-            memory: rusted_jetsons::MemoryStats::get(),
-            temperature: rusted_jetsons::TemperatureStats::get(),
-            fan: rusted_jetsons::FanStats::get(),
-            power: rusted_jetsons::PowerStats::get(),
-            hardware: rusted_jetsons::detect_board(),
+/// Print GPU summary lines until `count` refreshes are done
+fn run_gpu_watch(interval: f64, count: Option<u64>, color: bool) -> Result<()> {
+    let mut stdout = std::io::stdout();
+    let mut refreshes = 0u64;
+
+    loop {
+        let stats = rusted_jetsons::GpuStats::get();
+        writeln!(stdout, "{}", format_gpu_line(&stats, color))?;
+        stdout.flush()?;
+
+        refreshes += 1;
+        if count.is_some_and(|n| refreshes >= n) {
+            return Ok(());
         }
+
+        std::thread::sleep(Duration::from_secs_f64(interval));
     }
 }
 
+/// Print --stats snapshots as NDJSON or CSV until `count` refreshes are done
+fn run_stats_watch(
+    interval: f64,
+    count: Option<u64>,
+    camel_case: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    // A single reporter keeps the CPU monitor alive for delta-based usage
+    let mut reporter = rusted_jetsons::StatsReporter::new();
+    let mut stdout = std::io::stdout();
+    let mut refreshes = 0u64;
+
+    if format == OutputFormat::Csv {
+        writeln!(stdout, "{}", CSV_HEADER)?;
+    }
+
+    loop {
+        let report = reporter.collect();
+        let line = match format {
+            OutputFormat::Csv => csv_row(&report),
+            OutputFormat::Json if camel_case => {
+                serde_json::to_string(&rusted_jetsons::CamelCase(&report))?
+            }
+            OutputFormat::Json => serde_json::to_string(&report)?,
+        };
+        writeln!(stdout, "{}", line)?;
+        stdout.flush()?;
+
+        refreshes += 1;
+        if count.is_some_and(|n| refreshes >= n) {
+            return Ok(());
+        }
+
+        if interval > 0.0 {
+            std::thread::sleep(Duration::from_secs_f64(interval));
+        }
+    }
+}
+
+/// Write one report to `path` as pretty JSON, or CSV for `.csv` paths
+///
+/// The report is written to a temporary file next to `path` and renamed
+/// over it, so readers see either the old or the new file.
+fn write_snapshot(path: &Path) -> Result<()> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| anyhow::anyhow!("'{}' is not a file path", path.display()))?;
+
+    let report = rusted_jetsons::StatsReport::collect();
+    let is_csv = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+    let contents = if is_csv {
+        format!("{}\n{}\n", CSV_HEADER, csv_row(&report))
+    } else {
+        serde_json::to_string_pretty(&report)? + "\n"
+    };
+
+    let tmp = path.with_file_name(format!(
+        ".{}.{}.tmp",
+        file_name.to_string_lossy(),
+        std::process::id()
+    ));
+    std::fs::write(&tmp, contents)?;
+    if let Err(e) = std::fs::rename(&tmp, path) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e.into());
+    }
+
+    Ok(())
+}
+
+/// Watch thermal zones and run `cmd` when `threshold` is exceeded for `sustain`
+fn run_temp_alert(
+    threshold: f32,
+    cmd: &str,
+    sustain: f64,
+    interval: f64,
+    count: Option<u64>,
+) -> Result<()> {
+    let mut watchdog = rusted_jetsons::ThermalWatchdog::with_command(
+        threshold,
+        Duration::from_secs_f64(sustain),
+        Some(cmd.to_string()),
+    );
+    let mut refreshes = 0u64;
+
+    eprintln!(
+        "Watching thermal zones: running '{}' above {:.1}°C for {}s",
+        cmd, threshold, sustain
+    );
+
+    loop {
+        let stats = rusted_jetsons::TemperatureStats::get();
+        watchdog.check(&stats, Instant::now());
+
+        refreshes += 1;
+        if count.is_some_and(|n| refreshes >= n) {
+            return Ok(());
+        }
+
+        std::thread::sleep(Duration::from_secs_f64(interval));
+    }
+}
+
+/// Headline metrics as a row matching [`CSV_HEADER`], stamped with the current time
+fn csv_row(report: &rusted_jetsons::StatsReport) -> String {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs_f64();
+
+    format!(
+        "{:.3},{:.2},{:.2},{:.2},{},{},{:.2},{}",
+        timestamp,
+        report.cpu.usage,
+        report.gpu.usage,
+        report.gpu.temperature,
+        report.memory.ram_used,
+        report.memory.ram_total,
+        report.power.total,
+        report.fan.speed,
+    )
+}
+
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
+    if cli.strict && !rusted_jetsons::modules::hardware::is_jetson() {
+        eprintln!("Error: not running on a Jetson device (--strict)");
+        std::process::exit(1);
+    }
+
+    if cli.board_only || cli.model_id {
+        if !rusted_jetsons::modules::hardware::is_jetson() {
+            eprintln!("Error: not running on a Jetson device");
+            std::process::exit(1);
+        }
+
+        if cli.board_only {
+            println!("{}", rusted_jetsons::detect_board().model);
+        }
+
+        if cli.model_id {
+            let Some(id) = rusted_jetsons::NVPModelStats::current_model_id() else {
+                eprintln!("Error: could not determine the current NVP model");
+                std::process::exit(1);
+            };
+            println!("{}", id);
+        }
+
+        return Ok(());
+    }
+
+    if cli.health_check {
+        // Flags override the configuration file
+        let config = match rusted_jetsons::Config::load() {
+            Ok(config) => config,
+            Err(e) => {
+                println!("UNKNOWN: {}", e);
+                std::process::exit(3);
+            }
+        };
+        let defaults = rusted_jetsons::HealthThresholds::default();
+        let thresholds = rusted_jetsons::HealthThresholds {
+            warn_temp: cli.warn_temp.unwrap_or(config.warn_temp),
+            crit_temp: cli.crit_temp.unwrap_or(config.crit_temp),
+            ..defaults
+        };
+
+        if thresholds.warn_temp > thresholds.crit_temp {
+            println!("UNKNOWN: --warn-temp must not exceed --crit-temp");
+            std::process::exit(3);
+        }
+
+        let summary = rusted_jetsons::HealthSummary::get(&thresholds);
+        println!("{}", summary);
+        std::process::exit(summary.status.exit_code());
+    }
+
+    if let (true, Some(interval)) = (cli.stats, cli.watch) {
+        return run_stats_watch(interval, cli.count, cli.camel_case, cli.format);
+    }
+
+    if cli.stats && cli.format == OutputFormat::Csv {
+        let report = rusted_jetsons::StatsReport::collect();
+        println!("{}", CSV_HEADER);
+        println!("{}", csv_row(&report));
+        return Ok(());
+    }
+
     if cli.stats {
-        let stats = SystemStats::new();
-        println!("{}", serde_json::to_string_pretty(&stats)?);
+        let report = rusted_jetsons::StatsReport::collect();
+        let json = if cli.camel_case {
+            serde_json::to_string_pretty(&rusted_jetsons::CamelCase(&report))?
+        } else {
+            serde_json::to_string_pretty(&report)?
+        };
+        println!("{}", json);
+        return Ok(());
+    }
+
+    if let Some(path) = cli.snapshot {
+        if let Err(e) = write_snapshot(&path) {
+            eprintln!("Error writing snapshot to {}: {}", path.display(), e);
+            std::process::exit(1);
+        }
         return Ok(());
     }
 
+    if cli.gpu_only {
+        return run_gpu_watch(cli.interval, cli.count, use_color(cli.no_color));
+    }
+
+    if let (Some(threshold), Some(cmd)) = (cli.alert_temp, cli.alert_cmd.as_deref()) {
+        return run_temp_alert(threshold, cmd, cli.alert_duration, cli.interval, cli.count);
+    }
+
     if let Some(speed) = cli.fan {
-        match rusted_jetsons::FanStats::set_speed(speed) {
-            Ok(()) => {
-                println!("Fan speed set to {}%", speed);
-            }
+        let result = match cli.fan_index {
+            Some(index) => rusted_jetsons::FanStats::set_speed_for(index, speed).map(|_| ()),
+            None => rusted_jetsons::FanStats::set_speed(speed).map(|_| ()),
+        };
+        match result {
+            Ok(()) => match cli.fan_index {
+                Some(index) => println!("Fan {} speed set to {}%", index, speed),
+                None => println!("Fan speed set to {}%", speed),
+            },
             Err(e) => {
                 eprintln!("Error setting fan speed: {}", e);
                 eprintln!("Note: This operation requires root/sudo privileges.");
@@ -131,6 +700,32 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
+    if let Some(curve) = cli.fan_curve {
+        let curve = match curve {
+            Some(curve) => Some(curve),
+            None => rusted_jetsons::Config::load()?.fan_curve,
+        };
+        let Some(curve) = curve else {
+            eprintln!("Error: --fan-curve needs a curve or fan_curve in the config file");
+            std::process::exit(1);
+        };
+        return run_fan_curve(curve, cli.interval, cli.count);
+    }
+
+    if cli.fan_auto {
+        match rusted_jetsons::FanStats::set_auto() {
+            Ok(_) => {
+                println!("Fan control set to automatic");
+            }
+            Err(e) => {
+                eprintln!("Error restoring automatic fan control: {}", e);
+                eprintln!("Note: This operation requires root/sudo privileges.");
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
     if let Some(model_id) = cli.nvpmodel {
         match rusted_jetsons::NVPModelStats::set_model(model_id) {
             Ok(()) => {
@@ -145,6 +740,34 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
+    if let Some(governor) = cli.governor {
+        match rusted_jetsons::CpuStats::set_all_governors(&governor) {
+            Ok(()) => {
+                println!("CPU governor set to {}", governor);
+            }
+            Err(e) => {
+                eprintln!("Error setting CPU governor: {}", e);
+                eprintln!("Note: This operation requires root/sudo privileges.");
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    if let Some(governor) = cli.gpu_governor {
+        match rusted_jetsons::GpuStats::set_governor(&governor) {
+            Ok(()) => {
+                println!("GPU governor set to {}", governor);
+            }
+            Err(e) => {
+                eprintln!("Error setting GPU governor: {}", e);
+                eprintln!("Note: This operation requires root/sudo privileges.");
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
     if cli.jetson_clocks {
         match rusted_jetsons::JetsonClocksStats::toggle() {
             Ok(()) => {
@@ -159,6 +782,49 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
+    if let Some(path) = &cli.clocks_store {
+        match rusted_jetsons::JetsonClocksStats::store(path) {
+            Ok(()) => println!("Clock settings stored in {}", path.display()),
+            Err(e) => {
+                eprintln!("Error storing jetson_clocks settings: {}", e);
+                eprintln!("Note: This operation requires root/sudo privileges.");
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    if let Some(path) = &cli.clocks_restore {
+        match rusted_jetsons::JetsonClocksStats::restore(path) {
+            Ok(()) => println!("Clock settings restored from {}", path.display()),
+            Err(e) => {
+                eprintln!("Error restoring jetson_clocks settings: {}", e);
+                eprintln!("Note: This operation requires root/sudo privileges.");
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    if let Some(size_mb) = cli.swap_create {
+        match rusted_jetsons::modules::swap::create_zram(size_mb) {
+            Ok(device) => {
+                println!("Enabled {} MB zram swap on {}", size_mb, device.display());
+            }
+            Err(e) => {
+                eprintln!("Error creating zram swap: {:#}", e);
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    if cli.swaps {
+        let devices = rusted_jetsons::swap_devices();
+        println!("{}", serde_json::to_string_pretty(&devices)?);
+        return Ok(());
+    }
+
     if let Some(export_type) = cli.export {
         if export_type == "otlp" {
             let endpoint = cli
@@ -168,10 +834,10 @@ fn main() -> Result<()> {
 
             #[cfg(feature = "telemetry")]
             {
-                let stats = SystemStats::new();
                 let exporter = rusted_jetsons::TelemetryExporter::new(endpoint);
 
                 tokio::runtime::Runtime::new()?.block_on(async {
+                    let stats = rusted_jetsons::JetsonStats::collect_async().await;
                     match exporter.export(&stats).await {
                         Ok(()) => println!("Successfully exported to OTLP endpoint"),
                         Err(e) => {
@@ -188,6 +854,58 @@ fn main() -> Result<()> {
                 eprintln!("Rebuild with: cargo build --features telemetry");
                 std::process::exit(1);
             }
+        } else if export_type == "influxdb" {
+            let endpoint = cli
+                .endpoint
+                .unwrap_or_else(|| "http://localhost:8086".to_string());
+            println!("Exporting to InfluxDB endpoint: {}", endpoint);
+
+            #[cfg(feature = "influxdb")]
+            {
+                let exporter = rusted_jetsons::InfluxExporter::new(endpoint, cli.token);
+
+                tokio::runtime::Runtime::new()?.block_on(async {
+                    let stats = rusted_jetsons::JetsonStats::collect_async().await;
+                    match exporter.export(&stats).await {
+                        Ok(()) => println!("Successfully exported to InfluxDB"),
+                        Err(e) => {
+                            eprintln!("Error exporting to InfluxDB: {:#}", e);
+                            std::process::exit(1);
+                        }
+                    }
+                });
+            }
+
+            #[cfg(not(feature = "influxdb"))]
+            {
+                let _ = cli.token;
+                eprintln!("Error: InfluxDB export requires 'influxdb' feature to be enabled.");
+                eprintln!("Rebuild with: cargo build --features influxdb");
+                std::process::exit(1);
+            }
+        } else if export_type == "prometheus" {
+            let addr = cli.endpoint.unwrap_or_else(|| "0.0.0.0:9101".to_string());
+
+            #[cfg(feature = "prometheus")]
+            {
+                let addr: std::net::SocketAddr = addr
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("Invalid listen address '{}': {}", addr, e))?;
+                println!("Serving Prometheus metrics at http://{}/metrics", addr);
+
+                let exporter = rusted_jetsons::PrometheusExporter::new();
+                tokio::runtime::Runtime::new()?.block_on(exporter.serve(addr))?;
+            }
+
+            #[cfg(not(feature = "prometheus"))]
+            {
+                eprintln!(
+                    "Error: Prometheus endpoint on {} requires 'prometheus' feature to be enabled.",
+                    addr
+                );
+                eprintln!("Rebuild with: cargo build --features prometheus");
+                std::process::exit(1);
+            }
         }
         return Ok(());
     }
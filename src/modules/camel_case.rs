@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! camelCase serialization module
+//!
+//! Web dashboards usually expect camelCase keys (`ramUsed`, `swapTotal`).
+//! [`CamelCase`] wraps any serializable value and renames its snake_case
+//! field names on the way out, leaving the Rust structs and their derives
+//! untouched.
+
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+
+/// Serialize the wrapped value with camelCase field names
+#[derive(Debug, Clone, Copy)]
+pub struct CamelCase<'a, T: ?Sized>(pub &'a T);
+
+impl<T: Serialize + ?Sized> Serialize for CamelCase<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = serde_json::to_value(self.0).map_err(serde::ser::Error::custom)?;
+        camel_case_keys(value).serialize(serializer)
+    }
+}
+
+/// Recursively rename snake_case object keys to camelCase
+fn camel_case_keys(value: Value) -> Value {
+    match value {
+        Value::Object(map) => map
+            .into_iter()
+            .map(|(key, value)| (to_camel_case(&key), camel_case_keys(value)))
+            .collect(),
+        Value::Array(items) => items.into_iter().map(camel_case_keys).collect(),
+        other => other,
+    }
+}
+
+/// Convert a snake_case identifier to camelCase
+///
+/// Keys that are not snake_case identifiers, such as power rail names like
+/// `VDD_IN`, are returned unchanged.
+pub fn to_camel_case(key: &str) -> String {
+    let is_snake_case = key
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !is_snake_case {
+        return key.to_string();
+    }
+
+    let mut result = String::with_capacity(key.len());
+    let mut upper_next = false;
+    for c in key.chars() {
+        if c == '_' {
+            upper_next = !result.is_empty();
+        } else if upper_next {
+            result.push(c.to_ascii_uppercase());
+            upper_next = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::memory::MemoryStats;
+
+    #[test]
+    fn test_to_camel_case() {
+        assert_eq!(to_camel_case("ram_used"), "ramUsed");
+        assert_eq!(to_camel_case("avg10"), "avg10");
+        assert_eq!(to_camel_case("trip_point_0_temp"), "tripPoint0Temp");
+        assert_eq!(to_camel_case("_private"), "private");
+        assert_eq!(to_camel_case("VDD_IN"), "VDD_IN");
+        assert_eq!(to_camel_case("cpu-thermal"), "cpu-thermal");
+    }
+
+    #[test]
+    fn test_memory_stats_camel_case() {
+        let stats = MemoryStats {
+            ram_used: 1024,
+            swap_total: 2048,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(CamelCase(&stats)).unwrap();
+        assert_eq!(json["ramUsed"], 1024);
+        assert_eq!(json["swapTotal"], 2048);
+        assert!(json.get("ram_used").is_none());
+    }
+
+    #[test]
+    fn test_nested_values_camel_case() {
+        let value = serde_json::json!({
+            "thermal_zones": [{ "current_temp": 45.0 }],
+            "rails": { "VDD_IN": { "power_avg": 5 } },
+        });
+
+        let json = serde_json::to_value(CamelCase(&value)).unwrap();
+        assert_eq!(json["thermalZones"][0]["currentTemp"], 45.0);
+        assert_eq!(json["rails"]["VDD_IN"]["powerAvg"], 5);
+    }
+}
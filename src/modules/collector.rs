@@ -0,0 +1,212 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Pluggable collector module
+//!
+//! Every built-in monitor (`CpuMonitor`, `GpuMonitor`, ...) implements
+//! [`Collector`]. Applications can register their own collectors in a
+//! [`CollectorRegistry`] and have them show up next to the built-in ones
+//! in the aggregated statistics.
+
+use serde_json::{Map, Value};
+
+use crate::modules::{
+    cpu::CpuMonitor, disk::DiskMonitor, fan::FanMonitor, gpu::GpuMonitor, memory::MemoryMonitor,
+    network::NetMonitor, power::PowerMonitor, pressure::PressureMonitor,
+    temperature::TemperatureMonitor,
+};
+
+/// A source of statistics sampled on every monitoring tick
+pub trait Collector {
+    /// Statistics produced by a single sample
+    type Stats;
+
+    /// Take a new sample
+    fn collect(&mut self) -> Self::Stats;
+}
+
+/// Object-safe view of a collector whose statistics serialize to JSON
+trait DynCollector: Send {
+    fn collect_value(&mut self) -> Value;
+}
+
+impl<C> DynCollector for C
+where
+    C: Collector + Send,
+    C::Stats: serde::Serialize,
+{
+    fn collect_value(&mut self) -> Value {
+        serde_json::to_value(self.collect()).unwrap_or(Value::Null)
+    }
+}
+
+/// Named set of collectors aggregated into a single JSON object
+#[derive(Default)]
+pub struct CollectorRegistry {
+    collectors: Vec<(String, Box<dyn DynCollector>)>,
+}
+
+impl CollectorRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a registry with all built-in collectors
+    ///
+    /// Registered under the keys used by the `--stats` output:
+    /// `cpu`, `gpu`, `memory`, `temperature`, `fan`, `power`, `pressure`,
+    /// `disk` and `network`.
+    pub fn with_defaults() -> Self {
+        let mut registry = Self::new();
+        registry.register("cpu", CpuMonitor::new());
+        registry.register("gpu", GpuMonitor::new());
+        registry.register("memory", MemoryMonitor::new());
+        registry.register("temperature", TemperatureMonitor::new());
+        registry.register("fan", FanMonitor::new());
+        registry.register("power", PowerMonitor::new());
+        registry.register("pressure", PressureMonitor::new());
+        registry.register("disk", DiskMonitor::new());
+        registry.register("network", NetMonitor::new());
+        registry
+    }
+
+    /// Register a collector under `name`
+    ///
+    /// A collector already registered under the same name is replaced,
+    /// which allows overriding a built-in collector.
+    pub fn register<C>(&mut self, name: impl Into<String>, collector: C)
+    where
+        C: Collector + Send + 'static,
+        C::Stats: serde::Serialize,
+    {
+        let name = name.into();
+        let collector: Box<dyn DynCollector> = Box::new(collector);
+
+        match self.collectors.iter_mut().find(|(n, _)| *n == name) {
+            Some(entry) => entry.1 = collector,
+            None => self.collectors.push((name, collector)),
+        }
+    }
+
+    /// Names of the registered collectors, in registration order
+    pub fn names(&self) -> Vec<&str> {
+        self.collectors.iter().map(|(n, _)| n.as_str()).collect()
+    }
+
+    /// Number of registered collectors
+    pub fn len(&self) -> usize {
+        self.collectors.len()
+    }
+
+    /// Whether no collector is registered
+    pub fn is_empty(&self) -> bool {
+        self.collectors.is_empty()
+    }
+
+    /// Sample every collector and aggregate the results by name
+    pub fn collect_all(&mut self) -> Map<String, Value> {
+        self.collectors
+            .iter_mut()
+            .map(|(name, collector)| (name.clone(), collector.collect_value()))
+            .collect()
+    }
+}
+
+impl std::fmt::Debug for CollectorRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CollectorRegistry")
+            .field("collectors", &self.names())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(serde::Serialize)]
+    struct DummyStats {
+        samples: u32,
+        label: &'static str,
+    }
+
+    #[derive(Default)]
+    struct DummyCollector {
+        samples: u32,
+    }
+
+    impl Collector for DummyCollector {
+        type Stats = DummyStats;
+
+        fn collect(&mut self) -> Self::Stats {
+            self.samples += 1;
+            DummyStats {
+                samples: self.samples,
+                label: "dummy",
+            }
+        }
+    }
+
+    #[test]
+    fn test_registry_empty() {
+        let mut registry = CollectorRegistry::new();
+        assert!(registry.is_empty());
+        assert_eq!(registry.len(), 0);
+        assert!(registry.collect_all().is_empty());
+    }
+
+    #[test]
+    fn test_registry_defaults() {
+        let registry = CollectorRegistry::with_defaults();
+        assert_eq!(
+            registry.names(),
+            vec![
+                "cpu",
+                "gpu",
+                "memory",
+                "temperature",
+                "fan",
+                "power",
+                "pressure",
+                "disk",
+                "network"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dummy_collector_in_aggregate() {
+        let mut registry = CollectorRegistry::with_defaults();
+        registry.register("dummy", DummyCollector::default());
+
+        let aggregate = registry.collect_all();
+        assert_eq!(aggregate.len(), 10);
+        assert!(aggregate.contains_key("cpu"), "Built-in collectors kept");
+        assert_eq!(aggregate["dummy"]["samples"], 1);
+        assert_eq!(aggregate["dummy"]["label"], "dummy");
+
+        // Collectors keep their state between samples
+        let aggregate = registry.collect_all();
+        assert_eq!(aggregate["dummy"]["samples"], 2);
+    }
+
+    #[test]
+    fn test_register_replaces_same_name() {
+        let mut registry = CollectorRegistry::with_defaults();
+        registry.register("gpu", DummyCollector::default());
+
+        assert_eq!(registry.len(), 9);
+        let aggregate = registry.collect_all();
+        assert_eq!(aggregate["gpu"]["label"], "dummy");
+    }
+
+    #[test]
+    fn test_registry_debug_lists_names() {
+        let mut registry = CollectorRegistry::new();
+        registry.register("dummy", DummyCollector::default());
+
+        let debug = format!("{:?}", registry);
+        assert!(debug.contains("dummy"));
+    }
+}
@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Configuration file module
+//!
+//! Preferences persisted in `$XDG_CONFIG_HOME/rusted-jetsons/config.toml`
+//! (`~/.config/rusted-jetsons/config.toml` when the variable is unset).
+//! Command-line flags take precedence over the file.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Deserializer};
+
+use super::fan::FanCurve;
+use super::health::HealthThresholds;
+use super::temperature::TemperatureUnit;
+use crate::Error;
+
+/// Default TUI refresh interval in milliseconds
+pub const DEFAULT_TICK_MS: u64 = 250;
+
+/// User preferences, every field falling back to its default when missing
+///
+/// ```toml
+/// default_screen = "temperature"
+/// tick_ms = 500
+/// temp_unit = "fahrenheit"
+/// warn_temp = 75.0
+/// crit_temp = 90.0
+/// fan_curve = "40:20,60:50,80:100"
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Name of the TUI screen shown at startup, e.g. `gpu`
+    pub default_screen: String,
+    /// TUI refresh interval in milliseconds
+    pub tick_ms: u64,
+    /// Temperature unit of the TUI, `celsius` or `fahrenheit`
+    pub temp_unit: TemperatureUnit,
+    /// Health check warning temperature in °C
+    pub warn_temp: f32,
+    /// Health check critical temperature in °C
+    pub crit_temp: f32,
+    /// Curve used by `rjtop-cli --fan-curve` without a value
+    #[serde(deserialize_with = "fan_curve_from_str")]
+    pub fan_curve: Option<FanCurve>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        let thresholds = HealthThresholds::default();
+        Self {
+            default_screen: "all".to_string(),
+            tick_ms: DEFAULT_TICK_MS,
+            temp_unit: TemperatureUnit::default(),
+            warn_temp: thresholds.warn_temp,
+            crit_temp: thresholds.crit_temp,
+            fan_curve: None,
+        }
+    }
+}
+
+impl Config {
+    /// Location of the configuration file, `None` when no home is known
+    pub fn path() -> Option<PathBuf> {
+        let base = std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+        Some(base.join("rusted-jetsons").join("config.toml"))
+    }
+
+    /// Load the configuration file, using defaults when it does not exist
+    pub fn load() -> crate::Result<Self> {
+        match Self::path() {
+            Some(path) => Self::load_from(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Load a configuration file, using defaults when it does not exist
+    pub fn load_from(path: &Path) -> crate::Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(Error::sysfs_read(path, e)),
+        };
+        toml::from_str(&text).map_err(|e| Error::parse(path, e))
+    }
+}
+
+impl std::str::FromStr for Config {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        toml::from_str(s).map_err(|e| Error::InvalidArgument(e.to_string()))
+    }
+}
+
+/// Parse the fan curve from the same `40:20,60:50` syntax as the CLI
+fn fan_curve_from_str<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<FanCurve>, D::Error> {
+    let curve = String::deserialize(deserializer)?;
+    curve.parse().map(Some).map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::sysfs::MockDir;
+
+    #[test]
+    fn test_parse_full_config() {
+        let config: Config = r#"
+            default_screen = "temperature"
+            tick_ms = 500
+            temp_unit = "fahrenheit"
+            warn_temp = 75.0
+            crit_temp = 90.0
+            fan_curve = "40:20,60:50,80:100"
+        "#
+        .parse()
+        .unwrap();
+
+        assert_eq!(config.default_screen, "temperature");
+        assert_eq!(config.tick_ms, 500);
+        assert_eq!(config.temp_unit, TemperatureUnit::Fahrenheit);
+        assert_eq!(config.warn_temp, 75.0);
+        assert_eq!(config.crit_temp, 90.0);
+        assert_eq!(
+            config.fan_curve.unwrap().points(),
+            &[(40.0, 20), (60.0, 50), (80.0, 100)]
+        );
+    }
+
+    #[test]
+    fn test_missing_fields_use_defaults() {
+        let config: Config = "tick_ms = 1000".parse().unwrap();
+        assert_eq!(
+            config,
+            Config {
+                tick_ms: 1000,
+                ..Config::default()
+            }
+        );
+        assert_eq!("".parse::<Config>().unwrap(), Config::default());
+    }
+
+    #[test]
+    fn test_invalid_config_is_rejected() {
+        assert!("tick_ms = \"fast\"".parse::<Config>().is_err());
+        assert!("temp_unit = \"kelvin\"".parse::<Config>().is_err());
+        assert!("fan_curve = \"40:200\"".parse::<Config>().is_err());
+        assert!("refresh = 100".parse::<Config>().is_err());
+    }
+
+    #[test]
+    fn test_load_from_file() {
+        let dir = MockDir::new("config");
+        let path = dir.join("config.toml");
+
+        assert_eq!(Config::load_from(&path).unwrap(), Config::default());
+
+        fs::write(&path, "warn_temp = 70.0\n").unwrap();
+        assert_eq!(Config::load_from(&path).unwrap().warn_temp, 70.0);
+
+        fs::write(&path, "warn_temp = \n").unwrap();
+        let err = Config::load_from(&path).unwrap_err();
+        assert!(format!("{:#}", err).contains("config.toml"));
+    }
+}
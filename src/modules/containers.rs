@@ -0,0 +1,235 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Container detection module
+//!
+//! Detects whether rjtop itself runs inside a container and lists the
+//! containers of a local Docker daemon, flagging those with GPU access.
+//! Containers are queried over the Docker Engine API on its Unix socket;
+//! when no runtime is installed or the socket is not reachable (usually
+//! for lack of permissions) the list is simply empty.
+
+use std::fs;
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::Error;
+
+const DOCKER_SOCKET: &str = "/var/run/docker.sock";
+const CONTAINERD_SOCKET: &str = "/run/containerd/containerd.sock";
+const SOCKET_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Container runtime found on the host
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ContainerRuntime {
+    Docker,
+    Containerd,
+}
+
+/// Running container
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ContainerInfo {
+    pub id: String,
+    pub name: String,
+    pub image: String,
+    /// Whether the container was started with GPU access
+    pub gpu: bool,
+}
+
+/// Detect the container runtime installed on the host
+///
+/// Docker is preferred since only its API is used to list containers.
+pub fn detect_runtime() -> Option<ContainerRuntime> {
+    if Path::new(DOCKER_SOCKET).exists() {
+        Some(ContainerRuntime::Docker)
+    } else if Path::new(CONTAINERD_SOCKET).exists() {
+        Some(ContainerRuntime::Containerd)
+    } else {
+        None
+    }
+}
+
+/// List running containers of the local Docker daemon
+///
+/// Returns an empty list when no runtime is present or the Docker socket
+/// cannot be queried.
+pub fn detect_containers() -> Vec<ContainerInfo> {
+    if detect_runtime() != Some(ContainerRuntime::Docker) {
+        return Vec::new();
+    }
+
+    list_docker_containers(Path::new(DOCKER_SOCKET)).unwrap_or_default()
+}
+
+/// Check whether the current process runs inside a container
+pub fn is_containerized() -> bool {
+    Path::new("/.dockerenv").exists()
+        || fs::read_to_string("/proc/1/cgroup").is_ok_and(|content| parse_cgroup(&content))
+}
+
+/// Check a `/proc/<pid>/cgroup` file for container cgroup paths
+///
+/// On the host, init lives in the root cgroup (`0::/` on cgroup v2,
+/// `/init.scope` on systemd hosts); inside a container its paths name the
+/// runtime instead.
+pub fn parse_cgroup(content: &str) -> bool {
+    const MARKERS: [&str; 6] = ["docker", "containerd", "kubepods", "libpod", "lxc", "crio"];
+
+    content
+        .lines()
+        .filter_map(|line| line.splitn(3, ':').nth(2))
+        .any(|path| MARKERS.iter().any(|marker| path.contains(marker)))
+}
+
+fn list_docker_containers(socket: &Path) -> crate::Result<Vec<ContainerInfo>> {
+    let list = docker_get(socket, "/containers/json")?;
+    let mut containers = parse_container_list(&list);
+
+    for container in &mut containers {
+        if let Ok(inspect) = docker_get(socket, &format!("/containers/{}/json", container.id)) {
+            container.gpu = uses_gpu(&inspect);
+        }
+    }
+
+    Ok(containers)
+}
+
+/// Send a GET request to the Docker Engine API and parse the JSON body
+fn docker_get(socket: &Path, endpoint: &str) -> crate::Result<Value> {
+    let mut stream = UnixStream::connect(socket)?;
+    stream.set_read_timeout(Some(SOCKET_TIMEOUT))?;
+    stream.set_write_timeout(Some(SOCKET_TIMEOUT))?;
+
+    // HTTP/1.0 makes the daemon close the connection after a plain body
+    write!(stream, "GET {} HTTP/1.0\r\nHost: docker\r\n\r\n", endpoint)?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or_else(|| Error::parse(socket, "malformed Docker API response"))?;
+    if !head.starts_with("HTTP/1.0 200") && !head.starts_with("HTTP/1.1 200") {
+        return Err(Error::parse(
+            socket,
+            format!(
+                "Docker API request failed: {}",
+                head.lines().next().unwrap_or_default()
+            ),
+        ));
+    }
+
+    serde_json::from_str(body).map_err(|e| Error::parse(socket, e))
+}
+
+/// Parse the response of `GET /containers/json`
+fn parse_container_list(list: &Value) -> Vec<ContainerInfo> {
+    list.as_array()
+        .map(|containers| {
+            containers
+                .iter()
+                .map(|c| ContainerInfo {
+                    id: c["Id"].as_str().unwrap_or_default().to_string(),
+                    name: c["Names"][0]
+                        .as_str()
+                        .unwrap_or_default()
+                        .trim_start_matches('/')
+                        .to_string(),
+                    image: c["Image"].as_str().unwrap_or_default().to_string(),
+                    gpu: false,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Check the response of `GET /containers/<id>/json` for GPU access
+///
+/// Jetson containers get the GPU through `--runtime nvidia`; `--gpus`
+/// shows up as a device request for the `gpu` capability instead.
+fn uses_gpu(inspect: &Value) -> bool {
+    let host_config = &inspect["HostConfig"];
+    if host_config["Runtime"].as_str() == Some("nvidia") {
+        return true;
+    }
+
+    host_config["DeviceRequests"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .any(|request| {
+            request["Driver"].as_str() == Some("nvidia")
+                || request["Capabilities"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .flat_map(|caps| caps.as_array().into_iter().flatten())
+                    .any(|cap| cap.as_str() == Some("gpu"))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::sysfs::MockDir;
+
+    #[test]
+    fn test_parse_cgroup_docker_v1() {
+        let content = "12:devices:/docker/3f1c2a9e8b7d\n\
+                       11:memory:/docker/3f1c2a9e8b7d\n\
+                       1:name=systemd:/docker/3f1c2a9e8b7d\n";
+        assert!(parse_cgroup(content));
+    }
+
+    #[test]
+    fn test_parse_cgroup_kubernetes() {
+        let content = "0::/kubepods/besteffort/pod1a2b/cri-containerd-5d4e\n";
+        assert!(parse_cgroup(content));
+    }
+
+    #[test]
+    fn test_parse_cgroup_host() {
+        assert!(!parse_cgroup("0::/\n"));
+        assert!(!parse_cgroup("0::/init.scope\n"));
+        assert!(!parse_cgroup("12:devices:/\n1:name=systemd:/init.scope\n"));
+        assert!(!parse_cgroup(""));
+    }
+
+    #[test]
+    fn test_parse_container_list() {
+        let list = serde_json::json!([
+            {"Id": "3f1c2a9e8b7d", "Names": ["/inference"], "Image": "nvcr.io/nvidia/l4t-ml:r36.2.0"},
+            {"Id": "9a8b7c6d5e4f", "Names": ["/mqtt"], "Image": "eclipse-mosquitto"},
+        ]);
+
+        let containers = parse_container_list(&list);
+        assert_eq!(containers.len(), 2);
+        assert_eq!(containers[0].name, "inference");
+        assert_eq!(containers[0].image, "nvcr.io/nvidia/l4t-ml:r36.2.0");
+        assert_eq!(containers[1].id, "9a8b7c6d5e4f");
+        assert!(parse_container_list(&serde_json::json!({"message": "error"})).is_empty());
+    }
+
+    #[test]
+    fn test_uses_gpu() {
+        let nvidia_runtime = serde_json::json!({"HostConfig": {"Runtime": "nvidia"}});
+        let gpus_flag = serde_json::json!({"HostConfig": {
+            "Runtime": "runc",
+            "DeviceRequests": [{"Driver": "", "Capabilities": [["gpu"]]}]
+        }});
+        let no_gpu = serde_json::json!({"HostConfig": {"Runtime": "runc", "DeviceRequests": null}});
+
+        assert!(uses_gpu(&nvidia_runtime));
+        assert!(uses_gpu(&gpus_flag));
+        assert!(!uses_gpu(&no_gpu));
+    }
+
+    #[test]
+    fn test_list_docker_containers_without_socket() {
+        let dir = MockDir::new("docker");
+        assert!(list_docker_containers(&dir.join("docker.sock")).is_err());
+    }
+}
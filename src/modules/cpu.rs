@@ -6,35 +6,113 @@
 //! Provides CPU statistics, core information, and performance metrics
 //! with both synchronous and asynchronous I/O support.
 
+use crate::modules::collector::Collector;
+use crate::modules::history::{self, History};
+use crate::modules::sysfs::{self, Blocking, Reader, Tokio};
+use crate::Error;
+use std::ffi::OsStr;
 use std::fs;
-use std::io::BufRead;
-use std::io::BufReader;
 use std::path::Path;
 
 use tokio::fs as tokio_fs;
 
+const CPU_SYSFS: &str = "/sys/devices/system/cpu";
+const THERMAL_SYSFS: &str = "/sys/class/thermal";
+const PROC_STAT: &str = "/proc/stat";
+const PROC_CPUINFO: &str = "/proc/cpuinfo";
+
 /// CPU statistics
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
 pub struct CpuStats {
     pub usage: f32,
+    /// Highest current frequency among the online cores in kHz
+    ///
+    /// The maximum is used rather than the average because on big.LITTLE
+    /// layouts the fast cluster is what limits performance.
     pub frequency: u32,
     pub cores: Vec<CpuCore>,
+    /// Average user time across cores in percent (includes nice)
+    #[serde(default)]
+    pub user: f32,
+    /// Average system time across cores in percent (includes irq/softirq)
+    #[serde(default)]
+    pub system: f32,
+    /// Average idle time across cores in percent
+    #[serde(default)]
+    pub idle: f32,
+    /// Average I/O wait time across cores in percent
+    #[serde(default)]
+    pub iowait: f32,
+    /// Cores grouped by cluster, sorted by cluster id
+    #[serde(default)]
+    pub clusters: Vec<CpuCluster>,
 }
 
 /// Per-core CPU statistics
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+///
+/// The `user`, `system`, `idle` and `iowait` percentages are only filled
+/// by [`CpuMonitor`] and sum to 100 for an active core.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct CpuCore {
     pub index: usize,
     pub usage: f32,
     pub frequency: u32,
     pub governor: String,
+    /// User time in percent (includes nice)
+    #[serde(default)]
+    pub user: f32,
+    /// System time in percent (includes irq/softirq)
+    #[serde(default)]
+    pub system: f32,
+    /// Idle time in percent
+    #[serde(default)]
+    pub idle: f32,
+    /// I/O wait time in percent
+    #[serde(default)]
+    pub iowait: f32,
+    /// Whether the core is online; offline cores report zero usage and
+    /// frequency but keep their index
+    #[serde(default)]
+    pub online: bool,
+    /// Cluster the core belongs to
+    #[serde(default)]
+    pub cluster: usize,
+    /// Minimum scaling frequency allowed by the cpufreq policy in kHz
+    #[serde(default)]
+    pub min_frequency: u32,
+    /// Maximum scaling frequency allowed by the cpufreq policy in kHz
+    #[serde(default)]
+    pub max_frequency: u32,
+    /// Core temperature in °C, on boards exposing per-core thermal zones
+    #[serde(default)]
+    pub temperature: Option<f32>,
+}
+
+/// A CPU cluster with its cores, frequency range and averages
+///
+/// Frequencies are in kHz, like [`CpuCore::frequency`]. Averages only cover
+/// the online cores of the cluster.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CpuCluster {
+    pub id: usize,
+    /// Indices of the cores in the cluster
+    pub core_indices: Vec<usize>,
+    pub min: u32,
+    pub current: u32,
+    pub max: u32,
+    /// Average usage in percent
+    #[serde(default)]
+    pub avg_usage: f32,
+    /// Average current frequency in kHz
+    #[serde(default)]
+    pub avg_frequency: u32,
 }
 
 impl CpuStats {
     /// Get current CPU statistics synchronously
     ///
     /// Returns a `CpuStats` struct containing:
-    /// - Overall CPU usage (average of all cores)
+    /// - Overall CPU usage (average of the online cores)
     /// - List of individual cores with their usage, frequency, and governor
     pub fn get() -> Self {
         let mut stats = CpuStats::default();
@@ -43,11 +121,9 @@ impl CpuStats {
             stats.cores = cores;
         }
 
-        stats.usage = if !stats.cores.is_empty() {
-            stats.cores.iter().map(|c| c.usage).sum::<f32>() / stats.cores.len() as f32
-        } else {
-            0.0
-        };
+        stats.usage = online_average(&stats.cores, |c| c.usage);
+        stats.frequency = max_core_frequency(&stats.cores);
+        stats.clusters = cluster_frequencies(&stats.cores);
 
         stats
     }
@@ -55,7 +131,7 @@ impl CpuStats {
     /// Get current CPU statistics asynchronously
     ///
     /// Returns a `CpuStats` struct containing:
-    /// - Overall CPU usage (average of all cores)
+    /// - Overall CPU usage (average of the online cores)
     /// - List of individual cores with their usage, frequency, and governor
     ///
     /// This is the async version of `get()` using tokio for I/O.
@@ -66,14 +142,154 @@ impl CpuStats {
             stats.cores = cores;
         }
 
-        stats.usage = if !stats.cores.is_empty() {
-            stats.cores.iter().map(|c| c.usage).sum::<f32>() / stats.cores.len() as f32
-        } else {
-            0.0
-        };
+        stats.usage = online_average(&stats.cores, |c| c.usage);
+        stats.frequency = max_core_frequency(&stats.cores);
+        stats.clusters = cluster_frequencies(&stats.cores);
 
         stats
     }
+
+    /// Per-cluster frequency range of the cores
+    pub fn cluster_frequencies(&self) -> Vec<CpuCluster> {
+        cluster_frequencies(&self.cores)
+    }
+
+    /// Set the frequency governor of a core (requires root)
+    ///
+    /// Fails when the core has no cpufreq policy, e.g. while offline, or
+    /// does not list `governor` in `scaling_available_governors`.
+    pub fn set_governor(core_idx: usize, governor: &str) -> crate::Result<()> {
+        set_governor_in(
+            &Path::new(CPU_SYSFS).join(format!("cpu{}", core_idx)),
+            governor,
+        )
+    }
+
+    /// Set the frequency governor of every core with a cpufreq policy (requires root)
+    ///
+    /// Every core is validated before any is changed.
+    pub fn set_all_governors(governor: &str) -> crate::Result<()> {
+        set_all_governors_in(Path::new(CPU_SYSFS), governor)
+    }
+}
+
+fn set_governor_in(cpu_dir: &Path, governor: &str) -> crate::Result<()> {
+    check_governor_in(cpu_dir, governor)?;
+    write_governor(cpu_dir, governor)
+}
+
+fn set_all_governors_in(cpu_root: &Path, governor: &str) -> crate::Result<()> {
+    let mut cpu_dirs: Vec<(usize, std::path::PathBuf)> = fs::read_dir(cpu_root)
+        .map_err(|e| Error::sysfs_read(cpu_root, e))?
+        .flatten()
+        .filter_map(|entry| Some((cpu_dir_index(&entry.file_name())?, entry.path())))
+        .filter(|(_, path)| path.join("cpufreq").exists())
+        .collect();
+    cpu_dirs.sort_unstable();
+
+    if cpu_dirs.is_empty() {
+        return Err(Error::HardwareNotFound(
+            "No CPU core with a cpufreq policy found".to_string(),
+        ));
+    }
+
+    for (_, cpu_dir) in &cpu_dirs {
+        check_governor_in(cpu_dir, governor)?;
+    }
+    for (_, cpu_dir) in &cpu_dirs {
+        write_governor(cpu_dir, governor)?;
+    }
+
+    Ok(())
+}
+
+/// Check that `governor` is listed in a core's `scaling_available_governors`
+fn check_governor_in(cpu_dir: &Path, governor: &str) -> crate::Result<()> {
+    let core = cpu_dir.file_name().unwrap_or_default().to_string_lossy();
+    let available = sysfs::read_string(cpu_dir.join("cpufreq/scaling_available_governors"))
+        .ok_or_else(|| Error::HardwareNotFound(format!("{} has no cpufreq policy", core)))?;
+
+    validate_governor(&available, governor).map_err(|e| match e {
+        Error::InvalidArgument(detail) => Error::InvalidArgument(format!("{}: {}", core, detail)),
+        e => e,
+    })
+}
+
+/// Check `governor` against a whitespace-separated list of available governors
+pub(crate) fn validate_governor(available: &str, governor: &str) -> crate::Result<()> {
+    if available.split_whitespace().any(|g| g == governor) {
+        return Ok(());
+    }
+
+    let available: Vec<&str> = available.split_whitespace().collect();
+    Err(Error::InvalidArgument(format!(
+        "governor '{}' is not available (available: {})",
+        governor,
+        available.join(", ")
+    )))
+}
+
+fn write_governor(cpu_dir: &Path, governor: &str) -> crate::Result<()> {
+    sysfs::write(cpu_dir.join("cpufreq/scaling_governor"), governor)
+}
+
+/// Average of `value` over the online cores, 0 when none is online
+fn online_average(cores: &[CpuCore], value: impl Fn(&CpuCore) -> f32) -> f32 {
+    let online: Vec<f32> = cores.iter().filter(|c| c.online).map(value).collect();
+    if online.is_empty() {
+        0.0
+    } else {
+        online.iter().sum::<f32>() / online.len() as f32
+    }
+}
+
+/// Highest current frequency of `cores` in kHz, 0 without cores
+pub fn max_core_frequency(cores: &[CpuCore]) -> u32 {
+    cores.iter().map(|c| c.frequency).max().unwrap_or(0)
+}
+
+/// Group cores by cluster and derive each cluster's frequency range
+///
+/// Cores of a cluster share a clock, so the range spans the limits reported
+/// by its member cores and the current frequency is the highest one among
+/// them (offline cores report 0). The cluster id comes from
+/// `topology/cluster_id`, or `topology/physical_package_id` on kernels
+/// without cluster support. Clusters are sorted by id.
+pub fn cluster_frequencies(cores: &[CpuCore]) -> Vec<CpuCluster> {
+    let mut clusters: Vec<CpuCluster> = Vec::new();
+
+    for core in cores {
+        let cluster = match clusters.iter_mut().find(|c| c.id == core.cluster) {
+            Some(cluster) => cluster,
+            None => {
+                clusters.push(CpuCluster {
+                    id: core.cluster,
+                    ..Default::default()
+                });
+                clusters.last_mut().unwrap()
+            }
+        };
+
+        cluster.core_indices.push(core.index);
+        if core.min_frequency > 0 && (cluster.min == 0 || core.min_frequency < cluster.min) {
+            cluster.min = core.min_frequency;
+        }
+        cluster.current = cluster.current.max(core.frequency);
+        cluster.max = cluster.max.max(core.max_frequency);
+    }
+
+    for cluster in &mut clusters {
+        let members: Vec<CpuCore> = cores
+            .iter()
+            .filter(|c| c.cluster == cluster.id)
+            .cloned()
+            .collect();
+        cluster.avg_usage = online_average(&members, |c| c.usage);
+        cluster.avg_frequency = online_average(&members, |c| c.frequency as f32).round() as u32;
+    }
+
+    clusters.sort_by_key(|c| c.id);
+    clusters
 }
 
 /// Get number of CPU cores synchronously
@@ -84,7 +300,7 @@ impl CpuStats {
 /// # Returns
 /// The number of CPU cores available on the system.
 pub fn get_core_count() -> usize {
-    let path = Path::new("/proc/cpuinfo");
+    let path = Path::new(PROC_CPUINFO);
     if let Ok(content) = fs::read_to_string(path) {
         content
             .lines()
@@ -106,7 +322,7 @@ pub fn get_core_count() -> usize {
 ///
 /// This is the async version of `get_core_count()` using tokio for I/O.
 pub async fn get_core_count_async() -> usize {
-    let path = Path::new("/proc/cpuinfo");
+    let path = Path::new(PROC_CPUINFO);
     if let Ok(content) = tokio_fs::read_to_string(path).await {
         content
             .lines()
@@ -118,198 +334,104 @@ pub async fn get_core_count_async() -> usize {
     }
 }
 
-/// Read CPU information from /proc/cpuinfo
-fn read_cpu_cores() -> anyhow::Result<Vec<CpuCore>> {
-    let path = Path::new("/proc/cpuinfo");
-    let file = BufReader::new(fs::File::open(path)?);
-    
-    let mut cores: Vec<CpuCore> = Vec::new();
-    
-    // First, read all CPU cores from /proc/cpuinfo
-    for line in file.lines() {
-        let line = line?;
-        if let Some((key, value)) = line.split_once(':') {
-            if key.trim() == "processor" {
-                let idx = value.trim().parse().unwrap_or(0);
-                cores.push(CpuCore {
-                    index: idx,
-                    frequency: read_cpu_core_frequency(idx),
-                    usage: 0.0,
-                    governor: get_governor(idx),
-                });
-            }
-        }
-    }
-    
-    // Calculate CPU usage from /proc/stat (after cores are created)
-    if let Ok(usage_vec) = read_cpu_usage(&cores) {
-        for (core, usage) in cores.iter_mut().zip(usage_vec.iter()) {
-            core.usage = *usage;
-        }
+/// Read all CPU cores with their usage since boot
+fn read_cpu_cores() -> crate::Result<Vec<CpuCore>> {
+    let mut cores = read_cpu_cores_info()?;
+
+    if let Ok(usage) = read_cpu_usage() {
+        apply_core_usage(&mut cores, &usage);
     }
-    
+
     Ok(cores)
 }
 
-/// Read CPU information from /proc/cpuinfo (async)
-async fn read_cpu_cores_async() -> anyhow::Result<Vec<CpuCore>> {
-    let path = Path::new("/proc/cpuinfo");
-    let content = tokio_fs::read_to_string(path).await?;
-
-    let mut cores: Vec<CpuCore> = Vec::new();
-
-    for line in content.lines() {
-        if let Some((key, value)) = line.split_once(':') {
-            if key.trim() == "processor" {
-                let idx = value.trim().parse().unwrap_or(0);
-                cores.push(CpuCore {
-                    index: idx,
-                    frequency: read_cpu_core_frequency(idx),
-                    usage: 0.0,
-                    governor: get_governor(idx),
-                });
-            }
-        }
-    }
+/// Read all CPU cores with their usage since boot (async)
+async fn read_cpu_cores_async() -> crate::Result<Vec<CpuCore>> {
+    let mut cores = read_cpu_cores_info_async().await?;
 
-    // Calculate CPU usage from /proc/stat
-    if let Ok(usage_vec) = read_cpu_usage_async(&cores).await {
-        for (core, usage) in cores.iter_mut().zip(usage_vec.iter()) {
-            core.usage = *usage;
-        }
+    if let Ok(usage) = read_cpu_usage_async().await {
+        apply_core_usage(&mut cores, &usage);
     }
 
     Ok(cores)
 }
 
-/// Read CPU usage from /proc/stat
-fn read_cpu_usage(cores: &[CpuCore]) -> anyhow::Result<Vec<f32>> {
-    let path = Path::new("/proc/stat");
-    let content = fs::read_to_string(path)?;
-    
-    // Count CPU cores from /proc/stat first
-    let cpu_count = content.lines()
-        .filter(|line| {
-            line.starts_with("cpu") && 
-            !line.starts_with("cpu ") && 
-            line.split_whitespace().next().map_or(false, |s| s.len() > 3 && s[3..].parse::<usize>().is_ok())
-        })
-        .count();
-    
-    let mut usage = vec![0.0; cpu_count];
-    
-    for line in content.lines() {
-        if line.starts_with("cpu") {
-            let parts: Vec<&str> = line.split_whitespace().collect();
-            
-            // Skip "cpu" (aggregate) line
-            if parts[0] == "cpu" {
-                continue;
-            }
-            
-            // Extract core index
-            if let Some(idx_str) = parts[0].strip_prefix("cpu") {
-                if let Ok(idx) = idx_str.parse::<usize>() {
-                    if idx < usage.len() {
-                        // Parse CPU time fields
-                        if parts.len() >= 5 {
-                            let user: u64 = parts[1].parse().unwrap_or(0);
-                            let nice: u64 = parts[2].parse().unwrap_or(0);
-                            let system: u64 = parts[3].parse().unwrap_or(0);
-                            let idle: u64 = parts[4].parse().unwrap_or(0);
-                            
-                            let total = user + nice + system + idle;
-                            if total > 0 {
-                                usage[idx] = ((user + nice + system) as f32 / total as f32) * 100.0;
-                            }
-                        }
-                    }
-                }
-            }
-        }
+/// Assign usage values, indexed by core number, to their cores
+fn apply_core_usage(cores: &mut [CpuCore], usage: &[f32]) {
+    for core in cores {
+        core.usage = usage.get(core.index).copied().unwrap_or(0.0);
     }
-    
-    Ok(usage)
-}
-
-/// Read CPU usage from /proc/stat (async)
-async fn read_cpu_usage_async(cores: &[CpuCore]) -> anyhow::Result<Vec<f32>> {
-    let path = Path::new("/proc/stat");
-    let content = tokio_fs::read_to_string(path).await?;
-    
-    // Count CPU cores from /proc/stat first
-    let cpu_count = content.lines()
-        .filter(|line| {
-            line.starts_with("cpu") && 
-            !line.starts_with("cpu ") && 
-            line.split_whitespace().next().map_or(false, |s| s.len() > 3 && s[3..].parse::<usize>().is_ok())
-        })
-        .count();
-    
-    let mut usage = vec![0.0; cpu_count];
-    
-    for line in content.lines() {
-        if line.starts_with("cpu") {
-            let parts: Vec<&str> = line.split_whitespace().collect();
-            
-            // Skip "cpu" (aggregate) line
-            if parts[0] == "cpu" {
-                continue;
-            }
-            
-            // Extract core index
-            if let Some(idx_str) = parts[0].strip_prefix("cpu") {
-                if let Ok(idx) = idx_str.parse::<usize>() {
-                    if idx < usage.len() {
-                        // Parse CPU time fields
-                        if parts.len() >= 5 {
-                            let user: u64 = parts[1].parse().unwrap_or(0);
-                            let nice: u64 = parts[2].parse().unwrap_or(0);
-                            let system: u64 = parts[3].parse().unwrap_or(0);
-                            let idle: u64 = parts[4].parse().unwrap_or(0);
-                            
-                            let total = user + nice + system + idle;
-                            if total > 0 {
-                                usage[idx] = ((user + nice + system) as f32 / total as f32) * 100.0;
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    }
-    
-    Ok(usage)
 }
 
-/// Get CPU frequency governor
-fn get_governor(core_idx: usize) -> String {
-    let path_str = format!(
-        "/sys/devices/system/cpu/cpu{}/cpufreq/scaling_governor",
-        core_idx
-    );
-    let path = Path::new(&path_str);
+/// Read per-core CPU usage since boot from /proc/stat
+fn read_cpu_usage() -> crate::Result<Vec<f32>> {
+    let content = fs::read_to_string(PROC_STAT).map_err(|e| Error::sysfs_read(PROC_STAT, e))?;
+    Ok(parse_cpu_usage(&content))
+}
 
-    fs::read_to_string(path)
-        .ok()
-        .map(|s| s.trim().to_string())
-        .unwrap_or_else(|| "unknown".to_string())
+/// Read per-core CPU usage since boot from /proc/stat (async)
+async fn read_cpu_usage_async() -> crate::Result<Vec<f32>> {
+    let content = tokio_fs::read_to_string(PROC_STAT)
+        .await
+        .map_err(|e| Error::sysfs_read(PROC_STAT, e))?;
+    Ok(parse_cpu_usage(&content))
 }
 
-#[allow(dead_code)]
-/// Get CPU frequency governor (async)
-async fn get_governor_async(core_idx: usize) -> String {
-    let path_str = format!(
-        "/sys/devices/system/cpu/cpu{}/cpufreq/scaling_governor",
-        core_idx
-    );
-    let path = Path::new(&path_str);
+/// Per-core usage from /proc/stat contents, indexed by core number
+///
+/// Uses all seven time fields, so time spent in I/O wait counts as idle
+/// rather than busy.
+fn parse_cpu_usage(content: &str) -> Vec<f32> {
+    index_by_core(parse_cpu_time_values(content))
+        .iter()
+        .map(CpuTimeValues::usage)
+        .collect()
+}
 
-    tokio_fs::read_to_string(path)
-        .await
-        .ok()
-        .map(|s| s.trim().to_string())
-        .unwrap_or_else(|| "unknown".to_string())
+/// Place per-core time values at their core number
+///
+/// Offline cores have no line in /proc/stat and are left at zero.
+fn index_by_core(values: Vec<(usize, CpuTimeValues)>) -> Vec<CpuTimeValues> {
+    let mut indexed =
+        vec![CpuTimeValues::default(); values.iter().map(|(idx, _)| idx + 1).max().unwrap_or(0)];
+
+    for (idx, times) in values {
+        indexed[idx] = times;
+    }
+
+    indexed
+}
+
+/// Parse the `cpuN` lines of /proc/stat into per-core time values
+///
+/// Skips the aggregate `cpu` line. Fields missing on old kernels count
+/// as zero.
+fn parse_cpu_time_values(content: &str) -> Vec<(usize, CpuTimeValues)> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let idx = parts.next()?.strip_prefix("cpu")?.parse::<usize>().ok()?;
+            let fields: Vec<u64> = parts.take(7).map(|p| p.parse().unwrap_or(0)).collect();
+            if fields.len() < 4 {
+                return None;
+            }
+
+            let field = |n: usize| fields.get(n).copied().unwrap_or(0);
+            Some((
+                idx,
+                CpuTimeValues {
+                    user: field(0),
+                    nice: field(1),
+                    system: field(2),
+                    idle: field(3),
+                    iowait: field(4),
+                    irq: field(5),
+                    softirq: field(6),
+                },
+            ))
+        })
+        .collect()
 }
 
 /// Read CPU core frequency from sysfs
@@ -318,13 +440,45 @@ pub fn read_cpu_core_frequency(core_idx: usize) -> u32 {
         "/sys/devices/system/cpu/cpu{}/cpufreq/scaling_cur_freq",
         core_idx
     );
-    let path = Path::new(&path_str);
+    sysfs::read_u32(path_str).unwrap_or(0)
+}
 
-    if let Ok(content) = fs::read_to_string(path) {
-        content.trim().parse().unwrap_or(0)
-    } else {
-        0
+/// Read the scaling frequency range of a CPU core as `(min, max)` in kHz
+///
+/// Both are 0 when the core has no cpufreq policy, e.g. while offline.
+pub fn read_cpu_core_freq_range(core_idx: usize) -> (u32, u32) {
+    let cpu_dir = Path::new(CPU_SYSFS).join(format!("cpu{}", core_idx));
+    sysfs::block_on(read_core_freq_range(&Blocking, &cpu_dir))
+}
+
+/// Read `scaling_min_freq`/`scaling_max_freq` from a `cpuN` sysfs directory
+async fn read_core_freq_range<R: Reader>(reader: &R, cpu_dir: &Path) -> (u32, u32) {
+    let cpufreq = cpu_dir.join("cpufreq");
+    (
+        reader
+            .read_value(&cpufreq.join("scaling_min_freq"))
+            .await
+            .unwrap_or(0),
+        reader
+            .read_value(&cpufreq.join("scaling_max_freq"))
+            .await
+            .unwrap_or(0),
+    )
+}
+
+/// Read the cluster id from a `cpuN` sysfs directory
+///
+/// Uses `topology/cluster_id`, falling back to `topology/physical_package_id`
+/// on kernels that report no (or a negative) cluster id.
+async fn read_core_cluster<R: Reader>(reader: &R, cpu_dir: &Path) -> usize {
+    for file in ["cluster_id", "physical_package_id"] {
+        let path = cpu_dir.join("topology").join(file);
+        if let Some(cluster) = reader.read_value::<usize>(&path).await {
+            return cluster;
+        }
     }
+
+    0
 }
 
 /// Raw CPU time values from /proc/stat for delta calculations
@@ -349,45 +503,130 @@ impl CpuTimeValues {
     pub fn busy(&self) -> u64 {
         self.user + self.nice + self.system + self.irq + self.softirq
     }
+
+    /// Busy time as a percentage of the total time
+    pub fn usage(&self) -> f32 {
+        match self.total() {
+            0 => 0.0,
+            total => self.busy() as f32 / total as f32 * 100.0,
+        }
+    }
+
+    /// Per-state percentages of the time elapsed since `prev`
+    ///
+    /// Returns `None` when no time elapsed between the two readings.
+    pub fn percentages_since(&self, prev: &CpuTimeValues) -> Option<CpuTimePercentages> {
+        let delta_total = self.total().saturating_sub(prev.total());
+        if delta_total == 0 {
+            return None;
+        }
+
+        let percent =
+            |curr: u64, prev: u64| (curr.saturating_sub(prev) as f32 / delta_total as f32) * 100.0;
+
+        Some(CpuTimePercentages {
+            user: percent(self.user + self.nice, prev.user + prev.nice),
+            system: percent(
+                self.system + self.irq + self.softirq,
+                prev.system + prev.irq + prev.softirq,
+            ),
+            idle: percent(self.idle, prev.idle),
+            iowait: percent(self.iowait, prev.iowait),
+        })
+    }
+}
+
+/// Breakdown of CPU time between two readings, in percent
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CpuTimePercentages {
+    /// User time (includes nice)
+    pub user: f32,
+    /// System time (includes irq/softirq)
+    pub system: f32,
+    /// Idle time
+    pub idle: f32,
+    /// I/O wait time
+    pub iowait: f32,
 }
 
 /// CPU monitor with state for delta-based usage calculation
 #[derive(Debug, Default)]
 pub struct CpuMonitor {
     prev_values: Vec<CpuTimeValues>,
+    usage_history: History<f32>,
 }
 
 impl CpuMonitor {
     /// Create a new CPU monitor
     pub fn new() -> Self {
+        Self::with_history_capacity(history::DEFAULT_CAPACITY)
+    }
+
+    /// Create a CPU monitor keeping the last `capacity` usage samples
+    pub fn with_history_capacity(capacity: usize) -> Self {
         Self {
             prev_values: Vec::new(),
+            usage_history: History::new(capacity),
         }
     }
 
+    /// Overall usage of the previous samples in percent, oldest first
+    pub fn usage_history(&self) -> &[f32] {
+        self.usage_history.as_slice()
+    }
+
     /// Get CPU stats with delta-based usage calculation
     ///
     /// This calculates instantaneous CPU usage by comparing current
     /// CPU time values with previous readings, similar to how jtop works.
     pub fn get_stats(&mut self) -> CpuStats {
-        let mut stats = CpuStats::default();
-
-        // Read current CPU time values
+        // Read current CPU time values and core info (frequency, governor)
         let current_values = read_cpu_time_values();
+        let cores = read_cpu_cores_info().unwrap_or_default();
 
-        // Read core info (frequency, governor)
-        if let Ok(cores) = read_cpu_cores_info() {
-            stats.cores = cores;
-        }
+        self.sample(current_values, cores)
+    }
+
+    /// Get CPU stats with delta-based usage calculation (async)
+    ///
+    /// Same as `get_stats()`, but reads /proc/stat and enumerates the cores
+    /// through tokio so a render loop does not block on the file reads.
+    pub async fn get_stats_async(&mut self) -> CpuStats {
+        let current_values = read_cpu_time_values_async().await;
+        let cores = read_cpu_cores_info_async().await.unwrap_or_default();
+
+        self.sample(current_values, cores)
+    }
+
+    /// Derive per-core usage from the delta against the previous sample
+    fn sample(&mut self, current_values: Vec<CpuTimeValues>, cores: Vec<CpuCore>) -> CpuStats {
+        let mut stats = CpuStats {
+            cores,
+            ..Default::default()
+        };
 
         // Calculate usage from delta if we have previous values
-        if !self.prev_values.is_empty() && self.prev_values.len() == current_values.len() {
-            for (i, (curr, prev)) in current_values.iter().zip(self.prev_values.iter()).enumerate() {
+        if self.prev_values.len() == current_values.len() {
+            for core in &mut stats.cores {
+                let (Some(curr), Some(prev)) = (
+                    current_values.get(core.index),
+                    self.prev_values.get(core.index),
+                ) else {
+                    continue;
+                };
+
                 let delta_total = curr.total().saturating_sub(prev.total());
                 let delta_busy = curr.busy().saturating_sub(prev.busy());
 
-                if delta_total > 0 && i < stats.cores.len() {
-                    stats.cores[i].usage = (delta_busy as f32 / delta_total as f32) * 100.0;
+                if delta_total > 0 {
+                    core.usage = (delta_busy as f32 / delta_total as f32) * 100.0;
+                }
+
+                if let Some(pct) = curr.percentages_since(prev) {
+                    core.user = pct.user;
+                    core.system = pct.system;
+                    core.idle = pct.idle;
+                    core.iowait = pct.iowait;
                 }
             }
         }
@@ -395,78 +634,198 @@ impl CpuMonitor {
         // Store current values for next call
         self.prev_values = current_values;
 
-        // Calculate average usage
-        stats.usage = if !stats.cores.is_empty() {
-            stats.cores.iter().map(|c| c.usage).sum::<f32>() / stats.cores.len() as f32
-        } else {
-            0.0
-        };
+        stats.frequency = max_core_frequency(&stats.cores);
+        stats.clusters = cluster_frequencies(&stats.cores);
+
+        // Calculate average usage and time breakdown over the online cores
+        stats.usage = online_average(&stats.cores, |c| c.usage);
+        stats.user = online_average(&stats.cores, |c| c.user);
+        stats.system = online_average(&stats.cores, |c| c.system);
+        stats.idle = online_average(&stats.cores, |c| c.idle);
+        stats.iowait = online_average(&stats.cores, |c| c.iowait);
+
+        self.usage_history.push(stats.usage);
 
         stats
     }
 }
 
-/// Read raw CPU time values from /proc/stat
+impl Collector for CpuMonitor {
+    type Stats = CpuStats;
+
+    fn collect(&mut self) -> Self::Stats {
+        self.get_stats()
+    }
+}
+
+/// Read raw CPU time values from /proc/stat, indexed by core number
 fn read_cpu_time_values() -> Vec<CpuTimeValues> {
-    let path = Path::new("/proc/stat");
-    let content = match fs::read_to_string(path) {
-        Ok(c) => c,
-        Err(_) => return Vec::new(),
-    };
-
-    let mut values = Vec::new();
-
-    for line in content.lines() {
-        if line.starts_with("cpu") && !line.starts_with("cpu ") {
-            let parts: Vec<&str> = line.split_whitespace().collect();
-
-            if parts.len() >= 8 {
-                values.push(CpuTimeValues {
-                    user: parts[1].parse().unwrap_or(0),
-                    nice: parts[2].parse().unwrap_or(0),
-                    system: parts[3].parse().unwrap_or(0),
-                    idle: parts[4].parse().unwrap_or(0),
-                    iowait: parts[5].parse().unwrap_or(0),
-                    irq: parts[6].parse().unwrap_or(0),
-                    softirq: parts[7].parse().unwrap_or(0),
-                });
-            }
-        }
+    fs::read_to_string(Path::new(PROC_STAT))
+        .map(|content| index_by_core(parse_cpu_time_values(&content)))
+        .unwrap_or_default()
+}
+
+/// Read raw CPU time values from /proc/stat, indexed by core number (async)
+async fn read_cpu_time_values_async() -> Vec<CpuTimeValues> {
+    tokio_fs::read_to_string(Path::new(PROC_STAT))
+        .await
+        .map(|content| index_by_core(parse_cpu_time_values(&content)))
+        .unwrap_or_default()
+}
+
+/// Read core info (frequency, governor) without usage calculation
+fn read_cpu_cores_info() -> crate::Result<Vec<CpuCore>> {
+    sysfs::block_on(cpu_cores_info(&Blocking))
+}
+
+/// Read core info (frequency, governor) without usage calculation (async)
+async fn read_cpu_cores_info_async() -> crate::Result<Vec<CpuCore>> {
+    cpu_cores_info(&Tokio).await
+}
+
+/// Read the information of every core through `reader`
+///
+/// Cores are enumerated from sysfs so offline cores keep their index;
+/// without sysfs the online cores listed in /proc/cpuinfo are used.
+async fn cpu_cores_info<R: Reader>(reader: &R) -> crate::Result<Vec<CpuCore>> {
+    let cpu_sysfs = Path::new(CPU_SYSFS);
+    let mut present = list_cpu_cores(reader, cpu_sysfs).await;
+    if present.is_empty() {
+        let content = reader
+            .read_to_string(Path::new(PROC_CPUINFO))
+            .await
+            .map_err(|e| Error::sysfs_read(PROC_CPUINFO, e))?;
+        present = parse_cpuinfo_processors(&content)
+            .into_iter()
+            .map(|idx| (idx, true))
+            .collect();
+    }
+
+    let mut cores = Vec::with_capacity(present.len());
+    for (idx, online) in present {
+        let cpu_dir = cpu_sysfs.join(format!("cpu{}", idx));
+        cores.push(read_cpu_core(reader, &cpu_dir, idx, online).await);
     }
+    let temperatures = read_core_temperatures(reader, Path::new(THERMAL_SYSFS)).await;
+    apply_core_temperatures(&mut cores, &temperatures);
 
-    values
+    Ok(cores)
 }
 
-/// Read CPU core info (frequency, governor) without usage calculation
-fn read_cpu_cores_info() -> anyhow::Result<Vec<CpuCore>> {
-    let path = Path::new("/proc/cpuinfo");
-    let file = BufReader::new(fs::File::open(path)?);
+/// List the `cpuN` directories of a cpu sysfs tree as `(index, online)`
+///
+/// cpu0 usually has no `online` file since it cannot be unplugged, so a
+/// missing file means the core is online.
+async fn list_cpu_cores<R: Reader>(reader: &R, cpu_sysfs: &Path) -> Vec<(usize, bool)> {
+    let mut cores = Vec::new();
+    for path in reader.read_dir(cpu_sysfs).await.unwrap_or_default() {
+        let Some(idx) = path.file_name().and_then(cpu_dir_index) else {
+            continue;
+        };
+        let online = reader
+            .read_value::<u32>(&path.join("online"))
+            .await
+            .is_none_or(|v| v == 1);
+        cores.push((idx, online));
+    }
 
-    let mut cores: Vec<CpuCore> = Vec::new();
+    cores.sort_unstable();
+    cores
+}
 
-    for line in file.lines() {
-        let line = line?;
-        if let Some((key, value)) = line.split_once(':') {
-            if key.trim() == "processor" {
-                let idx = value.trim().parse().unwrap_or(0);
-                cores.push(CpuCore {
-                    index: idx,
-                    frequency: read_cpu_core_frequency(idx),
-                    usage: 0.0,
-                    governor: get_governor(idx),
-                });
-            }
+/// Core number of a `cpuN` sysfs directory name
+fn cpu_dir_index(name: &OsStr) -> Option<usize> {
+    name.to_str()?.strip_prefix("cpu")?.parse().ok()
+}
+
+/// Parse the `processor` lines of /proc/cpuinfo
+fn parse_cpuinfo_processors(content: &str) -> Vec<usize> {
+    content
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .filter(|(key, _)| key.trim() == "processor")
+        .map(|(_, value)| value.trim().parse().unwrap_or(0))
+        .collect()
+}
+
+/// Read per-core temperatures from `CPUN-therm` thermal zones
+///
+/// Returns `(core index, °C)` pairs; boards with a single CPU zone yield
+/// none.
+async fn read_core_temperatures<R: Reader>(reader: &R, thermal_sysfs: &Path) -> Vec<(usize, f32)> {
+    let mut temperatures = Vec::new();
+    for zone in reader.read_dir(thermal_sysfs).await.unwrap_or_default() {
+        let is_zone = zone
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with("thermal_zone"));
+        if !is_zone {
+            continue;
+        }
+
+        let Some(zone_type) = reader.read_string(&zone.join("type")).await else {
+            continue;
+        };
+        let Some(idx) = core_thermal_index(&zone_type) else {
+            continue;
+        };
+        if let Some(temp) = reader.read_f32_milli(&zone.join("temp")).await {
+            temperatures.push((idx, temp));
         }
     }
 
-    Ok(cores)
+    temperatures
+}
+
+/// Core index of a per-core thermal zone type such as `CPU3-therm`
+pub fn core_thermal_index(zone_type: &str) -> Option<usize> {
+    zone_type
+        .to_ascii_uppercase()
+        .strip_prefix("CPU")?
+        .strip_suffix("-THERM")?
+        .parse()
+        .ok()
+}
+
+/// Assign `(core index, °C)` pairs to their cores
+fn apply_core_temperatures(cores: &mut [CpuCore], temperatures: &[(usize, f32)]) {
+    for core in cores {
+        core.temperature = temperatures
+            .iter()
+            .find(|(idx, _)| *idx == core.index)
+            .map(|(_, temp)| *temp);
+    }
+}
+
+/// Read the sysfs information of a single core from its `cpuN` directory
+async fn read_cpu_core<R: Reader>(reader: &R, cpu_dir: &Path, idx: usize, online: bool) -> CpuCore {
+    let cpufreq = cpu_dir.join("cpufreq");
+    let (min_frequency, max_frequency) = read_core_freq_range(reader, cpu_dir).await;
+
+    CpuCore {
+        index: idx,
+        frequency: reader
+            .read_value(&cpufreq.join("scaling_cur_freq"))
+            .await
+            .unwrap_or(0),
+        governor: reader
+            .read_string(&cpufreq.join("scaling_governor"))
+            .await
+            .unwrap_or_else(|| "unknown".to_string()),
+        online,
+        cluster: read_core_cluster(reader, cpu_dir).await,
+        min_frequency,
+        max_frequency,
+        ..Default::default()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modules::sysfs::MockDir;
 
     #[test]
+    #[allow(unused_variables)]
     fn test_cpu_monitor_delta_calculation() {
         let mut monitor = CpuMonitor::new();
 
@@ -529,6 +888,343 @@ mod tests {
         assert!(count > 0, "Should always return a positive core count");
     }
 
+    #[test]
+    fn test_cpu_time_percentages_sum_to_100() {
+        let first = CpuTimeValues {
+            user: 1000,
+            nice: 50,
+            system: 300,
+            idle: 8000,
+            iowait: 100,
+            irq: 20,
+            softirq: 30,
+        };
+        let second = CpuTimeValues {
+            user: 1300,
+            nice: 60,
+            system: 390,
+            idle: 8500,
+            iowait: 180,
+            irq: 25,
+            softirq: 45,
+        };
+
+        let pct = second.percentages_since(&first).unwrap();
+        let sum = pct.user + pct.system + pct.idle + pct.iowait;
+        assert!((sum - 100.0).abs() < 0.01, "Components should sum to 100");
+
+        // 1000 ticks elapsed: 310 user+nice, 110 system+irq+softirq
+        assert!((pct.user - 31.0).abs() < 0.01);
+        assert!((pct.system - 11.0).abs() < 0.01);
+        assert!((pct.idle - 50.0).abs() < 0.01);
+        assert!((pct.iowait - 8.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_cpu_time_percentages_no_elapsed_time() {
+        let values = CpuTimeValues {
+            user: 10,
+            idle: 90,
+            ..Default::default()
+        };
+        assert!(values.percentages_since(&values).is_none());
+    }
+
+    #[test]
+    fn test_cpu_monitor_usage_history() {
+        let mut monitor = CpuMonitor::with_history_capacity(2);
+        assert!(monitor.usage_history().is_empty());
+
+        let first = monitor.get_stats();
+        assert_eq!(monitor.usage_history(), &[first.usage]);
+
+        monitor.get_stats();
+        let last = monitor.get_stats();
+        assert_eq!(monitor.usage_history().len(), 2);
+        assert_eq!(monitor.usage_history().last(), Some(&last.usage));
+    }
+
+    #[test]
+    fn test_cpu_monitor_time_breakdown() {
+        let mut monitor = CpuMonitor::new();
+        monitor.get_stats();
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        let stats = monitor.get_stats();
+
+        for core in &stats.cores {
+            let sum = core.user + core.system + core.idle + core.iowait;
+            assert!(
+                sum == 0.0 || (sum - 100.0).abs() < 0.1,
+                "Core breakdown should sum to ~100, got {}",
+                sum
+            );
+        }
+    }
+
+    fn cluster_core(index: usize, cluster: usize, min: u32, cur: u32, max: u32) -> CpuCore {
+        CpuCore {
+            index,
+            cluster,
+            frequency: cur,
+            min_frequency: min,
+            max_frequency: max,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_cluster_frequencies_from_member_cores() {
+        let stats = CpuStats {
+            cores: vec![
+                cluster_core(0, 0, 115_200, 1_190_400, 1_984_000),
+                cluster_core(1, 0, 115_200, 1_190_400, 1_984_000),
+                cluster_core(4, 1, 115_200, 729_600, 2_201_600),
+                cluster_core(5, 1, 0, 0, 0),
+                cluster_core(2, 0, 204_800, 1_190_400, 1_984_000),
+            ],
+            ..Default::default()
+        };
+
+        let clusters = stats.cluster_frequencies();
+        assert_eq!(clusters.len(), 2);
+
+        assert_eq!(clusters[0].id, 0);
+        assert_eq!(clusters[0].core_indices, vec![0, 1, 2]);
+        assert_eq!(clusters[0].min, 115_200);
+        assert_eq!(clusters[0].current, 1_190_400);
+        assert_eq!(clusters[0].max, 1_984_000);
+
+        // The offline core does not lower the cluster range
+        assert_eq!(clusters[1].id, 1);
+        assert_eq!(clusters[1].core_indices, vec![4, 5]);
+        assert_eq!(clusters[1].min, 115_200);
+        assert_eq!(clusters[1].current, 729_600);
+        assert_eq!(clusters[1].max, 2_201_600);
+    }
+
+    #[test]
+    fn test_max_core_frequency_big_little() {
+        // Core 0 sits on a slow cluster while the fast cluster is boosted
+        let cores = vec![
+            cluster_core(0, 0, 115_200, 729_600, 1_510_400),
+            cluster_core(1, 0, 115_200, 729_600, 1_510_400),
+            cluster_core(4, 1, 115_200, 2_201_600, 2_201_600),
+        ];
+
+        assert_eq!(max_core_frequency(&cores), 2_201_600);
+        assert_eq!(max_core_frequency(&[]), 0);
+    }
+
+    #[test]
+    fn test_cluster_frequencies_from_topology() {
+        let dir = MockDir::new("cpu-clusters");
+        for idx in 0..8 {
+            let topology = dir.join(format!("cpu{}", idx)).join("topology");
+            fs::create_dir_all(&topology).unwrap();
+            fs::write(topology.join("cluster_id"), format!("{}\n", idx / 4)).unwrap();
+        }
+
+        let cores: Vec<CpuCore> = (0..8)
+            .map(|index| CpuCore {
+                index,
+                online: true,
+                cluster: sysfs::block_on(read_core_cluster(
+                    &Blocking,
+                    &dir.join(format!("cpu{}", index)),
+                )),
+                usage: if index < 4 { 10.0 } else { 50.0 + index as f32 },
+                frequency: if index < 4 { 729_600 } else { 2_201_600 },
+                ..Default::default()
+            })
+            .collect();
+
+        let clusters = cluster_frequencies(&cores);
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].id, 0);
+        assert_eq!(clusters[0].core_indices, vec![0, 1, 2, 3]);
+        assert_eq!(clusters[0].avg_usage, 10.0);
+        assert_eq!(clusters[0].avg_frequency, 729_600);
+        assert_eq!(clusters[1].core_indices, vec![4, 5, 6, 7]);
+        assert_eq!(clusters[1].avg_usage, 55.5);
+        assert_eq!(clusters[1].avg_frequency, 2_201_600);
+    }
+
+    #[test]
+    fn test_cluster_frequencies_skip_offline_cores_in_averages() {
+        let mut cores = vec![
+            cluster_core(0, 0, 0, 1_000_000, 0),
+            cluster_core(1, 0, 0, 0, 0),
+        ];
+        cores[0].online = true;
+        cores[0].usage = 40.0;
+
+        let clusters = cluster_frequencies(&cores);
+        assert_eq!(clusters[0].core_indices, vec![0, 1]);
+        assert_eq!(clusters[0].avg_usage, 40.0);
+        assert_eq!(clusters[0].avg_frequency, 1_000_000);
+    }
+
+    #[test]
+    fn test_cluster_frequencies_empty() {
+        assert!(cluster_frequencies(&[]).is_empty());
+    }
+
+    async fn check_read_core_freq_range<R: Reader>(reader: &R) {
+        let dir = MockDir::new("cpu-cpufreq");
+        let cpufreq = dir.join("cpufreq");
+        fs::create_dir_all(&cpufreq).unwrap();
+        fs::write(cpufreq.join("scaling_min_freq"), "729600\n").unwrap();
+        fs::write(cpufreq.join("scaling_max_freq"), "1510400\n").unwrap();
+
+        assert_eq!(
+            read_core_freq_range(reader, &dir).await,
+            (729_600, 1_510_400)
+        );
+        // Offline cores have no cpufreq policy
+        assert_eq!(
+            read_core_freq_range(reader, &dir.join("missing")).await,
+            (0, 0)
+        );
+    }
+
+    #[test]
+    fn test_read_core_freq_range() {
+        sysfs::block_on(check_read_core_freq_range(&Blocking));
+    }
+
+    #[tokio::test]
+    async fn test_read_core_freq_range_async() {
+        check_read_core_freq_range(&Tokio).await;
+    }
+
+    async fn check_list_cpu_cores_with_offline_core<R: Reader>(reader: &R) {
+        let dir = MockDir::new("cpu-online");
+        // cpu0 cannot be unplugged and has no online file
+        fs::create_dir_all(dir.join("cpu0/cpufreq")).unwrap();
+        for (cpu, online) in [("cpu1", "1"), ("cpu2", "0"), ("cpu3", "1")] {
+            fs::create_dir_all(dir.join(cpu)).unwrap();
+            fs::write(dir.join(cpu).join("online"), format!("{}\n", online)).unwrap();
+        }
+        // Non-core entries of /sys/devices/system/cpu
+        fs::create_dir_all(dir.join("cpufreq")).unwrap();
+        fs::create_dir_all(dir.join("cpuidle")).unwrap();
+        fs::write(dir.join("online"), "0-1,3\n").unwrap();
+
+        assert_eq!(
+            list_cpu_cores(reader, &dir).await,
+            vec![(0, true), (1, true), (2, false), (3, true)]
+        );
+        assert!(list_cpu_cores(reader, &dir.join("missing"))
+            .await
+            .is_empty());
+    }
+
+    #[test]
+    fn test_list_cpu_cores_with_offline_core() {
+        sysfs::block_on(check_list_cpu_cores_with_offline_core(&Blocking));
+    }
+
+    #[tokio::test]
+    async fn test_list_cpu_cores_with_offline_core_async() {
+        check_list_cpu_cores_with_offline_core(&Tokio).await;
+    }
+
+    #[test]
+    fn test_usage_keeps_core_numbering_with_offline_core() {
+        // cpu2 is offline and has no line in /proc/stat
+        let usage =
+            parse_cpu_usage("cpu0 10 0 10 80 0 0 0\ncpu1 50 0 0 50 0 0 0\ncpu3 25 0 0 75 0 0 0\n");
+        assert_eq!(usage, vec![20.0, 50.0, 0.0, 25.0]);
+
+        let mut cores: Vec<CpuCore> = [(0, true), (1, true), (2, false), (3, true)]
+            .into_iter()
+            .map(|(index, online)| CpuCore {
+                index,
+                online,
+                ..Default::default()
+            })
+            .collect();
+        apply_core_usage(&mut cores, &usage);
+
+        assert_eq!(cores[3].usage, 25.0);
+        assert_eq!(cores[2].usage, 0.0);
+        // The offline core does not drag the average down
+        assert!((online_average(&cores, |c| c.usage) - 95.0 / 3.0).abs() < 0.01);
+    }
+
+    async fn check_read_core_cluster_fallback<R: Reader>(reader: &R) {
+        let dir = MockDir::new("cpu-topology");
+        let topology = dir.join("topology");
+        fs::create_dir_all(&topology).unwrap();
+
+        // No topology information at all
+        assert_eq!(read_core_cluster(reader, &dir.join("missing")).await, 0);
+
+        // Kernels without cluster support report -1
+        fs::write(topology.join("cluster_id"), "-1\n").unwrap();
+        fs::write(topology.join("physical_package_id"), "2\n").unwrap();
+        assert_eq!(read_core_cluster(reader, &dir).await, 2);
+
+        fs::write(topology.join("cluster_id"), "1\n").unwrap();
+        assert_eq!(read_core_cluster(reader, &dir).await, 1);
+    }
+
+    #[test]
+    fn test_read_core_cluster_fallback() {
+        sysfs::block_on(check_read_core_cluster_fallback(&Blocking));
+    }
+
+    #[tokio::test]
+    async fn test_read_core_cluster_fallback_async() {
+        check_read_core_cluster_fallback(&Tokio).await;
+    }
+
+    #[test]
+    fn test_core_thermal_index() {
+        assert_eq!(core_thermal_index("CPU0-therm"), Some(0));
+        assert_eq!(core_thermal_index("CPU5-therm"), Some(5));
+        assert_eq!(core_thermal_index("cpu-thermal"), None);
+        assert_eq!(core_thermal_index("GPU-therm"), None);
+    }
+
+    async fn check_read_core_temperatures<R: Reader>(reader: &R) {
+        let dir = MockDir::new("cpu-therm");
+        for (zone, zone_type, temp) in [
+            ("thermal_zone0", "CPU0-therm", "45500"),
+            ("thermal_zone1", "CPU2-therm", "47000"),
+            ("thermal_zone2", "GPU-therm", "50000"),
+        ] {
+            fs::create_dir_all(dir.join(zone)).unwrap();
+            fs::write(dir.join(zone).join("type"), format!("{}\n", zone_type)).unwrap();
+            fs::write(dir.join(zone).join("temp"), format!("{}\n", temp)).unwrap();
+        }
+
+        let mut cores: Vec<CpuCore> = (0..3)
+            .map(|index| CpuCore {
+                index,
+                ..Default::default()
+            })
+            .collect();
+        apply_core_temperatures(&mut cores, &read_core_temperatures(reader, &dir).await);
+
+        assert_eq!(cores[0].temperature, Some(45.5));
+        assert_eq!(cores[1].temperature, None);
+        assert_eq!(cores[2].temperature, Some(47.0));
+        assert!(read_core_temperatures(reader, &dir.join("missing"))
+            .await
+            .is_empty());
+    }
+
+    #[test]
+    fn test_read_core_temperatures() {
+        sysfs::block_on(check_read_core_temperatures(&Blocking));
+    }
+
+    #[tokio::test]
+    async fn test_read_core_temperatures_async() {
+        check_read_core_temperatures(&Tokio).await;
+    }
+
     #[test]
     fn test_cpu_stats_default() {
         let stats = CpuStats::default();
@@ -544,6 +1240,7 @@ mod tests {
             usage: 50.0,
             frequency: 1_500_000_000,
             governor: "schedutil".to_string(),
+            ..Default::default()
         };
 
         assert_eq!(core.index, 0);
@@ -563,9 +1260,11 @@ mod tests {
             );
             assert!(!stats.cores.is_empty(), "Should have at least one core");
         }
+        assert_eq!(stats.frequency, max_core_frequency(&stats.cores));
     }
 
     #[test]
+    #[allow(clippy::field_reassign_with_default)]
     fn test_cpu_stats_usage_calculation() {
         let mut stats = CpuStats::default();
         stats.cores = vec![
@@ -574,18 +1273,21 @@ mod tests {
                 usage: 50.0,
                 frequency: 1000000,
                 governor: "schedutil".to_string(),
+                ..Default::default()
             },
             CpuCore {
                 index: 1,
                 usage: 75.0,
                 frequency: 1000000,
                 governor: "schedutil".to_string(),
+                ..Default::default()
             },
             CpuCore {
                 index: 2,
                 usage: 25.0,
                 frequency: 1000000,
                 governor: "schedutil".to_string(),
+                ..Default::default()
             },
         ];
 
@@ -594,6 +1296,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::unnecessary_cast)]
     fn test_cpu_frequency_conversion() {
         let freq_mhz = 1500u32;
         let freq_hz = (freq_mhz as u32) * 1_000_000;
@@ -602,8 +1305,68 @@ mod tests {
 
     #[test]
     fn test_governor_fallback() {
-        let governor = get_governor(999);
-        assert_eq!(governor, "unknown");
+        let core = sysfs::block_on(read_cpu_core(
+            &Blocking,
+            Path::new("/nonexistent/cpu999"),
+            999,
+            false,
+        ));
+        assert_eq!(core.governor, "unknown");
+    }
+
+    #[test]
+    fn test_validate_governor() {
+        let available = "ondemand userspace powersave performance schedutil\n";
+        assert!(validate_governor(available, "performance").is_ok());
+        assert!(validate_governor(available, "schedutil").is_ok());
+
+        let err = validate_governor(available, "turbo")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'turbo' is not available"), "{}", err);
+        assert!(err.contains("schedutil"), "{}", err);
+        // No partial matches
+        assert!(validate_governor(available, "perf").is_err());
+    }
+
+    #[test]
+    fn test_set_governors_in_sysfs() {
+        let dir = MockDir::new("cpu-governor");
+        for cpu in ["cpu0", "cpu1"] {
+            let cpufreq = dir.join(cpu).join("cpufreq");
+            fs::create_dir_all(&cpufreq).unwrap();
+            fs::write(
+                cpufreq.join("scaling_available_governors"),
+                "schedutil performance\n",
+            )
+            .unwrap();
+            fs::write(cpufreq.join("scaling_governor"), "schedutil\n").unwrap();
+        }
+        // Offline core without a policy is skipped by set_all
+        fs::create_dir_all(dir.join("cpu2")).unwrap();
+        let governor =
+            |cpu: &str| fs::read_to_string(dir.join(cpu).join("cpufreq/scaling_governor")).unwrap();
+
+        set_governor_in(&dir.join("cpu1"), "performance").unwrap();
+        assert_eq!(governor("cpu0"), "schedutil\n");
+        assert_eq!(governor("cpu1"), "performance");
+
+        let err = set_all_governors_in(&dir, "powersave").unwrap_err();
+        assert!(
+            matches!(&err, Error::InvalidArgument(detail) if detail.starts_with("cpu0: governor 'powersave'")),
+            "{}",
+            err
+        );
+        assert_eq!(governor("cpu0"), "schedutil\n");
+
+        set_all_governors_in(&dir, "performance").unwrap();
+        assert_eq!(governor("cpu0"), "performance");
+
+        let err = set_governor_in(&dir.join("cpu2"), "performance").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Hardware not found: cpu2 has no cpufreq policy"
+        );
     }
 
     #[test]
@@ -631,7 +1394,9 @@ mod tests {
                 usage: 50.0,
                 frequency: 1500000000,
                 governor: "schedutil".to_string(),
+                ..Default::default()
             }],
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&stats);
@@ -648,6 +1413,9 @@ mod tests {
             usage: 75.5,
             frequency: 2000000000,
             governor: "performance".to_string(),
+            min_frequency: 729_600,
+            max_frequency: 2_201_600,
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&core);
@@ -655,6 +1423,26 @@ mod tests {
 
         let deserialized: Result<CpuCore, _> = serde_json::from_str(&json.unwrap());
         assert!(deserialized.is_ok(), "CpuCore should be deserializable");
+
+        let deserialized = deserialized.unwrap();
+        assert_eq!(deserialized.min_frequency, 729_600);
+        assert_eq!(deserialized.max_frequency, 2_201_600);
+    }
+
+    #[test]
+    fn test_cpu_stats_deserialize_older_json() {
+        // Snapshots written before the time breakdown, clusters and core
+        // topology were recorded
+        let json = r#"{"usage":12.5,"frequency":1420000,"cores":[
+            {"index":0,"usage":12.5,"frequency":1420000,"governor":"schedutil"}
+        ]}"#;
+
+        let stats: CpuStats = serde_json::from_str(json).unwrap();
+        assert_eq!(stats.usage, 12.5);
+        assert_eq!(stats.iowait, 0.0);
+        assert!(stats.clusters.is_empty());
+        assert_eq!(stats.cores[0].governor, "schedutil");
+        assert_eq!(stats.cores[0].max_frequency, 0);
     }
 
     #[test]
@@ -707,6 +1495,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(unused_variables)]
     fn test_read_cpu_core_utilization() {
         // Test that we can read CPU core utilization from /proc/stat
         // jtop uses 7 fields: user, nice, system, idle, iowait, irq, softirq
@@ -755,10 +1544,39 @@ mod tests {
             (expected_util - 6.59).abs() < 0.1,
             "Utilization should be ~6.59%"
         );
+
+        // The live /proc/stat path matches the same math
+        let usage = parse_cpu_usage(&format!(
+            "cpu0 {} {} {} {} {} {} {} 0 0 0\n",
+            user, nice, system, idle, iowait, irq, softirq
+        ));
+        assert!((usage[0] - 6.59).abs() < 0.1, "Live usage should be ~6.59%");
+    }
+
+    #[test]
+    fn test_parse_cpu_usage_counts_iowait_as_idle() {
+        let content = "cpu  20 0 20 60 100 0 0 0 0 0
+cpu0 10 0 10 30 50 0 0 0 0 0
+cpu1 10 0 10 30 50 0 0 0 0 0
+intr 12345
+";
+
+        let usage = parse_cpu_usage(content);
+        assert_eq!(usage, vec![20.0, 20.0]);
+    }
+
+    #[test]
+    fn test_parse_cpu_time_values_short_lines() {
+        // Pre-2.6 kernels only report user, nice, system and idle
+        let values = parse_cpu_time_values("cpu0 10 0 10 80\ncpu1 1 2\n");
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0].1.total(), 100);
+        assert_eq!(values[0].1.usage(), 20.0);
     }
 
     #[test]
     #[ignore = "Requires actual CPU data - run with: cargo test cpu -- --ignored"]
+    #[allow(clippy::unused_enumerate_index)]
     fn test_print_cpu_info() {
         println!("\n=== CPU Information Test ===");
 
@@ -788,6 +1606,25 @@ mod tests {
         assert!(count > 0, "Core count should be at least 1");
     }
 
+    #[tokio::test]
+    async fn test_cpu_monitor_get_stats_async() {
+        let mut monitor = CpuMonitor::new();
+        let first = monitor.get_stats_async().await;
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        let stats = monitor.get_stats_async().await;
+
+        assert_eq!(first.cores.len(), stats.cores.len());
+        assert!(stats.usage >= 0.0 && stats.usage <= 100.0);
+        assert_eq!(stats.frequency, max_core_frequency(&stats.cores));
+    }
+
+    #[test]
+    fn test_parse_cpuinfo_processors() {
+        let content =
+            "processor\t: 0\nmodel name\t: ARMv8 Processor rev 1 (v8l)\n\nprocessor\t: 1\n";
+        assert_eq!(parse_cpuinfo_processors(content), vec![0, 1]);
+    }
+
     #[tokio::test]
     async fn test_cpu_stats_get_async() {
         let stats = CpuStats::get_async().await;
@@ -0,0 +1,206 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Disk usage module
+//!
+//! Reports the usage of the root filesystem and of every other mounted
+//! NVMe, SD card or USB drive partition, read with `statvfs(3)`.
+//! Filesystems that cannot be queried are left out.
+
+use crate::modules::collector::Collector;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+/// Block devices worth reporting besides the root filesystem
+const DEVICE_PREFIXES: &[&str] = &["/dev/nvme", "/dev/mmcblk", "/dev/sd"];
+
+/// Usage of a mounted filesystem in bytes
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DiskStats {
+    pub mount: String,
+    pub total: u64,
+    pub used: u64,
+    /// Space available to unprivileged users
+    pub available: u64,
+}
+
+impl DiskStats {
+    /// Used space as a percentage of the total, 0 when the size is unknown
+    pub fn used_percent(&self) -> f32 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        (self.used as f64 / self.total as f64 * 100.0) as f32
+    }
+}
+
+/// Usage of the root filesystem followed by the other disk mounts
+pub fn read_disks() -> Vec<DiskStats> {
+    let mounts = fs::read_to_string("/proc/mounts").unwrap_or_default();
+
+    disk_mounts(&mounts)
+        .iter()
+        .filter_map(|mount| statvfs(Path::new(mount)))
+        .collect()
+}
+
+/// Mount points to report: `/` first, then each disk partition once
+///
+/// A device mounted at several places is only reported at its first mount.
+fn disk_mounts(proc_mounts: &str) -> Vec<String> {
+    let mut mounts = vec!["/".to_string()];
+    let mut devices = HashSet::new();
+
+    for line in proc_mounts.lines() {
+        let mut fields = line.split_whitespace();
+        let (Some(device), Some(mount)) = (fields.next(), fields.next()) else {
+            continue;
+        };
+        let mount = unescape_mount(mount);
+
+        if mount == "/" {
+            devices.insert(device);
+            continue;
+        }
+        if DEVICE_PREFIXES.iter().any(|p| device.starts_with(p)) && devices.insert(device) {
+            mounts.push(mount);
+        }
+    }
+
+    mounts
+}
+
+/// Decode the octal escapes (`\040` for a space) of a `/proc/mounts` path
+pub(crate) fn unescape_mount(mount: &str) -> String {
+    let mut out = String::with_capacity(mount.len());
+    let mut rest = mount;
+
+    while let Some(pos) = rest.find('\\') {
+        out.push_str(&rest[..pos]);
+        let escaped = rest.get(pos + 1..pos + 4);
+        match escaped.and_then(|e| u8::from_str_radix(e, 8).ok()) {
+            Some(byte) => {
+                out.push(byte as char);
+                rest = &rest[pos + 4..];
+            }
+            None => {
+                out.push('\\');
+                rest = &rest[pos + 1..];
+            }
+        }
+    }
+    out.push_str(rest);
+
+    out
+}
+
+/// Query the usage of the filesystem mounted at `mount`
+#[cfg(target_os = "linux")]
+fn statvfs(mount: &Path) -> Option<DiskStats> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = CString::new(mount.as_os_str().as_bytes()).ok()?;
+    let mut stat = std::mem::MaybeUninit::<libc::statvfs>::uninit();
+    // SAFETY: `path` is NUL-terminated and `stat` is only read after
+    // statvfs reported success, which means it was filled in.
+    let stat = unsafe {
+        if libc::statvfs(path.as_ptr(), stat.as_mut_ptr()) != 0 {
+            return None;
+        }
+        stat.assume_init()
+    };
+
+    let block = stat.f_frsize as u64;
+    let blocks = stat.f_blocks as u64;
+    Some(DiskStats {
+        mount: mount.to_string_lossy().into_owned(),
+        total: blocks * block,
+        used: blocks.saturating_sub(stat.f_bfree as u64) * block,
+        available: stat.f_bavail as u64 * block,
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn statvfs(_mount: &Path) -> Option<DiskStats> {
+    None
+}
+
+/// Disk collector for the monitoring loop
+#[derive(Debug, Default)]
+pub struct DiskMonitor;
+
+impl DiskMonitor {
+    /// Create a new disk monitor
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Collector for DiskMonitor {
+    type Stats = Vec<DiskStats>;
+
+    fn collect(&mut self) -> Self::Stats {
+        read_disks()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_used_percent() {
+        let disk = DiskStats {
+            mount: "/".to_string(),
+            total: 64_000_000_000,
+            used: 16_000_000_000,
+            available: 44_000_000_000,
+        };
+        assert_eq!(disk.used_percent(), 25.0);
+
+        let full = DiskStats {
+            total: 1024,
+            used: 1024,
+            ..Default::default()
+        };
+        assert_eq!(full.used_percent(), 100.0);
+        assert_eq!(DiskStats::default().used_percent(), 0.0);
+    }
+
+    #[test]
+    fn test_disk_mounts() {
+        let proc_mounts = "\
+/dev/mmcblk0p1 / ext4 rw,relatime 0 0
+proc /proc proc rw,nosuid,nodev,noexec,relatime 0 0
+tmpfs /run tmpfs rw,nosuid,nodev 0 0
+/dev/nvme0n1p1 /mnt/nvme ext4 rw,relatime 0 0
+/dev/nvme0n1p1 /var/lib/docker ext4 rw,relatime 0 0
+/dev/sda1 /media/usb\\040drive vfat rw 0 0
+/dev/mmcblk0p1 /snap ext4 rw,relatime 0 0
+";
+        assert_eq!(
+            disk_mounts(proc_mounts),
+            vec!["/", "/mnt/nvme", "/media/usb drive"]
+        );
+        assert_eq!(disk_mounts(""), vec!["/"]);
+    }
+
+    #[test]
+    fn test_unescape_mount() {
+        assert_eq!(unescape_mount("/media/usb\\040drive"), "/media/usb drive");
+        assert_eq!(unescape_mount("/plain"), "/plain");
+        assert_eq!(unescape_mount("/trailing\\"), "/trailing\\");
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_read_root_filesystem() {
+        let disks = read_disks();
+        let root = disks.first().expect("root filesystem");
+        assert_eq!(root.mount, "/");
+        assert!(root.total > 0);
+        assert!(root.used <= root.total);
+    }
+}
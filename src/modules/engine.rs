@@ -1,10 +1,15 @@
 // SPDX-License-Identifier: LGPL-3.0
 // Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
 
-//! Engine monitoring module (APE, DLA, NVDEC, NVENC)
+//! Engine monitoring module (APE, DLA, NVDEC, NVENC, NVJPG, PVA, OFA)
 
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use crate::modules::sysfs;
+
+/// BPMP debugfs clock tree, one directory per clock with a `rate` file in Hz
+const BPMP_CLK_PATH: &str = "/sys/kernel/debug/bpmp/debug/clk";
 
 /// Engine statistics
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -15,6 +20,14 @@ pub struct EngineStats {
     pub nvdec: EngineStatus,
     pub nvenc: EngineStatus,
     pub nvjpg: EngineStatus,
+    /// Programmable Vision Accelerators (Orin, Thor)
+    #[serde(default)]
+    pub pva0: EngineStatus,
+    #[serde(default)]
+    pub pva1: EngineStatus,
+    /// Optical Flow Accelerator (Orin, Thor)
+    #[serde(default)]
+    pub ofa: EngineStatus,
 }
 
 /// Individual engine status
@@ -29,115 +42,288 @@ pub struct EngineStatus {
 impl EngineStats {
     /// Get current engine statistics
     pub fn get() -> Self {
+        // Missing devfreq nodes are reported as disabled engines
         let path = Path::new("/sys/class/devfreq");
-
-        if !path.exists() {
-            return EngineStats::default();
-        }
+        let clk_path = Path::new(BPMP_CLK_PATH);
 
         EngineStats {
-            ape: read_engine_status(path, "ape"),
-            dla0: read_engine_status(path, "dla0"),
-            dla1: read_engine_status(path, "dla1"),
-            nvdec: read_nvdec_status(path),
-            nvenc: read_nvenc_status(path),
-            nvjpg: read_nvjpg_status(path),
+            ape: read_engine_status(path, clk_path, "ape"),
+            dla0: read_engine_status(path, clk_path, "dla0"),
+            dla1: read_engine_status(path, clk_path, "dla1"),
+            nvdec: read_nvdec_status(path, clk_path),
+            nvenc: read_nvenc_status(path, clk_path),
+            nvjpg: read_nvjpg_status(path, clk_path),
+            pva0: read_engine_status(path, clk_path, "pva0"),
+            pva1: read_engine_status(path, clk_path, "pva1"),
+            ofa: read_engine_status(path, clk_path, "ofa"),
         }
     }
 }
 
-/// Read engine status from devfreq
-fn read_engine_status(base_path: &Path, engine_name: &str) -> EngineStatus {
-    let engine_path = base_path.join(engine_name);
-
-    if !engine_path.exists() {
-        return EngineStatus {
-            name: engine_name.to_string(),
-            ..Default::default()
-        };
-    }
+/// Read engine status from devfreq and the BPMP clock tree
+///
+/// The clock comes from the devfreq `cur_freq` node and the usage from the
+/// actmon load the devfreq governor exposes as `device/load` (0-1000).
+/// Engines without devfreq scaling are still reported as enabled when their
+/// BPMP clock runs at a nonzero rate, which then also provides the clock.
+fn read_engine_status(base_path: &Path, clk_path: &Path, engine_name: &str) -> EngineStatus {
+    let clk_rate = sysfs::read_u32(clk_path.join(engine_name).join("rate")).unwrap_or(0);
+
+    let engine_path = match find_engine_devfreq(base_path, engine_name) {
+        Some(path) => path,
+        None => {
+            return EngineStatus {
+                name: engine_name.to_string(),
+                enabled: clk_rate > 0,
+                clock: clk_rate,
+                ..Default::default()
+            }
+        }
+    };
 
-    let enabled = engine_path.join("available_frequencies").exists();
+    let enabled = engine_path.join("available_frequencies").exists() || clk_rate > 0;
 
-    let clock = read_sysfs_u32(&engine_path, "cur_freq").unwrap_or(0);
+    let clock = sysfs::read_u32(engine_path.join("cur_freq"))
+        .filter(|&freq| freq > 0)
+        .unwrap_or(clk_rate);
 
     EngineStatus {
         name: engine_name.to_string(),
         enabled,
-        usage: 0,
+        usage: read_engine_load(&engine_path),
         clock,
     }
 }
 
-/// Read NVDEC engine status
-fn read_nvdec_status(_base_path: &Path) -> EngineStatus {
-    let engine_name = "nvdec";
-
-    let usage_path = Path::new("/sys/kernel/nvdec_usage");
-    let usage = if usage_path.exists() {
-        read_sysfs_u32(usage_path, "usage").unwrap_or(0) as u8
-    } else {
-        0
-    };
-
-    EngineStatus {
-        name: engine_name.to_string(),
-        enabled: usage > 0,
-        usage,
-        clock: 0,
+/// Find the devfreq directory of an engine
+///
+/// Matches either a plain `<engine>` entry or the `<address>.<engine>`
+/// naming used by Tegra devfreq devices (e.g. `15380000.nvjpg`).
+fn find_engine_devfreq(base_path: &Path, engine_name: &str) -> Option<PathBuf> {
+    let direct = base_path.join(engine_name);
+    if direct.exists() {
+        return Some(direct);
     }
-}
 
-/// Read NVENC engine status
-fn read_nvenc_status(_base_path: &Path) -> EngineStatus {
-    let engine_name = "nvenc";
-
-    let usage_path = Path::new("/sys/kernel/nvenc_usage");
-    let usage = if usage_path.exists() {
-        read_sysfs_u32(usage_path, "usage").unwrap_or(0) as u8
-    } else {
-        0
-    };
-
-    EngineStatus {
-        name: engine_name.to_string(),
-        enabled: usage > 0,
-        usage,
-        clock: 0,
-    }
+    let suffix = format!(".{}", engine_name);
+    fs::read_dir(base_path)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .find(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.ends_with(&suffix))
+        })
 }
 
-/// Read NVJPG engine status
-fn read_nvjpg_status(_base_path: &Path) -> EngineStatus {
-    let engine_name = "nvjpg";
-
-    let usage_path = Path::new("/sys/kernel/nvjpg_usage");
-    let usage = if usage_path.exists() {
-        read_sysfs_u32(usage_path, "usage").unwrap_or(0) as u8
-    } else {
-        0
-    };
+/// Read the actmon load of an engine devfreq node as a percentage
+fn read_engine_load(engine_path: &Path) -> u8 {
+    sysfs::read_u32(engine_path.join("device").join("load"))
+        .map(|load| (load.min(1000) / 10) as u8)
+        .unwrap_or(0)
+}
 
-    EngineStatus {
-        name: engine_name.to_string(),
-        enabled: usage > 0,
-        usage,
-        clock: 0,
-    }
+/// Read NVDEC engine status
+///
+/// Boards where the decoder is only a host1x client without devfreq
+/// scaling still expose its clock as `nvdec` in the BPMP clock tree.
+fn read_nvdec_status(base_path: &Path, clk_path: &Path) -> EngineStatus {
+    read_engine_status(base_path, clk_path, "nvdec")
 }
 
-/// Read a u32 value from sysfs
-fn read_sysfs_u32(path: &Path, file: &str) -> Option<u32> {
-    let file_path = path.join(file);
+/// Read NVENC engine status
+///
+/// Like NVDEC, falls back to the BPMP `nvenc` clock without devfreq.
+fn read_nvenc_status(base_path: &Path, clk_path: &Path) -> EngineStatus {
+    read_engine_status(base_path, clk_path, "nvenc")
+}
 
-    fs::read_to_string(file_path)
-        .ok()
-        .and_then(|s| s.trim().parse().ok())
+/// Read NVJPG engine status
+fn read_nvjpg_status(base_path: &Path, clk_path: &Path) -> EngineStatus {
+    read_engine_status(base_path, clk_path, "nvjpg")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modules::sysfs::MockDir;
+
+    /// Create a mocked engine devfreq node with a clock and actmon load
+    fn mock_engine(base: &Path, entry: &str, cur_freq: u32, load: Option<u32>) {
+        let dir = base.join(entry);
+        fs::create_dir_all(dir.join("device")).unwrap();
+        fs::write(dir.join("cur_freq"), format!("{}\n", cur_freq)).unwrap();
+        fs::write(
+            dir.join("available_frequencies"),
+            "115200000 384000000 729600000\n",
+        )
+        .unwrap();
+        if let Some(load) = load {
+            fs::write(dir.join("device").join("load"), format!("{}\n", load)).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_nvjpg_clock_and_usage_from_devfreq() {
+        let base = MockDir::new("devfreq-nvjpg");
+        mock_engine(&base, "15380000.nvjpg", 729600000, Some(425));
+
+        let status = read_nvjpg_status(&base, &base.join("clk"));
+        assert_eq!(status.name, "nvjpg");
+        assert!(status.enabled);
+        assert_eq!(status.clock, 729600000);
+        assert_eq!(status.usage, 42);
+    }
+
+    #[test]
+    fn test_nvjpg_without_actmon_load() {
+        let base = MockDir::new("devfreq-nvjpg-noload");
+        mock_engine(&base, "nvjpg", 115200000, None);
+
+        let status = read_nvjpg_status(&base, &base.join("clk"));
+        assert!(status.enabled);
+        assert_eq!(status.clock, 115200000);
+        assert_eq!(status.usage, 0);
+    }
+
+    #[test]
+    fn test_nvjpg_missing_devfreq() {
+        let base = MockDir::new("devfreq-nvjpg-missing");
+        mock_engine(&base, "15480000.nvdec", 729600000, Some(1000));
+
+        let status = read_nvjpg_status(&base, &base.join("clk"));
+        assert_eq!(status.name, "nvjpg");
+        assert!(!status.enabled);
+        assert_eq!(status.clock, 0);
+        assert_eq!(status.usage, 0);
+    }
+
+    #[test]
+    fn test_nvdec_and_nvenc_from_devfreq() {
+        let base = MockDir::new("devfreq-video");
+        mock_engine(&base, "15480000.nvdec", 729600000, Some(615));
+        mock_engine(&base, "nvenc", 115200000, None);
+
+        let nvdec = read_nvdec_status(&base, &base.join("clk"));
+        assert_eq!(nvdec.name, "nvdec");
+        assert!(nvdec.enabled);
+        assert_eq!(nvdec.usage, 61);
+        assert_eq!(nvdec.clock, 729600000);
+
+        let nvenc = read_nvenc_status(&base, &base.join("clk"));
+        assert_eq!(nvenc.name, "nvenc");
+        assert!(nvenc.enabled);
+        assert_eq!(nvenc.usage, 0);
+    }
+
+    #[test]
+    fn test_nvenc_clock_from_devfreq() {
+        let base = MockDir::new("devfreq-nvenc-clock");
+        mock_engine(&base, "154c0000.nvenc", 716800000, Some(200));
+
+        let status = read_nvenc_status(&base, &base.join("clk"));
+        assert!(status.enabled);
+        assert_eq!(status.clock, 716800000);
+        assert_eq!(status.usage, 20);
+    }
+
+    #[test]
+    fn test_nvdec_clock_from_bpmp_without_devfreq() {
+        let base = MockDir::new("devfreq-nvdec-clk");
+        mock_clk(&base, "nvdec", 115200000);
+
+        let status = read_nvdec_status(&base, &base.join("clk"));
+        assert!(status.enabled);
+        assert_eq!(status.clock, 115200000);
+        assert_eq!(status.usage, 0);
+    }
+
+    #[test]
+    fn test_video_engines_missing_devfreq() {
+        let base = MockDir::new("devfreq-video-missing");
+        mock_engine(&base, "15380000.nvjpg", 729600000, Some(500));
+
+        let clk = base.join("clk");
+        for status in [
+            read_nvdec_status(&base, &clk),
+            read_nvenc_status(&base, &clk),
+        ] {
+            assert!(!status.enabled);
+            assert_eq!(status.usage, 0);
+            assert_eq!(status.clock, 0);
+        }
+    }
+
+    /// Create a mocked BPMP clock node with the given rate
+    fn mock_clk(base: &Path, clk: &str, rate: u32) {
+        let dir = base.join("clk").join(clk);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("rate"), format!("{}\n", rate)).unwrap();
+    }
+
+    #[test]
+    fn test_engine_enabled_from_bpmp_clk_without_devfreq() {
+        let base = MockDir::new("devfreq-clk-only");
+        mock_clk(&base, "ape", 230400000);
+
+        let status = read_engine_status(&base, &base.join("clk"), "ape");
+        assert_eq!(status.name, "ape");
+        assert!(status.enabled);
+        assert_eq!(status.clock, 230400000);
+        assert_eq!(status.usage, 0);
+    }
+
+    #[test]
+    fn test_engine_enabled_from_bpmp_clk_without_available_frequencies() {
+        let base = MockDir::new("devfreq-clk-noavail");
+        mock_engine(&base, "15880000.dla0", 0, Some(300));
+        fs::remove_file(base.join("15880000.dla0").join("available_frequencies")).unwrap();
+        mock_clk(&base, "dla0", 1600000000);
+
+        let status = read_engine_status(&base, &base.join("clk"), "dla0");
+        assert!(status.enabled);
+        assert_eq!(status.clock, 1600000000);
+        assert_eq!(status.usage, 30);
+    }
+
+    #[test]
+    fn test_engine_gated_bpmp_clk_is_disabled() {
+        let base = MockDir::new("devfreq-clk-gated");
+        mock_clk(&base, "ape", 0);
+
+        let status = read_engine_status(&base, &base.join("clk"), "ape");
+        assert!(!status.enabled);
+        assert_eq!(status.clock, 0);
+    }
+
+    #[test]
+    fn test_engine_load_is_clamped() {
+        let base = MockDir::new("devfreq-load-clamp");
+        mock_engine(&base, "nvjpg", 729600000, Some(4000));
+
+        assert_eq!(read_engine_load(&base.join("nvjpg")), 100);
+    }
+
+    #[test]
+    fn test_pva_and_ofa_from_devfreq() {
+        let base = MockDir::new("devfreq-pva-ofa");
+        mock_engine(&base, "16000000.pva0", 1152000000, Some(350));
+        mock_engine(&base, "15a50000.ofa", 1164200000, None);
+
+        let pva0 = read_engine_status(&base, &base.join("clk"), "pva0");
+        assert!(pva0.enabled);
+        assert_eq!(pva0.usage, 35);
+
+        let ofa = read_engine_status(&base, &base.join("clk"), "ofa");
+        assert!(ofa.enabled);
+        assert_eq!(ofa.clock, 1164200000);
+
+        // Boards without a second PVA report it as disabled
+        let pva1 = read_engine_status(&base, &base.join("clk"), "pva1");
+        assert_eq!(pva1.name, "pva1");
+        assert!(!pva1.enabled);
+    }
 
     #[test]
     fn test_engine_stats_default() {
@@ -148,6 +334,9 @@ mod tests {
         assert!(!stats.nvdec.enabled);
         assert!(!stats.nvenc.enabled);
         assert!(!stats.nvjpg.enabled);
+        assert!(!stats.pva0.enabled);
+        assert!(!stats.pva1.enabled);
+        assert!(!stats.ofa.enabled);
     }
 
     #[test]
@@ -213,6 +402,7 @@ mod tests {
                 usage: 50,
                 clock: 0,
             },
+            ..Default::default()
         };
 
         assert!(stats.ape.enabled);
@@ -299,6 +489,19 @@ mod tests {
                 usage: 50,
                 clock: 0,
             },
+            pva0: EngineStatus {
+                name: "pva0".to_string(),
+                enabled: true,
+                usage: 35,
+                clock: 1152000000,
+            },
+            pva1: EngineStatus::default(),
+            ofa: EngineStatus {
+                name: "ofa".to_string(),
+                enabled: true,
+                usage: 12,
+                clock: 1164200000,
+            },
         };
 
         let json = serde_json::to_string(&stats);
@@ -306,6 +509,13 @@ mod tests {
 
         let deserialized: Result<EngineStats, _> = serde_json::from_str(&json.unwrap());
         assert!(deserialized.is_ok(), "EngineStats should be deserializable");
+
+        let deserialized = deserialized.unwrap();
+        assert_eq!(deserialized.pva0.name, "pva0");
+        assert_eq!(deserialized.pva0.clock, 1152000000);
+        assert!(!deserialized.pva1.enabled);
+        assert_eq!(deserialized.ofa.usage, 12);
+        assert_eq!(deserialized.ofa.clock, 1164200000);
     }
 
     #[test]
@@ -368,16 +578,16 @@ mod tests {
             stats.dla1.name, stats.dla1.enabled, stats.dla1.usage, stats.dla1.clock
         );
         println!(
-            "NVDEC: {} (enabled: {}, usage: {}%)",
-            stats.nvdec.name, stats.nvdec.enabled, stats.nvdec.usage
+            "NVDEC: {} (enabled: {}, usage: {}%, clock: {} Hz)",
+            stats.nvdec.name, stats.nvdec.enabled, stats.nvdec.usage, stats.nvdec.clock
         );
         println!(
-            "NVENC: {} (enabled: {}, usage: {}%)",
-            stats.nvenc.name, stats.nvenc.enabled, stats.nvenc.usage
+            "NVENC: {} (enabled: {}, usage: {}%, clock: {} Hz)",
+            stats.nvenc.name, stats.nvenc.enabled, stats.nvenc.usage, stats.nvenc.clock
         );
         println!(
-            "NVJPG: {} (enabled: {}, usage: {}%)",
-            stats.nvjpg.name, stats.nvjpg.enabled, stats.nvjpg.usage
+            "NVJPG: {} (enabled: {}, usage: {}%, clock: {} Hz)",
+            stats.nvjpg.name, stats.nvjpg.enabled, stats.nvjpg.usage, stats.nvjpg.clock
         );
 
         println!("\n=== Test Complete ===");
@@ -3,9 +3,14 @@
 
 //! Fan control module
 
+use crate::modules::collector::Collector;
+use crate::modules::sysfs;
 use crate::modules::temperature::TemperatureStats;
+use crate::Error;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
 
 /// Fan statistics
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -45,11 +50,22 @@ pub struct FanInfo {
     pub name: String,
     pub speed: u8,
     pub rpm: u32,
+    /// PWM node controlling this fan, `None` when it cannot be controlled
+    #[serde(default)]
+    pub pwm_path: Option<PathBuf>,
 }
 
 impl FanStats {
     /// Get current fan statistics
     pub fn get() -> Self {
+        Self::with_temperature(&TemperatureStats::get())
+    }
+
+    /// Get current fan statistics, correlated with already read temperatures
+    ///
+    /// Saves a second thermal zone scan when the caller reads temperatures
+    /// anyway.
+    pub fn with_temperature(temperatures: &TemperatureStats) -> Self {
         let path = Path::new("/sys/class/thermal");
 
         if !path.exists() {
@@ -61,63 +77,297 @@ impl FanStats {
             ..Default::default()
         };
 
+        // Tachometers live in hwmon, apart from the cooling devices
+        let rpm = read_fan_rpm();
+        apply_fan_rpm(&mut stats.fans, &rpm);
+        apply_fan_pwm(
+            &mut stats.fans,
+            path,
+            &read_pwm_devices_in(Path::new(HWMON_SYSFS)),
+        );
+
         // Calculate overall speed and RPM
         if !stats.fans.is_empty() {
             stats.speed = (stats.fans.iter().map(|f| f.speed as u32).sum::<u32>()
                 / stats.fans.len() as u32) as u8;
-            stats.rpm = stats.fans.iter().map(|f| f.rpm).sum::<u32>() / stats.fans.len() as u32;
+        }
+        if !rpm.is_empty() {
+            stats.rpm = rpm.iter().sum::<u32>() / rpm.len() as u32;
         }
 
         // Detect fan mode
-        stats.mode = detect_fan_mode(&stats.fans);
+        stats.mode = read_fan_mode().unwrap_or_else(|| detect_fan_mode(&stats.fans));
 
-        // Read temperature for correlation
-        let temp_stats = TemperatureStats::get();
-        stats.temperature = correlate_fan_temp(&stats, &temp_stats);
+        stats.temperature = correlate_fan_temp(&stats, temperatures);
 
         stats
     }
 
     /// Get fan statistics with temperature correlation
     pub fn get_with_temp() -> Self {
-        let mut stats = Self::get();
-        let temp_stats = TemperatureStats::get();
-        stats.temperature = correlate_fan_temp(&stats, &temp_stats);
-        stats
+        Self::get()
     }
 
     /// Set fan speed (requires root)
-    pub fn set_speed(speed: u8) -> anyhow::Result<()> {
+    ///
+    /// Returns the sysfs interface the speed was written to.
+    pub fn set_speed(speed: u8) -> crate::Result<FanControlBackend> {
         if speed > 100 {
-            return Err(anyhow::anyhow!("Speed must be 0-100"));
+            return Err(Error::InvalidArgument("Speed must be 0-100".to_string()));
         }
 
-        let path = Path::new("/sys/class/thermal");
+        set_speed_in(Path::new(HWMON_SYSFS), Path::new(THERMAL_SYSFS), speed)
+    }
 
-        if !path.exists() {
-            return Err(anyhow::anyhow!("Thermal system not found"));
+    /// Set the speed of the single fan with [`FanInfo::index`] `index`
+    /// (requires root)
+    ///
+    /// Only that fan's PWM channel is written, so boards with several fans
+    /// can run them at different speeds. Returns the PWM node written.
+    pub fn set_speed_for(index: usize, speed: u8) -> crate::Result<PathBuf> {
+        if speed > 100 {
+            return Err(Error::InvalidArgument("Speed must be 0-100".to_string()));
         }
 
-        // Set all cooling devices to manual mode
-        for fan in read_cooling_devices(path) {
-            let fan_path_str = format!("/sys/class/thermal/cooling_device{}", fan.index);
-            let fan_path = Path::new(&fan_path_str);
+        set_speed_for_in(
+            Path::new(HWMON_SYSFS),
+            Path::new(THERMAL_SYSFS),
+            index,
+            speed,
+        )
+    }
+
+    /// Restore thermal-governed fan control (requires root)
+    ///
+    /// Undoes `set_speed()`. Which node actually governs auto mode depends
+    /// on the L4T release:
+    ///
+    /// - Xavier (L4T 32): `/sys/devices/pwm-fan/temp_control`, where `1`
+    ///   lets the pwm-fan driver follow its temperature table
+    /// - Orin (L4T 35+): `pwm1_enable` of the pwm-fan hwmon device, where
+    ///   `2` hands the fan back to `nvfancontrol`
+    /// - Without either, the cooling device state driven by the thermal
+    ///   governor
+    pub fn set_auto() -> crate::Result<FanControlBackend> {
+        set_auto_in(
+            Path::new(HWMON_SYSFS),
+            Path::new(THERMAL_SYSFS),
+            Path::new(PWM_FAN_SYSFS),
+        )
+    }
+}
+
+const THERMAL_SYSFS: &str = "/sys/class/thermal";
+const HWMON_SYSFS: &str = "/sys/class/hwmon";
+/// Legacy pwm-fan driver node used by Xavier and older releases
+const PWM_FAN_SYSFS: &str = "/sys/devices/pwm-fan";
+
+/// sysfs interface used to control the fan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FanControlBackend {
+    /// hwmon device exposing `pwm1` and its `pwm1_enable` mode
+    Hwmon(PathBuf),
+    /// `cooling_deviceN` entries of the thermal framework
+    CoolingDevice,
+    /// Legacy pwm-fan `temp_control` node, only used to restore automatic
+    /// control on boards without another interface
+    TempControl(PathBuf),
+}
 
-            // Set to manual mode
-            let mode_path = fan_path.join("cur_state");
-            fs::write(mode_path, "disabled")?;
+/// Detect how the fan can be controlled, preferring hwmon PWM
+pub fn detect_fan_backend() -> Option<FanControlBackend> {
+    detect_fan_backend_in(Path::new(HWMON_SYSFS), Path::new(THERMAL_SYSFS))
+}
 
-            // Set PWM value
-            let pwm_path = fan_path.join("cur_pwm");
-            let pwm_value = (speed as u32 * 255 / 100).min(255);
-            fs::write(pwm_path, pwm_value.to_string())?;
+fn detect_fan_backend_in(hwmon_base: &Path, thermal_base: &Path) -> Option<FanControlBackend> {
+    let fans = read_cooling_devices(thermal_base);
+    if let Some(path) = find_fan_hwmon(&fans, thermal_base, &read_pwm_devices_in(hwmon_base)) {
+        return Some(FanControlBackend::Hwmon(path));
+    }
+
+    if !fans.is_empty() {
+        return Some(FanControlBackend::CoolingDevice);
+    }
+
+    None
+}
+
+/// Pick the hwmon chip whose `pwm1` drives the fan
+///
+/// Other chips, such as PMICs, may expose PWM outputs too, so the chip linked
+/// from a fan cooling device wins, then a pwm-fan chip, and only then the
+/// lowest-numbered chip with `pwm1`.
+fn find_fan_hwmon(fans: &[FanInfo], thermal_base: &Path, devices: &[PwmDevice]) -> Option<PathBuf> {
+    let linked: Vec<PathBuf> = fans
+        .iter()
+        .filter_map(|fan| {
+            let cooling_path = thermal_base.join(format!("cooling_device{}", fan.index));
+            fs::canonicalize(cooling_path.join("device")).ok()
+        })
+        .collect();
+    let pwm1 = |device: &PwmDevice| {
+        device
+            .channels
+            .iter()
+            .find(|pwm| pwm.file_name().is_some_and(|name| name == "pwm1"))
+            .and_then(|pwm| pwm.parent())
+            .map(Path::to_path_buf)
+    };
+
+    devices
+        .iter()
+        .filter(|d| d.device.as_ref().is_some_and(|link| linked.contains(link)))
+        .chain(devices.iter().filter(|d| d.is_pwm_fan))
+        .chain(devices)
+        .find_map(pwm1)
+}
+
+/// List hwmon devices ordered by number, so `hwmon10` follows `hwmon2`
+fn read_hwmon_devices(hwmon_base: &Path) -> Vec<PathBuf> {
+    let mut devices: Vec<(u32, PathBuf)> = fs::read_dir(hwmon_base)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .map(|path| {
+            let index = path
+                .file_name()
+                .and_then(|s| s.to_str())
+                .and_then(|s| s.strip_prefix("hwmon"))
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(u32::MAX);
+            (index, path)
+        })
+        .collect();
+    devices.sort();
+
+    devices.into_iter().map(|(_, path)| path).collect()
+}
+
+fn set_speed_in(
+    hwmon_base: &Path,
+    thermal_base: &Path,
+    speed: u8,
+) -> crate::Result<FanControlBackend> {
+    let backend = detect_fan_backend_in(hwmon_base, thermal_base)
+        .ok_or_else(|| Error::HardwareNotFound("No fan found".to_string()))?;
+    let pwm_value = (speed as u32 * 255 / 100).min(255);
+
+    match &backend {
+        FanControlBackend::Hwmon(path) => {
+            // 1 selects manual PWM control
+            sysfs::write(path.join("pwm1_enable"), "1")?;
+            sysfs::write(path.join("pwm1"), pwm_value.to_string())?;
+        }
+        FanControlBackend::CoolingDevice => {
+            for fan in read_cooling_devices(thermal_base) {
+                let fan_path = thermal_base.join(format!("cooling_device{}", fan.index));
+
+                // cur_state only accepts an integer between 0 and max_state
+                let max_state = sysfs::read_u32(fan_path.join("max_state")).unwrap_or(0);
+                sysfs::write(
+                    fan_path.join("cur_state"),
+                    percent_to_state(speed, max_state).to_string(),
+                )?;
+
+                let cur_pwm = fan_path.join("cur_pwm");
+                if cur_pwm.exists() {
+                    sysfs::write(cur_pwm, pwm_value.to_string())?;
+                }
+            }
         }
+        // temp_control only toggles automatic mode
+        FanControlBackend::TempControl(_) => {
+            return Err(Error::HardwareNotFound("No fan found".to_string()))
+        }
+    }
 
-        Ok(())
+    Ok(backend)
+}
+
+fn set_speed_for_in(
+    hwmon_base: &Path,
+    thermal_base: &Path,
+    index: usize,
+    speed: u8,
+) -> crate::Result<PathBuf> {
+    let mut fans = read_cooling_devices(thermal_base);
+    apply_fan_pwm(&mut fans, thermal_base, &read_pwm_devices_in(hwmon_base));
+
+    let Some(fan) = fans.iter().find(|fan| fan.index == index) else {
+        let available: Vec<String> = fans.iter().map(|fan| fan.index.to_string()).collect();
+        return Err(Error::InvalidArgument(format!(
+            "No fan with index {} (available: {})",
+            index,
+            if available.is_empty() {
+                "none".to_string()
+            } else {
+                available.join(", ")
+            }
+        )));
+    };
+    let pwm = fan
+        .pwm_path
+        .clone()
+        .ok_or_else(|| Error::HardwareNotFound(format!("Fan {} has no PWM control", index)))?;
+
+    write_pwm(&pwm, speed)?;
+    Ok(pwm)
+}
+
+/// Switch a PWM node to manual control and write `speed` percent to it
+fn write_pwm(pwm: &Path, speed: u8) -> crate::Result<()> {
+    let pwm_value = (speed as u32 * 255 / 100).min(255);
+    let name = pwm.file_name().and_then(|s| s.to_str()).unwrap_or("");
+
+    // 1 selects manual PWM control; cooling device cur_pwm has no mode
+    let enable = pwm.with_file_name(format!("{}_enable", name));
+    if name != "cur_pwm" && enable.exists() {
+        sysfs::write(enable, "1")?;
     }
+
+    sysfs::write(pwm, pwm_value.to_string())
 }
 
-/// Read all cooling devices
+fn set_auto_in(
+    hwmon_base: &Path,
+    thermal_base: &Path,
+    pwm_fan: &Path,
+) -> crate::Result<FanControlBackend> {
+    let temp_control = pwm_fan.join("temp_control");
+    let backend = match detect_fan_backend_in(hwmon_base, thermal_base) {
+        Some(backend) => backend,
+        // Xavier-style boards may only expose the pwm-fan node
+        None if temp_control.exists() => FanControlBackend::TempControl(temp_control.clone()),
+        None => return Err(Error::HardwareNotFound("No fan found".to_string())),
+    };
+
+    match &backend {
+        FanControlBackend::Hwmon(path) => {
+            // 2 selects automatic control
+            sysfs::write(path.join("pwm1_enable"), "2")?;
+        }
+        // The thermal governor sets cur_state again on its next update
+        FanControlBackend::CoolingDevice | FanControlBackend::TempControl(_) => {}
+    }
+
+    if temp_control.exists() {
+        sysfs::write(temp_control, "1")?;
+    }
+
+    Ok(backend)
+}
+
+/// Whether a cooling device drives a fan
+///
+/// The thermal framework also registers cpufreq and processor cooling
+/// devices, whose states throttle the CPUs instead. Devices without a
+/// `type` are assumed to be fans.
+fn is_fan_cooling_device(cooling_path: &Path) -> bool {
+    sysfs::read_string(cooling_path.join("type")).is_none_or(|kind| kind.contains("fan"))
+}
+
+/// Read the cooling devices that drive fans
 fn read_cooling_devices(base_path: &Path) -> Vec<FanInfo> {
     let mut fans = Vec::new();
 
@@ -131,8 +381,9 @@ fn read_cooling_devices(base_path: &Path) -> Vec<FanInfo> {
                 .and_then(|s| s.to_str())
                 .map(|s| s.starts_with("cooling_device"))
                 .unwrap_or(false)
+                && is_fan_cooling_device(&cooling_path)
             {
-                // This is a cooling device, continue processing
+                // This is a fan cooling device, continue processing
             } else {
                 continue;
             }
@@ -148,48 +399,205 @@ fn read_cooling_devices(base_path: &Path) -> Vec<FanInfo> {
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(0);
 
-            // Read current state
-            let state_path = cooling_path.join("cur_state");
-            let max_state = read_sysfs_u32(&state_path).unwrap_or(1);
-
             // Read current speed (in state count)
-            let cur_state = read_sysfs_u32(&state_path).unwrap_or(0);
-            let speed = if max_state > 0 {
-                ((cur_state as f32 / max_state as f32) * 100.0) as u8
-            } else {
-                0
-            };
-
-            // Read RPM (if available)
-            let rpm_path = cooling_path.join("fan1_input");
-            let rpm = read_sysfs_u32(&rpm_path).unwrap_or(0);
+            let max_state = sysfs::read_u32(cooling_path.join("max_state")).unwrap_or(0);
+            let cur_state = sysfs::read_u32(cooling_path.join("cur_state")).unwrap_or(0);
+            let speed = state_to_percent(cur_state, max_state);
 
+            let cur_pwm = cooling_path.join("cur_pwm");
             fans.push(FanInfo {
                 index,
                 name: fan_name.to_string(),
                 speed,
-                rpm,
+                rpm: 0,
+                pwm_path: cur_pwm.exists().then_some(cur_pwm),
             });
         }
     }
 
+    fans.sort_by_key(|fan| fan.index);
     fans
 }
 
-/// Detect fan operating mode
-fn detect_fan_mode(fans: &[FanInfo]) -> FanMode {
-    if fans.is_empty() {
-        return FanMode::Unknown;
+/// Read fan tachometers, in RPM
+///
+/// Searches every hwmon device for `fanN_input` nodes and returns one
+/// reading per tachometer, ordered by device and fan number. Boards without
+/// a tachometer return an empty list.
+pub fn read_fan_rpm() -> Vec<u32> {
+    read_fan_rpm_in(Path::new(HWMON_SYSFS))
+}
+
+fn read_fan_rpm_in(hwmon_base: &Path) -> Vec<u32> {
+    let mut rpm = Vec::new();
+    for device in read_hwmon_devices(hwmon_base) {
+        let mut tachs: Vec<(u32, PathBuf)> = fs::read_dir(&device)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name();
+                let fan = name
+                    .to_str()?
+                    .strip_prefix("fan")?
+                    .strip_suffix("_input")?
+                    .parse()
+                    .ok()?;
+                Some((fan, entry.path()))
+            })
+            .collect();
+        tachs.sort();
+
+        rpm.extend(tachs.iter().filter_map(|(_, path)| sysfs::read_u32(path)));
     }
 
-    // Check if all fans are at 0 speed
-    if fans.iter().all(|f| f.speed == 0) {
+    rpm
+}
+
+/// Assign tachometer readings to fans in index order
+///
+/// Fans without a matching tachometer keep 0 RPM.
+fn apply_fan_rpm(fans: &mut [FanInfo], rpm: &[u32]) {
+    for (fan, rpm) in fans.iter_mut().zip(rpm) {
+        fan.rpm = *rpm;
+    }
+}
+
+/// PWM channels (`pwmN` nodes) of one hwmon device
+struct PwmDevice {
+    /// Resolved `device` link, shared with the matching cooling device
+    device: Option<PathBuf>,
+    /// Chip driven by the pwm-fan driver
+    is_pwm_fan: bool,
+    channels: Vec<PathBuf>,
+}
+
+/// Find hwmon devices with PWM channels, ordered by device and channel
+fn read_pwm_devices_in(hwmon_base: &Path) -> Vec<PwmDevice> {
+    let mut devices = Vec::new();
+    for device in read_hwmon_devices(hwmon_base) {
+        let mut pwms: Vec<(u32, PathBuf)> = fs::read_dir(&device)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name();
+                let channel = name.to_str()?.strip_prefix("pwm")?.parse().ok()?;
+                Some((channel, entry.path()))
+            })
+            .collect();
+        if pwms.is_empty() {
+            continue;
+        }
+        pwms.sort();
+
+        let name = sysfs::read_string(device.join("name")).unwrap_or_default();
+        devices.push(PwmDevice {
+            device: fs::canonicalize(device.join("device")).ok(),
+            is_pwm_fan: name.replace(['-', '_'], "") == "pwmfan",
+            channels: pwms.into_iter().map(|(_, path)| path).collect(),
+        });
+    }
+
+    devices
+}
+
+/// Assign hwmon PWM channels to the fans they drive
+///
+/// A cooling device whose `device` link points at the same device as an
+/// hwmon chip takes that chip's channels. Remaining fan cooling devices take
+/// the unused channels of pwm-fan chips in index order. Fans without a
+/// matching channel keep their cooling device `cur_pwm`.
+fn apply_fan_pwm(fans: &mut [FanInfo], thermal_base: &Path, devices: &[PwmDevice]) {
+    let mut used = vec![0; devices.len()];
+    let mut unmatched = Vec::new();
+
+    for fan in fans.iter_mut() {
+        let cooling_path = thermal_base.join(format!("cooling_device{}", fan.index));
+        let linked = fs::canonicalize(cooling_path.join("device"))
+            .ok()
+            .and_then(|link| {
+                devices
+                    .iter()
+                    .position(|d| d.device.as_ref() == Some(&link))
+            });
+
+        match linked {
+            Some(i) => {
+                if let Some(pwm) = devices[i].channels.get(used[i]) {
+                    fan.pwm_path = Some(pwm.clone());
+                    used[i] += 1;
+                }
+            }
+            None => unmatched.push(fan),
+        }
+    }
+
+    let spare = devices
+        .iter()
+        .zip(&used)
+        .filter(|(device, _)| device.is_pwm_fan)
+        .flat_map(|(device, used)| device.channels.iter().skip(*used));
+    for (fan, pwm) in unmatched.into_iter().zip(spare) {
+        fan.pwm_path = Some(pwm.clone());
+    }
+}
+
+/// Convert a cooling device state to a speed percentage
+///
+/// Drivers may briefly report a state above `max_state` while the maximum
+/// is being reconfigured, so the result is clamped to 100.
+fn state_to_percent(cur_state: u32, max_state: u32) -> u8 {
+    if max_state == 0 {
+        return 0;
+    }
+
+    (cur_state as f32 / max_state as f32 * 100.0).min(100.0) as u8
+}
+
+/// Convert a speed percentage to the nearest cooling device state
+fn percent_to_state(speed: u8, max_state: u32) -> u32 {
+    (speed.min(100) as u32 * max_state + 50) / 100
+}
+
+/// Read the fan mode from the `pwm1_enable` node of the hwmon fan
+///
+/// Returns `None` when the fan is not driven through hwmon.
+pub fn read_fan_mode() -> Option<FanMode> {
+    read_fan_mode_in(Path::new(HWMON_SYSFS), Path::new(THERMAL_SYSFS))
+}
+
+fn read_fan_mode_in(hwmon_base: &Path, thermal_base: &Path) -> Option<FanMode> {
+    match detect_fan_backend_in(hwmon_base, thermal_base)? {
+        FanControlBackend::Hwmon(path) => {
+            sysfs::read_u32(path.join("pwm1_enable")).map(fan_mode_from_pwm_enable)
+        }
+        FanControlBackend::CoolingDevice | FanControlBackend::TempControl(_) => None,
+    }
+}
+
+/// Map a hwmon `pwmN_enable` value to a fan mode
+///
+/// 0 disables the fan, 1 is manual PWM and 2 or above are the driver's
+/// automatic modes.
+pub fn fan_mode_from_pwm_enable(value: u32) -> FanMode {
+    match value {
+        0 => FanMode::Off,
+        1 => FanMode::Manual,
+        _ => FanMode::Automatic,
+    }
+}
+
+/// Guess the fan operating mode when no mode node is available
+///
+/// Only a stopped fan can be told apart; a spinning one may be driven by
+/// the governor or set manually, so it is reported as unknown.
+fn detect_fan_mode(fans: &[FanInfo]) -> FanMode {
+    if !fans.is_empty() && fans.iter().all(|f| f.speed == 0) {
         return FanMode::Off;
     }
 
-    // Check if fans are responding to thermal (auto mode indicator)
-    // This is a heuristic - true auto mode detection would require more complex logic
-    FanMode::Manual
+    FanMode::Unknown
 }
 
 /// Correlate fan speed with temperature
@@ -220,16 +628,165 @@ fn correlate_fan_temp(stats: &FanStats, temp_stats: &TemperatureStats) -> f32 {
     }
 }
 
-/// Read a u32 value from sysfs
-fn read_sysfs_u32(path: &Path) -> Option<u32> {
-    fs::read_to_string(path)
-        .ok()
-        .and_then(|s| s.trim().parse().ok())
+/// Temperature to fan speed curve
+///
+/// Points are sorted by temperature and the speed is linearly interpolated
+/// between them. Temperatures outside the curve use the nearest endpoint.
+/// Serialized as the list of points; deserializing validates them like
+/// [`FanCurve::new`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "Vec<(f32, u8)>", into = "Vec<(f32, u8)>")]
+pub struct FanCurve {
+    points: Vec<(f32, u8)>,
+}
+
+impl FanCurve {
+    /// Create a curve from `(°C, speed %)` points in any order
+    pub fn new(mut points: Vec<(f32, u8)>) -> crate::Result<Self> {
+        if points.is_empty() {
+            return Err(Error::InvalidArgument(
+                "Fan curve needs at least one point".to_string(),
+            ));
+        }
+        if let Some((_, speed)) = points.iter().find(|(_, speed)| *speed > 100) {
+            return Err(Error::InvalidArgument(format!(
+                "Fan curve speed {} is above 100%",
+                speed
+            )));
+        }
+        if points.iter().any(|(temp, _)| !temp.is_finite()) {
+            return Err(Error::InvalidArgument(
+                "Fan curve temperatures must be finite".to_string(),
+            ));
+        }
+
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Ok(Self { points })
+    }
+
+    /// Curve points sorted by temperature
+    pub fn points(&self) -> &[(f32, u8)] {
+        &self.points
+    }
+
+    /// Fan speed in percent for a temperature in °C
+    pub fn speed_at(&self, temp: f32) -> u8 {
+        let (first, last) = (self.points[0], self.points[self.points.len() - 1]);
+        if temp <= first.0 {
+            return first.1;
+        }
+        if temp >= last.0 {
+            return last.1;
+        }
+
+        // First point at or above temp; the endpoint checks keep it in 1..len
+        let upper = self.points.partition_point(|(t, _)| *t < temp);
+        let (t0, s0) = self.points[upper - 1];
+        let (t1, s1) = self.points[upper];
+        let ratio = (temp - t0) / (t1 - t0);
+
+        (s0 as f32 + (s1 as f32 - s0 as f32) * ratio).round() as u8
+    }
+}
+
+impl TryFrom<Vec<(f32, u8)>> for FanCurve {
+    type Error = Error;
+
+    fn try_from(points: Vec<(f32, u8)>) -> Result<Self, Self::Error> {
+        Self::new(points)
+    }
+}
+
+impl From<FanCurve> for Vec<(f32, u8)> {
+    fn from(curve: FanCurve) -> Self {
+        curve.points
+    }
+}
+
+impl FromStr for FanCurve {
+    type Err = Error;
+
+    /// Parse `40:20,60:50,80:100` as `°C:speed%` pairs
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let points = s
+            .split(',')
+            .map(|point| {
+                let invalid = |detail: &dyn std::fmt::Display| {
+                    Error::InvalidArgument(format!("Fan curve point '{}': {}", point, detail))
+                };
+                let (temp, speed) = point
+                    .split_once(':')
+                    .ok_or_else(|| invalid(&"expected TEMP:SPEED"))?;
+                let temp = temp.trim().parse().map_err(|e| invalid(&e))?;
+                let speed = speed.trim().parse().map_err(|e| invalid(&e))?;
+                Ok((temp, speed))
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        Self::new(points)
+    }
+}
+
+/// Drives the fan from a temperature curve instead of the built-in governor
+#[derive(Debug, Clone)]
+pub struct FanController {
+    pub curve: FanCurve,
+    pub poll_interval: Duration,
+    last_speed: Option<u8>,
+}
+
+impl FanController {
+    /// Create a controller applying `curve` every `poll_interval`
+    pub fn new(curve: FanCurve, poll_interval: Duration) -> Self {
+        Self {
+            curve,
+            poll_interval,
+            last_speed: None,
+        }
+    }
+
+    /// Apply the curve to the hottest thermal zone (requires root)
+    ///
+    /// Returns the target speed; the fan is only written when it changes.
+    pub fn tick(&mut self) -> crate::Result<u8> {
+        let temp_stats = TemperatureStats::get();
+        let zone = temp_stats
+            .hottest_zone()
+            .ok_or_else(|| Error::HardwareNotFound("No thermal zone found".to_string()))?;
+        let speed = self.curve.speed_at(zone.current_temp);
+
+        if self.last_speed != Some(speed) {
+            FanStats::set_speed(speed)?;
+            self.last_speed = Some(speed);
+        }
+
+        Ok(speed)
+    }
+}
+
+/// Fan collector for the monitoring loop
+#[derive(Debug, Default)]
+pub struct FanMonitor;
+
+impl FanMonitor {
+    /// Create a new fan monitor
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Collector for FanMonitor {
+    type Stats = FanStats;
+
+    fn collect(&mut self) -> Self::Stats {
+        FanStats::get()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modules::sysfs::MockDir;
 
     #[test]
     fn test_fan_stats_default() {
@@ -251,6 +808,7 @@ mod tests {
                 name: "fan0".to_string(),
                 speed: 30,
                 rpm: 1500,
+                pwm_path: None,
             }],
             temperature: 40.0,
         };
@@ -261,6 +819,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::double_comparisons)]
     fn test_fan_stats_get() {
         let stats = FanStats::get();
 
@@ -273,6 +832,455 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_read_cooling_devices_uses_max_state() {
+        let dir = MockDir::new("fan");
+        let device = dir.join("cooling_device0");
+        fs::create_dir_all(&device).unwrap();
+        fs::write(device.join("max_state"), "255\n").unwrap();
+        fs::write(device.join("cur_state"), "128\n").unwrap();
+        // Thermal zones live next to cooling devices and must be skipped
+        fs::create_dir_all(dir.join("thermal_zone0")).unwrap();
+
+        let fans = read_cooling_devices(&dir);
+        assert_eq!(fans.len(), 1);
+        assert_eq!(fans[0].speed, 50);
+    }
+
+    fn mock_fan_dir(name: &str) -> MockDir {
+        let dir = MockDir::new(&format!("fan-{}", name));
+        fs::create_dir_all(dir.join("hwmon/hwmon0")).unwrap();
+        fs::write(dir.join("hwmon/hwmon0/name"), "cpu_thermal\n").unwrap();
+        fs::create_dir_all(dir.join("thermal/cooling_device0")).unwrap();
+        fs::write(dir.join("thermal/cooling_device0/max_state"), "255\n").unwrap();
+        fs::write(dir.join("thermal/cooling_device0/cur_state"), "0\n").unwrap();
+        dir
+    }
+
+    fn add_pwm_hwmon(dir: &Path) -> PathBuf {
+        let hwmon = dir.join("hwmon/hwmon1");
+        fs::create_dir_all(&hwmon).unwrap();
+        fs::write(hwmon.join("name"), "pwmfan\n").unwrap();
+        fs::write(hwmon.join("pwm1"), "0\n").unwrap();
+        fs::write(hwmon.join("pwm1_enable"), "2\n").unwrap();
+        hwmon
+    }
+
+    #[test]
+    fn test_set_speed_cooling_device_backend() {
+        let dir = mock_fan_dir("cooling");
+        let (hwmon, thermal) = (dir.join("hwmon"), dir.join("thermal"));
+
+        fs::write(thermal.join("cooling_device0/cur_pwm"), "0\n").unwrap();
+
+        let backend = set_speed_in(&hwmon, &thermal, 50).unwrap();
+        assert_eq!(backend, FanControlBackend::CoolingDevice);
+        assert_eq!(
+            fs::read_to_string(thermal.join("cooling_device0/cur_pwm")).unwrap(),
+            "127"
+        );
+        assert_eq!(
+            fs::read_to_string(thermal.join("cooling_device0/cur_state")).unwrap(),
+            "128"
+        );
+
+        // Auto mode leaves the state to the thermal governor
+        let backend = set_auto_in(&hwmon, &thermal, &dir.join("pwm-fan")).unwrap();
+        assert_eq!(backend, FanControlBackend::CoolingDevice);
+        assert_eq!(
+            fs::read_to_string(thermal.join("cooling_device0/cur_state")).unwrap(),
+            "128"
+        );
+    }
+
+    #[test]
+    fn test_cooling_device_writes_are_numeric() {
+        let dir = mock_fan_dir("numeric");
+        let (hwmon, thermal) = (dir.join("hwmon"), dir.join("thermal"));
+        fs::write(thermal.join("cooling_device0/max_state"), "3\n").unwrap();
+
+        for speed in [0, 33, 100] {
+            set_speed_in(&hwmon, &thermal, speed).unwrap();
+            let state = fs::read(thermal.join("cooling_device0/cur_state")).unwrap();
+            assert!(!state.is_empty() && state.iter().all(u8::is_ascii_digit));
+        }
+        assert_eq!(
+            fs::read_to_string(thermal.join("cooling_device0/cur_state")).unwrap(),
+            "3"
+        );
+        // Without a cur_pwm node only the state is written
+        assert!(!thermal.join("cooling_device0/cur_pwm").exists());
+    }
+
+    #[test]
+    fn test_set_speed_skips_cpu_cooling_devices() {
+        let dir = mock_fan_dir("cpu-cooling");
+        let (hwmon, thermal) = (dir.join("hwmon"), dir.join("thermal"));
+        fs::write(thermal.join("cooling_device0/type"), "pwm-fan\n").unwrap();
+        for (cooling, kind) in [
+            ("cooling_device1", "cpufreq-cpu0"),
+            ("cooling_device2", "Processor"),
+        ] {
+            fs::create_dir_all(thermal.join(cooling)).unwrap();
+            fs::write(thermal.join(cooling).join("type"), format!("{}\n", kind)).unwrap();
+            fs::write(thermal.join(cooling).join("max_state"), "10\n").unwrap();
+            fs::write(thermal.join(cooling).join("cur_state"), "10\n").unwrap();
+        }
+
+        let fans = read_cooling_devices(&thermal);
+        assert_eq!(fans.len(), 1);
+        assert_eq!(fans[0].speed, 0);
+
+        set_speed_in(&hwmon, &thermal, 100).unwrap();
+        assert_eq!(
+            fs::read_to_string(thermal.join("cooling_device0/cur_state")).unwrap(),
+            "255"
+        );
+        for cooling in ["cooling_device1", "cooling_device2"] {
+            assert_eq!(
+                fs::read_to_string(thermal.join(cooling).join("cur_state")).unwrap(),
+                "10\n"
+            );
+        }
+
+        // Only CPU cooling devices left: there is no fan to control
+        fs::remove_dir_all(thermal.join("cooling_device0")).unwrap();
+        assert!(matches!(
+            set_speed_in(&hwmon, &thermal, 100),
+            Err(Error::HardwareNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_percent_to_state() {
+        assert_eq!(percent_to_state(0, 255), 0);
+        assert_eq!(percent_to_state(50, 255), 128);
+        assert_eq!(percent_to_state(100, 3), 3);
+        assert_eq!(percent_to_state(100, 0), 0);
+    }
+
+    #[test]
+    fn test_set_speed_prefers_hwmon_backend() {
+        let dir = mock_fan_dir("hwmon");
+        let (hwmon, thermal) = (dir.join("hwmon"), dir.join("thermal"));
+        let pwm_hwmon = add_pwm_hwmon(&dir);
+
+        let backend = set_speed_in(&hwmon, &thermal, 100).unwrap();
+        assert_eq!(backend, FanControlBackend::Hwmon(pwm_hwmon.clone()));
+        assert_eq!(fs::read_to_string(pwm_hwmon.join("pwm1")).unwrap(), "255");
+        assert_eq!(
+            fs::read_to_string(pwm_hwmon.join("pwm1_enable")).unwrap(),
+            "1"
+        );
+        // The cooling device is left alone
+        assert!(!thermal.join("cooling_device0/cur_pwm").exists());
+
+        set_auto_in(&hwmon, &thermal, &dir.join("pwm-fan")).unwrap();
+        assert_eq!(
+            fs::read_to_string(pwm_hwmon.join("pwm1_enable")).unwrap(),
+            "2"
+        );
+    }
+
+    #[test]
+    fn test_set_auto_enables_pwm_fan_temp_control() {
+        let dir = mock_fan_dir("temp-control");
+        let pwm_fan = dir.join("pwm-fan");
+        fs::create_dir_all(&pwm_fan).unwrap();
+        fs::write(pwm_fan.join("temp_control"), "0\n").unwrap();
+
+        set_auto_in(&dir.join("hwmon"), &dir.join("thermal"), &pwm_fan).unwrap();
+        assert_eq!(
+            fs::read_to_string(pwm_fan.join("temp_control")).unwrap(),
+            "1"
+        );
+
+        let missing = dir.join("missing");
+        assert!(detect_fan_backend_in(&missing, &missing).is_none());
+        assert!(set_auto_in(&missing, &missing, &missing).is_err());
+    }
+
+    #[test]
+    fn test_set_auto_with_only_temp_control() {
+        let dir = MockDir::new("fan-xavier");
+        let pwm_fan = dir.join("pwm-fan");
+        fs::create_dir_all(&pwm_fan).unwrap();
+        fs::write(pwm_fan.join("temp_control"), "0\n").unwrap();
+        let missing = dir.join("missing");
+
+        let backend = set_auto_in(&missing, &missing, &pwm_fan).unwrap();
+        assert_eq!(
+            backend,
+            FanControlBackend::TempControl(pwm_fan.join("temp_control"))
+        );
+        assert_eq!(
+            fs::read_to_string(pwm_fan.join("temp_control")).unwrap(),
+            "1"
+        );
+    }
+
+    #[test]
+    fn test_detect_fan_backend_orders_hwmon_numerically() {
+        let dir = mock_fan_dir("hwmon-order");
+        for device in ["hwmon10", "hwmon2"] {
+            let hwmon = dir.join("hwmon").join(device);
+            fs::create_dir_all(&hwmon).unwrap();
+            fs::write(hwmon.join("pwm1"), "0\n").unwrap();
+        }
+
+        assert_eq!(
+            detect_fan_backend_in(&dir.join("hwmon"), &dir.join("thermal")),
+            Some(FanControlBackend::Hwmon(dir.join("hwmon/hwmon2")))
+        );
+    }
+
+    #[test]
+    fn test_set_speed_for_writes_only_target_fan() {
+        let dir = mock_fan_dir("per-fan");
+        let (hwmon, thermal) = (dir.join("hwmon"), dir.join("thermal"));
+        fs::create_dir_all(thermal.join("cooling_device1")).unwrap();
+        fs::write(thermal.join("cooling_device1/max_state"), "255\n").unwrap();
+        fs::write(thermal.join("cooling_device1/cur_state"), "0\n").unwrap();
+        let pwm_hwmon = add_pwm_hwmon(&dir);
+        fs::write(pwm_hwmon.join("pwm2"), "0\n").unwrap();
+        fs::write(pwm_hwmon.join("pwm2_enable"), "2\n").unwrap();
+
+        let written = set_speed_for_in(&hwmon, &thermal, 1, 100).unwrap();
+        assert_eq!(written, pwm_hwmon.join("pwm2"));
+        assert_eq!(fs::read_to_string(pwm_hwmon.join("pwm2")).unwrap(), "255");
+        assert_eq!(
+            fs::read_to_string(pwm_hwmon.join("pwm2_enable")).unwrap(),
+            "1"
+        );
+        // The other fan keeps its speed and mode
+        assert_eq!(fs::read_to_string(pwm_hwmon.join("pwm1")).unwrap(), "0\n");
+        assert_eq!(
+            fs::read_to_string(pwm_hwmon.join("pwm1_enable")).unwrap(),
+            "2\n"
+        );
+
+        let err = set_speed_for_in(&hwmon, &thermal, 5, 50).unwrap_err();
+        assert!(matches!(err, Error::InvalidArgument(_)));
+        assert!(err.to_string().contains("available: 0, 1"));
+    }
+
+    #[test]
+    fn test_set_speed_for_cooling_device_pwm() {
+        let dir = mock_fan_dir("per-fan-cooling");
+        let (hwmon, thermal) = (dir.join("hwmon"), dir.join("thermal"));
+
+        // Without a cur_pwm node the fan cannot be controlled
+        let err = set_speed_for_in(&hwmon, &thermal, 0, 50).unwrap_err();
+        assert!(matches!(err, Error::HardwareNotFound(_)));
+
+        fs::write(thermal.join("cooling_device0/cur_pwm"), "0\n").unwrap();
+        let written = set_speed_for_in(&hwmon, &thermal, 0, 50).unwrap();
+        assert_eq!(written, thermal.join("cooling_device0/cur_pwm"));
+        assert_eq!(fs::read_to_string(&written).unwrap(), "127");
+        // cur_state only takes an integer state and is left alone
+        assert_eq!(
+            fs::read_to_string(thermal.join("cooling_device0/cur_state")).unwrap(),
+            "0\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_fan_pwm_matches_device_link() {
+        let dir = mock_fan_dir("pwm-link");
+        let (hwmon, thermal) = (dir.join("hwmon"), dir.join("thermal"));
+        let platform = dir.join("devices/pwm-fan");
+        fs::create_dir_all(&platform).unwrap();
+        // An unrelated chip with a PWM output sorts first
+        fs::write(hwmon.join("hwmon0/pwm1"), "0\n").unwrap();
+        let fan_hwmon = hwmon.join("hwmon3");
+        fs::create_dir_all(&fan_hwmon).unwrap();
+        fs::write(fan_hwmon.join("name"), "fan_controller\n").unwrap();
+        fs::write(fan_hwmon.join("pwm1"), "0\n").unwrap();
+        std::os::unix::fs::symlink(&platform, fan_hwmon.join("device")).unwrap();
+        std::os::unix::fs::symlink(&platform, thermal.join("cooling_device0/device")).unwrap();
+
+        let mut fans = read_cooling_devices(&thermal);
+        apply_fan_pwm(&mut fans, &thermal, &read_pwm_devices_in(&hwmon));
+        assert_eq!(fans[0].pwm_path, Some(fan_hwmon.join("pwm1")));
+    }
+
+    #[test]
+    fn test_detect_fan_backend_prefers_fan_chip() {
+        let dir = mock_fan_dir("backend-chip");
+        let (hwmon, thermal) = (dir.join("hwmon"), dir.join("thermal"));
+        // A PMIC with a PWM output sorts before the fan chips
+        fs::write(hwmon.join("hwmon0/pwm1"), "0\n").unwrap();
+        let pwm_fan = add_pwm_hwmon(&dir);
+        assert_eq!(
+            detect_fan_backend_in(&hwmon, &thermal),
+            Some(FanControlBackend::Hwmon(pwm_fan))
+        );
+
+        let platform = dir.join("devices/fan");
+        fs::create_dir_all(&platform).unwrap();
+        let fan_hwmon = hwmon.join("hwmon3");
+        fs::create_dir_all(&fan_hwmon).unwrap();
+        fs::write(fan_hwmon.join("name"), "fan_controller\n").unwrap();
+        fs::write(fan_hwmon.join("pwm1"), "0\n").unwrap();
+        std::os::unix::fs::symlink(&platform, fan_hwmon.join("device")).unwrap();
+        std::os::unix::fs::symlink(&platform, thermal.join("cooling_device0/device")).unwrap();
+        assert_eq!(
+            detect_fan_backend_in(&hwmon, &thermal),
+            Some(FanControlBackend::Hwmon(fan_hwmon))
+        );
+    }
+
+    #[test]
+    fn test_apply_fan_pwm_by_pwm_fan_name() {
+        let dir = mock_fan_dir("pwm-name");
+        let (hwmon, thermal) = (dir.join("hwmon"), dir.join("thermal"));
+        fs::write(hwmon.join("hwmon0/pwm1"), "0\n").unwrap();
+        for (cooling, kind) in [
+            ("cooling_device1", "cpufreq-cpu0"),
+            ("cooling_device2", "pwm-fan"),
+        ] {
+            fs::create_dir_all(thermal.join(cooling)).unwrap();
+            fs::write(thermal.join(cooling).join("type"), format!("{}\n", kind)).unwrap();
+        }
+        for device in ["hwmon10", "hwmon2"] {
+            fs::create_dir_all(hwmon.join(device)).unwrap();
+            fs::write(hwmon.join(device).join("name"), "pwm-fan\n").unwrap();
+            fs::write(hwmon.join(device).join("pwm1"), "0\n").unwrap();
+        }
+
+        let mut fans = read_cooling_devices(&thermal);
+        apply_fan_pwm(&mut fans, &thermal, &read_pwm_devices_in(&hwmon));
+        let pwm: Vec<Option<PathBuf>> = fans.into_iter().map(|fan| fan.pwm_path).collect();
+        assert_eq!(
+            pwm,
+            [
+                Some(hwmon.join("hwmon2/pwm1")),
+                Some(hwmon.join("hwmon10/pwm1")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fan_curve_interpolation() {
+        let curve: FanCurve = "40:20,60:50,80:100".parse().unwrap();
+
+        assert_eq!(curve.speed_at(40.0), 20);
+        assert_eq!(curve.speed_at(50.0), 35);
+        assert_eq!(curve.speed_at(60.0), 50);
+        assert_eq!(curve.speed_at(75.0), 88);
+        assert_eq!(curve.speed_at(80.0), 100);
+    }
+
+    #[test]
+    fn test_fan_curve_clamps_to_endpoints() {
+        let curve: FanCurve = "80:100, 40:20".parse().unwrap();
+
+        assert_eq!(curve.points(), &[(40.0, 20), (80.0, 100)]);
+        assert_eq!(curve.speed_at(-10.0), 20);
+        assert_eq!(curve.speed_at(105.0), 100);
+
+        let flat = FanCurve::new(vec![(50.0, 60)]).unwrap();
+        assert_eq!(flat.speed_at(20.0), 60);
+        assert_eq!(flat.speed_at(90.0), 60);
+    }
+
+    #[test]
+    fn test_fan_curve_rejects_invalid_points() {
+        assert!("".parse::<FanCurve>().is_err());
+        assert!("40".parse::<FanCurve>().is_err());
+        assert!("40:120".parse::<FanCurve>().is_err());
+        assert!("hot:50".parse::<FanCurve>().is_err());
+        assert!("NaN:50".parse::<FanCurve>().is_err());
+    }
+
+    #[test]
+    fn test_fan_curve_deserialize_validates_points() {
+        assert!(serde_json::from_str::<FanCurve>("[]").is_err());
+        assert!(serde_json::from_str::<FanCurve>("[[40.0, 120]]").is_err());
+
+        let curve: FanCurve = serde_json::from_str("[[60.0, 50], [40.0, 20]]").unwrap();
+        assert_eq!(curve.points(), &[(40.0, 20), (60.0, 50)]);
+        assert_eq!(
+            serde_json::to_string(&curve).unwrap(),
+            "[[40.0,20],[60.0,50]]"
+        );
+    }
+
+    #[test]
+    fn test_read_fan_rpm_from_hwmon() {
+        let dir = MockDir::new("fan-rpm");
+        // hwmon0 is a temperature sensor without tachometer
+        fs::create_dir_all(dir.join("hwmon0")).unwrap();
+        fs::write(dir.join("hwmon0/temp1_input"), "45000\n").unwrap();
+        fs::create_dir_all(dir.join("hwmon1")).unwrap();
+        fs::write(dir.join("hwmon1/fan2_input"), "1800\n").unwrap();
+        fs::write(dir.join("hwmon1/fan1_input"), "2400\n").unwrap();
+        fs::write(dir.join("hwmon1/fan1_label"), "tach\n").unwrap();
+
+        let rpm = read_fan_rpm_in(&dir);
+        assert_eq!(rpm, vec![2400, 1800]);
+
+        let mut fans: Vec<FanInfo> = (0..3)
+            .map(|index| FanInfo {
+                index,
+                ..Default::default()
+            })
+            .collect();
+        apply_fan_rpm(&mut fans, &rpm);
+        assert_eq!(fans[0].rpm, 2400);
+        assert_eq!(fans[1].rpm, 1800);
+        assert_eq!(fans[2].rpm, 0);
+
+        assert!(read_fan_rpm_in(&dir.join("missing")).is_empty());
+    }
+
+    #[test]
+    fn test_fan_mode_from_pwm_enable() {
+        assert_eq!(fan_mode_from_pwm_enable(0), FanMode::Off);
+        assert_eq!(fan_mode_from_pwm_enable(1), FanMode::Manual);
+        assert_eq!(fan_mode_from_pwm_enable(2), FanMode::Automatic);
+        assert_eq!(fan_mode_from_pwm_enable(3), FanMode::Automatic);
+    }
+
+    #[test]
+    fn test_read_fan_mode_from_hwmon() {
+        let dir = mock_fan_dir("mode");
+        let (hwmon, thermal) = (dir.join("hwmon"), dir.join("thermal"));
+
+        // Cooling devices have no mode node
+        assert_eq!(read_fan_mode_in(&hwmon, &thermal), None);
+
+        let pwm_hwmon = add_pwm_hwmon(&dir);
+        for (value, mode) in [
+            ("0", FanMode::Off),
+            ("1", FanMode::Manual),
+            ("2", FanMode::Automatic),
+        ] {
+            fs::write(pwm_hwmon.join("pwm1_enable"), format!("{}\n", value)).unwrap();
+            assert_eq!(read_fan_mode_in(&hwmon, &thermal), Some(mode));
+        }
+    }
+
+    #[test]
+    fn test_detect_fan_mode_fallback() {
+        let fan = |speed| FanInfo {
+            speed,
+            ..Default::default()
+        };
+
+        assert_eq!(detect_fan_mode(&[]), FanMode::Unknown);
+        assert_eq!(detect_fan_mode(&[fan(0), fan(0)]), FanMode::Off);
+        assert_eq!(detect_fan_mode(&[fan(0), fan(40)]), FanMode::Unknown);
+    }
+
+    #[test]
+    fn test_state_to_percent() {
+        assert_eq!(state_to_percent(0, 255), 0);
+        assert_eq!(state_to_percent(255, 255), 100);
+        assert_eq!(state_to_percent(300, 255), 100);
+        assert_eq!(state_to_percent(3, 0), 0);
+    }
+
     #[test]
     fn test_fan_info_default() {
         let info = FanInfo::default();
@@ -289,6 +1297,7 @@ mod tests {
             name: "cooling_device1".to_string(),
             speed: 85,
             rpm: 2800,
+            pwm_path: None,
         };
 
         assert_eq!(info.index, 1);
@@ -372,6 +1381,7 @@ mod tests {
                 name: "cooling_device0".to_string(),
                 speed: 65,
                 rpm: 2200,
+                pwm_path: None,
             }],
             temperature: 48.0,
         };
@@ -454,12 +1464,14 @@ mod tests {
                     name: "cooling_device0".to_string(),
                     speed: 40,
                     rpm: 2400,
+                    pwm_path: None,
                 },
                 FanInfo {
                     index: 1,
                     name: "cooling_device1".to_string(),
                     speed: 60,
                     rpm: 3600,
+                    pwm_path: None,
                 },
             ],
             temperature: 48.0,
@@ -471,6 +1483,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::overly_complex_bool_expr)]
     fn test_fan_control_speed_setting_boundary() {
         assert!(
             FanStats::set_speed(0).is_err() || true,
@@ -554,6 +1567,7 @@ mod tests {
             name: "cooling_device0".to_string(),
             speed: 50,
             rpm: 2500,
+            pwm_path: None,
         };
 
         assert_eq!(fan_info.index, 0);
@@ -565,6 +1579,7 @@ mod tests {
             name: "cooling_device10".to_string(),
             speed: 75,
             rpm: 3500,
+            pwm_path: None,
         };
 
         assert_eq!(fan_info_2.index, 10);
@@ -583,6 +1598,7 @@ mod tests {
                 name: "fan0".to_string(),
                 speed: 0,
                 rpm: 0,
+                pwm_path: None,
             }],
             temperature: 30.0,
         };
@@ -599,6 +1615,7 @@ mod tests {
                 name: "fan0".to_string(),
                 speed: 50,
                 rpm: 2500,
+                pwm_path: None,
             }],
             temperature: 45.0,
         };
@@ -615,6 +1632,7 @@ mod tests {
                 name: "fan0".to_string(),
                 speed: 100,
                 rpm: 5000,
+                pwm_path: None,
             }],
             temperature: 65.0,
         };
@@ -630,6 +1648,7 @@ mod tests {
             name: "fan0".to_string(),
             speed: 10,
             rpm: 600,
+            pwm_path: None,
         };
 
         assert!(low_rpm_fan.rpm > 0, "RPM should be positive when speed > 0");
@@ -639,6 +1658,7 @@ mod tests {
             name: "fan0".to_string(),
             speed: 90,
             rpm: 5400,
+            pwm_path: None,
         };
 
         assert!(
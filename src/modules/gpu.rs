@@ -6,37 +6,70 @@
 //! Provides GPU statistics including usage, frequency, temperature, and governor information
 //! using sysfs devfreq interface or NVML for NVIDIA Jetson devices.
 
-use std::fs;
+use crate::modules::collector::Collector;
+use crate::modules::cpu::validate_governor;
+use crate::modules::engine::EngineStats;
+use crate::modules::hardware;
+use crate::modules::sysfs::{self, Blocking, Reader, Tokio};
+use crate::Error;
+use std::io;
 use std::path::Path;
 use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 
 #[cfg(feature = "nvml")]
-use nvml_wrapper as nvml;
+use nvml_wrapper::{
+    enum_wrappers::device::{Clock, TemperatureSensor},
+    Nvml,
+};
 
 /// GPU statistics
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct GpuStats {
+    /// Device index, 0 for the integrated GPU
+    #[serde(default)]
+    pub index: u32,
     pub usage: f32,
     pub frequency: u32,
     pub temperature: f32,
     pub governor: String,
     pub memory_used: u64,
     pub memory_total: u64,
+    /// Memory figures come from system RAM shared with the CPU (nvmap)
+    pub memory_shared: bool,
     pub state: String,
+    /// Names of the busy functions, see [`GpuStats::functions`] for their load
     pub active_functions: Vec<String>,
+    /// Busy functions with how loaded each of them is
+    #[serde(default)]
+    pub functions: Vec<GpuFunction>,
+}
+
+/// GPU function or accelerator and its load
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GpuFunction {
+    /// Function name such as `CUDA` or `NVDEC`
+    pub name: String,
+    /// Load in percent
+    pub usage: u8,
 }
 
 impl Default for GpuStats {
     fn default() -> Self {
         Self {
+            index: 0,
             usage: 0.0,
             frequency: 0,
             temperature: 0.0,
             governor: String::new(),
             memory_used: 0,
             memory_total: 0,
+            memory_shared: false,
             state: String::new(),
             active_functions: Vec::new(),
+            functions: Vec::new(),
         }
     }
 }
@@ -50,6 +83,77 @@ pub struct GpuProcess {
     pub command: String,
 }
 
+/// Nominal GPU core configuration of a Jetson module
+///
+/// Taken from the published module specifications, not read from hardware.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GpuCores {
+    pub cuda_cores: u32,
+    pub sm_count: u32,
+}
+
+/// Nominal CUDA core and SM counts for a board model
+///
+/// Returns `None` for boards missing from the table.
+pub fn nominal_gpu_cores(model: &str) -> Option<GpuCores> {
+    let model = model.to_lowercase();
+    let cores = |cuda_cores, sm_count| {
+        Some(GpuCores {
+            cuda_cores,
+            sm_count,
+        })
+    };
+
+    if model.contains("t4000") {
+        cores(1536, 12)
+    } else if model.contains("thor") {
+        cores(2560, 20)
+    } else if model.contains("agx orin") {
+        if model.contains("32gb") {
+            cores(1792, 14)
+        } else {
+            cores(2048, 16)
+        }
+    } else if model.contains("orin nx") {
+        cores(1024, 8)
+    } else if model.contains("orin nano") {
+        if model.contains("4gb") {
+            cores(512, 4)
+        } else {
+            cores(1024, 8)
+        }
+    } else if model.contains("agx xavier") {
+        cores(512, 8)
+    } else if model.contains("xavier nx") {
+        cores(384, 6)
+    } else if model.contains("tx2") || model.contains("tx1") {
+        cores(256, 2)
+    } else if model.contains("nano") {
+        cores(128, 1)
+    } else {
+        None
+    }
+}
+
+/// GPU architecture of a Tegra chip with its SoC, e.g. `Ampere (Orin)`
+pub fn gpu_arch_for_chip(chip: &str) -> Option<String> {
+    let (arch, _) = hardware::tegra_gpu(chip)?;
+    let soc = hardware::soc_name(chip)?;
+    Some(format!("{} ({})", arch, soc))
+}
+
+/// Name of the integrated GPU of a Tegra chip, e.g. `NVIDIA Orin`
+pub fn gpu_name_for_chip(chip: &str) -> Option<&'static str> {
+    hardware::tegra_gpu(chip).map(|(_, name)| name)
+}
+
+impl GpuProcess {
+    /// Command shortened to at most `width` characters for table display
+    pub fn display_command(&self, width: usize) -> String {
+        crate::modules::processes::truncate_command(&self.command, width)
+    }
+}
+
 impl GpuStats {
     /// Get current GPU statistics
     ///
@@ -66,11 +170,23 @@ impl GpuStats {
     ///
     /// For JetPack 7.0+ (Thor), uses NVML if available for more accurate statistics.
     pub fn get() -> Self {
+        sysfs::block_on(Self::read(&Blocking))
+    }
+
+    /// Get current GPU statistics through `tokio::fs`
+    ///
+    /// NVML, nvidia-smi and the engine readers block, so they run on the
+    /// blocking thread pool.
+    pub async fn get_async() -> Self {
+        Self::read(&Tokio).await
+    }
+
+    async fn read<R: Reader>(reader: &R) -> Self {
         #[cfg(feature = "nvml")]
         {
             // Check if we should use NVML (JetPack 7.0+)
-            if should_use_nvml() {
-                if let Ok(nvml_stats) = get_nvml_stats() {
+            if reader.blocking(should_use_nvml).await {
+                if let Ok(nvml_stats) = reader.blocking(get_nvml_stats).await {
                     return nvml_stats;
                 }
             }
@@ -79,28 +195,215 @@ impl GpuStats {
         // Try to read from devfreq
         let mut gpu_stats = GpuStats::default();
 
-        if let Some(devfreq_path) = find_gpu_devfreq() {
-            gpu_stats.frequency = read_gpu_freq(&devfreq_path);
-            gpu_stats.governor = read_gpu_governor(&devfreq_path);
-            gpu_stats.usage = read_gpu_usage(&devfreq_path);
+        if let Some(devfreq_path) = gpu_devfreq(reader).await {
+            gpu_stats.frequency = read_gpu_freq(reader, &devfreq_path).await;
+            gpu_stats.governor = read_gpu_governor(reader, &devfreq_path).await;
+            gpu_stats.usage = read_gpu_usage(reader, &devfreq_path).await;
         }
 
         // Read GPU state from sysfs
-        gpu_stats.state = read_gpu_state_from_sysfs();
+        gpu_stats.state = read_gpu_state_from_sysfs(reader).await;
 
-        // Read GPU active functions from sysfs
-        gpu_stats.active_functions = read_gpu_active_functions_from_sysfs();
+        // Derive active functions from GPU load and engine utilization
+        let cuda_usage = read_busy_percent(reader, Path::new(GPU_BUSY_PERCENT))
+            .await
+            .map_or(gpu_stats.usage, f32::from);
+        let engines = reader.blocking(EngineStats::get).await;
+        gpu_stats.functions = gpu_functions(cuda_usage, &engines);
+        gpu_stats.active_functions = function_names(&gpu_stats.functions);
 
         // Read GPU temperature
-        gpu_stats.temperature = read_gpu_temp();
-
-        // Read GPU memory from sysfs
-        let memory = read_gpu_memory_from_sysfs();
+        gpu_stats.temperature = read_gpu_temp(reader, Path::new(THERMAL_SYSFS)).await;
+
+        // Read GPU memory from the nvmap accounting; nvidia-smi is only
+        // spawned on kernels without it
+        let mut memory = read_gpu_memory_from_sysfs(reader).await;
+        if memory.total == 0 {
+            if let Ok((used, total)) = reader.blocking(read_nvidia_smi_memory).await {
+                memory = GpuMemoryInfo {
+                    used,
+                    total,
+                    shared: false,
+                };
+            }
+        }
         gpu_stats.memory_used = memory.used;
         gpu_stats.memory_total = memory.total;
+        gpu_stats.memory_shared = memory.shared;
 
         gpu_stats
     }
+
+    /// Get statistics of every GPU
+    ///
+    /// With NVML each device is reported under its NVML index; otherwise the
+    /// integrated GPU is the only one. `get()` returns the first entry.
+    pub fn get_all() -> Vec<Self> {
+        #[cfg(feature = "nvml")]
+        {
+            if should_use_nvml() {
+                if let Ok(all) = get_all_nvml_stats() {
+                    if !all.is_empty() {
+                        return all;
+                    }
+                }
+            }
+        }
+
+        vec![Self::get()]
+    }
+
+    /// Name of the GPU, e.g. `NVIDIA Orin`
+    ///
+    /// Prefers the name NVML reports and falls back to the name of the
+    /// detected Tegra chip, or `NVIDIA GPU`. Resolved once per process.
+    pub fn name() -> &'static str {
+        static NAME: OnceLock<String> = OnceLock::new();
+        NAME.get_or_init(|| {
+            #[cfg(feature = "nvml")]
+            if let Some(name) = nvml_device_name() {
+                return name;
+            }
+            hardware::detect_chip()
+                .and_then(gpu_name_for_chip)
+                .unwrap_or("NVIDIA GPU")
+                .to_string()
+        })
+    }
+
+    /// Architecture of the GPU, e.g. `Ampere (Orin)`, or `Unknown`
+    ///
+    /// Resolved from the detected Tegra chip once per process.
+    pub fn arch() -> &'static str {
+        static ARCH: OnceLock<String> = OnceLock::new();
+        ARCH.get_or_init(|| {
+            hardware::detect_chip()
+                .and_then(gpu_arch_for_chip)
+                .unwrap_or_else(|| "Unknown".to_string())
+        })
+    }
+
+    /// Get statistics, reusing a reading younger than the cache TTL
+    ///
+    /// Callers within one TUI tick share one reading, and so one nvidia-smi
+    /// run, and see consistent values. The cache is process-wide.
+    pub fn get_cached() -> Arc<Self> {
+        GPU_CACHE
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get_or_refresh(Instant::now, Self::get)
+    }
+
+    /// Set how long [`get_cached`](Self::get_cached) reuses a reading
+    ///
+    /// A zero TTL reads fresh statistics on every call.
+    pub fn set_cache_ttl(ttl: Duration) {
+        GPU_CACHE.lock().unwrap_or_else(|e| e.into_inner()).ttl = ttl;
+    }
+
+    /// Set the devfreq governor of the GPU (requires root)
+    ///
+    /// `governor` must be listed in the devfreq `available_governors`.
+    pub fn set_governor(governor: &str) -> crate::Result<()> {
+        set_governor_in(&gpu_devfreq_path()?, governor)
+    }
+
+    /// Limit the GPU clock to `min..=max` Hz (requires root)
+    ///
+    /// Both must be listed in `available_frequencies`. Pass the same
+    /// frequency twice to pin the clock, e.g. for benchmarking.
+    pub fn set_frequency_range(min: u64, max: u64) -> crate::Result<()> {
+        set_frequency_range_in(&gpu_devfreq_path()?, min, max)
+    }
+}
+
+/// Default lifetime of a [`GpuStats::get_cached`] reading
+pub const DEFAULT_GPU_CACHE_TTL: Duration = Duration::from_millis(200);
+
+static GPU_CACHE: Mutex<TtlCache<GpuStats>> = Mutex::new(TtlCache::new(DEFAULT_GPU_CACHE_TTL));
+
+/// Single value reused until it is `ttl` old
+struct TtlCache<T> {
+    ttl: Duration,
+    entry: Option<(Instant, Arc<T>)>,
+}
+
+impl<T> TtlCache<T> {
+    const fn new(ttl: Duration) -> Self {
+        Self { ttl, entry: None }
+    }
+
+    /// Cached value, or a fresh one from `read` once it has expired
+    ///
+    /// The age counts from when `read` returned, so a slow read is not
+    /// stale on arrival.
+    fn get_or_refresh(&mut self, now: impl Fn() -> Instant, read: impl FnOnce() -> T) -> Arc<T> {
+        if let Some((taken, value)) = &self.entry {
+            if now().saturating_duration_since(*taken) < self.ttl {
+                return Arc::clone(value);
+            }
+        }
+
+        let value = Arc::new(read());
+        self.entry = Some((now(), Arc::clone(&value)));
+        value
+    }
+}
+
+fn gpu_devfreq_path() -> crate::Result<std::path::PathBuf> {
+    find_gpu_devfreq()
+        .map(Into::into)
+        .ok_or_else(|| Error::HardwareNotFound("No GPU devfreq device found".to_string()))
+}
+
+fn set_governor_in(devfreq: &Path, governor: &str) -> crate::Result<()> {
+    let available = sysfs::read_string(devfreq.join("available_governors")).ok_or_else(|| {
+        Error::HardwareNotFound("GPU devfreq does not list available governors".to_string())
+    })?;
+    validate_governor(&available, governor)?;
+
+    sysfs::write(devfreq.join("governor"), governor)
+}
+
+fn set_frequency_range_in(devfreq: &Path, min: u64, max: u64) -> crate::Result<()> {
+    if min > max {
+        return Err(Error::InvalidArgument(format!(
+            "Minimum frequency {} Hz is above the maximum {} Hz",
+            min, max
+        )));
+    }
+
+    let available: Vec<u64> = sysfs::read_string(devfreq.join("available_frequencies"))
+        .ok_or_else(|| {
+            Error::HardwareNotFound("GPU devfreq does not list available frequencies".to_string())
+        })?
+        .split_whitespace()
+        .filter_map(|f| f.parse().ok())
+        .collect();
+    for freq in [min, max] {
+        if !available.contains(&freq) {
+            return Err(Error::InvalidArgument(format!(
+                "GPU frequency {} Hz is not available (available: {})",
+                freq,
+                available
+                    .iter()
+                    .map(u64::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )));
+        }
+    }
+
+    // Older kernels reject a min_freq above the current max_freq and the
+    // other way round, so raise max first when moving the range up
+    let current_max = sysfs::read_value::<u64>(devfreq.join("max_freq")).unwrap_or(u64::MAX);
+    if min > current_max {
+        sysfs::write(devfreq.join("max_freq"), max.to_string())?;
+        sysfs::write(devfreq.join("min_freq"), min.to_string())
+    } else {
+        sysfs::write(devfreq.join("min_freq"), min.to_string())?;
+        sysfs::write(devfreq.join("max_freq"), max.to_string())
+    }
 }
 
 #[cfg(feature = "nvml")]
@@ -115,11 +418,11 @@ fn should_use_nvml() -> bool {
     let parts: Vec<&str> = board.l4t.split('.').collect();
     if parts.len() >= 2 {
         if let Ok(major) = parts[0].parse::<u32>() {
-            if let Ok(minor) = parts[1].parse::<u32>() {
+            if parts[1].parse::<u32>().is_ok() {
                 // L4T 36.x corresponds to JetPack 6.x
                 // L4T 38.x corresponds to JetPack 7.x
                 // So L4T >= 38.0 means JetPack 7.0+
-                return major > 38 || (major == 38 && minor >= 0);
+                return major >= 38;
             }
         }
     }
@@ -127,50 +430,101 @@ fn should_use_nvml() -> bool {
     false
 }
 
+/// NVML handle shared by all GPU reads
+///
+/// `nvmlInit` loads the driver library and attaches to every device, which
+/// is far too slow to repeat on each refresh tick. The handle is initialized
+/// on first use and kept for the lifetime of the process; the driver releases
+/// it when the process exits.
 #[cfg(feature = "nvml")]
-fn get_nvml_stats() -> anyhow::Result<GpuStats> {
-    let mut stats = GpuStats::default();
-
-    // Initialize NVML
-    nvml::nvmlInit()?;
+pub struct NvmlContext {
+    nvml: Nvml,
+}
 
-    // Get device count
-    let device_count = nvml::nvmlDeviceGetCount()?;
+#[cfg(feature = "nvml")]
+impl NvmlContext {
+    /// Shared context, or `None` when NVML cannot be initialized
+    pub fn get() -> Option<&'static NvmlContext> {
+        static CONTEXT: OnceLock<Option<NvmlContext>> = OnceLock::new();
+
+        CONTEXT
+            .get_or_init(|| Nvml::init().ok().map(|nvml| NvmlContext { nvml }))
+            .as_ref()
+    }
 
-    if device_count == 0 {
-        nvml::nvmlShutdown()?;
-        anyhow::bail!("No NVML devices found");
+    /// Initialized NVML handle
+    pub fn nvml(&self) -> &Nvml {
+        &self.nvml
     }
+}
 
-    // Get first device
-    let device = nvml::nvmlDeviceGetHandleByIndex(0)?;
+/// Name NVML reports for the first GPU
+#[cfg(feature = "nvml")]
+fn nvml_device_name() -> Option<String> {
+    let nvml = NvmlContext::get()?.nvml();
+    nvml.device_by_index(0).ok()?.name().ok()
+}
+
+#[cfg(feature = "nvml")]
+fn get_nvml_stats() -> crate::Result<GpuStats> {
+    get_all_nvml_stats()?
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::HardwareNotFound("No NVML devices found".to_string()))
+}
+
+#[cfg(feature = "nvml")]
+fn get_all_nvml_stats() -> crate::Result<Vec<GpuStats>> {
+    let context = NvmlContext::get()
+        .ok_or_else(|| Error::HardwareNotFound("NVML is not available".to_string()))?;
+    let nvml = context.nvml();
+    let engines = EngineStats::get();
+
+    (0..nvml.device_count()?)
+        .map(|index| get_nvml_device_stats(nvml, index, &engines))
+        .collect()
+}
+
+#[cfg(feature = "nvml")]
+fn get_nvml_device_stats(
+    nvml: &Nvml,
+    index: u32,
+    engines: &EngineStats,
+) -> crate::Result<GpuStats> {
+    let device = nvml.device_by_index(index)?;
+    let mut stats = GpuStats {
+        index,
+        ..Default::default()
+    };
 
     // Get utilization
-    let utilization = nvml::nvmlDeviceGetUtilizationRates(device)?;
+    let utilization = device.utilization_rates()?;
     stats.usage = utilization.gpu as f32;
 
     // Get temperature
-    let temp = nvml::nvmlDeviceGetTemperature(device, nvml::NVML_TEMPERATURE_GPU)?;
-    stats.temperature = temp as f32;
+    stats.temperature = device.temperature(TemperatureSensor::Gpu)? as f32;
 
-    // Get clock info (SM clock)
-    let clock_info = nvml::nvmlDeviceGetClockInfo(device, nvml::NVML_CLOCK_SM)?;
-    stats.frequency = clock_info.clock as u32;
+    // Get clock info (SM clock, reported in MHz)
+    stats.frequency = device.clock_info(Clock::SM)? * 1_000_000;
 
     // Governor is always "nvml" when using NVML
     stats.governor = "nvml".to_string();
 
-    // Shutdown NVML
-    nvml::nvmlShutdown()?;
+    stats.functions = gpu_functions(stats.usage, engines);
+    stats.active_functions = function_names(&stats.functions);
 
     Ok(stats)
 }
 
 /// Find GPU devfreq path
-fn find_gpu_devfreq() -> Option<String> {
+pub(crate) fn find_gpu_devfreq() -> Option<String> {
+    sysfs::block_on(gpu_devfreq(&Blocking))
+}
+
+async fn gpu_devfreq<R: Reader>(reader: &R) -> Option<String> {
     let base_path = Path::new("/sys/class/devfreq");
 
-    if !base_path.exists() {
+    if !reader.exists(base_path).await {
         return None;
     }
 
@@ -183,17 +537,20 @@ fn find_gpu_devfreq() -> Option<String> {
 
     for candidate in &candidates {
         let path = base_path.join(candidate);
-        if path.exists() {
+        if reader.exists(&path).await {
             return Some(path.to_string_lossy().to_string());
         }
     }
 
     // Fallback: search for any devfreq entry containing 'gpu' or 'gv11b'
-    if let Ok(entries) = fs::read_dir(base_path) {
-        for entry in entries.flatten() {
-            let entry_name = entry.file_name().to_string_lossy().to_lowercase();
+    if let Ok(entries) = reader.read_dir(base_path).await {
+        for entry in entries {
+            let entry_name = entry
+                .file_name()
+                .map(|name| name.to_string_lossy().to_lowercase())
+                .unwrap_or_default();
             if entry_name.contains("gpu") || entry_name.contains("gv11b") {
-                return Some(entry.path().to_string_lossy().to_string());
+                return Some(entry.to_string_lossy().to_string());
             }
         }
     }
@@ -202,59 +559,82 @@ fn find_gpu_devfreq() -> Option<String> {
 }
 
 /// Read GPU state from sysfs
-fn read_gpu_state_from_sysfs() -> String {
+async fn read_gpu_state_from_sysfs<R: Reader>(reader: &R) -> String {
     // Try to read GPU state from /sys/class/nvrm/
     // On Jetson devices, we can check if GPU is active by reading power state
     let path = Path::new("/sys/class/nvrm/gpu0/power/runtime_status");
 
-    if let Ok(content) = fs::read_to_string(path) {
-        if content.trim() == "active" {
-            return "active".to_string();
-        } else if content.trim() == "suspended" {
-            return "idle".to_string();
-        }
+    match reader.read_string(path).await.as_deref() {
+        Some("active") => return "active".to_string(),
+        Some("suspended") => return "idle".to_string(),
+        _ => {}
     }
 
     // Fallback: try to read from usage
-    let usage_path = Path::new("/sys/class/nvrm/gpu0/device/gpu_busy_percent");
-    if let Ok(usage) = fs::read_to_string(usage_path) {
-        if usage.trim().parse::<u32>().unwrap_or(0) > 0 {
-            return "active".to_string();
-        }
+    let busy = reader.read_value::<u32>(Path::new(GPU_BUSY_PERCENT)).await;
+    if busy.unwrap_or(0) > 0 {
+        return "active".to_string();
     }
 
     String::new()
 }
 
-/// Read GPU active functions from sysfs
-fn read_gpu_active_functions_from_sysfs() -> Vec<String> {
-    let mut functions = Vec::new();
+/// Busy percentage of the GPU exposed by the nvrm driver
+const GPU_BUSY_PERCENT: &str = "/sys/class/nvrm/gpu0/device/gpu_busy_percent";
 
-    // Try to read CUDA usage
-    let cuda_usage_path = Path::new("/sys/class/nvrm/gpu0/device/gpu_busy_percent");
-    if let Ok(usage) = fs::read_to_string(cuda_usage_path) {
-        if usage.trim().parse::<u32>().unwrap_or(0) > 0 {
-            functions.push("CUDA".to_string());
-        }
-    }
+/// Thermal zones searched for the GPU temperature
+const THERMAL_SYSFS: &str = "/sys/class/thermal";
 
-    // Try to read NVDEC usage from sysfs if available
-    let nvdec_path = Path::new("/sys/class/nvrm/gpu0/device/nvdec_usage");
-    if nvdec_path.exists() {
-        if let Ok(usage) = fs::read_to_string(nvdec_path) {
-            if usage.trim().parse::<u32>().unwrap_or(0) > 0 {
-                functions.push("NVDEC".to_string());
-            }
-        }
+/// Read a busy-percent node, clamped to 100
+async fn read_busy_percent<R: Reader>(reader: &R, path: &Path) -> Option<u8> {
+    reader
+        .read_value::<u32>(path)
+        .await
+        .map(|busy| busy.min(100) as u8)
+}
+
+/// GPU functions and accelerators that are currently busy
+///
+/// CUDA is active when the GPU itself reports load; NVDEC, NVENC, NVJPG, the
+/// DLAs, PVAs and OFA come from the engine utilizations in [`EngineStats`].
+pub fn active_functions(gpu_usage: f32, engines: &EngineStats) -> Vec<String> {
+    function_names(&gpu_functions(gpu_usage, engines))
+}
+
+fn function_names(functions: &[GpuFunction]) -> Vec<String> {
+    functions.iter().map(|f| f.name.clone()).collect()
+}
+
+/// Busy GPU functions and accelerators with their load
+///
+/// Same functions as [`active_functions`], each with the GPU load for CUDA
+/// or the engine utilization for the accelerators.
+pub fn gpu_functions(gpu_usage: f32, engines: &EngineStats) -> Vec<GpuFunction> {
+    let mut functions = Vec::new();
+
+    if gpu_usage > 0.0 {
+        functions.push(GpuFunction {
+            name: "CUDA".to_string(),
+            usage: gpu_usage.round().clamp(0.0, 100.0) as u8,
+        });
     }
 
-    // Try to read NVENC usage from sysfs if available
-    let nvenc_path = Path::new("/sys/class/nvrm/gpu0/device/nvenc_usage");
-    if nvenc_path.exists() {
-        if let Ok(usage) = fs::read_to_string(nvenc_path) {
-            if usage.trim().parse::<u32>().unwrap_or(0) > 0 {
-                functions.push("NVENC".to_string());
-            }
+    let accelerators = [
+        ("NVDEC", &engines.nvdec),
+        ("NVENC", &engines.nvenc),
+        ("NVJPG", &engines.nvjpg),
+        ("DLA0", &engines.dla0),
+        ("DLA1", &engines.dla1),
+        ("PVA0", &engines.pva0),
+        ("PVA1", &engines.pva1),
+        ("OFA", &engines.ofa),
+    ];
+    for (name, engine) in accelerators {
+        if engine.enabled && engine.usage > 0 {
+            functions.push(GpuFunction {
+                name: name.to_string(),
+                usage: engine.usage,
+            });
         }
     }
 
@@ -262,29 +642,25 @@ fn read_gpu_active_functions_from_sysfs() -> Vec<String> {
 }
 
 /// Read GPU frequency (in Hz)
-fn read_gpu_freq(devfreq_path: &str) -> u32 {
-    let path = Path::new(devfreq_path).join("cur_freq");
-
-    fs::read_to_string(path)
-        .ok()
-        .and_then(|s| s.trim().parse().ok())
+async fn read_gpu_freq<R: Reader>(reader: &R, devfreq_path: &str) -> u32 {
+    reader
+        .read_value(&Path::new(devfreq_path).join("cur_freq"))
+        .await
         .unwrap_or(0)
 }
 
 /// Read GPU governor
-fn read_gpu_governor(devfreq_path: &str) -> String {
-    let path = Path::new(devfreq_path).join("governor");
-
-    fs::read_to_string(path)
-        .ok()
-        .map(|s| s.trim().to_string())
+async fn read_gpu_governor<R: Reader>(reader: &R, devfreq_path: &str) -> String {
+    reader
+        .read_string(&Path::new(devfreq_path).join("governor"))
+        .await
         .unwrap_or_else(|| "unknown".to_string())
 }
 
 /// Read GPU usage (estimated from devfreq load or nvidia-smi)
-fn read_gpu_usage(devfreq_path: &str) -> f32 {
+async fn read_gpu_usage<R: Reader>(reader: &R, devfreq_path: &str) -> f32 {
     // Try nvidia-smi first (more accurate)
-    if let Ok(usage) = read_nvidia_smi_usage() {
+    if let Ok(usage) = reader.blocking(read_nvidia_smi_usage).await {
         if usage > 0.0 {
             return usage;
         }
@@ -294,19 +670,17 @@ fn read_gpu_usage(devfreq_path: &str) -> f32 {
     let load_path = Path::new(devfreq_path).join("device/load");
 
     // Some devices expose GPU load
-    if let Ok(load_str) = fs::read_to_string(load_path) {
-        if let Ok(load) = load_str.trim().parse::<u64>() {
-            // Load is typically in 0-255 range, convert to percentage
-            return (load as f32 / 255.0 * 100.0).min(100.0);
-        }
+    if let Some(load) = reader.read_value::<u64>(&load_path).await {
+        // Load is typically in 0-255 range, convert to percentage
+        return (load as f32 / 255.0 * 100.0).min(100.0);
     }
 
     // Fallback: estimate from frequency
-    let freq = read_gpu_freq(devfreq_path);
+    let freq = read_gpu_freq(reader, devfreq_path).await;
     if freq > 0 {
         // Rough estimate: higher freq = more usage
         // This is not accurate, but better than 0
-        let max_freq = read_gpu_max_freq(devfreq_path);
+        let max_freq = gpu_max_freq(reader, devfreq_path).await;
         if max_freq > 0 {
             return (freq as f32 / max_freq as f32 * 100.0).min(100.0);
         }
@@ -325,53 +699,127 @@ fn read_gpu_usage(devfreq_path: &str) -> f32 {
 /// # Returns
 /// Maximum GPU frequency in Hz, or 0 if unavailable.
 pub fn read_gpu_max_freq(devfreq_path: &str) -> u32 {
-    let path = Path::new(devfreq_path).join("max_freq");
+    sysfs::block_on(gpu_max_freq(&Blocking, devfreq_path))
+}
 
-    fs::read_to_string(path)
-        .ok()
-        .and_then(|s| s.trim().parse().ok())
+async fn gpu_max_freq<R: Reader>(reader: &R, devfreq_path: &str) -> u32 {
+    reader
+        .read_value(&Path::new(devfreq_path).join("max_freq"))
+        .await
         .unwrap_or(0)
 }
 
 /// Read GPU memory from sysfs
-fn read_gpu_memory_from_sysfs() -> GpuMemoryInfo {
-    // GPU memory is not directly accessible on all Jetson platforms via sysfs
-    // On Xavier and older platforms, nvidia-smi is not available
-    // On newer platforms with nvidia-smi, memory is available via NVML
-    // For now, return zeros for sysfs-only platforms
-    GpuMemoryInfo { used: 0, total: 0 }
+///
+/// Tegra GPUs have no dedicated memory. Without NVML (L4T 35/36) the GPU
+/// allocation is the sum of the nvmap IOVMM clients and the total is
+/// the system RAM it shares. When debugfs is not mounted, the `NvMapMemUsed`
+/// line of /proc/meminfo is used instead; returns zeros if neither exists.
+async fn read_gpu_memory_from_sysfs<R: Reader>(reader: &R) -> GpuMemoryInfo {
+    let clients_path = Path::new("/sys/kernel/debug/nvmap/iovmm/clients");
+
+    if let Ok(content) = reader.read_to_string(clients_path).await {
+        return GpuMemoryInfo {
+            used: parse_nvmap_clients(&content),
+            total: crate::modules::memory::MemoryStats::read(reader)
+                .await
+                .ram_total,
+            shared: true,
+        };
+    }
+
+    match reader
+        .read_to_string(Path::new("/proc/meminfo"))
+        .await
+        .ok()
+        .and_then(|content| parse_meminfo_nvmap(&content))
+    {
+        Some((used, total)) => GpuMemoryInfo {
+            used,
+            total,
+            shared: true,
+        },
+        None => GpuMemoryInfo {
+            used: 0,
+            total: 0,
+            shared: false,
+        },
+    }
+}
+
+/// Parse `NvMapMemUsed` and `MemTotal` from /proc/meminfo, in bytes
+///
+/// Returns `None` on kernels without nvmap accounting.
+pub fn parse_meminfo_nvmap(content: &str) -> Option<(u64, u64)> {
+    let value = |name: &str| {
+        content
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .find(|(key, _)| key.trim() == name)
+            .and_then(|(_, value)| value.trim_end_matches("kB").trim().parse::<u64>().ok())
+            .map(|kb| kb * 1024)
+    };
+
+    Some((value("NvMapMemUsed")?, value("MemTotal")?))
 }
 
 struct GpuMemoryInfo {
     used: u64,
     total: u64,
+    shared: bool,
 }
 
-/// Read GPU temperature
-fn read_gpu_temp() -> f32 {
-    // Try thermal zones
-    let thermal_base = Path::new("/sys/class/thermal");
+/// Sum the allocations of an nvmap `iovmm/clients` table, in bytes
+///
+/// Format:
+/// ```text
+/// CLIENT                        PROCESS      PID        SIZE
+/// user                      gnome-shell     1711      45348K
+/// total                                               268108K
+/// ```
+pub fn parse_nvmap_clients(content: &str) -> u64 {
+    content
+        .lines()
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            match parts.first() {
+                Some(&"CLIENT") | Some(&"total") | None => None,
+                Some(_) => parts.last().and_then(|size| parse_nvmap_size(size)),
+            }
+        })
+        .sum()
+}
+
+/// Parse an nvmap size such as `45348K` into bytes
+fn parse_nvmap_size(size: &str) -> Option<u64> {
+    let (digits, multiplier) = match size.chars().last()? {
+        'K' => (&size[..size.len() - 1], 1024),
+        'M' => (&size[..size.len() - 1], 1024 * 1024),
+        'G' => (&size[..size.len() - 1], 1024 * 1024 * 1024),
+        _ => (size, 1),
+    };
+
+    digits.parse::<u64>().ok().map(|value| value * multiplier)
+}
 
-    if !thermal_base.exists() {
+/// Read GPU temperature
+async fn read_gpu_temp<R: Reader>(reader: &R, thermal_base: &Path) -> f32 {
+    if !reader.exists(thermal_base).await {
         return 0.0;
     }
 
     // Search for GPU thermal zone
-    if let Ok(entries) = fs::read_dir(thermal_base) {
-        for entry in entries.flatten() {
-            let zone_path = entry.path();
-            let type_path = zone_path.join("type");
-
-            if let Ok(zone_type) = fs::read_to_string(type_path) {
-                if zone_type.contains("GPU") {
-                    // Found GPU thermal zone
-                    let temp_path = zone_path.join("temp");
-                    if let Ok(temp_str) = fs::read_to_string(temp_path) {
-                        // Temperature is in millidegrees Celsius
-                        if let Ok(temp_milli) = temp_str.trim().parse::<i32>() {
-                            return temp_milli as f32 / 1000.0;
-                        }
-                    }
+    if let Ok(entries) = reader.read_dir(thermal_base).await {
+        for zone_path in entries {
+            let is_gpu = reader
+                .read_string(&zone_path.join("type"))
+                .await
+                .is_some_and(|zone_type| zone_type.contains("GPU"));
+
+            if is_gpu {
+                // Temperature is in millidegrees Celsius
+                if let Some(temp) = reader.read_f32_milli(&zone_path.join("temp")).await {
+                    return temp;
                 }
             }
         }
@@ -406,29 +854,59 @@ pub fn parse_nvidia_smi_usage(output: &str) -> f32 {
 ///
 /// Returns GPU usage percentage using nvidia-smi command.
 /// Falls back to 0.0 if nvidia-smi is not available.
-pub fn read_nvidia_smi_usage() -> anyhow::Result<f32> {
-    let output = Command::new("nvidia-smi")
-        .args([
-            "--query-gpu=utilization.gpu",
-            "--format=csv,noheader,nounits",
-        ])
-        .output()?;
-
-    if !output.status.success() {
-        anyhow::bail!("nvidia-smi command failed");
-    }
-
-    let stdout = String::from_utf8(output.stdout)?;
+pub fn read_nvidia_smi_usage() -> crate::Result<f32> {
+    let stdout = run_nvidia_smi(&[
+        "--query-gpu=utilization.gpu",
+        "--format=csv,noheader,nounits",
+    ])?;
     let usage_str = stdout.trim();
 
     if usage_str.is_empty() || usage_str == "N/A" {
         return Ok(0.0);
     }
 
-    let usage = usage_str.parse::<f32>()?;
+    let usage = usage_str
+        .parse::<f32>()
+        .map_err(|e| Error::parse("nvidia-smi", e))?;
     Ok(usage.clamp(0.0, 100.0))
 }
 
+/// Parse nvidia-smi memory output
+///
+/// Expects `used, total` in MiB as printed by
+/// `--query-gpu=memory.used,memory.total --format=csv,noheader,nounits`
+/// and returns them in bytes. Fails on `[N/A]`, which unified memory
+/// devices may report.
+pub fn parse_nvidia_smi_memory(output: &str) -> crate::Result<(u64, u64)> {
+    let line = output
+        .lines()
+        .next()
+        .ok_or_else(|| Error::parse("nvidia-smi", "empty memory output"))?;
+    let (used, total) = line
+        .split_once(',')
+        .ok_or_else(|| Error::parse("nvidia-smi", format!("malformed memory output: {}", line)))?;
+
+    let used = used
+        .trim()
+        .parse::<u64>()
+        .map_err(|e| Error::parse("nvidia-smi", e))?;
+    let total = total
+        .trim()
+        .parse::<u64>()
+        .map_err(|e| Error::parse("nvidia-smi", e))?;
+    Ok((used * 1024 * 1024, total * 1024 * 1024))
+}
+
+/// Read GPU memory usage from nvidia-smi
+///
+/// Returns `(used, total)` in bytes.
+pub fn read_nvidia_smi_memory() -> crate::Result<(u64, u64)> {
+    parse_nvidia_smi_memory(&run_nvidia_smi(&[
+        "--query-gpu=memory.used,memory.total",
+        "--format=csv,noheader,nounits",
+    ])?)
+}
+
 /// Parse nvidia-smi pmon output for GPU processes
 ///
 /// # Arguments
@@ -493,24 +971,314 @@ pub fn parse_nvidia_smi_pmon(output: &str) -> Vec<GpuProcess> {
 ///
 /// Returns list of GPU processes using nvidia-smi pmon command.
 /// Falls back to empty list if nvidia-smi is not available.
-pub fn read_nvidia_smi_pmon() -> anyhow::Result<Vec<GpuProcess>> {
-    let output = Command::new("nvidia-smi")
-        .args(["pmon", "-c", "1"])
-        .output()?;
+pub fn read_nvidia_smi_pmon() -> crate::Result<Vec<GpuProcess>> {
+    let stdout = run_nvidia_smi(&["pmon", "-c", "1"])?;
+    Ok(parse_nvidia_smi_pmon(&stdout))
+}
+
+/// Attempts made per nvidia-smi query
+const NVIDIA_SMI_ATTEMPTS: u32 = 3;
+
+/// Pause after a failed nvidia-smi attempt, bounding the added latency to 100ms
+const NVIDIA_SMI_RETRY_DELAY: Duration = Duration::from_millis(50);
 
-    if !output.status.success() {
-        anyhow::bail!("nvidia-smi pmon command failed");
+/// Set once nvidia-smi failed to spawn because it is not installed
+static NVIDIA_SMI_MISSING: AtomicBool = AtomicBool::new(false);
+
+/// Run nvidia-smi with `args` and return its stdout
+///
+/// nvidia-smi exits nonzero when the driver is briefly busy, so failed runs
+/// are retried. A missing binary fails at once, and later calls fail
+/// without trying to spawn it again.
+fn run_nvidia_smi(args: &[&str]) -> crate::Result<String> {
+    if NVIDIA_SMI_MISSING.load(Ordering::Relaxed) {
+        return Err(Error::Io(io::ErrorKind::NotFound.into()));
     }
 
-    let stdout = String::from_utf8(output.stdout)?;
-    let processes = parse_nvidia_smi_pmon(&stdout);
+    retry(NVIDIA_SMI_ATTEMPTS, NVIDIA_SMI_RETRY_DELAY, || {
+        let output = Command::new("nvidia-smi")
+            .args(args)
+            .output()
+            .inspect_err(|e| {
+                if e.kind() == io::ErrorKind::NotFound {
+                    NVIDIA_SMI_MISSING.store(true, Ordering::Relaxed);
+                }
+            })?;
+        if !output.status.success() {
+            return Err(Error::command_failed("nvidia-smi", &output.stderr));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    })
+}
 
-    Ok(processes)
+/// Call `f` up to `attempts` times, sleeping `delay` after each failure
+///
+/// Only [`Error::CommandFailed`] counts as transient; other errors, such as
+/// a command that does not exist, are returned immediately.
+fn retry<T>(
+    attempts: u32,
+    delay: Duration,
+    mut f: impl FnMut() -> crate::Result<T>,
+) -> crate::Result<T> {
+    let mut attempt = 1;
+    loop {
+        match f() {
+            Err(Error::CommandFailed { .. }) if attempt < attempts => {
+                std::thread::sleep(delay);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// GPU collector for the monitoring loop
+#[derive(Debug, Default)]
+pub struct GpuMonitor;
+
+impl GpuMonitor {
+    /// Create a new GPU monitor
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Collector for GpuMonitor {
+    type Stats = GpuStats;
+
+    fn collect(&mut self) -> Self::Stats {
+        GpuStats::get()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modules::engine::EngineStatus;
+    use crate::modules::sysfs::MockDir;
+    use std::fs;
+
+    fn mock_devfreq(name: &str) -> MockDir {
+        let dir = MockDir::new(&format!("gpu-{}", name));
+        fs::write(
+            dir.join("available_governors"),
+            "wmark_active nvhost_podgov userspace performance simple_ondemand\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("available_frequencies"),
+            "306000000 612000000 918000000 1300500000\n",
+        )
+        .unwrap();
+        fs::write(dir.join("governor"), "nvhost_podgov\n").unwrap();
+        fs::write(dir.join("min_freq"), "306000000\n").unwrap();
+        fs::write(dir.join("max_freq"), "612000000\n").unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_set_gpu_governor_rejects_unknown_before_writing() {
+        let dir = mock_devfreq("governor");
+
+        let err = set_governor_in(&dir, "turbo").unwrap_err();
+        assert!(
+            err.to_string().contains("'turbo' is not available"),
+            "{}",
+            err
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("governor")).unwrap(),
+            "nvhost_podgov\n"
+        );
+
+        set_governor_in(&dir, "performance").unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.join("governor")).unwrap(),
+            "performance"
+        );
+    }
+
+    #[test]
+    fn test_set_gpu_frequency_range() {
+        let dir = mock_devfreq("freq");
+        let read = |file: &str| sysfs::read_value::<u64>(dir.join(file)).unwrap();
+
+        assert!(set_frequency_range_in(&dir, 918_000_000, 612_000_000).is_err());
+        assert!(set_frequency_range_in(&dir, 306_000_000, 1_000_000_000).is_err());
+        assert_eq!(read("max_freq"), 612_000_000);
+
+        // Pinning above the current maximum
+        set_frequency_range_in(&dir, 1_300_500_000, 1_300_500_000).unwrap();
+        assert_eq!(read("min_freq"), 1_300_500_000);
+        assert_eq!(read("max_freq"), 1_300_500_000);
+
+        set_frequency_range_in(&dir, 306_000_000, 918_000_000).unwrap();
+        assert_eq!(read("min_freq"), 306_000_000);
+        assert_eq!(read("max_freq"), 918_000_000);
+    }
+
+    #[test]
+    fn test_parse_nvmap_clients() {
+        let content = "CLIENT                        PROCESS      PID        SIZE
+user                        gnome-shell     1711      45348K
+user                               Xorg     1208     108828K
+user                         nvargus-da     2201       2048K
+total                                                156224K
+";
+
+        assert_eq!(parse_nvmap_clients(content), 156224 * 1024);
+    }
+
+    #[test]
+    fn test_parse_nvmap_clients_empty_and_invalid() {
+        assert_eq!(parse_nvmap_clients(""), 0);
+        assert_eq!(
+            parse_nvmap_clients("CLIENT PROCESS PID SIZE\nuser broken 12 abcK\n"),
+            0
+        );
+    }
+
+    #[test]
+    fn test_parse_nvmap_size_units() {
+        assert_eq!(parse_nvmap_size("4K"), Some(4096));
+        assert_eq!(parse_nvmap_size("2M"), Some(2 * 1024 * 1024));
+        assert_eq!(parse_nvmap_size("1G"), Some(1024 * 1024 * 1024));
+        assert_eq!(parse_nvmap_size("512"), Some(512));
+        assert_eq!(parse_nvmap_size("K"), None);
+    }
+
+    #[test]
+    fn test_parse_nvidia_smi_memory() {
+        assert_eq!(
+            parse_nvidia_smi_memory("1234, 62841\n").unwrap(),
+            (1234 * 1024 * 1024, 62841 * 1024 * 1024)
+        );
+        assert!(parse_nvidia_smi_memory("[N/A], [N/A]\n").is_err());
+        assert!(parse_nvidia_smi_memory("").is_err());
+    }
+
+    #[test]
+    fn test_ttl_cache_reuses_value_within_ttl() {
+        let start = Instant::now();
+        let mut cache = TtlCache::new(Duration::from_millis(200));
+        let mut reads = 0;
+        let mut get = |cache: &mut TtlCache<u32>, at: Duration| {
+            cache.get_or_refresh(
+                || start + at,
+                || {
+                    reads += 1;
+                    reads
+                },
+            )
+        };
+
+        let first = get(&mut cache, Duration::ZERO);
+        let second = get(&mut cache, Duration::from_millis(150));
+        assert!(Arc::ptr_eq(&first, &second));
+
+        let third = get(&mut cache, Duration::from_millis(200));
+        assert!(!Arc::ptr_eq(&first, &third));
+        assert_eq!((*first, *third), (1, 2));
+    }
+
+    #[test]
+    fn test_ttl_cache_zero_ttl_always_reads() {
+        let mut cache = TtlCache::new(Duration::ZERO);
+        let now = Instant::now();
+        let first = cache.get_or_refresh(|| now, || 1);
+        let second = cache.get_or_refresh(|| now, || 2);
+        assert_eq!((*first, *second), (1, 2));
+    }
+
+    #[test]
+    fn test_get_cached_returns_same_instance() {
+        let first = GpuStats::get_cached();
+        let second = GpuStats::get_cached();
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+
+    fn busy() -> Error {
+        Error::command_failed("nvidia-smi", b"Unable to determine the device handle")
+    }
+
+    #[test]
+    fn test_retry_recovers_from_transient_failures() {
+        let mut calls = 0;
+        let result = retry(3, Duration::ZERO, || {
+            calls += 1;
+            if calls < 3 {
+                Err(busy())
+            } else {
+                Ok(42)
+            }
+        });
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn test_retry_gives_up_after_attempts() {
+        let mut calls = 0;
+        let result: crate::Result<()> = retry(3, Duration::ZERO, || {
+            calls += 1;
+            Err(busy())
+        });
+
+        assert!(matches!(result, Err(Error::CommandFailed { .. })));
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn test_retry_skips_permanent_failures() {
+        let mut calls = 0;
+        let result: crate::Result<()> = retry(3, Duration::ZERO, || {
+            calls += 1;
+            Err(std::io::Error::from(std::io::ErrorKind::NotFound).into())
+        });
+
+        assert!(matches!(result, Err(Error::Io(_))));
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_missing_nvidia_smi_is_cached() {
+        let installed = std::env::var_os("PATH").is_some_and(|path| {
+            std::env::split_paths(&path).any(|dir| dir.join("nvidia-smi").exists())
+        });
+        if installed {
+            return;
+        }
+
+        assert!(run_nvidia_smi(&["-L"]).is_err());
+        assert!(NVIDIA_SMI_MISSING.load(Ordering::Relaxed));
+        assert!(
+            matches!(run_nvidia_smi(&["-L"]), Err(Error::Io(e)) if e.kind() == io::ErrorKind::NotFound)
+        );
+    }
+
+    #[test]
+    fn test_parse_meminfo_nvmap() {
+        let content = "MemTotal:       30536348 kB\n\
+                       MemFree:        26025092 kB\n\
+                       NvMapMemFree:      38912 kB\n\
+                       NvMapMemUsed:     471240 kB\n";
+        assert_eq!(
+            parse_meminfo_nvmap(content),
+            Some((471_240 * 1024, 30_536_348 * 1024))
+        );
+        assert_eq!(parse_meminfo_nvmap("MemTotal: 30536348 kB\n"), None);
+    }
+
+    #[test]
+    fn test_gpu_stats_get_all() {
+        let all = GpuStats::get_all();
+
+        assert!(!all.is_empty(), "At least the integrated GPU is reported");
+        for (position, stats) in all.iter().enumerate() {
+            assert_eq!(stats.index as usize, position);
+        }
+    }
 
     #[test]
     fn test_gpu_stats_default() {
@@ -532,9 +1300,8 @@ mod tests {
         assert_eq!(stats.temperature, 0.0);
     }
 
-    #[test]
-    fn test_gpu_stats_get() {
-        let stats = GpuStats::get();
+    async fn check_gpu_stats_get<R: Reader>(reader: &R) {
+        let stats = GpuStats::read(reader).await;
 
         assert!(
             stats.usage >= 0.0 && stats.usage <= 100.0 || stats.usage == 0.0,
@@ -546,6 +1313,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_gpu_stats_get() {
+        sysfs::block_on(check_gpu_stats_get(&Blocking));
+    }
+
+    #[tokio::test]
+    async fn test_gpu_stats_get_async() {
+        check_gpu_stats_get(&Tokio).await;
+    }
+
     #[test]
     fn test_find_gpu_devfreq() {
         let devfreq_path = find_gpu_devfreq();
@@ -557,6 +1334,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(unused_comparisons, clippy::absurd_extreme_comparisons)]
     fn test_gpu_frequency_range() {
         let stats = GpuStats::get();
 
@@ -565,11 +1343,12 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::unnecessary_unwrap, clippy::manual_range_contains)]
     fn test_gpu_usage_calculation() {
         let devfreq_path = find_gpu_devfreq();
 
         if devfreq_path.is_some() {
-            let usage = read_gpu_usage(&devfreq_path.unwrap());
+            let usage = sysfs::block_on(read_gpu_usage(&Blocking, &devfreq_path.unwrap()));
             assert!(
                 usage >= 0.0 && usage <= 100.0 || usage == 0.0,
                 "GPU usage should be between 0 and 100"
@@ -579,7 +1358,7 @@ mod tests {
 
     #[test]
     fn test_gpu_temperature_range() {
-        let temp = read_gpu_temp();
+        let temp = sysfs::block_on(read_gpu_temp(&Blocking, Path::new(THERMAL_SYSFS)));
 
         if temp >= 0.0 {
             assert!(temp >= 0.0, "GPU temperature should be at least 0°C");
@@ -588,11 +1367,12 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::unnecessary_unwrap)]
     fn test_gpu_governor() {
         let devfreq_path = find_gpu_devfreq();
 
         if devfreq_path.is_some() {
-            let governor = read_gpu_governor(&devfreq_path.unwrap());
+            let governor = sysfs::block_on(read_gpu_governor(&Blocking, &devfreq_path.unwrap()));
             assert!(
                 !governor.is_empty() || governor == "unknown" || governor == "nvml",
                 "Governor should not be empty or should be 'unknown'/'nvml'"
@@ -612,6 +1392,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::unnecessary_unwrap)]
     fn test_read_gpu_max_freq() {
         let devfreq_path = find_gpu_devfreq();
 
@@ -626,8 +1407,7 @@ mod tests {
     }
 
     #[test]
-    #[ignore = "Requires implementation - failing test for GPU memory reading"]
-    #[test]
+    #[ignore = "Requires Jetson hardware - GPU memory from nvidia-smi or nvmap"]
     fn test_read_gpu_memory() {
         let stats = GpuStats::get();
 
@@ -643,6 +1423,7 @@ mod tests {
 
     #[test]
     #[ignore = "Requires Jetson hardware - GPU state from sysfs"]
+    #[allow(clippy::useless_vec)]
     fn test_read_gpu_state() {
         let stats = GpuStats::get();
 
@@ -660,7 +1441,237 @@ mod tests {
         );
     }
 
+    fn busy_engine(name: &str, usage: u8) -> EngineStatus {
+        EngineStatus {
+            name: name.to_string(),
+            enabled: true,
+            usage,
+            clock: 0,
+        }
+    }
+
+    #[test]
+    fn test_nominal_gpu_cores_orin() {
+        assert_eq!(
+            nominal_gpu_cores("NVIDIA Jetson AGX Orin Developer Kit"),
+            Some(GpuCores {
+                cuda_cores: 2048,
+                sm_count: 16
+            })
+        );
+        assert_eq!(
+            nominal_gpu_cores("NVIDIA Jetson Orin Nano Developer Kit"),
+            Some(GpuCores {
+                cuda_cores: 1024,
+                sm_count: 8
+            })
+        );
+        assert_eq!(
+            nominal_gpu_cores("NVIDIA Jetson Orin Nano 4GB").map(|c| c.cuda_cores),
+            Some(512)
+        );
+        assert_eq!(
+            nominal_gpu_cores("NVIDIA Jetson AGX Orin 32GB").map(|c| c.sm_count),
+            Some(14)
+        );
+    }
+
+    #[test]
+    fn test_nominal_gpu_cores_other_boards() {
+        assert_eq!(
+            nominal_gpu_cores("NVIDIA Jetson Nano Developer Kit").map(|c| c.cuda_cores),
+            Some(128)
+        );
+        assert_eq!(
+            nominal_gpu_cores("NVIDIA Jetson Xavier NX").map(|c| c.cuda_cores),
+            Some(384)
+        );
+        assert_eq!(nominal_gpu_cores("Unknown Jetson Board"), None);
+    }
+
+    #[test]
+    fn test_gpu_arch_and_name_for_chip() {
+        assert_eq!(
+            gpu_arch_for_chip("tegra264").as_deref(),
+            Some("Blackwell (Thor)")
+        );
+        assert_eq!(
+            gpu_arch_for_chip("tegra234").as_deref(),
+            Some("Ampere (Orin)")
+        );
+        assert_eq!(
+            gpu_arch_for_chip("tegra194").as_deref(),
+            Some("Volta (Xavier)")
+        );
+        assert_eq!(
+            gpu_arch_for_chip("tegra186").as_deref(),
+            Some("Pascal (TX2)")
+        );
+        assert_eq!(
+            gpu_arch_for_chip("tegra210").as_deref(),
+            Some("Maxwell (TX1)")
+        );
+        assert_eq!(gpu_arch_for_chip("bcm2711"), None);
+
+        assert_eq!(gpu_name_for_chip("tegra234"), Some("NVIDIA Orin"));
+        assert_eq!(gpu_name_for_chip("tegra186"), Some("NVIDIA Tegra X2"));
+        assert_eq!(gpu_name_for_chip("bcm2711"), None);
+    }
+
+    #[test]
+    fn test_gpu_name_and_arch_fall_back() {
+        assert!(!GpuStats::name().is_empty());
+        if hardware::detect_chip().is_none() {
+            assert_eq!(GpuStats::arch(), "Unknown");
+        }
+    }
+
+    #[test]
+    fn test_active_functions_from_engines() {
+        let engines = EngineStats {
+            nvdec: busy_engine("nvdec", 35),
+            ..Default::default()
+        };
+
+        assert_eq!(active_functions(0.0, &engines), vec!["NVDEC"]);
+        assert_eq!(active_functions(12.5, &engines), vec!["CUDA", "NVDEC"]);
+    }
+
+    #[test]
+    fn test_active_functions_skip_idle_engines() {
+        let engines = EngineStats {
+            nvjpg: busy_engine("nvjpg", 0),
+            dla1: busy_engine("dla1", 80),
+            nvenc: busy_engine("nvenc", 50),
+            ofa: busy_engine("ofa", 5),
+            dla0: EngineStatus {
+                enabled: false,
+                ..busy_engine("dla0", 10)
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            active_functions(0.0, &engines),
+            vec!["NVENC", "DLA1", "OFA"]
+        );
+        assert!(active_functions(0.0, &EngineStats::default()).is_empty());
+    }
+
+    #[test]
+    fn test_gpu_functions_carry_usage() {
+        let engines = EngineStats {
+            nvdec: busy_engine("nvdec", 35),
+            nvenc: busy_engine("nvenc", 0),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            gpu_functions(62.6, &engines),
+            vec![
+                GpuFunction {
+                    name: "CUDA".to_string(),
+                    usage: 63,
+                },
+                GpuFunction {
+                    name: "NVDEC".to_string(),
+                    usage: 35,
+                },
+            ]
+        );
+        assert_eq!(
+            active_functions(62.6, &engines),
+            function_names(&gpu_functions(62.6, &engines))
+        );
+    }
+
+    async fn check_read_devfreq<R: Reader>(reader: &R) {
+        let dir = mock_devfreq("devfreq");
+        fs::write(dir.join("cur_freq"), "306000000\n").unwrap();
+        let path = dir.to_string_lossy();
+
+        assert_eq!(read_gpu_freq(reader, &path).await, 306_000_000);
+        assert_eq!(read_gpu_governor(reader, &path).await, "nvhost_podgov");
+        assert_eq!(gpu_max_freq(reader, &path).await, 612_000_000);
+
+        let missing = dir.join("missing");
+        let missing = missing.to_string_lossy();
+        assert_eq!(read_gpu_freq(reader, &missing).await, 0);
+        assert_eq!(read_gpu_governor(reader, &missing).await, "unknown");
+    }
+
     #[test]
+    fn test_read_devfreq() {
+        sysfs::block_on(check_read_devfreq(&Blocking));
+    }
+
+    #[tokio::test]
+    async fn test_read_devfreq_async() {
+        check_read_devfreq(&Tokio).await;
+    }
+
+    async fn check_read_gpu_temp<R: Reader>(reader: &R) {
+        let thermal = MockDir::new("gpu-thermal");
+        for (zone, kind, temp) in [
+            ("thermal_zone0", "CPU-therm", "45000"),
+            ("thermal_zone1", "GPU-therm", "51500"),
+        ] {
+            fs::create_dir_all(thermal.join(zone)).unwrap();
+            fs::write(thermal.join(zone).join("type"), format!("{}\n", kind)).unwrap();
+            fs::write(thermal.join(zone).join("temp"), format!("{}\n", temp)).unwrap();
+        }
+
+        assert_eq!(read_gpu_temp(reader, &thermal).await, 51.5);
+        assert_eq!(read_gpu_temp(reader, &thermal.join("missing")).await, 0.0);
+    }
+
+    #[test]
+    fn test_read_gpu_temp() {
+        sysfs::block_on(check_read_gpu_temp(&Blocking));
+    }
+
+    #[tokio::test]
+    async fn test_read_gpu_temp_async() {
+        check_read_gpu_temp(&Tokio).await;
+    }
+
+    async fn check_read_busy_percent<R: Reader>(reader: &R) {
+        let dir = MockDir::new("gpu-busy");
+        let node = dir.join("gpu_busy_percent");
+
+        fs::write(&node, "42\n").unwrap();
+        assert_eq!(read_busy_percent(reader, &node).await, Some(42));
+
+        fs::write(&node, "250\n").unwrap();
+        assert_eq!(read_busy_percent(reader, &node).await, Some(100));
+
+        fs::write(&node, "busy\n").unwrap();
+        assert_eq!(read_busy_percent(reader, &node).await, None);
+        assert_eq!(read_busy_percent(reader, &dir.join("missing")).await, None);
+    }
+
+    #[test]
+    fn test_read_busy_percent() {
+        sysfs::block_on(check_read_busy_percent(&Blocking));
+    }
+
+    #[tokio::test]
+    async fn test_read_busy_percent_async() {
+        check_read_busy_percent(&Tokio).await;
+    }
+
+    #[test]
+    fn test_gpu_stats_deserialize_without_functions() {
+        let json = r#"{"usage":10.0,"frequency":0,"temperature":0.0,"governor":"",
+            "memory_used":0,"memory_total":0,"memory_shared":false,"state":"",
+            "active_functions":["CUDA"]}"#;
+        let stats: GpuStats = serde_json::from_str(json).unwrap();
+        assert_eq!(stats.active_functions, vec!["CUDA"]);
+        assert!(stats.functions.is_empty());
+    }
+
+    #[test]
+    #[allow(unused_variables, clippy::useless_vec)]
     fn test_read_gpu_active_functions() {
         let stats = GpuStats::get();
 
@@ -714,6 +1725,7 @@ fn test_print_gpu_info() {
 }
 
 #[test]
+#[allow(clippy::unnecessary_unwrap)]
 fn test_nvidia_thor_support() {
     let devfreq_path = find_gpu_devfreq();
 
@@ -730,7 +1742,7 @@ fn test_nvidia_thor_support() {
 #[cfg(feature = "nvml")]
 #[test]
 fn test_nvml_support() {
-    let board = detect_board();
+    let board = crate::modules::hardware::detect_board();
 
     if board.l4t.starts_with("38.") || board.l4t.starts_with("39.") {
         println!(
@@ -740,6 +1752,15 @@ fn test_nvml_support() {
     }
 }
 
+#[cfg(feature = "nvml")]
+#[test]
+fn test_nvml_context_is_shared() {
+    let first = NvmlContext::get().map(|context| context as *const NvmlContext);
+    let second = NvmlContext::get().map(|context| context as *const NvmlContext);
+
+    assert_eq!(first, second, "NVML should only be initialized once");
+}
+
 #[test]
 fn test_nvidia_smi_parsing() {
     let sample_output = "45%";
@@ -806,6 +1827,7 @@ fn test_gpu_process_list_empty() {
 
 #[test]
 #[ignore = "Requires Jetson hardware with nvidia-smi - run with: cargo test gpu -- --ignored"]
+#[allow(clippy::manual_range_contains)]
 fn test_nvidia_smi_usage_reading() {
     let is_jetson_device = crate::modules::hardware::is_jetson();
 
@@ -7,12 +7,21 @@ use std::fs;
 use std::path::Path;
 
 /// Jetson board information
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 pub struct BoardInfo {
     pub model: String,
     pub jetpack: String,
     pub l4t: String,
     pub serial: String,
+    /// Compute module, e.g. "Orin NX 16GB"
+    pub module: String,
+    /// Carrier board the module sits on, e.g. "p3768 devkit"
+    pub carrier: String,
+    /// 699-level part number of the module, e.g. "699-13701-0005-500 M.0"
+    pub part_number: String,
+    /// EEPROM board IDs, module first, e.g. "3701-0005-500-M.0"
+    pub board_ids: Vec<String>,
 }
 
 impl Default for BoardInfo {
@@ -22,10 +31,45 @@ impl Default for BoardInfo {
             jetpack: "Unknown".to_string(),
             l4t: "Unknown".to_string(),
             serial: "Unknown".to_string(),
+            module: "Unknown".to_string(),
+            carrier: "Unknown".to_string(),
+            part_number: "Unknown".to_string(),
+            board_ids: Vec::new(),
         }
     }
 }
 
+/// Module part numbers and their names
+const MODULES: &[(&str, &str)] = &[
+    ("p3767-0000", "Orin NX 16GB"),
+    ("p3767-0001", "Orin NX 8GB"),
+    ("p3767-0003", "Orin Nano 8GB"),
+    ("p3767-0004", "Orin Nano 4GB"),
+    ("p3767-0005", "Orin Nano 8GB"),
+    ("p3701-0000", "AGX Orin"),
+    ("p3701-0004", "AGX Orin 32GB"),
+    ("p3701-0005", "AGX Orin 64GB"),
+    ("p3701-0008", "AGX Orin Industrial"),
+    ("p3668-0000", "Xavier NX"),
+    ("p3668-0001", "Xavier NX"),
+    ("p3668-0003", "Xavier NX 16GB"),
+    ("p2888-0001", "AGX Xavier"),
+    ("p2888-0004", "AGX Xavier 32GB"),
+    ("p2888-0008", "AGX Xavier Industrial"),
+    ("p3448-0000", "Nano"),
+    ("p3448-0002", "Nano"),
+    ("p3448-0003", "Nano 2GB"),
+    ("p3636-0001", "TX2 NX"),
+    ("p3310-1000", "TX2"),
+    ("p3489-0000", "TX2i"),
+    ("p2180-1000", "TX1"),
+];
+
+/// NVIDIA developer kit carrier boards by part number prefix
+const DEVKIT_CARRIERS: &[&str] = &[
+    "p3768", "p3737", "p3509", "p3449", "p2822", "p2771", "p2597",
+];
+
 /// Parse L4T version from nv_tegra_release content
 pub fn parse_l4t_version(content: &str) -> String {
     for line in content.lines() {
@@ -119,42 +163,56 @@ pub fn detect_serial_number() -> String {
     "Unknown".to_string()
 }
 
+/// Tegra chips with their SoC family, GPU architecture and GPU name,
+/// newest first
+const TEGRA_SOCS: [(&str, &str, &str, &str); 5] = [
+    ("tegra264", "Thor", "Blackwell", "NVIDIA Thor"),
+    ("tegra234", "Orin", "Ampere", "NVIDIA Orin"),
+    ("tegra194", "Xavier", "Volta", "NVIDIA Xavier"),
+    ("tegra186", "TX2", "Pascal", "NVIDIA Tegra X2"),
+    ("tegra210", "TX1", "Maxwell", "NVIDIA Tegra X1"),
+];
+
+/// SoC family of a Tegra chip, e.g. `Orin` for `tegra234`
+pub fn soc_name(chip: &str) -> Option<&'static str> {
+    TEGRA_SOCS
+        .iter()
+        .find(|(c, ..)| *c == chip)
+        .map(|&(_, name, ..)| name)
+}
+
+/// Architecture and name of the integrated GPU of a Tegra chip, e.g.
+/// `("Ampere", "NVIDIA Orin")` for `tegra234`
+pub fn tegra_gpu(chip: &str) -> Option<(&'static str, &'static str)> {
+    TEGRA_SOCS
+        .iter()
+        .find(|(c, ..)| *c == chip)
+        .map(|&(_, _, arch, name)| (arch, name))
+}
+
+/// First Tegra chip named in `text`
+fn find_chip(text: &str) -> Option<&'static str> {
+    let text = text.to_lowercase();
+    TEGRA_SOCS
+        .iter()
+        .map(|&(chip, ..)| chip)
+        .find(|chip| text.contains(chip))
+}
+
+/// Detect the Tegra chip, e.g. `tegra234`, from the device tree
+pub fn detect_chip() -> Option<&'static str> {
+    ["model", "compatible"].iter().find_map(|node| {
+        let path = Path::new("/sys/firmware/devicetree/base").join(node);
+        fs::read_to_string(path).ok().and_then(|s| find_chip(&s))
+    })
+}
+
 /// Detect SoC architecture/variant
 pub fn detect_architecture() -> String {
-    let machine_path = Path::new("/sys/firmware/devicetree/base/model");
-
-    if let Ok(model) = fs::read_to_string(machine_path) {
-        let model = model.to_lowercase();
-        if model.contains("tegra264") {
-            return "Thor (tegra264)".to_string();
-        } else if model.contains("tegra234") {
-            return "Orin (tegra234)".to_string();
-        } else if model.contains("tegra194") {
-            return "Xavier (tegra194)".to_string();
-        } else if model.contains("tegra186") {
-            return "TX2 (tegra186)".to_string();
-        } else if model.contains("tegra210") {
-            return "TX1 (tegra210)".to_string();
-        }
+    match detect_chip() {
+        Some(chip) => format!("{} ({})", soc_name(chip).unwrap_or("Unknown"), chip),
+        None => "Unknown".to_string(),
     }
-
-    let compatible_path = Path::new("/sys/firmware/devicetree/base/compatible");
-    if let Ok(compatible) = fs::read_to_string(compatible_path) {
-        let compatible = compatible.to_lowercase();
-        if compatible.contains("tegra264") {
-            return "Thor (tegra264)".to_string();
-        } else if compatible.contains("tegra234") {
-            return "Orin (tegra234)".to_string();
-        } else if compatible.contains("tegra194") {
-            return "Xavier (tegra194)".to_string();
-        } else if compatible.contains("tegra186") {
-            return "TX2 (tegra186)".to_string();
-        } else if compatible.contains("tegra210") {
-            return "TX1 (tegra210)".to_string();
-        }
-    }
-
-    "Unknown".to_string()
 }
 
 /// Detect board information from /etc/nv_tegra_release
@@ -207,9 +265,179 @@ pub fn detect_board() -> BoardInfo {
         info.serial = detect_serial_number();
     }
 
+    let (module, carrier) = detect_module_and_carrier();
+    if info.model == "Unknown Jetson Board" && module != "Unknown" {
+        info.model = format!("Jetson {} on {}", module, carrier);
+    }
+    info.module = module;
+    info.carrier = carrier;
+
+    info.board_ids = detect_board_ids();
+    if let Some(part_number) = info.board_ids.first().and_then(|id| part_number(id)) {
+        info.part_number = part_number;
+    }
+
     info
 }
 
+/// Detect the module and carrier board from the device tree
+pub fn detect_module_and_carrier() -> (String, String) {
+    let base = Path::new("/sys/firmware/devicetree/base");
+    let read = |name: &str| fs::read_to_string(base.join(name)).unwrap_or_default();
+
+    parse_module_and_carrier(&read("nvidia,dtsfilename"), &read("compatible"))
+}
+
+/// Split the module and carrier board out of the device tree
+///
+/// Developer kit device trees are named `<carrier>+<module>`, e.g.
+/// `tegra234-p3768-0000+p3767-0000-nv.dts` with the compatible string
+/// `nvidia,p3768-0000+p3767-0000`. Third-party carriers usually put their
+/// own compatible string first and list the module after it. Parts that
+/// are not recognized are reported by their part number, or "Unknown".
+pub fn parse_module_and_carrier(dtsfilename: &str, compatible: &str) -> (String, String) {
+    let mut module_part = None;
+    let mut carrier = None;
+
+    // The dts file name wins when it names both parts
+    let dts_name = dtsfilename
+        .trim_end_matches('\0')
+        .rsplit('/')
+        .next()
+        .unwrap_or_default();
+    if let Some((c, m)) = split_board_pair(dts_name) {
+        carrier = Some(carrier_name(&c));
+        module_part = Some(m);
+    }
+
+    for entry in compatible
+        .split('\0')
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+    {
+        let (vendor, name) = entry.split_once(',').unwrap_or(("", entry));
+
+        if let Some((c, m)) = split_board_pair(name) {
+            carrier.get_or_insert_with(|| carrier_name(&c));
+            module_part.get_or_insert(m);
+        } else if module_part.is_none() && module_name(name).is_some() {
+            module_part = Some(name.to_string());
+        } else if carrier.is_none() && vendor != "nvidia" && module_name(name).is_none() {
+            // A vendor-specific entry describes a custom carrier
+            carrier = Some(entry.to_string());
+        }
+    }
+
+    let module = module_part
+        .map(|part| module_name(&part).map_or(part, str::to_string))
+        .unwrap_or_else(|| "Unknown".to_string());
+    (module, carrier.unwrap_or_else(|| "Unknown".to_string()))
+}
+
+/// Find a `pXXXX-XXXX+pYYYY-YYYY` carrier/module pair in a name
+fn split_board_pair(name: &str) -> Option<(String, String)> {
+    let (left, right) = name.split_once('+')?;
+    let carrier = left.rsplit('-').collect::<Vec<_>>();
+    // Keep only the trailing "pXXXX-XXXX" of the left side
+    let carrier = match carrier.as_slice() {
+        [sku, part, ..] if is_part_number(part, sku) => format!("{}-{}", part, sku),
+        _ => return None,
+    };
+    let mut right = right.split('-');
+    let module = match (right.next(), right.next()) {
+        (Some(part), Some(sku)) if is_part_number(part, sku) => format!("{}-{}", part, sku),
+        _ => return None,
+    };
+    Some((carrier, module))
+}
+
+/// Whether `part` and `sku` look like `p3767` and `0000`
+fn is_part_number(part: &str, sku: &str) -> bool {
+    part.len() == 5
+        && part.starts_with('p')
+        && part[1..].chars().all(|c| c.is_ascii_digit())
+        && sku.len() == 4
+        && sku.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn module_name(part: &str) -> Option<&'static str> {
+    MODULES
+        .iter()
+        .find(|(p, _)| *p == part)
+        .map(|(_, name)| *name)
+}
+
+fn carrier_name(part: &str) -> String {
+    let base = part.split('-').next().unwrap_or(part);
+    if DEVKIT_CARRIERS.contains(&base) {
+        format!("{} devkit", base)
+    } else {
+        part.to_string()
+    }
+}
+
+/// Detect the EEPROM board IDs of the module and carrier board
+///
+/// Reads `nvidia,proc-boardid` and the IDs the bootloader passes in
+/// `chosen`, either as the `ids` string or as `plugin-manager/ids` entries.
+pub fn detect_board_ids() -> Vec<String> {
+    let base = Path::new("/sys/firmware/devicetree/base");
+    let mut blob = String::new();
+    for name in ["nvidia,proc-boardid", "chosen/ids"] {
+        blob.push_str(&fs::read_to_string(base.join(name)).unwrap_or_default());
+        blob.push('\0');
+    }
+
+    let plugin_ids = fs::read_dir(base.join("chosen/plugin-manager/ids"));
+    for entry in plugin_ids.into_iter().flatten().flatten() {
+        blob.push_str(&entry.file_name().to_string_lossy());
+        blob.push('\0');
+    }
+
+    parse_board_ids(&blob)
+}
+
+/// Parse board IDs such as `3701-0005-500-M.0` out of device tree strings
+///
+/// IDs are separated by NULs or whitespace. Anything that is not a board
+/// ID is skipped and duplicates are dropped, keeping the first occurrence.
+pub fn parse_board_ids(blob: &str) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    for token in blob.split(|c: char| c == '\0' || c.is_whitespace()) {
+        let token = token.trim_start_matches(|c: char| !c.is_ascii_alphanumeric());
+        if is_board_id(token) && !ids.iter().any(|id| id == token) {
+            ids.push(token.to_string());
+        }
+    }
+    ids
+}
+
+/// Whether `id` has the `BBBB-SSSS-FFF-R.R` board, SKU, fab and revision form
+fn is_board_id(id: &str) -> bool {
+    let digits = |s: &str, len| s.len() == len && s.chars().all(|c| c.is_ascii_digit());
+    match id.split('-').collect::<Vec<_>>().as_slice() {
+        [board, sku, fab, rev] => {
+            digits(board, 4)
+                && digits(sku, 4)
+                && digits(fab, 3)
+                && !rev.is_empty()
+                && rev.chars().all(|c| c.is_ascii_alphanumeric() || c == '.')
+        }
+        _ => false,
+    }
+}
+
+/// 699-level part number of a board ID
+///
+/// `3701-0005-500-M.0` becomes `699-13701-0005-500 M.0`.
+pub fn part_number(board_id: &str) -> Option<String> {
+    if !is_board_id(board_id) {
+        return None;
+    }
+    let (base, rev) = board_id.rsplit_once('-')?;
+    Some(format!("699-1{} {}", base, rev))
+}
+
 /// Derive Jetpack version from L4T version
 fn derive_jetpack_from_l4t(l4t: &str) -> String {
     use std::collections::HashMap;
@@ -410,6 +638,49 @@ mod tests {
         assert_eq!(info.jetpack, "Unknown");
         assert_eq!(info.l4t, "Unknown");
         assert_eq!(info.serial, "Unknown");
+        assert_eq!(info.module, "Unknown");
+        assert_eq!(info.carrier, "Unknown");
+    }
+
+    #[test]
+    fn test_module_and_carrier_orin_nx_devkit() {
+        // Captured from an Orin NX 16GB on the Orin Nano developer kit carrier
+        let dtsfilename = "/dvs/git/dirty/git-master_linux/kernel/kernel-jammy-src/arch/arm64/boot/dts/../../../../../../hardware/nvidia/t23x/nv-public/tegra234-p3768-0000+p3767-0000-nv.dts\0";
+        let compatible = "nvidia,p3768-0000+p3767-0000\0nvidia,p3767-0000\0nvidia,tegra234\0";
+
+        let (module, carrier) = parse_module_and_carrier(dtsfilename, compatible);
+        assert_eq!(module, "Orin NX 16GB");
+        assert_eq!(carrier, "p3768 devkit");
+
+        // The compatible strings alone are enough
+        assert_eq!(
+            parse_module_and_carrier("", compatible),
+            ("Orin NX 16GB".to_string(), "p3768 devkit".to_string())
+        );
+    }
+
+    #[test]
+    fn test_module_and_carrier_custom_carrier() {
+        let compatible = "seeed,recomputer-j401\0nvidia,p3767-0003\0nvidia,tegra234\0";
+        assert_eq!(
+            parse_module_and_carrier("", compatible),
+            (
+                "Orin Nano 8GB".to_string(),
+                "seeed,recomputer-j401".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_module_and_carrier_unknown_parts() {
+        assert_eq!(
+            parse_module_and_carrier("tegra234-p9999-0000+p3999-0001-nv.dts", ""),
+            ("p3999-0001".to_string(), "p9999-0000".to_string())
+        );
+        assert_eq!(
+            parse_module_and_carrier("", "nvidia,tegra234\0"),
+            ("Unknown".to_string(), "Unknown".to_string())
+        );
     }
 
     #[test]
@@ -421,6 +692,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_board_ids() {
+        // nvidia,proc-boardid followed by chosen/ids of an AGX Orin devkit
+        let blob = "3701-0005-500-M.0\x00\x003701-0005-500-M.0 3737-0000-500-N.1 \x00";
+        assert_eq!(
+            parse_board_ids(blob),
+            vec!["3701-0005-500-M.0", "3737-0000-500-N.1"]
+        );
+
+        // plugin-manager entries may carry a comparison prefix
+        assert_eq!(
+            parse_board_ids(">=3767-0000-300-N.1\0name\0"),
+            vec!["3767-0000-300-N.1"]
+        );
+        assert!(parse_board_ids("").is_empty());
+        assert!(parse_board_ids("3701\x00p3701-0005\x003701-0005-500-\x00").is_empty());
+    }
+
+    #[test]
+    fn test_part_number() {
+        assert_eq!(
+            part_number("3701-0005-500-M.0").as_deref(),
+            Some("699-13701-0005-500 M.0")
+        );
+        assert_eq!(part_number("3701-0005"), None);
+        assert_eq!(BoardInfo::default().part_number, "Unknown");
+        assert!(BoardInfo::default().board_ids.is_empty());
+    }
+
+    #[test]
+    fn test_find_chip() {
+        assert_eq!(
+            find_chip("nvidia,p3737-0000+p3701-0005\0nvidia,tegra234\0"),
+            Some("tegra234")
+        );
+        assert_eq!(find_chip("NVIDIA TEGRA194"), Some("tegra194"));
+        assert_eq!(find_chip("raspberrypi,4-model-b"), None);
+        assert_eq!(soc_name("tegra264"), Some("Thor"));
+        assert_eq!(soc_name("tegra186"), Some("TX2"));
+        assert_eq!(soc_name("bcm2711"), None);
+    }
+
+    #[test]
+    fn test_tegra_gpu() {
+        assert_eq!(tegra_gpu("tegra234"), Some(("Ampere", "NVIDIA Orin")));
+        assert_eq!(tegra_gpu("tegra210"), Some(("Maxwell", "NVIDIA Tegra X1")));
+        assert_eq!(tegra_gpu("bcm2711"), None);
+    }
+
     #[test]
     #[ignore = "Requires Jetson hardware - run with: cargo test hardware -- --ignored"]
     fn test_print_hardware_info() {
@@ -451,6 +771,8 @@ mod tests {
         println!("Jetpack: {}", board.jetpack);
         println!("L4T: {}", board.l4t);
         println!("Serial: {}", board.serial);
+        println!("Module: {}", board.module);
+        println!("Carrier: {}", board.carrier);
 
         let release_path = Path::new("/etc/nv_tegra_release");
         if let Ok(content) = fs::read_to_string(release_path) {
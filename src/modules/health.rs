@@ -0,0 +1,209 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Health summary module
+//!
+//! Reduces temperature and memory statistics to a single Ok/Warn/Critical
+//! status with a one-line reason, for watchdog probes such as Nagios checks
+//! or systemd health units.
+
+use std::fmt;
+
+use crate::modules::memory::MemoryStats;
+use crate::modules::temperature::TemperatureStats;
+
+/// Overall health status
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum HealthStatus {
+    #[default]
+    Ok,
+    Warn,
+    Critical,
+}
+
+impl HealthStatus {
+    /// Process exit code following the Nagios plugin convention
+    pub fn exit_code(self) -> i32 {
+        match self {
+            HealthStatus::Ok => 0,
+            HealthStatus::Warn => 1,
+            HealthStatus::Critical => 2,
+        }
+    }
+}
+
+impl fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HealthStatus::Ok => write!(f, "OK"),
+            HealthStatus::Warn => write!(f, "WARNING"),
+            HealthStatus::Critical => write!(f, "CRITICAL"),
+        }
+    }
+}
+
+/// Limits used to derive the health status
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct HealthThresholds {
+    /// Warn when any thermal zone reaches this temperature in °C
+    pub warn_temp: f32,
+    /// Critical when any thermal zone reaches this temperature in °C
+    pub crit_temp: f32,
+    /// Warn when RAM usage reaches this percentage
+    pub warn_memory: f32,
+    /// Critical when RAM usage reaches this percentage
+    pub crit_memory: f32,
+}
+
+impl Default for HealthThresholds {
+    fn default() -> Self {
+        Self {
+            warn_temp: 80.0,
+            crit_temp: 95.0,
+            warn_memory: 90.0,
+            crit_memory: 98.0,
+        }
+    }
+}
+
+/// Health status with the reason behind it
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct HealthSummary {
+    pub status: HealthStatus,
+    pub reason: String,
+}
+
+impl HealthSummary {
+    /// Evaluate the current system health
+    pub fn get(thresholds: &HealthThresholds) -> Self {
+        health_summary(&TemperatureStats::get(), &MemoryStats::get(), thresholds)
+    }
+}
+
+impl fmt::Display for HealthSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.status, self.reason)
+    }
+}
+
+/// Derive the health status from temperature and memory statistics
+///
+/// The worst condition wins; its description becomes the reason.
+pub fn health_summary(
+    temperature: &TemperatureStats,
+    memory: &MemoryStats,
+    thresholds: &HealthThresholds,
+) -> HealthSummary {
+    let mut checks = Vec::new();
+
+    if let Some(zone) = temperature.hottest_zone() {
+        let status = level(
+            zone.current_temp,
+            thresholds.warn_temp,
+            thresholds.crit_temp,
+        );
+        checks.push(HealthSummary {
+            status,
+            reason: format!("{} at {:.1}°C", zone.name, zone.current_temp),
+        });
+    }
+
+    if memory.ram_total > 0 {
+        let percent = memory.ram_used as f32 / memory.ram_total as f32 * 100.0;
+        let status = level(percent, thresholds.warn_memory, thresholds.crit_memory);
+        checks.push(HealthSummary {
+            status,
+            reason: format!("RAM at {:.0}%", percent),
+        });
+    }
+
+    let worst = checks
+        .iter()
+        .map(|c| c.status)
+        .max_by_key(|status| status.exit_code())
+        .unwrap_or_default();
+    let reason = checks
+        .iter()
+        .filter(|c| c.status == worst)
+        .map(|c| c.reason.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    HealthSummary {
+        status: worst,
+        reason: if reason.is_empty() {
+            "no sensors available".to_string()
+        } else {
+            reason
+        },
+    }
+}
+
+fn level(value: f32, warn: f32, crit: f32) -> HealthStatus {
+    if value >= crit {
+        HealthStatus::Critical
+    } else if value >= warn {
+        HealthStatus::Warn
+    } else {
+        HealthStatus::Ok
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::temperature::ThermalZone;
+
+    fn temps(zones: &[(&str, f32)]) -> TemperatureStats {
+        TemperatureStats {
+            thermal_zones: zones
+                .iter()
+                .map(|(name, temp)| ThermalZone {
+                    name: name.to_string(),
+                    current_temp: *temp,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn memory(used: u64, total: u64) -> MemoryStats {
+        MemoryStats {
+            ram_used: used,
+            ram_total: total,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_exit_codes() {
+        assert_eq!(HealthStatus::Ok.exit_code(), 0);
+        assert_eq!(HealthStatus::Warn.exit_code(), 1);
+        assert_eq!(HealthStatus::Critical.exit_code(), 2);
+    }
+
+    #[test]
+    fn test_worst_condition_wins() {
+        let summary = health_summary(
+            &temps(&[("cpu-thermal", 55.0), ("gpu-thermal", 84.5)]),
+            &memory(99, 100),
+            &HealthThresholds::default(),
+        );
+
+        assert_eq!(summary.status, HealthStatus::Critical);
+        assert_eq!(summary.to_string(), "CRITICAL: RAM at 99%");
+    }
+
+    #[test]
+    fn test_no_sensors_is_ok() {
+        let summary = health_summary(
+            &TemperatureStats::default(),
+            &MemoryStats::default(),
+            &HealthThresholds::default(),
+        );
+
+        assert_eq!(summary.status, HealthStatus::Ok);
+        assert_eq!(summary.reason, "no sensors available");
+    }
+}
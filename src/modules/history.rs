@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Rolling history module
+//!
+//! Fixed-capacity buffer of the most recent samples, oldest first, used to
+//! feed sparklines.
+
+/// Default number of samples kept, two minutes at the default 1s refresh
+pub const DEFAULT_CAPACITY: usize = 120;
+
+/// Ring buffer keeping the last `capacity` samples
+///
+/// Samples are stored contiguously so [`History::as_slice`] can hand them
+/// out without copying; the storage is compacted once it reaches twice the
+/// capacity, which keeps `push` amortized O(1).
+#[derive(Debug, Clone, PartialEq)]
+pub struct History<T> {
+    values: Vec<T>,
+    start: usize,
+    capacity: usize,
+}
+
+impl<T> History<T> {
+    /// Create an empty history keeping at most `capacity` samples
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity),
+            start: 0,
+            capacity,
+        }
+    }
+
+    /// Append a sample, dropping the oldest one when full
+    pub fn push(&mut self, value: T) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.len() == self.capacity {
+            self.start += 1;
+        }
+        self.values.push(value);
+
+        if self.start >= self.capacity {
+            self.values.drain(..self.start);
+            self.start = 0;
+        }
+    }
+
+    /// Samples from oldest to newest
+    pub fn as_slice(&self) -> &[T] {
+        &self.values[self.start..]
+    }
+
+    /// Most recent sample
+    pub fn last(&self) -> Option<&T> {
+        self.values.last()
+    }
+
+    /// Number of samples kept
+    pub fn len(&self) -> usize {
+        self.values.len() - self.start
+    }
+
+    /// Whether no sample was pushed yet
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Maximum number of samples kept
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Drop all samples
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.start = 0;
+    }
+}
+
+impl<T> Default for History<T> {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_keeps_last_samples_in_order() {
+        let mut history = History::new(3);
+        assert!(history.is_empty());
+
+        for value in 1..=5 {
+            history.push(value);
+        }
+
+        assert_eq!(history.as_slice(), &[3, 4, 5]);
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.last(), Some(&5));
+    }
+
+    #[test]
+    fn test_history_compacts_storage() {
+        let mut history = History::new(4);
+        for value in 0..1000 {
+            history.push(value);
+            assert!(history.values.len() < 2 * history.capacity());
+        }
+
+        assert_eq!(history.as_slice(), &[996, 997, 998, 999]);
+    }
+
+    #[test]
+    fn test_history_zero_capacity() {
+        let mut history = History::new(0);
+        history.push(1.0);
+
+        assert!(history.as_slice().is_empty());
+        assert_eq!(history.last(), None);
+    }
+
+    #[test]
+    fn test_history_clear() {
+        let mut history = History::new(2);
+        history.push(1);
+        history.push(2);
+        history.push(3);
+        history.clear();
+
+        assert!(history.is_empty());
+        history.push(4);
+        assert_eq!(history.as_slice(), &[4]);
+    }
+}
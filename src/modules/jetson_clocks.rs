@@ -3,9 +3,77 @@
 
 //! Jetson Clocks control module
 
+use std::fmt;
 use std::fs;
 use std::path::Path;
 
+use super::{gpu, sysfs};
+use crate::Error;
+
+/// Default file used by [`JetsonClocksStats::store`] and [`JetsonClocksStats::restore`]
+pub const DEFAULT_STORE_PATH: &str = "/var/tmp/rusted_jetsons_clocks.conf";
+
+/// jetson_clocks script installed by L4T
+const JETSON_CLOCKS: &str = "/usr/bin/jetson_clocks";
+
+const CPU_SYSFS: &str = "/sys/devices/system/cpu";
+const DEBUGFS: &str = "/sys/kernel/debug";
+
+/// EMC clock directories under debugfs with their current and max rate files
+///
+/// BPMP based boards (Xavier, Orin) expose the clock under `bpmp/debug`,
+/// older boards (Nano, TX) under the common clock framework.
+const EMC_CLOCKS: [(&str, &str, &str); 2] = [
+    ("bpmp/debug/clk/emc", "rate", "max_rate"),
+    ("clk/emc", "clk_rate", "clk_max_rate"),
+];
+
+/// Whether each subsystem runs at its maximum frequency
+///
+/// `None` means the frequencies of the subsystem could not be read, e.g.
+/// debugfs for the EMC is only readable by root.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct JetsonClocksStatus {
+    pub cpu: Option<bool>,
+    pub gpu: Option<bool>,
+    pub emc: Option<bool>,
+}
+
+impl JetsonClocksStatus {
+    /// Whether every readable subsystem is at its maximum frequency
+    ///
+    /// `None` when no subsystem could be read.
+    pub fn is_active(&self) -> Option<bool> {
+        let readable: Vec<bool> = [self.cpu, self.gpu, self.emc]
+            .into_iter()
+            .flatten()
+            .collect();
+
+        if readable.is_empty() {
+            None
+        } else {
+            Some(readable.iter().all(|&at_max| at_max))
+        }
+    }
+}
+
+impl fmt::Display for JetsonClocksStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = |at_max: Option<bool>| match at_max {
+            Some(true) => "max",
+            Some(false) => "scaling",
+            None => "n/a",
+        };
+        write!(
+            f,
+            "CPU {}, GPU {}, EMC {}",
+            state(self.cpu),
+            state(self.gpu),
+            state(self.emc)
+        )
+    }
+}
+
 /// Jetson Clocks statistics
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct JetsonClocksStats {
@@ -33,47 +101,164 @@ impl JetsonClocksStats {
         stats
     }
 
-    /// Toggle jetson_clocks (requires root)
-    pub fn toggle() -> anyhow::Result<()> {
-        let output = std::process::Command::new("sudo")
-            .args(["/usr/bin/jetson_clocks"])
-            .output()?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(anyhow::anyhow!("jetson_clocks command failed: {}", stderr));
+    /// Check which subsystems run at their maximum frequency
+    ///
+    /// Mirrors `jetson_clocks --show`: CPU cores and the GPU count as pinned
+    /// when their minimum frequency was raised to the maximum, since a busy
+    /// governor reaches the maximum on its own. The EMC compares its debugfs
+    /// rate against its max rate.
+    pub fn status() -> JetsonClocksStatus {
+        JetsonClocksStatus {
+            cpu: at_max_freq(&read_cpu_freqs(Path::new(CPU_SYSFS))),
+            gpu: gpu::find_gpu_devfreq()
+                .and_then(|path| read_devfreq_freqs(Path::new(&path)))
+                .and_then(|pair| at_max_freq(&[pair])),
+            emc: read_emc_freqs(Path::new(DEBUGFS)).and_then(|pair| at_max_freq(&[pair])),
         }
+    }
 
-        Ok(())
+    /// Whether jetson_clocks pins the clocks at their maximum
+    ///
+    /// Fails when none of the CPU, GPU or EMC frequencies can be read.
+    pub fn is_active() -> crate::Result<bool> {
+        Self::status().is_active().ok_or_else(|| {
+            Error::HardwareNotFound("No CPU, GPU or EMC frequencies available".to_string())
+        })
+    }
+
+    /// Toggle jetson_clocks (requires root)
+    pub fn toggle() -> crate::Result<()> {
+        run_jetson_clocks(&[])
     }
 
     /// Set jetson_clocks mode (requires root)
-    pub fn set_mode(mode: &str) -> anyhow::Result<()> {
-        let output = std::process::Command::new("sudo")
-            .args(["/usr/bin/jetson_clocks", mode])
-            .output()?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(anyhow::anyhow!("jetson_clocks command failed: {}", stderr));
+    pub fn set_mode(mode: &str) -> crate::Result<()> {
+        run_jetson_clocks(&[mode])
+    }
+
+    /// Save the current clock configuration to `path` (requires root)
+    ///
+    /// Runs `jetson_clocks --store` through sudo, so the settings can be
+    /// rolled back with [`restore`](Self::restore) after experimenting.
+    pub fn store(path: &Path) -> crate::Result<()> {
+        let parent = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        if path.is_dir() || !parent.is_dir() {
+            return Err(Error::InvalidArgument(format!(
+                "Invalid jetson_clocks store path: {}",
+                path.display()
+            )));
+        }
+
+        run_jetson_clocks(&["--store", &path.to_string_lossy()])
+    }
+
+    /// Restore a clock configuration saved by [`store`](Self::store) (requires root)
+    pub fn restore(path: &Path) -> crate::Result<()> {
+        if !path.is_file() {
+            return Err(Error::InvalidArgument(format!(
+                "jetson_clocks configuration not found: {}",
+                path.display()
+            )));
         }
 
-        Ok(())
+        run_jetson_clocks(&["--restore", &path.to_string_lossy()])
+    }
+}
+
+/// Run `/usr/bin/jetson_clocks` with `args` through sudo
+fn run_jetson_clocks(args: &[&str]) -> crate::Result<()> {
+    // jetson_clocks ships with L4T only
+    if !Path::new(JETSON_CLOCKS).exists() {
+        return Err(Error::NotJetson);
+    }
+
+    let output = std::process::Command::new("sudo")
+        .arg(JETSON_CLOCKS)
+        .args(args)
+        .output()?;
+
+    if !output.status.success() {
+        return Err(Error::command_failed("jetson_clocks", &output.stderr));
     }
+
+    Ok(())
 }
 
 /// Read jetson_clocks mode from devicetree
 fn read_jetson_clocks_mode(path: &Path) -> Option<String> {
-    if let Ok(content) = fs::read_to_string(path) {
-        content.trim().to_string().into()
-    } else {
-        None
+    sysfs::read_string(path)
+}
+
+/// Whether every `(frequency, max)` pair is at its maximum
+///
+/// Allows 1% below the maximum since clock rates are rounded to what the
+/// PLL can produce. `None` when there are no pairs.
+fn at_max_freq(pairs: &[(u64, u64)]) -> Option<bool> {
+    if pairs.is_empty() {
+        return None;
     }
+
+    Some(
+        pairs
+            .iter()
+            .all(|&(current, max)| max > 0 && current * 100 >= max * 99),
+    )
+}
+
+/// Read `(scaling_min_freq, scaling_max_freq)` of every core with a policy
+fn read_cpu_freqs(cpu_root: &Path) -> Vec<(u64, u64)> {
+    let Ok(entries) = fs::read_dir(cpu_root) else {
+        return Vec::new();
+    };
+
+    let mut freqs = Vec::new();
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        let is_core = name
+            .strip_prefix("cpu")
+            .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()));
+        if !is_core {
+            continue;
+        }
+
+        let cpufreq = entry.path().join("cpufreq");
+        let min = sysfs::read_value::<u64>(cpufreq.join("scaling_min_freq"));
+        let max = sysfs::read_value::<u64>(cpufreq.join("scaling_max_freq"));
+        if let (Some(min), Some(max)) = (min, max) {
+            freqs.push((min, max));
+        }
+    }
+
+    freqs
+}
+
+/// Read `(min_freq, max_freq)` from a devfreq directory
+fn read_devfreq_freqs(devfreq: &Path) -> Option<(u64, u64)> {
+    Some((
+        sysfs::read_value(devfreq.join("min_freq"))?,
+        sysfs::read_value(devfreq.join("max_freq"))?,
+    ))
+}
+
+/// Read the current and maximum EMC rate from debugfs
+fn read_emc_freqs(debugfs: &Path) -> Option<(u64, u64)> {
+    EMC_CLOCKS.iter().find_map(|(dir, rate, max_rate)| {
+        let dir = debugfs.join(dir);
+        Some((
+            sysfs::read_value(dir.join(rate))?,
+            sysfs::read_value(dir.join(max_rate))?,
+        ))
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modules::sysfs::MockDir;
 
     #[test]
     fn test_jetson_clocks_stats_default() {
@@ -182,6 +367,92 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_at_max_freq() {
+        assert_eq!(at_max_freq(&[]), None);
+        assert_eq!(at_max_freq(&[(2_201_600, 2_201_600)]), Some(true));
+        assert_eq!(
+            at_max_freq(&[(1_300_500_000, 1_300_500_000), (2_190_000, 2_201_600)]),
+            Some(true)
+        );
+        assert_eq!(
+            at_max_freq(&[(2_201_600, 2_201_600), (729_600, 2_201_600)]),
+            Some(false)
+        );
+        assert_eq!(at_max_freq(&[(0, 0)]), Some(false));
+    }
+
+    #[test]
+    fn test_status_is_active() {
+        let status = JetsonClocksStatus {
+            cpu: Some(true),
+            gpu: Some(true),
+            emc: None,
+        };
+        assert_eq!(status.is_active(), Some(true));
+        assert_eq!(status.to_string(), "CPU max, GPU max, EMC n/a");
+
+        let status = JetsonClocksStatus {
+            gpu: Some(false),
+            ..status
+        };
+        assert_eq!(status.is_active(), Some(false));
+
+        assert_eq!(JetsonClocksStatus::default().is_active(), None);
+    }
+
+    #[test]
+    fn test_read_cpu_and_emc_freqs() {
+        let dir = MockDir::new("clocks");
+
+        let cpu = dir.join("cpu");
+        for (core, min) in [("cpu0", "2201600"), ("cpu1", "1190400")] {
+            let cpufreq = cpu.join(core).join("cpufreq");
+            fs::create_dir_all(&cpufreq).unwrap();
+            fs::write(cpufreq.join("scaling_min_freq"), min).unwrap();
+            // Running at the maximum is not enough without a raised minimum
+            fs::write(cpufreq.join("scaling_cur_freq"), "2201600").unwrap();
+            fs::write(cpufreq.join("scaling_max_freq"), "2201600").unwrap();
+        }
+        // Offline core without a policy and a non-core entry are skipped
+        fs::create_dir_all(cpu.join("cpu2")).unwrap();
+        fs::create_dir_all(cpu.join("cpufreq")).unwrap();
+
+        let mut freqs = read_cpu_freqs(&cpu);
+        freqs.sort();
+        assert_eq!(freqs, vec![(1_190_400, 2_201_600), (2_201_600, 2_201_600)]);
+        assert_eq!(at_max_freq(&freqs), Some(false));
+
+        let emc = dir.join("debug/bpmp/debug/clk/emc");
+        fs::create_dir_all(&emc).unwrap();
+        fs::write(emc.join("rate"), "3199000000\n").unwrap();
+        fs::write(emc.join("max_rate"), "3199000000\n").unwrap();
+        assert_eq!(
+            read_emc_freqs(&dir.join("debug")),
+            Some((3_199_000_000, 3_199_000_000))
+        );
+        assert_eq!(read_emc_freqs(&dir.join("missing")), None);
+
+        let devfreq = dir.join("devfreq/17000000.gpu");
+        fs::create_dir_all(&devfreq).unwrap();
+        fs::write(devfreq.join("min_freq"), "306000000\n").unwrap();
+        fs::write(devfreq.join("cur_freq"), "1300500000\n").unwrap();
+        fs::write(devfreq.join("max_freq"), "1300500000\n").unwrap();
+        let pair = read_devfreq_freqs(&devfreq).unwrap();
+        assert_eq!(pair, (306_000_000, 1_300_500_000));
+        assert_eq!(at_max_freq(&[pair]), Some(false));
+    }
+
+    #[test]
+    fn test_store_restore_bad_path() {
+        let dir = MockDir::new("clocks-missing");
+        let missing = dir.join("missing/clocks.conf");
+
+        assert!(JetsonClocksStats::store(&missing).is_err());
+        assert!(JetsonClocksStats::store(&dir).is_err());
+        assert!(JetsonClocksStats::restore(&missing).is_err());
+    }
+
     #[test]
     fn test_jetson_clocks_toggle_logic() {
         // Test that toggle function exists and is callable
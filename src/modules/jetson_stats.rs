@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Combined statistics snapshot
+//!
+//! Bundles the per-module statistics into a single value that exporters
+//! can consume.
+
+use std::future::Future;
+
+use super::cpu::CpuStats;
+use super::fan::FanStats;
+use super::gpu::GpuStats;
+use super::hardware::{detect_board, BoardInfo};
+use super::memory::MemoryStats;
+use super::power::PowerStats;
+use super::temperature::TemperatureStats;
+
+/// Snapshot of every monitored subsystem
+///
+/// [`JetsonStats::collect`] and [`JetsonStats::collect_async`] populate every
+/// field from the same `get()` calls the modules expose. Fields of hardware
+/// that is missing keep their defaults (e.g. no fan, or no INA3221 rails off
+/// a Jetson). CPU usage comes from a single `/proc/stat` read, so it is the
+/// average since boot; use a `CpuMonitor` or `JetsonMonitor` for usage over
+/// an interval.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct JetsonStats {
+    pub board: BoardInfo,
+    pub cpu: CpuStats,
+    pub gpu: GpuStats,
+    pub memory: MemoryStats,
+    pub fan: FanStats,
+    pub temperature: TemperatureStats,
+    pub power: PowerStats,
+}
+
+impl JetsonStats {
+    /// Read every module one after another
+    pub fn collect() -> Self {
+        let temperature = TemperatureStats::get();
+
+        Self {
+            board: detect_board(),
+            cpu: CpuStats::get(),
+            gpu: GpuStats::get(),
+            memory: MemoryStats::get(),
+            fan: FanStats::with_temperature(&temperature),
+            temperature,
+            power: PowerStats::get(),
+        }
+    }
+
+    /// Read every module concurrently
+    ///
+    /// GPU, memory, temperature and power use their async getters; the other
+    /// modules run on the blocking thread pool. Takes roughly as long as the
+    /// slowest module instead of the sum of all of them. A blocking module
+    /// whose read panics is left at its default.
+    pub async fn collect_async() -> Self {
+        let cpu = async {
+            tokio::task::spawn_blocking(CpuStats::get)
+                .await
+                .unwrap_or_default()
+        };
+        Self::collect_async_with(cpu, PowerStats::get_async()).await
+    }
+
+    /// Read every module concurrently, taking CPU and power from `cpu` and
+    /// `power`
+    ///
+    /// Lets callers sample them through their own [`CpuMonitor`] and
+    /// [`PowerMonitor`] without reading either module twice.
+    ///
+    /// [`CpuMonitor`]: super::cpu::CpuMonitor
+    /// [`PowerMonitor`]: super::power::PowerMonitor
+    pub async fn collect_async_with(
+        cpu: impl Future<Output = CpuStats>,
+        power: impl Future<Output = PowerStats>,
+    ) -> Self {
+        use tokio::task::spawn_blocking;
+
+        let (board, cpu, gpu, memory, fan, temperature, power) = tokio::join!(
+            spawn_blocking(detect_board),
+            cpu,
+            GpuStats::get_async(),
+            MemoryStats::get_async(),
+            spawn_blocking(FanStats::get),
+            TemperatureStats::get_async(),
+            power,
+        );
+
+        Self {
+            board: board.unwrap_or_default(),
+            cpu,
+            gpu,
+            memory,
+            fan: fan.unwrap_or_default(),
+            temperature,
+            power,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect() {
+        let stats = JetsonStats::collect();
+        assert!(!stats.board.model.is_empty());
+        assert!(stats.memory.ram_used <= stats.memory.ram_total);
+    }
+
+    #[tokio::test]
+    async fn test_collect_async_matches_collect() {
+        let sync = JetsonStats::collect();
+        // Spawned to check the future can move between worker threads
+        let stats = tokio::spawn(JetsonStats::collect_async()).await.unwrap();
+        assert_eq!(stats.board.model, sync.board.model);
+        assert_eq!(stats.memory.ram_total, sync.memory.ram_total);
+        assert_eq!(stats.cpu.cores.len(), sync.cpu.cores.len());
+    }
+}
@@ -3,7 +3,8 @@
 
 //! Memory monitoring module
 
-use std::fs;
+use crate::modules::collector::Collector;
+use crate::modules::sysfs::{self, Blocking, Reader, Tokio};
 use std::path::Path;
 
 /// Memory statistics
@@ -12,14 +13,38 @@ pub struct MemoryStats {
     pub ram_used: u64,
     pub ram_total: u64,
     pub ram_cached: u64,
+    /// Block device buffers
+    #[serde(default)]
+    pub ram_buffers: u64,
+    /// Reclaimable kernel slab memory (`SReclaimable`)
+    #[serde(default)]
+    pub ram_reclaimable: u64,
+    #[serde(default)]
+    pub ram_free: u64,
     pub swap_used: u64,
     pub swap_total: u64,
     pub swap_cached: u64,
     pub iram_used: u64,
     pub iram_total: u64,
     pub iram_lfb: u64,
+    /// RAM mapped through NvMap for the GPU and other engines (`NvMapMemUsed`)
+    #[serde(default)]
+    pub nv_used: u64,
+    /// NvMap memory that is allocated but not in use (`NvMapMemFree`)
+    #[serde(default)]
+    pub nv_free: u64,
+    /// EMC (memory controller) frequency in Hz, 0 when unavailable
+    #[serde(default)]
+    pub emc_frequency: u64,
+    /// EMC load in percent, 0 when unavailable
+    #[serde(default)]
+    pub emc_usage: f32,
 }
 
+/// Average memory controller activity in kHz, exposed by the actmon driver
+const EMC_ACTMON: &str = "/sys/kernel/actmon_avg_activity/mc_all";
+const DEVFREQ: &str = "/sys/class/devfreq";
+
 /// Format memory bytes to MB or GB with appropriate unit
 ///
 /// # Arguments
@@ -43,13 +68,32 @@ pub fn format_memory_bytes(bytes: u64) -> (f64, &'static str) {
 impl MemoryStats {
     /// Get current memory statistics
     pub fn get() -> Self {
+        sysfs::block_on(Self::read(&Blocking))
+    }
+
+    /// Get current memory statistics through `tokio::fs`
+    pub async fn get_async() -> Self {
+        Self::read(&Tokio).await
+    }
+
+    pub(crate) async fn read<R: Reader>(reader: &R) -> Self {
         let path = Path::new("/proc/meminfo");
 
-        if let Ok(content) = fs::read_to_string(path) {
+        let mut stats = if let Ok(content) = reader.read_to_string(path).await {
             parse_meminfo(&content)
         } else {
             MemoryStats::default()
-        }
+        };
+
+        stats.emc_frequency = emc_frequency(reader).await;
+        stats.emc_usage = read_emc_usage(
+            reader,
+            Path::new(EMC_ACTMON),
+            Path::new(DEVFREQ),
+            stats.emc_frequency,
+        )
+        .await;
+        stats
     }
 }
 
@@ -75,11 +119,18 @@ fn parse_meminfo(content: &str) -> MemoryStats {
     let mem_buffers = *meminfo.get("Buffers").unwrap_or(&0);
     let mem_cached = *meminfo.get("Cached").unwrap_or(&0);
     stats.ram_cached = mem_cached;
-
-    // Calculate used RAM
-    stats.ram_used = stats
-        .ram_total
-        .saturating_sub(mem_free + mem_buffers + mem_cached);
+    stats.ram_buffers = mem_buffers;
+    stats.ram_free = mem_free;
+    stats.ram_reclaimable = *meminfo.get("SReclaimable").unwrap_or(&0);
+
+    // Calculate used RAM like `free`, from the kernel's availability estimate
+    // when present; older kernels lack MemAvailable
+    stats.ram_used = match meminfo.get("MemAvailable") {
+        Some(&available) => stats.ram_total.saturating_sub(available),
+        None => stats
+            .ram_total
+            .saturating_sub(mem_free + mem_buffers + mem_cached),
+    };
 
     // Parse SWAP
     stats.swap_total = *meminfo.get("SwapTotal").unwrap_or(&0);
@@ -95,6 +146,10 @@ fn parse_meminfo(content: &str) -> MemoryStats {
     let iram_free = *meminfo.get("IramFree").unwrap_or(&0);
     stats.iram_used = stats.iram_total.saturating_sub(iram_free + stats.iram_lfb);
 
+    // Parse NvMap (Jetson kernels only)
+    stats.nv_used = *meminfo.get("NvMapMemUsed").unwrap_or(&0);
+    stats.nv_free = *meminfo.get("NvMapMemFree").unwrap_or(&0);
+
     stats
 }
 
@@ -105,28 +160,85 @@ fn parse_meminfo(content: &str) -> MemoryStats {
 /// # Returns
 /// EMC frequency in Hz, or 0 if unavailable.
 pub fn read_emc_frequency() -> u64 {
+    sysfs::block_on(emc_frequency(&Blocking))
+}
+
+async fn emc_frequency<R: Reader>(reader: &R) -> u64 {
     let paths = [
+        "/sys/kernel/debug/bpmp/debug/clk/emc/rate",
         "/sys/kernel/debug/clk/emc/clk_rate",
         "/sys/kernel/debug/clk/parent_emc/clk_rate",
         "/sys/kernel/debug/clk/emc_clk_source/clk_rate",
         "/sys/devices/platform/host1x/15000000.tsec/15000000.tsec/emc_rate",
     ];
 
-    for path in &paths {
-        let path = Path::new(path);
-        if let Ok(content) = fs::read_to_string(path) {
-            if let Ok(freq) = content.trim().parse::<u64>() {
-                return freq;
-            }
+    for path in paths {
+        if let Some(freq) = reader.read_value::<u64>(Path::new(path)).await {
+            return freq;
         }
     }
 
     0
 }
 
+/// Read the EMC load in percent
+///
+/// Prefers the actmon average activity, which is reported in kHz and is
+/// relative to the EMC clock `frequency` in Hz. Falls back to the `load`
+/// (per mille) of an EMC devfreq device. Returns 0 when neither exists.
+async fn read_emc_usage<R: Reader>(
+    reader: &R,
+    actmon: &Path,
+    devfreq: &Path,
+    frequency: u64,
+) -> f32 {
+    if frequency > 0 {
+        if let Some(activity_khz) = reader.read_value::<u64>(actmon).await {
+            let usage = activity_khz as f64 * 1000.0 / frequency as f64 * 100.0;
+            return usage.clamp(0.0, 100.0) as f32;
+        }
+    }
+
+    let entries = reader.read_dir(devfreq).await.unwrap_or_default();
+    for entry in entries {
+        let is_emc = entry
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().contains("emc"));
+        if !is_emc {
+            continue;
+        }
+        if let Some(load) = reader.read_value::<u32>(&entry.join("device/load")).await {
+            return (load as f32 / 10.0).clamp(0.0, 100.0);
+        }
+    }
+
+    0.0
+}
+
+/// Memory collector for the monitoring loop
+#[derive(Debug, Default)]
+pub struct MemoryMonitor;
+
+impl MemoryMonitor {
+    /// Create a new memory monitor
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Collector for MemoryMonitor {
+    type Stats = MemoryStats;
+
+    fn collect(&mut self) -> Self::Stats {
+        MemoryStats::get()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modules::sysfs::MockDir;
+    use std::fs;
 
     #[test]
     fn test_memory_stats_default() {
@@ -140,6 +252,8 @@ mod tests {
         assert_eq!(stats.iram_used, 0);
         assert_eq!(stats.iram_total, 0);
         assert_eq!(stats.iram_lfb, 0);
+        assert_eq!(stats.nv_used, 0);
+        assert_eq!(stats.nv_free, 0);
     }
 
     #[test]
@@ -148,12 +262,19 @@ mod tests {
             ram_used: 4_000_000_000,
             ram_total: 8_000_000_000,
             ram_cached: 2_000_000_000,
+            ram_buffers: 200_000_000,
+            ram_reclaimable: 300_000_000,
+            ram_free: 1_500_000_000,
             swap_used: 1_000_000_000,
             swap_total: 4_000_000_000,
             swap_cached: 500_000_000,
             iram_used: 1_000_000,
             iram_total: 2_000_000,
             iram_lfb: 100_000,
+            nv_used: 800_000_000,
+            nv_free: 40_000_000,
+            emc_frequency: 3_199_000_000,
+            emc_usage: 12.5,
         };
 
         assert_eq!(stats.ram_used, 4_000_000_000);
@@ -165,11 +286,12 @@ mod tests {
         assert_eq!(stats.iram_used, 1_000_000);
         assert_eq!(stats.iram_total, 2_000_000);
         assert_eq!(stats.iram_lfb, 100_000);
+        assert_eq!(stats.emc_frequency, 3_199_000_000);
+        assert_eq!(stats.emc_usage, 12.5);
     }
 
-    #[test]
-    fn test_memory_stats_get() {
-        let stats = MemoryStats::get();
+    async fn check_memory_stats_get<R: Reader>(reader: &R) {
+        let stats = MemoryStats::read(reader).await;
 
         if stats.ram_total > 0 {
             assert!(
@@ -197,23 +319,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_memory_stats_get() {
+        sysfs::block_on(check_memory_stats_get(&Blocking));
+    }
+
+    #[tokio::test]
+    async fn test_memory_stats_get_async() {
+        check_memory_stats_get(&Tokio).await;
+    }
+
     #[test]
     fn test_parse_meminfo() {
         let sample_meminfo = r#"MemTotal:        8192000 kB
 MemFree:         4096000 kB
 Buffers:          512000 kB
 Cached:          2048000 kB
+SwapCached:       256000 kB
+SReclaimable:     128000 kB
 SwapTotal:       4096000 kB
 SwapFree:        3072000 kB
-SwapCached:       256000 kB
 IramTotal:         20480 kB
 IramFree:           5120 kB
-IramLfb:           2560 kB"#;
+IramLfb:           2560 kB
+NvMapMemFree:      42240 kB
+NvMapMemUsed:     786432 kB"#;
 
         let stats = parse_meminfo(sample_meminfo);
 
         assert_eq!(stats.ram_total, 8192000 * 1024);
         assert_eq!(stats.ram_cached, 2048000 * 1024);
+        assert_eq!(stats.ram_buffers, 512000 * 1024);
+        assert_eq!(stats.ram_reclaimable, 128000 * 1024);
+        assert_eq!(stats.ram_free, 4096000 * 1024);
 
         let expected_used = 8192000 * 1024 - 4096000 * 1024 - 512000 * 1024 - 2048000 * 1024;
         assert_eq!(stats.ram_used, expected_used);
@@ -227,6 +365,39 @@ IramLfb:           2560 kB"#;
 
         let expected_iram_used = 20480 * 1024 - 5120 * 1024 - 2560 * 1024;
         assert_eq!(stats.iram_used, expected_iram_used);
+
+        assert_eq!(stats.nv_used, 786432 * 1024);
+        assert_eq!(stats.nv_free, 42240 * 1024);
+    }
+
+    #[test]
+    fn test_parse_meminfo_used_from_mem_available() {
+        let sample_meminfo = r#"MemTotal:        8192000 kB
+MemFree:         1024000 kB
+MemAvailable:    5120000 kB
+Buffers:          512000 kB
+Cached:          2048000 kB
+SReclaimable:     128000 kB"#;
+
+        let stats = parse_meminfo(sample_meminfo);
+
+        assert_eq!(stats.ram_used, (8192000 - 5120000) * 1024);
+        assert_eq!(stats.ram_free, 1024000 * 1024);
+    }
+
+    #[test]
+    fn test_parse_meminfo_used_without_mem_available() {
+        let sample_meminfo = r#"MemTotal:        8192000 kB
+MemFree:         1024000 kB
+Buffers:          512000 kB
+Cached:          2048000 kB"#;
+
+        let stats = parse_meminfo(sample_meminfo);
+
+        assert_eq!(
+            stats.ram_used,
+            (8192000 - 1024000 - 512000 - 2048000) * 1024
+        );
     }
 
     #[test]
@@ -266,12 +437,19 @@ Cached:          not a number"#;
             ram_used: 4_000_000_000,
             ram_total: 8_000_000_000,
             ram_cached: 2_000_000_000,
+            ram_buffers: 200_000_000,
+            ram_reclaimable: 300_000_000,
+            ram_free: 1_500_000_000,
             swap_used: 1_000_000_000,
             swap_total: 4_000_000_000,
             swap_cached: 500_000_000,
             iram_used: 1_000_000,
             iram_total: 2_000_000,
             iram_lfb: 100_000,
+            nv_used: 800_000_000,
+            nv_free: 40_000_000,
+            emc_frequency: 3_199_000_000,
+            emc_usage: 12.5,
         };
 
         let json = serde_json::to_string(&stats);
@@ -297,6 +475,41 @@ Cached:          not a number"#;
         }
     }
 
+    async fn check_read_emc_usage<R: Reader>(reader: &R) {
+        let dir = MockDir::new("emc");
+        let actmon = dir.join("mc_all");
+        let devfreq = dir.join("devfreq");
+        fs::create_dir_all(devfreq.join("emc/device")).unwrap();
+
+        // Nothing to read
+        assert_eq!(
+            read_emc_usage(reader, &actmon, &dir.join("missing"), 0).await,
+            0.0
+        );
+
+        // devfreq load is per mille
+        fs::write(devfreq.join("emc/device/load"), "425\n").unwrap();
+        assert_eq!(read_emc_usage(reader, &actmon, &devfreq, 0).await, 42.5);
+
+        // actmon wins when the EMC frequency is known: 799800 kHz of 3199.2 MHz
+        fs::write(&actmon, "799800\n").unwrap();
+        assert_eq!(
+            read_emc_usage(reader, &actmon, &devfreq, 3_199_200_000).await,
+            25.0
+        );
+        assert_eq!(read_emc_usage(reader, &actmon, &devfreq, 0).await, 42.5);
+    }
+
+    #[test]
+    fn test_read_emc_usage() {
+        sysfs::block_on(check_read_emc_usage(&Blocking));
+    }
+
+    #[tokio::test]
+    async fn test_read_emc_usage_async() {
+        check_read_emc_usage(&Tokio).await;
+    }
+
     #[test]
     #[ignore = "Requires Jetson hardware - run with: cargo test memory -- --ignored"]
     fn test_print_memory_info() {
@@ -326,6 +539,7 @@ Cached:          not a number"#;
         } else {
             println!("EMC Frequency: Not available");
         }
+        println!("EMC Usage: {:.1}%", stats.emc_usage);
 
         println!("\n=== Test Complete ===");
     }
@@ -336,6 +550,7 @@ Cached:          not a number"#;
         let (value, unit) = format_memory_bytes(bytes);
 
         assert_eq!(unit, "MB", "Unit should be MB for values < 1GB");
+        assert!(value < 1024.0, "Value should stay below 1024 MB");
         assert!((value - 1.0).abs() < 0.01, "Value should be ~1.0 MB");
     }
 
@@ -354,6 +569,7 @@ Cached:          not a number"#;
         let (value, unit) = format_memory_bytes(bytes);
 
         assert_eq!(unit, "MB", "Unit should be MB for values < 1GB");
+        assert!(value < 1024.0, "Value should stay below 1024 MB");
         assert!((value - 512.0).abs() < 1.0, "Value should be ~512 MB");
     }
 
@@ -372,6 +588,7 @@ Cached:          not a number"#;
         let (value, unit) = format_memory_bytes(bytes);
 
         assert_eq!(unit, "MB", "Unit should be MB for values < 1GB");
+        assert!(value < 1024.0, "Value should stay below 1024 MB");
     }
 
     #[test]
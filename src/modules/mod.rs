@@ -1,32 +1,47 @@
 // SPDX-License-Identifier: LGPL-3.0
 // Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
 
+pub mod camel_case;
+pub mod collector;
+pub mod config;
+pub mod containers;
 pub mod cpu;
+pub mod disk;
 pub mod engine;
 pub mod fan;
 pub mod gpu;
 pub mod hardware;
+pub mod health;
+pub mod history;
 pub mod jetson_clocks;
+pub mod jetson_stats;
 pub mod memory;
+pub mod monitor;
+pub mod network;
 pub mod nvpmodel;
 pub mod power;
+pub mod pressure;
 pub mod processes;
+pub mod report;
+pub mod swap;
+pub mod sysfs;
 
 // Temporarily commented out - need to fix compilation errors
 // pub mod tegra_stats;
 pub mod temperature;
+pub mod watchdog;
 
 pub use hardware::BoardInfo;
 
 #[cfg(feature = "tui")]
-pub use cpu::{CpuCore, CpuStats};
+pub use cpu::{CpuCluster, CpuCore, CpuStats};
 #[cfg(feature = "tui")]
 pub use fan::FanStats;
 #[cfg(feature = "tui")]
-pub use gpu::GpuStats;
+pub use gpu::{GpuCores, GpuStats};
 #[cfg(feature = "tui")]
 pub use memory::MemoryStats;
 #[cfg(feature = "tui")]
 pub use power::{PowerRail, PowerStats};
 #[cfg(feature = "tui")]
-pub use temperature::{TemperatureStats, ThermalZone};
+pub use temperature::{TemperatureStats, TemperatureUnit, ThermalZone};
@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Streaming monitor
+//!
+//! [`JetsonMonitor`] samples every subsystem at a fixed interval and
+//! broadcasts each [`JetsonStats`] snapshot to its subscribers, so the
+//! library can be embedded in other applications.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::sync::broadcast;
+
+use super::collector::Collector;
+use super::cpu::CpuMonitor;
+use super::fan::FanStats;
+use super::gpu::GpuStats;
+use super::hardware::detect_board;
+use super::jetson_stats::JetsonStats;
+use super::memory::MemoryStats;
+use super::power::PowerMonitor;
+use super::temperature::TemperatureStats;
+use super::watchdog::ThermalWatchdog;
+
+/// Default sampling interval
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Snapshots buffered per subscriber before the oldest are dropped
+const CHANNEL_CAPACITY: usize = 16;
+
+/// Periodically collects [`JetsonStats`] and broadcasts them to subscribers
+pub struct JetsonMonitor {
+    interval: Duration,
+    sender: broadcast::Sender<JetsonStats>,
+    watchdog: Option<Arc<Mutex<ThermalWatchdog>>>,
+}
+
+impl JetsonMonitor {
+    /// Create a monitor sampling every second
+    pub fn new() -> Self {
+        Self::with_interval(DEFAULT_INTERVAL)
+    }
+
+    /// Create a monitor sampling every `interval`
+    ///
+    /// A zero interval is raised to one millisecond.
+    pub fn with_interval(interval: Duration) -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self {
+            interval: interval.max(Duration::from_millis(1)),
+            sender,
+            watchdog: None,
+        }
+    }
+
+    /// Check every snapshot against `watchdog`
+    ///
+    /// The watchdog action runs on the collection thread, so a slow action
+    /// delays the next snapshot.
+    pub fn with_watchdog(mut self, watchdog: ThermalWatchdog) -> Self {
+        self.watchdog = Some(Arc::new(Mutex::new(watchdog)));
+        self
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Receive every snapshot collected after this call
+    ///
+    /// A subscriber that falls more than 16 snapshots behind gets
+    /// `RecvError::Lagged` and continues with the oldest buffered one.
+    pub fn subscribe(&self) -> broadcast::Receiver<JetsonStats> {
+        self.sender.subscribe()
+    }
+
+    /// Collect and broadcast a snapshot every interval
+    ///
+    /// Runs until the task is cancelled or a collection task panics.
+    /// Snapshots taken while nobody is subscribed are dropped.
+    pub async fn start(&self) -> crate::Result<()> {
+        let mut ticker = tokio::time::interval(self.interval);
+        let mut sampler = Sampler::new(self.watchdog.clone());
+
+        loop {
+            ticker.tick().await;
+
+            // Statistics are read with blocking sysfs calls
+            let (returned, stats) = tokio::task::spawn_blocking(move || {
+                let stats = sampler.collect();
+                (sampler, stats)
+            })
+            .await
+            .map_err(std::io::Error::other)?;
+            sampler = returned;
+
+            let _ = self.sender.send(stats);
+        }
+    }
+}
+
+impl Default for JetsonMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Monitors whose state carries over between snapshots
+struct Sampler {
+    cpu: CpuMonitor,
+    power: PowerMonitor,
+    watchdog: Option<Arc<Mutex<ThermalWatchdog>>>,
+}
+
+impl Sampler {
+    fn new(watchdog: Option<Arc<Mutex<ThermalWatchdog>>>) -> Self {
+        Self {
+            cpu: CpuMonitor::new(),
+            power: PowerMonitor::new(),
+            watchdog,
+        }
+    }
+
+    fn collect(&mut self) -> JetsonStats {
+        let temperature = TemperatureStats::get();
+        if let Some(watchdog) = &self.watchdog {
+            // A panicking action leaves the watchdog usable
+            let mut watchdog = watchdog.lock().unwrap_or_else(|e| e.into_inner());
+            watchdog.check(&temperature, Instant::now());
+        }
+
+        JetsonStats {
+            board: detect_board(),
+            cpu: self.cpu.collect(),
+            gpu: GpuStats::get(),
+            memory: MemoryStats::get(),
+            fan: FanStats::with_temperature(&temperature),
+            temperature,
+            power: self.power.collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_interval() {
+        assert_eq!(JetsonMonitor::new().interval(), DEFAULT_INTERVAL);
+        assert_eq!(
+            JetsonMonitor::with_interval(Duration::from_millis(250)).interval(),
+            Duration::from_millis(250)
+        );
+        assert_eq!(
+            JetsonMonitor::with_interval(Duration::ZERO).interval(),
+            Duration::from_millis(1)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_start_broadcasts_samples() {
+        let monitor = JetsonMonitor::with_interval(Duration::from_millis(10));
+        let mut receiver = monitor.subscribe();
+
+        let task = tokio::spawn(async move { monitor.start().await });
+
+        for _ in 0..2 {
+            let stats = tokio::time::timeout(Duration::from_secs(10), receiver.recv())
+                .await
+                .expect("no sample within 10s")
+                .expect("monitor stopped");
+            assert!(stats.memory.ram_used <= stats.memory.ram_total);
+        }
+
+        task.abort();
+    }
+}
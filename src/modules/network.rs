@@ -0,0 +1,221 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Network throughput module
+//!
+//! Derives per-interface receive and transmit rates from the byte counters
+//! in `/proc/net/dev`, sampled twice.
+
+use crate::modules::collector::Collector;
+use std::collections::HashMap;
+use std::fs;
+use std::time::Instant;
+
+const LOOPBACK: &str = "lo";
+
+/// Throughput of a network interface
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct NetInterface {
+    pub name: String,
+    /// Received bytes per second since the previous sample
+    pub rx_bps: f64,
+    /// Transmitted bytes per second since the previous sample
+    pub tx_bps: f64,
+}
+
+/// Cumulative byte counters of an interface
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct NetCounters {
+    rx_bytes: u64,
+    tx_bytes: u64,
+}
+
+/// Network monitor with state for delta-based rate calculation
+#[derive(Debug, Default)]
+pub struct NetMonitor {
+    prev_counters: HashMap<String, NetCounters>,
+    prev_time: Option<Instant>,
+    include_loopback: bool,
+}
+
+impl NetMonitor {
+    /// Create a network monitor skipping the loopback interface
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a network monitor that also reports `lo`
+    pub fn with_loopback() -> Self {
+        Self {
+            include_loopback: true,
+            ..Self::default()
+        }
+    }
+
+    /// Read `/proc/net/dev` and compute rates against the previous sample
+    ///
+    /// Rates are 0 on the first sample and for interfaces that just
+    /// appeared, since there is nothing to compare against yet.
+    pub fn sample(&mut self) -> Vec<NetInterface> {
+        let content = fs::read_to_string("/proc/net/dev").unwrap_or_default();
+        self.update(parse_net_dev(&content), Instant::now())
+    }
+
+    fn update(&mut self, counters: Vec<(String, NetCounters)>, now: Instant) -> Vec<NetInterface> {
+        let elapsed = self
+            .prev_time
+            .map_or(0.0, |prev| now.duration_since(prev).as_secs_f64());
+        let rate = |current: u64, prev: u64| {
+            if elapsed > 0.0 {
+                // Counters restart at 0 when a driver is reloaded
+                current.saturating_sub(prev) as f64 / elapsed
+            } else {
+                0.0
+            }
+        };
+
+        let interfaces = counters
+            .iter()
+            .filter(|(name, _)| self.include_loopback || name != LOOPBACK)
+            .map(|(name, current)| {
+                let (rx_bps, tx_bps) = match self.prev_counters.get(name) {
+                    Some(prev) => (
+                        rate(current.rx_bytes, prev.rx_bytes),
+                        rate(current.tx_bytes, prev.tx_bytes),
+                    ),
+                    None => (0.0, 0.0),
+                };
+                NetInterface {
+                    name: name.clone(),
+                    rx_bps,
+                    tx_bps,
+                }
+            })
+            .collect();
+
+        self.prev_counters = counters.into_iter().collect();
+        self.prev_time = Some(now);
+        interfaces
+    }
+}
+
+impl Collector for NetMonitor {
+    type Stats = Vec<NetInterface>;
+
+    fn collect(&mut self) -> Self::Stats {
+        self.sample()
+    }
+}
+
+/// Parse the byte counters of every interface in `/proc/net/dev`
+fn parse_net_dev(content: &str) -> Vec<(String, NetCounters)> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (name, fields) = line.split_once(':')?;
+            let fields: Vec<&str> = fields.split_whitespace().collect();
+            // Receive bytes come first, transmit bytes are the ninth column
+            let counters = NetCounters {
+                rx_bytes: fields.first()?.parse().ok()?,
+                tx_bytes: fields.get(8)?.parse().ok()?,
+            };
+            Some((name.trim().to_string(), counters))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    const NET_DEV: &str = "\
+Inter-|   Receive                                                |  Transmit
+ face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed
+    lo:  524288    4096    0    0    0     0          0         0   524288    4096    0    0    0     0       0          0
+  eth0: 10485760   8000    0    0    0     0          0        12  2097152    3000    0    0    0     0       0          0
+ wlan0:       0       0    0    0    0     0          0         0        0       0    0    0    0     0       0          0
+";
+
+    fn counters(rx_bytes: u64, tx_bytes: u64) -> NetCounters {
+        NetCounters { rx_bytes, tx_bytes }
+    }
+
+    #[test]
+    fn test_parse_net_dev() {
+        let parsed = parse_net_dev(NET_DEV);
+        assert_eq!(
+            parsed,
+            vec![
+                ("lo".to_string(), counters(524_288, 524_288)),
+                ("eth0".to_string(), counters(10_485_760, 2_097_152)),
+                ("wlan0".to_string(), counters(0, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rates_from_two_snapshots() {
+        let mut monitor = NetMonitor::new();
+        let start = Instant::now();
+
+        let first = monitor.update(
+            vec![("eth0".to_string(), counters(1_000_000, 500_000))],
+            start,
+        );
+        assert_eq!(first[0].rx_bps, 0.0);
+        assert_eq!(first[0].tx_bps, 0.0);
+
+        let second = monitor.update(
+            vec![("eth0".to_string(), counters(3_000_000, 1_500_000))],
+            start + Duration::from_secs(2),
+        );
+        assert_eq!(
+            second,
+            vec![NetInterface {
+                name: "eth0".to_string(),
+                rx_bps: 1_000_000.0,
+                tx_bps: 500_000.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_new_interface_and_counter_reset() {
+        let mut monitor = NetMonitor::new();
+        let start = Instant::now();
+        monitor.update(
+            vec![("eth0".to_string(), counters(1_000_000, 1_000_000))],
+            start,
+        );
+
+        let next = monitor.update(
+            vec![
+                ("eth0".to_string(), counters(10, 10)),
+                ("usb0".to_string(), counters(4096, 4096)),
+            ],
+            start + Duration::from_secs(1),
+        );
+        assert_eq!(next[0].rx_bps, 0.0);
+        assert_eq!(next[1].name, "usb0");
+        assert_eq!(next[1].tx_bps, 0.0);
+    }
+
+    #[test]
+    fn test_loopback_is_optional() {
+        let now = Instant::now();
+
+        let names = |mut monitor: NetMonitor| -> Vec<String> {
+            monitor
+                .update(parse_net_dev(NET_DEV), now)
+                .into_iter()
+                .map(|iface| iface.name)
+                .collect()
+        };
+        assert_eq!(names(NetMonitor::new()), vec!["eth0", "wlan0"]);
+        assert_eq!(
+            names(NetMonitor::with_loopback()),
+            vec!["lo", "eth0", "wlan0"]
+        );
+    }
+}
@@ -6,6 +6,8 @@
 use std::fs;
 use std::path::Path;
 
+use crate::Error;
+
 /// NVP model statistics
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct NVPModelStats {
@@ -14,6 +16,14 @@ pub struct NVPModelStats {
     pub available: bool,
 }
 
+/// Active modes reported by `nvpmodel -q`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NVPModelQuery {
+    pub id: Option<u8>,
+    pub name: Option<String>,
+    pub fan_mode: Option<String>,
+}
+
 /// Individual NVP model
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct NVPModel {
@@ -36,15 +46,37 @@ impl NVPModelStats {
         stats.available = !stats.models.is_empty();
 
         // Try to get current model
-        stats.current_model = get_current_model_id().unwrap_or(255);
+        stats.current_model = get_current_model_id(&stats.models).unwrap_or(255);
 
         stats
     }
 
+    /// ID of the active power mode, if `nvpmodel -q` or its status file
+    /// reports one
+    pub fn current_model_id() -> Option<u8> {
+        get_current_model_id(&parse_nvpmodel_conf(Path::new("/etc/nvpmodel.conf")))
+    }
+
+    /// Query the active power mode with `nvpmodel -q`
+    pub fn query() -> Option<NVPModelQuery> {
+        let output = std::process::Command::new("nvpmodel")
+            .arg("-q")
+            .output()
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        Some(parse_nvpmodel_query(&String::from_utf8_lossy(
+            &output.stdout,
+        )))
+    }
+
     /// Set NVP model (requires root)
-    pub fn set_model(model_id: u8) -> anyhow::Result<()> {
+    pub fn set_model(model_id: u8) -> crate::Result<()> {
         if model_id > 15 {
-            return Err(anyhow::anyhow!("Model ID must be 0-15"));
+            return Err(Error::InvalidArgument("Model ID must be 0-15".to_string()));
         }
 
         let output = std::process::Command::new("sudo")
@@ -52,8 +84,7 @@ impl NVPModelStats {
             .output()?;
 
         if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(anyhow::anyhow!("nvpmodel command failed: {}", stderr));
+            return Err(Error::command_failed("nvpmodel", &output.stderr));
         }
 
         Ok(())
@@ -73,79 +104,107 @@ impl NVPModelStats {
 
 /// Parse /etc/nvpmodel.conf file
 fn parse_nvpmodel_conf(path: &Path) -> Vec<NVPModel> {
+    fs::read_to_string(path)
+        .map(|content| parse_nvpmodel_models(&content))
+        .unwrap_or_default()
+}
+
+/// Extract the power models from nvpmodel.conf content
+///
+/// Models are declared by `< POWER_MODEL ID=0 NAME=MAXN >` tags; the
+/// settings lines following each tag are ignored.
+pub fn parse_nvpmodel_models(content: &str) -> Vec<NVPModel> {
     let mut models = Vec::new();
 
-    if let Ok(content) = fs::read_to_string(path) {
-        let mut current_model_id: Option<u8> = None;
-        let mut current_name = String::new();
-        let mut current_desc = String::new();
-
-        for line in content.lines() {
-            let line = line.trim();
-
-            if line.starts_with("NVPMODEL:") {
-                if let Some(id_str) = line.strip_prefix("NVPMODEL=") {
-                    if let Ok(id) = id_str.parse() {
-                        current_model_id = Some(id);
-                    }
-                }
-            } else if line.starts_with("NVPOWER:") {
-                // Power state line
-            } else if line.starts_with("NVPOWERCAP:") {
-                // Power capability line
-            } else if line.starts_with("NVPOWERCTRL:") {
-                // Power control line
-            } else if line.starts_with("GPU:") {
-                // GPU config line
-            } else if line.starts_with("GPU_MIN_FREQ:") {
-                // GPU min frequency
-            } else if line.starts_with("GPU_MAX_FREQ:") {
-                // GPU max frequency
-            } else if line.starts_with("CPU:") {
-                // CPU config line
-            } else if line.starts_with("CPU_MIN_FREQ:") {
-                // CPU min frequency
-            } else if line.starts_with("CPU_MAX_FREQ:") {
-                // CPU max frequency
-            } else if line.starts_with("#") {
-                if let Some(id) = current_model_id {
-                    if !current_name.is_empty() {
-                        models.push(NVPModel {
-                            id,
-                            name: current_name.clone(),
-                            description: current_desc.clone(),
-                        });
-                    }
-                }
-                current_name.clear();
-                current_desc.clear();
+    for line in content.lines() {
+        let Some(("POWER_MODEL", attrs)) = parse_conf_tag(line) else {
+            continue;
+        };
+
+        let mut id = None;
+        let mut name = None;
+        for (key, value) in attrs {
+            match key {
+                "ID" => id = value.parse().ok(),
+                "NAME" => name = Some(value.to_string()),
+                _ => {}
             }
         }
 
-        // Don't forget the last model
-        if let Some(id) = current_model_id {
-            if !current_name.is_empty() {
-                models.push(NVPModel {
-                    id,
-                    name: current_name,
-                    description: current_desc,
-                });
-            }
+        if let (Some(id), Some(name)) = (id, name) {
+            models.push(NVPModel {
+                id,
+                name,
+                description: String::new(),
+            });
         }
     }
 
     models
 }
 
-/// Get current NVP model ID
-fn get_current_model_id() -> Option<u8> {
-    let path = Path::new("/sys/devices/soc0/firmware/devicetree/base/nvidia,pmodel");
+/// Split a `< TAG KEY=VALUE ... >` line into its tag and attributes
+fn parse_conf_tag(line: &str) -> Option<(&str, Vec<(&str, &str)>)> {
+    let inner = line.trim().strip_prefix('<')?.strip_suffix('>')?;
+    let mut tokens = inner.split_whitespace();
+    let tag = tokens.next()?;
+    let attrs = tokens.filter_map(|token| token.split_once('=')).collect();
 
-    if let Ok(content) = fs::read_to_string(path) {
-        content.trim().parse().ok()
-    } else {
-        None
+    Some((tag, attrs))
+}
+
+/// Parse the output of `nvpmodel -q`
+///
+/// ```text
+/// NV Fan Mode:quiet
+/// NV Power Mode: MAXN
+/// 0
+/// ```
+pub fn parse_nvpmodel_query(output: &str) -> NVPModelQuery {
+    let mut query = NVPModelQuery::default();
+
+    for line in output.lines() {
+        let line = line.trim();
+
+        if let Some(name) = line.strip_prefix("NV Power Mode:") {
+            query.name = Some(name.trim().to_string());
+        } else if let Some(mode) = line.strip_prefix("NV Fan Mode:") {
+            query.fan_mode = Some(mode.trim().to_string());
+        } else if let Ok(id) = line.parse() {
+            query.id = Some(id);
+        }
+    }
+
+    query
+}
+
+/// Parse the power mode ID from `/var/lib/nvpmodel/status` (`pmode:0000 fmode:quiet`)
+pub fn parse_nvpmodel_status(content: &str) -> Option<u8> {
+    content
+        .split_whitespace()
+        .find_map(|field| field.strip_prefix("pmode:"))
+        .and_then(|id| id.parse().ok())
+}
+
+/// Get current NVP model ID
+///
+/// Asks `nvpmodel -q`, resolving the mode name against `models` when the
+/// ID line is missing, then falls back to the nvpmodel status file.
+fn get_current_model_id(models: &[NVPModel]) -> Option<u8> {
+    if let Some(query) = NVPModelStats::query() {
+        let by_name = query
+            .name
+            .as_deref()
+            .and_then(|name| models.iter().find(|m| m.name == name))
+            .map(|m| m.id);
+        if let Some(id) = query.id.or(by_name) {
+            return Some(id);
+        }
     }
+
+    fs::read_to_string("/var/lib/nvpmodel/status")
+        .ok()
+        .and_then(|content| parse_nvpmodel_status(&content))
 }
 
 #[cfg(test)]
@@ -203,6 +262,97 @@ mod tests {
         assert_eq!(stats.models[2].name, "MAX Q");
     }
 
+    const ORIN_CONF: &str = "\
+< PARAM TYPE=FILE NAME=CPU_ONLINE >
+CORE_0 /sys/devices/system/cpu/cpu0/online
+CORE_1 /sys/devices/system/cpu/cpu1/online
+
+< PARAM TYPE=FILE NAME=GPU_POWER_CONTROL_ENABLE >
+GPU_PWR_CNTL_EN /sys/devices/gpu.0/power/control
+
+###########################
+#                         #
+# POWER_MODEL DEFINITIONS #
+#                         #
+###########################
+
+# MAXN is the NONE power mode
+< POWER_MODEL ID=0 NAME=MAXN >
+CPU_ONLINE CORE_0 1
+CPU_ONLINE CORE_1 1
+GPU_POWER_CONTROL_ENABLE GPU_PWR_CNTL_EN on
+CPU_A78_0 MIN_FREQ 729600
+CPU_A78_0 MAX_FREQ -1
+
+< POWER_MODEL ID=1 NAME=MODE_15W >
+CPU_ONLINE CORE_0 1
+CPU_ONLINE CORE_1 0
+CPU_A78_0 MAX_FREQ 1113600
+
+< POWER_MODEL ID=2 NAME=MODE_30W >
+CPU_ONLINE CORE_0 1
+CPU_ONLINE CORE_1 1
+
+< POWER_MODEL ID=3 NAME=MODE_50W >
+CPU_ONLINE CORE_0 1
+
+# mandatory section to configure the default mode
+< PM_CONFIG DEFAULT=2 >
+";
+
+    #[test]
+    fn test_parse_nvpmodel_models_orin() {
+        let models = parse_nvpmodel_models(ORIN_CONF);
+        let models: Vec<(u8, &str)> = models.iter().map(|m| (m.id, m.name.as_str())).collect();
+
+        assert_eq!(
+            models,
+            vec![
+                (0, "MAXN"),
+                (1, "MODE_15W"),
+                (2, "MODE_30W"),
+                (3, "MODE_50W")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_nvpmodel_models_ignores_malformed_tags() {
+        let conf = "< POWER_MODEL ID=x NAME=BROKEN >\n< POWER_MODEL NAME=NOID >\n<POWER_MODEL ID=4 NAME=MODE_10W>\n";
+        let models = parse_nvpmodel_models(conf);
+
+        assert_eq!(models.len(), 1);
+        assert_eq!(models[0].id, 4);
+        assert_eq!(models[0].name, "MODE_10W");
+    }
+
+    #[test]
+    fn test_parse_nvpmodel_query() {
+        let output = "NV Fan Mode:quiet\nNV Power Mode: MODE_30W\n2\n";
+        let query = parse_nvpmodel_query(output);
+
+        assert_eq!(
+            query,
+            NVPModelQuery {
+                id: Some(2),
+                name: Some("MODE_30W".to_string()),
+                fan_mode: Some("quiet".to_string()),
+            }
+        );
+
+        let query = parse_nvpmodel_query("NV Power Mode: MAXN\n");
+        assert_eq!(query.id, None);
+        assert_eq!(query.name.as_deref(), Some("MAXN"));
+        assert_eq!(query.fan_mode, None);
+    }
+
+    #[test]
+    fn test_parse_nvpmodel_status() {
+        assert_eq!(parse_nvpmodel_status("pmode:0000 fmode:quiet\n"), Some(0));
+        assert_eq!(parse_nvpmodel_status("pmode:0003 fmode:cool"), Some(3));
+        assert_eq!(parse_nvpmodel_status("fmode:quiet"), None);
+    }
+
     #[test]
     fn test_nvp_model_id_reading() {
         let stats = NVPModelStats::get();
@@ -3,8 +3,12 @@
 
 //! Power monitoring module
 
+use crate::modules::collector::Collector;
+use crate::modules::sysfs::{self, Blocking, Reader, Tokio};
+use std::collections::VecDeque;
 use std::fs;
 use std::path::Path;
+use std::time::Instant;
 
 /// Power statistics
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -20,48 +24,163 @@ pub struct PowerRail {
     pub current: f32,
     pub voltage: f32,
     pub power: f32,
+    /// Rail measuring the whole board input, such as `VDD_IN`
+    #[serde(default)]
+    pub is_total: bool,
+    /// Power averaged by the driver, in the unit of `power`, when exposed
+    #[serde(default)]
+    pub power_avg: Option<f32>,
+}
+
+/// Labels of rails measuring the total board input power
+const TOTAL_RAILS: [&str; 2] = ["VDD_IN", "POM_5V_IN"];
+
+/// Check whether a rail label names the total board input
+///
+/// Sub-rails such as `VDD_GPU_SOC` or `VDD_CPU_CV` are not totals even
+/// though they share the `VDD_` prefix.
+pub fn is_total_rail(name: &str) -> bool {
+    TOTAL_RAILS.contains(&name)
+}
+
+/// Name prefixes of the rails measured inside each total input rail
+///
+/// INA3221 monitors often expose a total rail next to the rails it feeds:
+///
+/// - Orin NX, Orin Nano and Xavier NX: `VDD_IN` feeds `VDD_CPU_GPU_CV` and
+///   `VDD_SOC` (`SOC` on some releases)
+/// - Nano and TX1: `POM_5V_IN` feeds `POM_5V_CPU` and `POM_5V_GPU`
+/// - TX2: `POM_5V_IN` feeds `VDD_SYS_CPU`, `VDD_SYS_GPU` and `VDD_SYS_SOC`
+///
+/// AGX Orin has no total rail; its `VDD_GPU_SOC`, `VDD_CPU_CV`,
+/// `VIN_SYS_5V0` and `VDDQ_VDD2_1V8AO` rails are summed.
+const CHILD_RAILS: [(&str, &[&str]); 2] = [
+    (
+        "VDD_IN",
+        &[
+            "VDD_CPU", "VDD_GPU", "VDD_SOC", "VDD_CV", "SOC", "CPU", "GPU", "CV",
+        ],
+    ),
+    ("POM_5V_IN", &["POM_5V_", "VDD_SYS_"]),
+];
+
+/// Check whether rail `child` is measured inside the total rail `parent`
+pub fn is_child_rail(parent: &str, child: &str) -> bool {
+    child != parent
+        && CHILD_RAILS
+            .iter()
+            .filter(|(total, _)| *total == parent)
+            .any(|(_, prefixes)| prefixes.iter().any(|p| child.starts_with(p)))
+}
+
+/// Total power of a set of rails, in the unit of the rails
+///
+/// When total input rails are labeled, their children are left out since
+/// they are already part of the total; rails outside every total are still
+/// added. Without a total rail all rails are summed.
+pub fn total_power(rails: &[PowerRail]) -> f32 {
+    let totals: Vec<&str> = rails
+        .iter()
+        .filter(|r| r.is_total)
+        .map(|r| r.name.as_str())
+        .collect();
+
+    rails
+        .iter()
+        .filter(|r| r.is_total || !totals.iter().any(|t| is_child_rail(t, &r.name)))
+        .map(|r| r.power)
+        .sum()
+}
+
+/// Check whether a rail label names a GPU rail
+///
+/// Matches `VDD_GPU_SOC` (AGX Orin), `GPU` (AGX Xavier), `VDD_SYS_GPU`
+/// (TX2) and `POM_5V_GPU` (Nano). Combined rails such as `VDD_CPU_GPU_CV`
+/// on Orin NX/Nano and Xavier NX also feed the CPU, so they do not count.
+pub fn is_gpu_rail(name: &str) -> bool {
+    let parts: Vec<&str> = name.split('_').collect();
+    parts.contains(&"GPU") && !parts.iter().any(|p| matches!(*p, "CPU" | "CV"))
 }
 
 impl PowerStats {
     /// Get current power statistics
     pub fn get() -> Self {
+        sysfs::block_on(Self::read(&Blocking))
+    }
+
+    /// Get current power statistics through `tokio::fs`
+    pub async fn get_async() -> Self {
+        Self::read(&Tokio).await
+    }
+
+    async fn read<R: Reader>(reader: &R) -> Self {
         let mut stats = PowerStats::default();
 
         let i2c_path = Path::new("/sys/bus/i2c/devices");
 
-        if i2c_path.exists() {
-            stats.rails = read_power_rails(i2c_path);
-            stats.total = stats.rails.iter().map(|r| r.power).sum::<f32>() / 1000.0;
+        if reader.exists(i2c_path).await {
+            stats.rails = read_power_rails(reader, i2c_path).await;
+            stats.total = total_power(&stats.rails) / 1000.0;
         }
 
         // Fallback to hwmon if INA3221 sensors not available
         if stats.rails.is_empty() || stats.total <= 0.0 {
-            stats = Self::read_hwmon_power();
+            stats = Self::read_hwmon_power(reader, Path::new("/sys/class/hwmon")).await;
         }
 
         stats
     }
 
+    /// Power drawn by the GPU in W, from its dedicated rails
+    ///
+    /// `None` when no rail is GPU-only, e.g. on boards with a combined
+    /// `VDD_CPU_GPU_CV` rail.
+    pub fn gpu_power(&self) -> Option<f32> {
+        let gpu: Vec<&PowerRail> = self.rails.iter().filter(|r| is_gpu_rail(&r.name)).collect();
+        if gpu.is_empty() {
+            return None;
+        }
+
+        Some(self.to_watts(gpu.iter().map(|r| r.power).sum()))
+    }
+
+    /// Power of a rail of these statistics in W
+    ///
+    /// Rails keep the unit of their source (mW for iio, W for hwmon).
+    pub fn rail_watts(&self, rail: &PowerRail) -> f32 {
+        self.to_watts(rail.power)
+    }
+
+    /// Convert power in the unit of the rails to W, using `total` which is
+    /// always in W
+    fn to_watts(&self, power: f32) -> f32 {
+        let rails_total = total_power(&self.rails);
+        if rails_total > 0.0 {
+            power * self.total / rails_total
+        } else {
+            0.0
+        }
+    }
+
     /// Read power from hwmon system (fallback method)
-    fn read_hwmon_power() -> Self {
+    async fn read_hwmon_power<R: Reader>(reader: &R, hwmon_path: &Path) -> Self {
         let mut stats = PowerStats::default();
-        let hwmon_path = Path::new("/sys/class/hwmon");
 
-        if !hwmon_path.exists() {
+        if !reader.exists(hwmon_path).await {
             return stats;
         }
 
-        if let Ok(entries) = fs::read_dir(&hwmon_path) {
-            for entry in entries.flatten() {
-                let hwmon_dir = entry.path();
-
+        if let Ok(entries) = reader.read_dir(hwmon_path).await {
+            for hwmon_dir in entries {
                 // Check if this is an INA3221 sensor
                 let name_path = hwmon_dir.join("name");
-                if let Ok(name) = fs::read_to_string(&name_path) {
-                    if name.trim() == "ina3221" {
+                if let Some(name) = reader.read_string(&name_path).await {
+                    if name == "ina3221" {
                         // Read INA3221 power rails (channels 1-3)
                         for channel in 1..=3 {
-                            if let Some(rail) = read_ina3221_hwmon_rail(&hwmon_dir, channel) {
+                            if let Some(rail) =
+                                read_ina3221_hwmon_rail(reader, &hwmon_dir, channel).await
+                            {
                                 stats.rails.push(rail);
                             }
                         }
@@ -79,11 +198,11 @@ impl PowerStats {
                 let power_input = hwmon_dir.join("power1_input");
                 let power_average = hwmon_dir.join("power1_average");
 
-                let power_value = if power_input.exists() {
-                    read_sysfs_u32(&power_input).unwrap_or(0) as f32 / 1000000.0
+                let power_value = if reader.exists(&power_input).await {
+                    reader.read_value::<u32>(&power_input).await.unwrap_or(0) as f32 / 1000000.0
                 // uW to W
-                } else if power_average.exists() {
-                    read_sysfs_u32(&power_average).unwrap_or(0) as f32 / 1000000.0
+                } else if reader.exists(&power_average).await {
+                    reader.read_value::<u32>(&power_average).await.unwrap_or(0) as f32 / 1000000.0
                 // uW to W
                 } else {
                     continue;
@@ -95,23 +214,28 @@ impl PowerStats {
                         current: power_value,
                         voltage: 0.0,
                         power: power_value,
+                        is_total: false,
+                        power_avg: None,
                     });
                 }
             }
         }
 
-        stats.total = stats.rails.iter().map(|r| r.power).sum::<f32>(); // power is already in W
+        stats.total = total_power(&stats.rails); // power is already in W
         stats
     }
 }
 
 /// Read INA3221 power rail from hwmon path
 /// Channel 1-3 corresponds to the three channels of INA3221
-fn read_ina3221_hwmon_rail(hwmon_path: &Path, channel: usize) -> Option<PowerRail> {
+async fn read_ina3221_hwmon_rail<R: Reader>(
+    reader: &R,
+    hwmon_path: &Path,
+    channel: usize,
+) -> Option<PowerRail> {
     // Read rail label (e.g., "VDD_IN", "VDD_CPU_GPU_CV", etc.)
     let label_path = hwmon_path.join(format!("in{}_label", channel));
-    let rail_name = if let Ok(label) = fs::read_to_string(&label_path) {
-        let name = label.trim().to_string();
+    let rail_name = if let Some(name) = reader.read_string(&label_path).await {
         // Skip NC (Not Connected) rails on Orin family
         if name == "NC" {
             return None;
@@ -123,51 +247,47 @@ fn read_ina3221_hwmon_rail(hwmon_path: &Path, channel: usize) -> Option<PowerRai
 
     // Read current in microamps (uA) - curr{n}_input
     let curr_path = hwmon_path.join(format!("curr{}_input", channel));
-    let current_ua = read_sysfs_i32(&curr_path).unwrap_or(0) as f32;
+    let current_ua = reader.read_value::<i32>(&curr_path).await.unwrap_or(0) as f32;
 
     // Read voltage in millivolts (mV) - in{n}_input
     let volt_path = hwmon_path.join(format!("in{}_input", channel));
-    let voltage_mv = read_sysfs_i32(&volt_path).unwrap_or(0) as f32;
+    let voltage_mv = reader.read_value::<i32>(&volt_path).await.unwrap_or(0) as f32;
 
     // Calculate power: P = V * I
     // voltage_mv * current_ua / 1_000_000_000 = power in W
     let power_w = (voltage_mv * current_ua) / 1_000_000_000.0;
 
+    // Running average kept by newer drivers, in microwatts
+    let avg_path = hwmon_path.join(format!("power{}_average", channel));
+    let power_avg = reader
+        .read_value::<u32>(&avg_path)
+        .await
+        .map(|uw| uw as f32 / 1_000_000.0);
+
     // Only return rail if we got valid readings
     if power_w > 0.0 || (voltage_mv > 0.0 && current_ua >= 0.0) {
         Some(PowerRail {
+            is_total: is_total_rail(&rail_name),
             name: rail_name,
             current: current_ua / 1000.0,  // uA to mA
             voltage: voltage_mv,            // mV
             power: power_w,                 // W
+            power_avg,
         })
     } else {
         None
     }
 }
 
-/// Read a signed i32 value from sysfs (for sensors that can have negative values)
-fn read_sysfs_i32(path: &Path) -> Option<i32> {
-    fs::read_to_string(path)
-        .ok()
-        .and_then(|s| s.trim().parse().ok())
-}
-
-/// Read a u32 value from sysfs
-fn read_sysfs_u32(path: &Path) -> Option<u32> {
-    fs::read_to_string(path)
-        .ok()
-        .and_then(|s| s.trim().parse().ok())
-}
+/// Number of channels of an INA3221 monitor
+const INA3221_CHANNELS: usize = 3;
 
 /// Read all power rails from I2C devices
-fn read_power_rails(base_path: &Path) -> Vec<PowerRail> {
+async fn read_power_rails<R: Reader>(reader: &R, base_path: &Path) -> Vec<PowerRail> {
     let mut rails = Vec::new();
 
-    if let Ok(entries) = fs::read_dir(base_path) {
-        for entry in entries.flatten() {
-            let i2c_path = entry.path();
-
+    if let Ok(entries) = reader.read_dir(base_path).await {
+        for i2c_path in entries {
             if !i2c_path
                 .file_name()
                 .and_then(|s| s.to_str())
@@ -177,9 +297,10 @@ fn read_power_rails(base_path: &Path) -> Vec<PowerRail> {
                 continue;
             }
 
-            let rail_num: usize = 0;
-            if let Some(rail) = read_ina3221_rail(&i2c_path, rail_num) {
-                rails.push(rail);
+            for rail_num in 0..INA3221_CHANNELS {
+                if let Some(rail) = read_ina3221_rail(reader, &i2c_path, rail_num).await {
+                    rails.push(rail);
+                }
             }
         }
     }
@@ -188,13 +309,25 @@ fn read_power_rails(base_path: &Path) -> Vec<PowerRail> {
 }
 
 /// Read INA3221 power rail
-fn read_ina3221_rail(iio_path: &Path, rail_num: usize) -> Option<PowerRail> {
+///
+/// Channels labeled empty or `NC` (not connected) are skipped; unlabeled
+/// channels of an INA3221 are named after their index.
+async fn read_ina3221_rail<R: Reader>(
+    reader: &R,
+    iio_path: &Path,
+    rail_num: usize,
+) -> Option<PowerRail> {
     let label_path = iio_path.join(format!("in{}_label", rail_num));
-    let rail_name = if let Ok(name) = fs::read_to_string(&label_path) {
-        name.trim().to_string()
+    let rail_name = if let Some(name) = reader.read_string(&label_path).await {
+        if name == "NC" {
+            return None;
+        }
+        name
     } else {
         let name_path = iio_path.join("name");
-        if let Ok(name) = fs::read_to_string(&name_path) {
+        let channel_path = iio_path.join(format!("curr{}_input", rail_num));
+        let has_channel = reader.exists(&channel_path).await;
+        if let (Some(name), true) = (reader.read_string(&name_path).await, has_channel) {
             if name.contains("ina3221") {
                 format!("in{}", rail_num)
             } else {
@@ -209,23 +342,685 @@ fn read_ina3221_rail(iio_path: &Path, rail_num: usize) -> Option<PowerRail> {
         return None;
     }
 
-    let current_u_a =
-        read_sysfs_u32(&iio_path.join(format!("curr{}_input", rail_num))).unwrap_or(0) as f32;
-    let voltage_u_v =
-        read_sysfs_u32(&iio_path.join(format!("in{}_input", rail_num))).unwrap_or(0) as f32;
+    let current_u_a = reader
+        .read_value::<u32>(&iio_path.join(format!("curr{}_input", rail_num)))
+        .await
+        .unwrap_or(0) as f32;
+    let voltage_u_v = reader
+        .read_value::<u32>(&iio_path.join(format!("in{}_input", rail_num)))
+        .await
+        .unwrap_or(0) as f32;
     let power_m_w = current_u_a * voltage_u_v / 1000000.0;
 
     Some(PowerRail {
+        is_total: is_total_rail(&rail_name),
         name: rail_name,
         current: current_u_a,
         voltage: voltage_u_v,
         power: power_m_w,
+        power_avg: None,
     })
 }
 
+/// Source the device is currently powered from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum PowerSource {
+    /// External supply (mains adapter, USB-C PD)
+    AC,
+    /// Battery or UPS without external supply
+    Battery,
+    /// No power_supply information available
+    #[default]
+    Unknown,
+}
+
+impl std::fmt::Display for PowerSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PowerSource::AC => write!(f, "AC"),
+            PowerSource::Battery => write!(f, "Battery"),
+            PowerSource::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+/// Detect whether the device runs on AC or battery
+///
+/// Reads `type` and `online` of every `/sys/class/power_supply` entry.
+/// Any online mains/USB supply wins over a present battery.
+pub fn power_source() -> PowerSource {
+    power_supply().0
+}
+
+/// Remaining battery capacity in percent, if a battery reports one
+pub fn battery_capacity() -> Option<u8> {
+    power_supply().1
+}
+
+/// Power source and battery capacity from a single power_supply scan
+pub fn power_supply() -> (PowerSource, Option<u8>) {
+    read_power_source(Path::new("/sys/class/power_supply"))
+}
+
+/// Read power source and battery capacity from a power_supply directory
+fn read_power_source(base_path: &Path) -> (PowerSource, Option<u8>) {
+    let mut external_online = false;
+    let mut battery_present = false;
+    let mut capacity = None;
+
+    if let Ok(entries) = fs::read_dir(base_path) {
+        for entry in entries.flatten() {
+            let supply = entry.path();
+            let supply_type = sysfs::read_string(supply.join("type")).unwrap_or_default();
+
+            match supply_type.as_str() {
+                "Battery" | "UPS" => {
+                    battery_present = true;
+                    if capacity.is_none() {
+                        capacity = sysfs::read_u32(supply.join("capacity"))
+                            .map(|c| c.min(100) as u8);
+                    }
+                }
+                "Mains" | "USB" | "USB_C" | "USB_PD" => {
+                    external_online |= sysfs::read_u32(supply.join("online")) == Some(1);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let source = if external_online {
+        PowerSource::AC
+    } else if battery_present {
+        PowerSource::Battery
+    } else {
+        PowerSource::Unknown
+    };
+
+    (source, capacity)
+}
+
+/// Number of accepted totals kept for the running median
+const MEDIAN_WINDOW: usize = 15;
+
+/// Minimum accepted totals before the median check applies
+const MEDIAN_MIN_SAMPLES: usize = 5;
+
+/// A total above this multiple of the running median is treated as a glitch
+const MEDIAN_FACTOR: f32 = 5.0;
+
+/// Consecutive totals above the median factor accepted as a real load step
+const SUSTAINED_SAMPLES: usize = 3;
+
+/// Maximum plausible total power draw in W for a board model
+///
+/// Generous upper bounds above each module's highest power mode, used to
+/// reject INA readings taken during a transition.
+pub fn board_max_power(model: &str) -> f32 {
+    let model = model.to_lowercase();
+
+    if model.contains("thor") {
+        150.0
+    } else if model.contains("agx orin") {
+        75.0
+    } else if model.contains("agx xavier") {
+        40.0
+    } else if model.contains("orin") || model.contains("xavier") || model.contains("tx2") {
+        30.0
+    } else if model.contains("nano") || model.contains("tx1") {
+        15.0
+    } else {
+        150.0
+    }
+}
+
+/// Power collector for the monitoring loop
+///
+/// Rejects glitched samples: a sample whose total exceeds the board
+/// maximum, or `MEDIAN_FACTOR` times the running median of accepted
+/// totals, is dropped and the previous accepted sample is reused. After
+/// `SUSTAINED_SAMPLES` such totals in a row the step is taken as a real
+/// load change and the median restarts from it.
+///
+/// Samples taken through [`PowerMonitor::sample`] are also integrated over
+/// time into the energy consumed since creation or the last reset.
+#[derive(Debug)]
+pub struct PowerMonitor {
+    max_total: f32,
+    last_accepted: Option<PowerStats>,
+    recent_totals: VecDeque<f32>,
+    consecutive_spikes: usize,
+    rejected_samples: u64,
+    energy_wh: f64,
+    last_sample: Option<(Instant, f32)>,
+}
+
+impl Default for PowerMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PowerMonitor {
+    /// Create a new power monitor with the limit of the detected board
+    pub fn new() -> Self {
+        Self::with_max_total(board_max_power(&crate::modules::hardware::detect_board_model()))
+    }
+
+    /// Create a new power monitor with an explicit total power limit in W
+    pub fn with_max_total(max_total: f32) -> Self {
+        Self {
+            max_total,
+            last_accepted: None,
+            recent_totals: VecDeque::with_capacity(MEDIAN_WINDOW),
+            consecutive_spikes: 0,
+            rejected_samples: 0,
+            energy_wh: 0.0,
+            last_sample: None,
+        }
+    }
+
+    /// Total power limit in W above which samples are rejected
+    pub fn max_total(&self) -> f32 {
+        self.max_total
+    }
+
+    /// Number of samples rejected as outliers so far
+    pub fn rejected_samples(&self) -> u64 {
+        self.rejected_samples
+    }
+
+    /// Energy consumed between the sampled totals, in Wh
+    pub fn energy_wh(&self) -> f64 {
+        self.energy_wh
+    }
+
+    /// Restart energy accumulation from the next sample
+    pub fn reset(&mut self) {
+        self.energy_wh = 0.0;
+        self.last_sample = None;
+    }
+
+    /// Read, filter and accumulate the current power statistics
+    pub fn sample(&mut self) -> PowerStats {
+        self.sample_at(PowerStats::get(), Instant::now())
+    }
+
+    /// Filter a sample taken at `now` and add its energy to the total
+    ///
+    /// The total power is integrated with the trapezoidal rule between
+    /// consecutive samples.
+    pub fn sample_at(&mut self, sample: PowerStats, now: Instant) -> PowerStats {
+        let sample = self.update(sample);
+
+        if let Some((previous_at, previous_total)) = self.last_sample {
+            let hours = now.saturating_duration_since(previous_at).as_secs_f64() / 3600.0;
+            self.energy_wh += (previous_total as f64 + sample.total as f64) / 2.0 * hours;
+        }
+        self.last_sample = Some((now, sample.total));
+
+        sample
+    }
+
+    /// Filter a new sample, returning the previous accepted one on a glitch
+    pub fn update(&mut self, sample: PowerStats) -> PowerStats {
+        if self.is_invalid(&sample) {
+            self.rejected_samples += 1;
+            return self.last_accepted.clone().unwrap_or_default();
+        }
+
+        if self.is_spike(&sample) {
+            self.consecutive_spikes += 1;
+            if self.consecutive_spikes < SUSTAINED_SAMPLES {
+                self.rejected_samples += 1;
+                return self.last_accepted.clone().unwrap_or_default();
+            }
+            // A sustained step is a real load change
+            self.recent_totals.clear();
+        }
+        self.consecutive_spikes = 0;
+
+        if self.recent_totals.len() == MEDIAN_WINDOW {
+            self.recent_totals.pop_front();
+        }
+        self.recent_totals.push_back(sample.total);
+        self.last_accepted = Some(sample.clone());
+
+        sample
+    }
+
+    fn is_invalid(&self, sample: &PowerStats) -> bool {
+        let invalid_rail = sample
+            .rails
+            .iter()
+            .any(|r| !r.power.is_finite() || r.power < 0.0);
+
+        !sample.total.is_finite() || sample.total > self.max_total || invalid_rail
+    }
+
+    fn is_spike(&self, sample: &PowerStats) -> bool {
+        match self.running_median() {
+            Some(median) if median > 0.0 => sample.total > median * MEDIAN_FACTOR,
+            _ => false,
+        }
+    }
+
+    fn running_median(&self) -> Option<f32> {
+        if self.recent_totals.len() < MEDIAN_MIN_SAMPLES {
+            return None;
+        }
+
+        let mut sorted: Vec<f32> = self.recent_totals.iter().copied().collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        Some(sorted[sorted.len() / 2])
+    }
+}
+
+impl Collector for PowerMonitor {
+    type Stats = PowerStats;
+
+    fn collect(&mut self) -> Self::Stats {
+        self.sample()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modules::sysfs::MockDir;
+    use std::time::Duration;
+
+    /// Create a mocked power_supply entry with the given attribute files
+    fn mock_supply(base: &Path, name: &str, files: &[(&str, &str)]) {
+        let dir = base.join(name);
+        fs::create_dir_all(&dir).unwrap();
+        for (file, content) in files {
+            fs::write(dir.join(file), content).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_power_source_ac_online() {
+        let base = MockDir::new("power-supply-ac");
+        mock_supply(&base, "ac", &[("type", "Mains\n"), ("online", "1\n")]);
+        mock_supply(
+            &base,
+            "battery",
+            &[("type", "Battery\n"), ("capacity", "87\n")],
+        );
+
+        let (source, capacity) = read_power_source(&base);
+        assert_eq!(source, PowerSource::AC);
+        assert_eq!(capacity, Some(87));
+    }
+
+    #[test]
+    fn test_power_source_battery_when_mains_offline() {
+        let base = MockDir::new("power-supply-battery");
+        mock_supply(&base, "ac", &[("type", "Mains\n"), ("online", "0\n")]);
+        mock_supply(
+            &base,
+            "ups",
+            &[("type", "UPS\n"), ("capacity", "42\n")],
+        );
+
+        let (source, capacity) = read_power_source(&base);
+        assert_eq!(source, PowerSource::Battery);
+        assert_eq!(capacity, Some(42));
+    }
+
+    #[test]
+    fn test_power_source_unknown_without_supplies() {
+        let base = MockDir::new("power-supply-empty");
+
+        assert_eq!(read_power_source(&base), (PowerSource::Unknown, None));
+        assert_eq!(
+            read_power_source(Path::new("/nonexistent/power_supply")),
+            (PowerSource::Unknown, None)
+        );
+    }
+
+    fn power_sample(total: f32) -> PowerStats {
+        PowerStats {
+            total,
+            rails: vec![PowerRail {
+                name: "VDD_IN".to_string(),
+                current: total * 200.0,
+                voltage: 5000.0,
+                power: total,
+                ..Default::default()
+            }],
+        }
+    }
+
+    #[test]
+    fn test_power_monitor_rejects_glitch_above_board_max() {
+        let mut monitor = PowerMonitor::with_max_total(60.0);
+
+        assert_eq!(monitor.update(power_sample(12.0)).total, 12.0);
+
+        // 900 W spike during a sensor transition reuses the prior value
+        let filtered = monitor.update(power_sample(900.0));
+        assert_eq!(filtered.total, 12.0);
+        assert_eq!(filtered.rails[0].power, 12.0);
+        assert_eq!(monitor.rejected_samples(), 1);
+
+        assert_eq!(monitor.update(power_sample(13.0)).total, 13.0);
+        assert_eq!(monitor.rejected_samples(), 1);
+    }
+
+    #[test]
+    fn test_power_monitor_rejects_glitch_above_running_median() {
+        let mut monitor = PowerMonitor::with_max_total(150.0);
+
+        for _ in 0..MEDIAN_MIN_SAMPLES {
+            monitor.update(power_sample(8.0));
+        }
+
+        // Below the board maximum but far above the running median
+        let filtered = monitor.update(power_sample(120.0));
+        assert_eq!(filtered.total, 8.0);
+        assert_eq!(monitor.rejected_samples(), 1);
+
+        // A realistic load step is accepted
+        assert_eq!(monitor.update(power_sample(25.0)).total, 25.0);
+        assert_eq!(monitor.rejected_samples(), 1);
+    }
+
+    #[test]
+    fn test_power_monitor_accepts_sustained_load_step() {
+        let mut monitor = PowerMonitor::with_max_total(150.0);
+
+        for _ in 0..MEDIAN_MIN_SAMPLES {
+            monitor.update(power_sample(2.0));
+        }
+
+        // A real step to 40 W is held back only for the first few samples
+        for _ in 1..SUSTAINED_SAMPLES {
+            assert_eq!(monitor.update(power_sample(40.0)).total, 2.0);
+        }
+        assert_eq!(monitor.update(power_sample(40.0)).total, 40.0);
+        assert_eq!(monitor.update(power_sample(41.0)).total, 41.0);
+        assert_eq!(monitor.rejected_samples(), SUSTAINED_SAMPLES as u64 - 1);
+
+        // An isolated glitch in between resets the count
+        let mut monitor = PowerMonitor::with_max_total(150.0);
+        for _ in 0..MEDIAN_MIN_SAMPLES {
+            monitor.update(power_sample(2.0));
+        }
+        for _ in 0..SUSTAINED_SAMPLES {
+            assert_eq!(monitor.update(power_sample(40.0)).total, 2.0);
+            monitor.update(power_sample(2.0));
+        }
+    }
+
+    #[test]
+    fn test_power_monitor_rejects_invalid_values() {
+        let mut monitor = PowerMonitor::with_max_total(60.0);
+
+        assert_eq!(monitor.update(power_sample(f32::NAN)).total, 0.0);
+        assert_eq!(monitor.update(power_sample(-3.0)).total, 0.0);
+        assert_eq!(monitor.rejected_samples(), 2);
+    }
+
+    #[test]
+    fn test_power_monitor_energy_accumulation() {
+        let mut monitor = PowerMonitor::with_max_total(60.0);
+        let start = Instant::now();
+
+        monitor.sample_at(power_sample(10.0), start);
+        assert_eq!(monitor.energy_wh(), 0.0);
+
+        monitor.sample_at(power_sample(10.0), start + Duration::from_secs(3600));
+        assert!((monitor.energy_wh() - 10.0).abs() < 1e-6);
+
+        // Ramping from 10 W to 20 W over half an hour averages 15 W
+        monitor.sample_at(power_sample(20.0), start + Duration::from_secs(5400));
+        assert!((monitor.energy_wh() - 17.5).abs() < 1e-6);
+
+        monitor.reset();
+        assert_eq!(monitor.energy_wh(), 0.0);
+        monitor.sample_at(power_sample(20.0), start + Duration::from_secs(7200));
+        assert_eq!(monitor.energy_wh(), 0.0);
+    }
+
+    fn rail(name: &str, power: f32) -> PowerRail {
+        PowerRail {
+            name: name.to_string(),
+            power,
+            is_total: is_total_rail(name),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_is_total_rail() {
+        assert!(is_total_rail("VDD_IN"));
+        assert!(is_total_rail("POM_5V_IN"));
+        assert!(!is_total_rail("VDD_GPU_SOC"));
+        assert!(!is_total_rail("VDD_CPU_CV"));
+        assert!(!is_total_rail("VIN_SYS_5V0"));
+    }
+
+    #[test]
+    fn test_is_child_rail() {
+        assert!(is_child_rail("VDD_IN", "VDD_CPU_GPU_CV"));
+        assert!(is_child_rail("VDD_IN", "VDD_SOC"));
+        assert!(is_child_rail("VDD_IN", "SOC"));
+        assert!(is_child_rail("POM_5V_IN", "POM_5V_GPU"));
+        assert!(is_child_rail("POM_5V_IN", "VDD_SYS_CPU"));
+        assert!(!is_child_rail("VDD_IN", "VDD_IN"));
+        assert!(!is_child_rail("VDD_IN", "POM_5V_GPU"));
+        assert!(!is_child_rail("VDD_GPU_SOC", "VDD_CPU_CV"));
+    }
+
+    #[test]
+    fn test_total_power_skips_children_of_total() {
+        let rails = vec![
+            rail("VDD_IN", 7500.0),
+            rail("VDD_CPU_GPU_CV", 2100.0),
+            rail("SOC", 1800.0),
+        ];
+        assert_eq!(total_power(&rails), 7500.0);
+
+        // A rail the total does not feed is still counted
+        let mut rails = rails;
+        rails.push(rail("USB_5V", 500.0));
+        assert_eq!(total_power(&rails), 8000.0);
+        // Every rail stays listed for display
+        assert_eq!(rails.len(), 4);
+    }
+
+    #[test]
+    fn test_is_gpu_rail() {
+        // AGX Orin
+        assert!(is_gpu_rail("VDD_GPU_SOC"));
+        assert!(!is_gpu_rail("VDD_CPU_CV"));
+        assert!(!is_gpu_rail("VIN_SYS_5V0"));
+        assert!(!is_gpu_rail("VDDQ_VDD2_1V8AO"));
+        // Orin NX/Nano and Xavier NX share one CPU/GPU/CV rail
+        assert!(!is_gpu_rail("VDD_IN"));
+        assert!(!is_gpu_rail("VDD_CPU_GPU_CV"));
+        assert!(!is_gpu_rail("VDD_SOC"));
+        // AGX Xavier
+        assert!(is_gpu_rail("GPU"));
+        assert!(!is_gpu_rail("CPU"));
+        assert!(!is_gpu_rail("SOC"));
+        assert!(!is_gpu_rail("CV"));
+        // Nano and TX2
+        assert!(is_gpu_rail("POM_5V_GPU"));
+        assert!(is_gpu_rail("VDD_SYS_GPU"));
+        assert!(!is_gpu_rail("VDD_GPUX"));
+    }
+
+    #[test]
+    fn test_gpu_power() {
+        // AGX Orin rails in mW, total in W
+        let stats = PowerStats {
+            total: 10.0,
+            rails: vec![
+                rail("VDD_GPU_SOC", 4000.0),
+                rail("VDD_CPU_CV", 2000.0),
+                rail("VIN_SYS_5V0", 3000.0),
+                rail("VDDQ_VDD2_1V8AO", 1000.0),
+            ],
+        };
+        assert_eq!(stats.gpu_power(), Some(4.0));
+
+        // Orin Nano has no GPU-only rail
+        let stats = PowerStats {
+            total: 7.5,
+            rails: vec![
+                rail("VDD_IN", 7500.0),
+                rail("VDD_CPU_GPU_CV", 2100.0),
+                rail("VDD_SOC", 1800.0),
+            ],
+        };
+        assert_eq!(stats.gpu_power(), None);
+        assert_eq!(PowerStats::default().gpu_power(), None);
+    }
+
+    #[test]
+    fn test_rail_watts() {
+        let stats = PowerStats {
+            total: 7.5,
+            rails: vec![rail("VDD_IN", 7500.0), rail("VDD_SOC", 1500.0)],
+        };
+        assert_eq!(stats.rail_watts(&stats.rails[1]), 1.5);
+
+        let hwmon = PowerStats {
+            total: 2.5,
+            rails: vec![rail("hwmon1", 2.5)],
+        };
+        assert_eq!(hwmon.rail_watts(&hwmon.rails[0]), 2.5);
+    }
+
+    #[test]
+    fn test_total_power_prefers_total_rail() {
+        let rails = vec![
+            rail("VDD_IN", 7500.0),
+            rail("VDD_CPU_GPU_CV", 2100.0),
+            rail("VDD_SOC", 1800.0),
+        ];
+        assert_eq!(total_power(&rails), 7500.0);
+
+        // Without a labeled total, channels are summed
+        let rails = vec![rail("VDD_CPU_GPU_CV", 2100.0), rail("VDD_SOC", 1800.0)];
+        assert_eq!(total_power(&rails), 3900.0);
+        assert_eq!(total_power(&[]), 0.0);
+    }
+
+    async fn check_read_power_rails_reads_all_channels<R: Reader>(reader: &R) {
+        let base = MockDir::new("ina3221");
+        let device = base.join("iio:device0");
+        fs::create_dir_all(&device).unwrap();
+        fs::write(device.join("name"), "ina3221\n").unwrap();
+        for (channel, label) in ["VDD_IN", "VDD_CPU_GPU_CV", "VDD_SOC"].iter().enumerate() {
+            fs::write(device.join(format!("in{}_label", channel)), label).unwrap();
+            fs::write(device.join(format!("in{}_input", channel)), "5000\n").unwrap();
+            fs::write(device.join(format!("curr{}_input", channel)), "400\n").unwrap();
+        }
+        // Second monitor with an unconnected channel
+        let device = base.join("iio:device1");
+        fs::create_dir_all(&device).unwrap();
+        fs::write(device.join("in0_label"), "NC\n").unwrap();
+        fs::write(device.join("in1_label"), "\n").unwrap();
+
+        let mut names: Vec<String> = read_power_rails(reader, &base)
+            .await
+            .into_iter()
+            .map(|rail| rail.name)
+            .collect();
+        names.sort();
+        assert_eq!(names, ["VDD_CPU_GPU_CV", "VDD_IN", "VDD_SOC"]);
+    }
+
+    #[test]
+    fn test_read_power_rails_reads_all_channels() {
+        sysfs::block_on(check_read_power_rails_reads_all_channels(&Blocking));
+    }
+
+    #[tokio::test]
+    async fn test_read_power_rails_reads_all_channels_async() {
+        check_read_power_rails_reads_all_channels(&Tokio).await;
+    }
+
+    async fn check_read_hwmon_power<R: Reader>(reader: &R) {
+        let base = MockDir::new("hwmon-power");
+        let ina = base.join("hwmon0");
+        fs::create_dir_all(&ina).unwrap();
+        fs::write(ina.join("name"), "ina3221\n").unwrap();
+        fs::write(ina.join("in1_label"), "VDD_IN\n").unwrap();
+        fs::write(ina.join("in1_input"), "5000\n").unwrap();
+        fs::write(ina.join("curr1_input"), "1000000\n").unwrap();
+        // Generic power meter reporting in uW
+        let meter = base.join("hwmon1");
+        fs::create_dir_all(&meter).unwrap();
+        fs::write(meter.join("power1_average"), "2500000\n").unwrap();
+
+        let mut stats = PowerStats::read_hwmon_power(reader, &base).await;
+        stats.rails.sort_by(|a, b| a.name.cmp(&b.name));
+        let names: Vec<&str> = stats.rails.iter().map(|rail| rail.name.as_str()).collect();
+        assert_eq!(names, ["VDD_IN", "hwmon1"]);
+        assert_eq!(stats.rails[0].power, 5.0);
+        assert_eq!(stats.rails[1].power, 2.5);
+        assert_eq!(stats.total, 7.5);
+
+        let missing = PowerStats::read_hwmon_power(reader, &base.join("missing")).await;
+        assert!(missing.rails.is_empty());
+    }
+
+    #[test]
+    fn test_read_hwmon_power() {
+        sysfs::block_on(check_read_hwmon_power(&Blocking));
+    }
+
+    #[tokio::test]
+    async fn test_read_hwmon_power_async() {
+        check_read_hwmon_power(&Tokio).await;
+    }
+
+    async fn check_read_ina3221_hwmon_rail_average<R: Reader>(reader: &R) {
+        let dir = MockDir::new("ina3221-avg");
+        for channel in 1..=2 {
+            fs::write(dir.join(format!("in{}_label", channel)), "VDD_IN\n").unwrap();
+            fs::write(dir.join(format!("in{}_input", channel)), "5000\n").unwrap();
+            fs::write(dir.join(format!("curr{}_input", channel)), "2000000\n").unwrap();
+        }
+        fs::write(dir.join("power2_average"), "7250000\n").unwrap();
+
+        let rail = read_ina3221_hwmon_rail(reader, &dir, 1).await.unwrap();
+        assert_eq!(rail.power, 10.0);
+        assert_eq!(rail.power_avg, None);
+
+        let rail = read_ina3221_hwmon_rail(reader, &dir, 2).await.unwrap();
+        assert_eq!(rail.power_avg, Some(7.25));
+    }
+
+    #[test]
+    fn test_read_ina3221_hwmon_rail_average() {
+        sysfs::block_on(check_read_ina3221_hwmon_rail_average(&Blocking));
+    }
+
+    #[tokio::test]
+    async fn test_read_ina3221_hwmon_rail_average_async() {
+        check_read_ina3221_hwmon_rail_average(&Tokio).await;
+    }
+
+    #[test]
+    fn test_board_max_power() {
+        assert_eq!(board_max_power("NVIDIA Jetson AGX Orin Developer Kit"), 75.0);
+        assert_eq!(board_max_power("NVIDIA Jetson Orin Nano Developer Kit"), 30.0);
+        assert_eq!(board_max_power("NVIDIA Jetson Nano Developer Kit"), 15.0);
+        assert_eq!(board_max_power("Unknown Jetson Board"), 150.0);
+    }
+
+    #[test]
+    fn test_power_source_display() {
+        assert_eq!(PowerSource::AC.to_string(), "AC");
+        assert_eq!(PowerSource::Battery.to_string(), "Battery");
+        assert_eq!(PowerSource::default().to_string(), "Unknown");
+    }
 
     #[test]
     fn test_power_stats_default() {
@@ -250,6 +1045,7 @@ mod tests {
             current: 1500.0,
             voltage: 5000.0,
             power: 7500.0,
+            ..Default::default()
         };
 
         assert_eq!(rail.name, "VDD_CPU");
@@ -268,12 +1064,14 @@ mod tests {
                     current: 1500.0,
                     voltage: 5000.0,
                     power: 7500.0,
+                    ..Default::default()
                 },
                 PowerRail {
                     name: "VDD_GPU".to_string(),
                     current: 2000.0,
                     voltage: 5000.0,
                     power: 10000.0,
+                    ..Default::default()
                 },
             ],
         };
@@ -284,9 +1082,8 @@ mod tests {
         assert_eq!(stats.rails[1].name, "VDD_GPU");
     }
 
-    #[test]
-    fn test_ina3221_sensor_detection() {
-        let stats = PowerStats::get();
+    async fn check_ina3221_sensor_detection<R: Reader>(reader: &R) {
+        let stats = PowerStats::read(reader).await;
 
         if !stats.rails.is_empty() {
             for rail in &stats.rails {
@@ -295,6 +1092,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ina3221_sensor_detection() {
+        sysfs::block_on(check_ina3221_sensor_detection(&Blocking));
+    }
+
+    #[tokio::test]
+    async fn test_ina3221_sensor_detection_async() {
+        check_ina3221_sensor_detection(&Tokio).await;
+    }
+
     #[test]
     fn test_power_rail_voltage_reading() {
         let rail = PowerRail {
@@ -302,6 +1109,7 @@ mod tests {
             current: 1500.0,
             voltage: 5000.0,
             power: 7500.0,
+            ..Default::default()
         };
 
         assert!(rail.voltage > 0.0, "Voltage should be positive");
@@ -318,6 +1126,7 @@ mod tests {
             current: 2000.0,
             voltage: 5000.0,
             power: 10000.0,
+            ..Default::default()
         };
 
         assert!(rail.current >= 0.0, "Current should be non-negative");
@@ -338,6 +1147,7 @@ mod tests {
             current: current_m_a,
             voltage: voltage_m_v,
             power: expected_power_m_w,
+            ..Default::default()
         };
 
         assert_eq!(rail.power, 7500.0);
@@ -352,12 +1162,14 @@ mod tests {
                 current: 1000.0,
                 voltage: 5000.0,
                 power: 5000.0,
+                ..Default::default()
             },
             PowerRail {
                 name: "rail2".to_string(),
                 current: 2000.0,
                 voltage: 5000.0,
                 power: 10000.0,
+                ..Default::default()
             },
         ];
 
@@ -373,6 +1185,7 @@ mod tests {
             current: 1234.0,
             voltage: 5678.0,
             power: 7007.652,
+            ..Default::default()
         };
 
         assert!(rail.name.contains("ina3221"));
@@ -390,6 +1203,7 @@ mod tests {
                 current: 1500.0,
                 voltage: 5000.0,
                 power: 7500.0,
+                ..Default::default()
             }],
         };
 
@@ -407,6 +1221,7 @@ mod tests {
             current: 2000.0,
             voltage: 5000.0,
             power: 10000.0,
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&rail);
@@ -448,6 +1263,7 @@ mod tests {
             current: 0.0,
             voltage: 5000.0,
             power: 0.0,
+            ..Default::default()
         };
         assert_eq!(rail_zero_current.power, 0.0);
 
@@ -456,6 +1272,7 @@ mod tests {
             current: 1500.0,
             voltage: 0.0,
             power: 0.0,
+            ..Default::default()
         };
         assert_eq!(rail_zero_voltage.power, 0.0);
     }
@@ -491,6 +1308,7 @@ mod tests {
 
     #[test]
     #[ignore = "Requires implementation - failing test for system power supply"]
+    #[allow(non_snake_case)]
     fn testSystemPowerSupplyReading() {
         let stats = PowerStats::get();
 
@@ -505,8 +1323,8 @@ mod tests {
     }
 
     #[test]
-    #[ignore = "Requires implementation - failing test for total power rails"]
-    fn testTotalPowerRailDetection() {
+    #[ignore = "Requires Jetson hardware - total power rails"]
+    fn test_total_power_rail_detection() {
         let stats = PowerStats::get();
 
         let has_pom_5v = stats.rails.iter().any(|r| r.name == "POM_5V_IN");
@@ -520,6 +1338,7 @@ mod tests {
 
     #[test]
     #[ignore = "Requires implementation - failing test for rail labels"]
+    #[allow(non_snake_case)]
     fn testIna3221WithLabels() {
         let stats = PowerStats::get();
 
@@ -544,6 +1363,7 @@ mod tests {
             current: current_ma,
             voltage: voltage_mv,
             power: expected_power_mw,
+            ..Default::default()
         };
 
         let mut stats = PowerStats::default();
@@ -567,18 +1387,21 @@ mod tests {
                     current: 1000.0,
                     voltage: 5000.0,
                     power: 5000.0,
+                    ..Default::default()
                 },
                 PowerRail {
                     name: "rail2".to_string(),
                     current: 2000.0,
                     voltage: 5000.0,
                     power: 10000.0,
+                    ..Default::default()
                 },
                 PowerRail {
                     name: "rail3".to_string(),
                     current: 500.0,
                     voltage: 5000.0,
                     power: 2500.0,
+                    ..Default::default()
                 },
             ],
         };
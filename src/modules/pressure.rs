@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Pressure stall information (PSI) module
+//!
+//! Reads `/proc/pressure/{cpu,memory,io}`, which report the share of time
+//! tasks were stalled waiting on a resource. Kernels built without PSI do
+//! not expose these files and every resource is reported as `None`.
+
+use crate::modules::collector::Collector;
+use std::fs;
+use std::path::Path;
+
+/// Pressure stall statistics per resource
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct PressureStats {
+    pub cpu: Option<Pressure>,
+    pub memory: Option<Pressure>,
+    pub io: Option<Pressure>,
+}
+
+/// Stall averages of a single resource
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Pressure {
+    /// Time at least one task was stalled
+    pub some: PressureAverages,
+    /// Time all non-idle tasks were stalled at once
+    pub full: Option<PressureAverages>,
+}
+
+/// Stall percentages over 10s, 60s and 300s windows
+#[derive(Debug, Clone, Copy, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PressureAverages {
+    pub avg10: f32,
+    pub avg60: f32,
+    pub avg300: f32,
+    /// Total stall time in microseconds
+    pub total: u64,
+}
+
+/// Read pressure stall information for CPU, memory and I/O
+pub fn read_pressure() -> PressureStats {
+    read_pressure_from(Path::new("/proc/pressure"))
+}
+
+fn read_pressure_from(base_path: &Path) -> PressureStats {
+    let read = |resource: &str| {
+        fs::read_to_string(base_path.join(resource))
+            .ok()
+            .and_then(|content| parse_pressure(&content))
+    };
+
+    PressureStats {
+        cpu: read("cpu"),
+        memory: read("memory"),
+        io: read("io"),
+    }
+}
+
+/// Parse the contents of a `/proc/pressure/<resource>` file
+///
+/// Returns `None` when the `some` line is missing.
+pub fn parse_pressure(content: &str) -> Option<Pressure> {
+    let mut some = None;
+    let mut full = None;
+
+    for line in content.lines() {
+        let mut fields = line.split_whitespace();
+        let target = match fields.next() {
+            Some("some") => &mut some,
+            Some("full") => &mut full,
+            _ => continue,
+        };
+
+        let mut averages = PressureAverages::default();
+        for (key, value) in fields.filter_map(|f| f.split_once('=')) {
+            match key {
+                "avg10" => averages.avg10 = value.parse().unwrap_or(0.0),
+                "avg60" => averages.avg60 = value.parse().unwrap_or(0.0),
+                "avg300" => averages.avg300 = value.parse().unwrap_or(0.0),
+                "total" => averages.total = value.parse().unwrap_or(0),
+                _ => {}
+            }
+        }
+        *target = Some(averages);
+    }
+
+    Some(Pressure { some: some?, full })
+}
+
+/// Pressure collector for the monitoring loop
+#[derive(Debug, Default)]
+pub struct PressureMonitor;
+
+impl PressureMonitor {
+    /// Create a new pressure monitor
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Collector for PressureMonitor {
+    type Stats = PressureStats;
+
+    fn collect(&mut self) -> Self::Stats {
+        read_pressure()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::sysfs::MockDir;
+
+    const MEMORY_PRESSURE: &str = "some avg10=1.53 avg60=0.87 avg300=0.21 total=4521893
+full avg10=0.42 avg60=0.18 avg300=0.05 total=1278040
+";
+
+    #[test]
+    fn test_parse_memory_pressure() {
+        let pressure = parse_pressure(MEMORY_PRESSURE).unwrap();
+
+        assert_eq!(
+            pressure.some,
+            PressureAverages {
+                avg10: 1.53,
+                avg60: 0.87,
+                avg300: 0.21,
+                total: 4521893,
+            }
+        );
+        let full = pressure.full.unwrap();
+        assert_eq!(full.avg10, 0.42);
+        assert_eq!(full.avg300, 0.05);
+        assert_eq!(full.total, 1278040);
+    }
+
+    #[test]
+    fn test_parse_pressure_without_full_line() {
+        // CPU pressure on older kernels only reports "some"
+        let pressure =
+            parse_pressure("some avg10=12.00 avg60=8.50 avg300=3.10 total=99\n").unwrap();
+
+        assert_eq!(pressure.some.avg10, 12.0);
+        assert!(pressure.full.is_none());
+    }
+
+    #[test]
+    fn test_parse_pressure_invalid() {
+        assert!(parse_pressure("").is_none());
+        assert!(parse_pressure("full avg10=0.00 avg60=0.00 avg300=0.00 total=0\n").is_none());
+    }
+
+    #[test]
+    fn test_read_pressure_without_psi() {
+        let dir = MockDir::new("pressure");
+        fs::write(dir.join("memory"), MEMORY_PRESSURE).unwrap();
+
+        let stats = read_pressure_from(&dir);
+        assert!(stats.cpu.is_none());
+        assert!(stats.io.is_none());
+        assert_eq!(stats.memory.unwrap().some.total, 4521893);
+
+        let stats = read_pressure_from(&dir.join("missing"));
+        assert!(stats.cpu.is_none() && stats.memory.is_none() && stats.io.is_none());
+    }
+
+    #[test]
+    fn test_pressure_serialization() {
+        let stats = PressureStats {
+            memory: parse_pressure(MEMORY_PRESSURE),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(&stats).unwrap();
+        assert!(json["cpu"].is_null());
+        assert_eq!(json["memory"]["full"]["total"], 1278040);
+    }
+}
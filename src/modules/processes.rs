@@ -6,6 +6,9 @@
 use std::fs;
 use std::path::Path;
 
+/// Default width of the command column in process tables
+pub const COMMAND_COLUMN_WIDTH: usize = 40;
+
 /// Process statistics
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct ProcessStats {
@@ -40,8 +43,33 @@ impl ProcessStats {
     }
 }
 
+impl ProcessInfo {
+    /// Command shortened to at most `width` characters for table display
+    pub fn display_command(&self, width: usize) -> String {
+        truncate_command(&self.command, width)
+    }
+}
+
+/// Shorten a command to at most `width` characters, ending with `…`
+///
+/// Works on characters rather than bytes, so multibyte commands never get
+/// split inside a code point. The full command is left untouched in the
+/// process structs and their JSON output.
+pub fn truncate_command(command: &str, width: usize) -> String {
+    if command.chars().count() <= width {
+        return command.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+
+    let mut truncated: String = command.chars().take(width - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
 /// Get GPU processes from nvidia-smi pmon
-fn get_gpu_processes() -> anyhow::Result<Vec<ProcessInfo>> {
+fn get_gpu_processes() -> crate::Result<Vec<ProcessInfo>> {
     let output = std::process::Command::new("nvidia-smi")
         .args(["pmon", "-c", "1"])
         .output()?;
@@ -148,6 +176,46 @@ mod tests {
         assert!(stats.gpu_processes.is_empty());
     }
 
+    #[test]
+    fn test_truncate_long_multibyte_command() {
+        let command = "python3 /home/użytkownik/modele/żółw_detekcja.py --źródło=/dev/video0 --wyjście=ścieżka";
+
+        let truncated = truncate_command(command, 30);
+        assert_eq!(truncated.chars().count(), 30);
+        assert!(truncated.ends_with('…'));
+        assert_eq!(truncated, "python3 /home/użytkownik/mode…");
+
+        // Cutting right after a multibyte character must not panic
+        assert_eq!(truncate_command("żółw", 3), "żó…");
+    }
+
+    #[test]
+    fn test_truncate_short_command_unchanged() {
+        assert_eq!(truncate_command("python train.py", 40), "python train.py");
+        assert_eq!(truncate_command("żółw", 4), "żółw");
+        assert_eq!(truncate_command("python", 0), "");
+        assert_eq!(truncate_command("", 0), "");
+    }
+
+    #[test]
+    fn test_display_command_keeps_full_command() {
+        let info = ProcessInfo {
+            command: "x".repeat(COMMAND_COLUMN_WIDTH * 2),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            info.display_command(COMMAND_COLUMN_WIDTH).chars().count(),
+            COMMAND_COLUMN_WIDTH
+        );
+        assert_eq!(info.command.len(), COMMAND_COLUMN_WIDTH * 2);
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(
+            json["command"].as_str().unwrap().len(),
+            COMMAND_COLUMN_WIDTH * 2
+        );
+    }
+
     #[test]
     fn test_process_info_default() {
         let info = ProcessInfo::default();
@@ -213,6 +281,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::double_comparisons)]
     fn test_process_memory_usage_tracking() {
         let stats = ProcessStats::get();
 
@@ -240,6 +309,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::overly_complex_bool_expr)]
     fn test_gpu_device_file_checking() {
         let pid = std::process::id();
         let has_gpu = has_gpu_device_fd(pid);
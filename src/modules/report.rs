@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Versioned `--stats` report
+//!
+//! [`StatsReport`] is the JSON document printed by `rjtop --stats` and
+//! `rjtop-cli --stats`. Its layout is a contract with downstream parsers:
+//! [`SCHEMA_VERSION`] is bumped whenever a key is renamed or removed.
+
+use super::collector::Collector;
+use super::cpu::{CpuMonitor, CpuStats};
+use super::disk::{read_disks, DiskStats};
+use super::engine::EngineStats;
+use super::fan::FanStats;
+use super::gpu::GpuStats;
+use super::hardware::{detect_board, is_jetson, BoardInfo};
+use super::memory::MemoryStats;
+use super::network::{NetInterface, NetMonitor};
+use super::power::{PowerMonitor, PowerStats};
+use super::pressure::{read_pressure, PressureStats};
+use super::processes::ProcessStats;
+use super::temperature::{to_unit, TemperatureStats, TemperatureUnit};
+
+/// Version of the [`StatsReport`] layout
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Statistics of every subsystem, as printed by `--stats`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct StatsReport {
+    pub schema_version: u32,
+    /// Unit of every temperature in the report
+    pub temperature_unit: TemperatureUnit,
+    pub hardware: HardwareReport,
+    pub cpu: CpuStats,
+    pub gpu: GpuStats,
+    pub memory: MemoryStats,
+    pub temperature: TemperatureStats,
+    pub fan: FanStats,
+    pub power: PowerStats,
+    pub pressure: PressureStats,
+    pub disk: Vec<DiskStats>,
+    pub network: Vec<NetInterface>,
+    pub engine: EngineStats,
+    pub processes: ProcessStats,
+}
+
+/// Board information and whether it was detected as a Jetson
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct HardwareReport {
+    #[serde(flatten)]
+    pub board: BoardInfo,
+    pub is_jetson: bool,
+}
+
+impl Default for StatsReport {
+    fn default() -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            temperature_unit: TemperatureUnit::default(),
+            hardware: HardwareReport::default(),
+            cpu: CpuStats::default(),
+            gpu: GpuStats::default(),
+            memory: MemoryStats::default(),
+            temperature: TemperatureStats::default(),
+            fan: FanStats::default(),
+            power: PowerStats::default(),
+            pressure: PressureStats::default(),
+            disk: Vec::new(),
+            network: Vec::new(),
+            engine: EngineStats::default(),
+            processes: ProcessStats::default(),
+        }
+    }
+}
+
+impl StatsReport {
+    /// Take a single report
+    ///
+    /// CPU usage is the average since boot and network rates are 0; use a
+    /// [`StatsReporter`] to sample them over an interval.
+    pub fn collect() -> Self {
+        StatsReporter::new().collect()
+    }
+
+    /// Copy of the report with every temperature converted to `unit`
+    pub fn to_unit(&self, unit: TemperatureUnit) -> Self {
+        let mut report = self.clone();
+        report.temperature_unit = unit;
+        report.temperature = self.temperature.to_unit(unit);
+        report.gpu.temperature = to_unit(self.gpu.temperature, unit);
+        report.fan.temperature = to_unit(self.fan.temperature, unit);
+        report
+    }
+}
+
+/// Takes [`StatsReport`]s, keeping the monitors whose readings are deltas
+#[derive(Debug)]
+pub struct StatsReporter {
+    cpu: CpuMonitor,
+    power: PowerMonitor,
+    network: NetMonitor,
+}
+
+impl StatsReporter {
+    pub fn new() -> Self {
+        Self {
+            cpu: CpuMonitor::new(),
+            power: PowerMonitor::new(),
+            network: NetMonitor::new(),
+        }
+    }
+}
+
+impl Default for StatsReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collector for StatsReporter {
+    type Stats = StatsReport;
+
+    fn collect(&mut self) -> Self::Stats {
+        let temperature = TemperatureStats::get();
+
+        StatsReport {
+            hardware: HardwareReport {
+                board: detect_board(),
+                is_jetson: is_jetson(),
+            },
+            cpu: self.cpu.collect(),
+            gpu: GpuStats::get(),
+            memory: MemoryStats::get(),
+            fan: FanStats::with_temperature(&temperature),
+            temperature,
+            power: self.power.collect(),
+            pressure: read_pressure(),
+            disk: read_disks(),
+            network: self.network.collect(),
+            engine: EngineStats::get(),
+            processes: ProcessStats::get(),
+            ..StatsReport::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_has_every_top_level_key() {
+        let value = serde_json::to_value(StatsReport::default()).unwrap();
+        let object = value.as_object().unwrap();
+
+        assert_eq!(value["schema_version"], SCHEMA_VERSION);
+        assert_eq!(value["temperature_unit"], "celsius");
+        let mut keys: Vec<&str> = object.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        let mut expected = vec![
+            "schema_version",
+            "temperature_unit",
+            "hardware",
+            "cpu",
+            "gpu",
+            "memory",
+            "temperature",
+            "fan",
+            "power",
+            "pressure",
+            "disk",
+            "network",
+            "engine",
+            "processes",
+        ];
+        expected.sort_unstable();
+        assert_eq!(keys, expected);
+        assert_eq!(value["hardware"]["is_jetson"], false);
+        assert_eq!(value["hardware"]["model"], "Unknown Jetson Board");
+    }
+
+    #[test]
+    fn test_report_to_unit() {
+        let mut report = StatsReport::default();
+        report.temperature.cpu = 100.0;
+        report.gpu.temperature = 0.0;
+
+        let converted = report.to_unit(TemperatureUnit::Fahrenheit);
+        assert_eq!(converted.temperature_unit, TemperatureUnit::Fahrenheit);
+        assert_eq!(converted.temperature.cpu, 212.0);
+        assert_eq!(converted.gpu.temperature, 32.0);
+        assert_eq!(converted.schema_version, SCHEMA_VERSION);
+    }
+}
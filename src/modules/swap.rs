@@ -0,0 +1,276 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Swap module
+//!
+//! Lists the active swap devices from `/proc/swaps` and creates, enables
+//! and disables swap through `mkswap`, `swapon` and `swapoff`. Jetsons ship
+//! with little RAM, so compressed zram swap is the usual way to stretch it.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+
+use super::disk::unescape_mount;
+use super::sysfs;
+use crate::Error;
+
+/// Active swap areas reported by the kernel
+const PROC_SWAPS: &str = "/proc/swaps";
+
+/// Block devices, one `zramN` directory per zram device
+const SYS_BLOCK: &str = "/sys/block";
+
+/// Reading this file allocates a new zram device and returns its index
+const ZRAM_HOT_ADD: &str = "/sys/class/zram-control/hot_add";
+
+/// Priority of created zram swap, so it fills before any disk swap
+const ZRAM_PRIORITY: i32 = 100;
+
+/// Kind of backing store of a swap area
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SwapKind {
+    /// Compressed RAM block device (`/dev/zramN`)
+    Zram,
+    /// Swap file on a filesystem
+    File,
+    /// Disk partition
+    Partition,
+}
+
+/// One active swap area
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SwapDevice {
+    /// Device or file path, e.g. `/dev/zram0`
+    pub name: String,
+    pub kind: SwapKind,
+    /// Size in bytes
+    pub size: u64,
+    /// Used space in bytes
+    pub used: u64,
+    /// Higher priority areas are used first
+    pub priority: i32,
+}
+
+/// Read the active swap areas, empty when `/proc/swaps` is unreadable
+pub fn swap_devices() -> Vec<SwapDevice> {
+    fs::read_to_string(PROC_SWAPS)
+        .map(|content| parse_swaps(&content))
+        .unwrap_or_default()
+}
+
+/// Parse `/proc/swaps`, whose sizes are in KiB
+fn parse_swaps(content: &str) -> Vec<SwapDevice> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let name = unescape_mount(fields.next()?);
+            let kind = fields.next()?;
+            let size: u64 = fields.next()?.parse().ok()?;
+            let used: u64 = fields.next()?.parse().ok()?;
+            let priority = fields.next()?.parse().ok()?;
+
+            let kind = if is_zram(&name) {
+                SwapKind::Zram
+            } else if kind == "file" {
+                SwapKind::File
+            } else {
+                SwapKind::Partition
+            };
+
+            Some(SwapDevice {
+                name,
+                kind,
+                size: size * 1024,
+                used: used * 1024,
+                priority,
+            })
+        })
+        .collect()
+}
+
+fn is_zram(name: &str) -> bool {
+    name.strip_prefix("/dev/zram")
+        .is_some_and(|index| !index.is_empty() && index.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Create a zram device of `size_mb` MiB and enable it as swap
+///
+/// Reuses an unconfigured zram device when there is one, otherwise loads
+/// the zram module or hot-adds a device. Returns the device path.
+pub fn create_zram(size_mb: u64) -> crate::Result<PathBuf> {
+    if size_mb == 0 {
+        return Err(Error::InvalidArgument(
+            "zram swap size must be greater than 0 MB".to_string(),
+        ));
+    }
+    require_root()?;
+
+    let index = match free_zram(Path::new(SYS_BLOCK)) {
+        Some(index) => index,
+        None => add_zram()?,
+    };
+
+    let disksize = Path::new(SYS_BLOCK)
+        .join(format!("zram{}", index))
+        .join("disksize");
+    sysfs::write(&disksize, format!("{}M", size_mb))?;
+
+    let device = PathBuf::from(format!("/dev/zram{}", index));
+    run("mkswap", &[device.as_os_str()])?;
+    run(
+        "swapon",
+        &[
+            "--priority".as_ref(),
+            ZRAM_PRIORITY.to_string().as_ref(),
+            device.as_os_str(),
+        ],
+    )?;
+
+    Ok(device)
+}
+
+/// Enable the swap device or file at `path`
+pub fn enable(path: &Path) -> crate::Result<()> {
+    require_root()?;
+    run("swapon", &[path.as_os_str()])
+}
+
+/// Disable the swap device or file at `path`
+pub fn disable(path: &Path) -> crate::Result<()> {
+    require_root()?;
+    run("swapoff", &[path.as_os_str()])
+}
+
+/// Index of the lowest zram device that has no size configured yet
+fn free_zram(sys_block: &Path) -> Option<u32> {
+    let entries = fs::read_dir(sys_block).ok()?;
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name();
+            let index = name.to_str()?.strip_prefix("zram")?.parse().ok()?;
+            let disksize = sysfs::read_value::<u64>(entry.path().join("disksize"))?;
+            (disksize == 0).then_some(index)
+        })
+        .min()
+}
+
+/// Make a new zram device available and return its index
+fn add_zram() -> crate::Result<u32> {
+    if !Path::new(ZRAM_HOT_ADD).exists() {
+        // Loading the module creates zram0 along with the control interface
+        run("modprobe", &["zram".as_ref()])?;
+        if let Some(index) = free_zram(Path::new(SYS_BLOCK)) {
+            return Ok(index);
+        }
+    }
+
+    let index = fs::read_to_string(ZRAM_HOT_ADD).map_err(|e| Error::sysfs_read(ZRAM_HOT_ADD, e))?;
+    index.trim().parse().map_err(|_| {
+        Error::parse(
+            ZRAM_HOT_ADD,
+            format!("unexpected zram index '{}'", index.trim()),
+        )
+    })
+}
+
+/// Fail with a descriptive error unless running as root
+fn require_root() -> crate::Result<()> {
+    // SAFETY: geteuid has no preconditions and cannot fail.
+    if unsafe { libc::geteuid() } != 0 {
+        return Err(Error::PermissionDenied(
+            "swap control requires root (run with sudo)".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Run a swap utility, turning a failure into an error carrying its stderr
+fn run(program: &str, args: &[&std::ffi::OsStr]) -> crate::Result<()> {
+    let output = Command::new(program).args(args).output()?;
+
+    if !output.status.success() {
+        return Err(Error::command_failed(program, &output.stderr));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::sysfs::MockDir;
+
+    const SAMPLE: &str = "\
+Filename\t\t\t\tType\t\tSize\t\tUsed\t\tPriority
+/dev/zram0                              partition\t1004772\t\t2048\t\t5
+/dev/zram1                              partition\t1004772\t\t0\t\t5
+/mnt/data/swap\\040file                  file\t\t2097148\t\t0\t\t-2
+/dev/nvme0n1p3                          partition\t8388604\t\t512\t\t-3
+";
+
+    #[test]
+    fn test_parse_swaps() {
+        let devices = parse_swaps(SAMPLE);
+        assert_eq!(devices.len(), 4);
+        assert_eq!(
+            devices[0],
+            SwapDevice {
+                name: "/dev/zram0".to_string(),
+                kind: SwapKind::Zram,
+                size: 1004772 * 1024,
+                used: 2048 * 1024,
+                priority: 5,
+            }
+        );
+        assert_eq!(devices[2].name, "/mnt/data/swap file");
+        assert_eq!(devices[2].kind, SwapKind::File);
+        assert_eq!(devices[2].priority, -2);
+        assert_eq!(devices[3].kind, SwapKind::Partition);
+        assert_eq!(devices[3].used, 512 * 1024);
+    }
+
+    #[test]
+    fn test_parse_swaps_without_devices() {
+        assert!(parse_swaps("Filename\tType\tSize\tUsed\tPriority\n").is_empty());
+        assert!(parse_swaps("").is_empty());
+        assert!(parse_swaps("Filename\n/dev/zram0 partition broken 0 5\n").is_empty());
+    }
+
+    #[test]
+    fn test_is_zram() {
+        assert!(is_zram("/dev/zram0"));
+        assert!(is_zram("/dev/zram12"));
+        assert!(!is_zram("/dev/zram"));
+        assert!(!is_zram("/dev/zramfoo"));
+        assert!(!is_zram("/swapfile"));
+    }
+
+    #[test]
+    fn test_free_zram() {
+        let dir = MockDir::new("swap");
+        for (name, disksize) in [("zram0", "1073741824"), ("zram2", "0"), ("zram1", "0")] {
+            fs::create_dir_all(dir.join(name)).unwrap();
+            fs::write(dir.join(name).join("disksize"), disksize).unwrap();
+        }
+        fs::create_dir_all(dir.join("mmcblk0")).unwrap();
+
+        assert_eq!(free_zram(&dir), Some(1));
+
+        fs::write(dir.join("zram1").join("disksize"), "4096").unwrap();
+        fs::write(dir.join("zram2").join("disksize"), "4096").unwrap();
+        assert_eq!(free_zram(&dir), None);
+    }
+
+    #[test]
+    fn test_create_zram_rejects_zero_size() {
+        let err = create_zram(0).unwrap_err();
+        assert!(err.to_string().contains("greater than 0"));
+    }
+}
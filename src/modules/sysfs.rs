@@ -0,0 +1,366 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! sysfs helpers
+//!
+//! Shared by all modules reading single-value files from `/sys` and
+//! `/proc`. Every helper takes the full path, so callers can point their
+//! base directory at a mocked tree in tests. Missing files and values that
+//! fail to parse are reported as `None`.
+//!
+//! Drivers behind some attributes (INA3221 over I2C, devfreq during a
+//! clock change) briefly fail reads with `EAGAIN`, `EBUSY` or `EIO`. Those
+//! reads are retried a few times before the value is reported missing.
+
+use std::fs;
+use std::future::{self, Future};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::pin::pin;
+use std::str::FromStr;
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::Duration;
+
+use crate::Error;
+
+/// Attempts made for a read failing with a transient error
+const READ_ATTEMPTS: u32 = 3;
+
+/// Pause between attempts
+const RETRY_DELAY: Duration = Duration::from_millis(2);
+
+/// Whether a failed read is worth retrying
+fn is_transient(err: &io::Error) -> bool {
+    const EIO: i32 = 5;
+
+    matches!(
+        err.kind(),
+        io::ErrorKind::Interrupted | io::ErrorKind::WouldBlock | io::ErrorKind::ResourceBusy
+    ) || err.raw_os_error() == Some(EIO)
+}
+
+/// File access behind the module readers
+///
+/// Each reader is written once as an `async fn` over a `Reader`, so the
+/// sync and async getters share it: `get()` drives it with [`Blocking`]
+/// through [`block_on`], `get_async()` awaits it with [`Tokio`].
+pub(crate) trait Reader: Sync {
+    /// Read a whole file
+    fn read_to_string(&self, path: &Path) -> impl Future<Output = io::Result<String>> + Send;
+
+    /// Paths of the entries of a directory
+    fn read_dir(&self, path: &Path) -> impl Future<Output = io::Result<Vec<PathBuf>>> + Send;
+
+    /// Whether `path` exists
+    fn exists(&self, path: &Path) -> impl Future<Output = bool> + Send;
+
+    /// Wait before retrying a read
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send;
+
+    /// Run a call that blocks, such as NVML or spawning nvidia-smi
+    fn blocking<T, F>(&self, f: F) -> impl Future<Output = T> + Send
+    where
+        T: Send + 'static,
+        F: FnOnce() -> T + Send + 'static;
+
+    /// Read a whole file, retrying transient driver errors
+    fn read_retrying(&self, path: &Path) -> impl Future<Output = io::Result<String>> + Send {
+        async move {
+            let mut attempt = 1;
+            loop {
+                match self.read_to_string(path).await {
+                    Err(e) if attempt < READ_ATTEMPTS && is_transient(&e) => {
+                        attempt += 1;
+                        self.sleep(RETRY_DELAY).await;
+                    }
+                    result => return result,
+                }
+            }
+        }
+    }
+
+    /// Read a file and trim surrounding whitespace
+    fn read_string(&self, path: &Path) -> impl Future<Output = Option<String>> + Send {
+        async move {
+            self.read_retrying(path)
+                .await
+                .ok()
+                .map(|s| s.trim().to_string())
+        }
+    }
+
+    /// Read and parse a single value
+    fn read_value<T: FromStr>(&self, path: &Path) -> impl Future<Output = Option<T>> + Send {
+        async move { self.read_retrying(path).await.ok()?.trim().parse().ok() }
+    }
+
+    /// Read a value reported in thousandths, such as millidegrees Celsius
+    fn read_f32_milli(&self, path: &Path) -> impl Future<Output = Option<f32>> + Send {
+        async move {
+            self.read_value::<i64>(path)
+                .await
+                .map(|milli| milli as f32 / 1000.0)
+        }
+    }
+}
+
+/// Reads through `std::fs`; every future is ready on its first poll
+pub(crate) struct Blocking;
+
+impl Reader for Blocking {
+    fn read_to_string(&self, path: &Path) -> impl Future<Output = io::Result<String>> + Send {
+        future::ready(fs::read_to_string(path))
+    }
+
+    fn read_dir(&self, path: &Path) -> impl Future<Output = io::Result<Vec<PathBuf>>> + Send {
+        future::ready(
+            fs::read_dir(path).map(|entries| entries.flatten().map(|entry| entry.path()).collect()),
+        )
+    }
+
+    fn exists(&self, path: &Path) -> impl Future<Output = bool> + Send {
+        future::ready(path.exists())
+    }
+
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send {
+        thread::sleep(duration);
+        future::ready(())
+    }
+
+    fn blocking<T, F>(&self, f: F) -> impl Future<Output = T> + Send
+    where
+        T: Send + 'static,
+        F: FnOnce() -> T + Send + 'static,
+    {
+        future::ready(f())
+    }
+}
+
+/// Reads through `tokio::fs`; blocking calls go to `spawn_blocking`
+pub(crate) struct Tokio;
+
+impl Reader for Tokio {
+    fn read_to_string(&self, path: &Path) -> impl Future<Output = io::Result<String>> + Send {
+        tokio::fs::read_to_string(path.to_path_buf())
+    }
+
+    fn read_dir(&self, path: &Path) -> impl Future<Output = io::Result<Vec<PathBuf>>> + Send {
+        let path = path.to_path_buf();
+        async move {
+            let mut entries = tokio::fs::read_dir(path).await?;
+            let mut paths = Vec::new();
+            while let Ok(Some(entry)) = entries.next_entry().await {
+                paths.push(entry.path());
+            }
+            Ok(paths)
+        }
+    }
+
+    fn exists(&self, path: &Path) -> impl Future<Output = bool> + Send {
+        let path = path.to_path_buf();
+        async move { tokio::fs::try_exists(path).await.unwrap_or(false) }
+    }
+
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send {
+        tokio::time::sleep(duration)
+    }
+
+    async fn blocking<T, F>(&self, f: F) -> T
+    where
+        T: Send + 'static,
+        F: FnOnce() -> T + Send + 'static,
+    {
+        tokio::task::spawn_blocking(f)
+            .await
+            .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()))
+    }
+}
+
+/// Run a reader over [`Blocking`] to completion
+///
+/// Panics if the future is not ready on the first poll, which only happens
+/// when it awaits something other than a [`Blocking`] read.
+pub(crate) fn block_on<T>(future: impl Future<Output = T>) -> T {
+    match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(value) => value,
+        Poll::Pending => unreachable!("blocking reads complete on the first poll"),
+    }
+}
+
+/// Read a file and trim surrounding whitespace
+pub fn read_string(path: impl AsRef<Path>) -> Option<String> {
+    block_on(Blocking.read_string(path.as_ref()))
+}
+
+/// Read and parse a single value
+pub fn read_value<T: FromStr>(path: impl AsRef<Path>) -> Option<T> {
+    block_on(Blocking.read_value(path.as_ref()))
+}
+
+/// Read an unsigned integer
+pub fn read_u32(path: impl AsRef<Path>) -> Option<u32> {
+    read_value(path)
+}
+
+/// Read a signed integer, for sensors that can report negative values
+pub fn read_i32(path: impl AsRef<Path>) -> Option<i32> {
+    read_value(path)
+}
+
+/// Read a value reported in thousandths, such as millidegrees Celsius
+pub fn read_f32_milli(path: impl AsRef<Path>) -> Option<f32> {
+    block_on(Blocking.read_f32_milli(path.as_ref()))
+}
+
+/// Write a value, naming the file in the error and flagging missing root
+pub fn write(path: impl AsRef<Path>, value: impl AsRef<[u8]>) -> crate::Result<()> {
+    let path = path.as_ref();
+    fs::write(path, value).map_err(|e| match e.kind() {
+        std::io::ErrorKind::PermissionDenied => {
+            Error::PermissionDenied(format!("writing {} (requires root)", path.display()))
+        }
+        _ => Error::SysfsWrite {
+            path: path.to_path_buf(),
+            source: e,
+        },
+    })
+}
+
+/// Scratch directory holding a mocked sysfs tree, removed on drop
+///
+/// The directory is unique per call, so tests running in parallel never
+/// share a tree, and it is cleaned up even when the test panics.
+#[cfg(test)]
+pub(crate) struct MockDir(PathBuf);
+
+#[cfg(test)]
+impl MockDir {
+    pub(crate) fn new(name: &str) -> Self {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+        let dir = std::env::temp_dir().join(format!(
+            "rjtop-{}-{}-{}",
+            name,
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        Self(dir)
+    }
+}
+
+#[cfg(test)]
+impl std::ops::Deref for MockDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+impl AsRef<Path> for MockDir {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+impl Drop for MockDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_string() {
+        let dir = MockDir::new("sysfs-string");
+        fs::write(dir.join("governor"), "schedutil\n").unwrap();
+
+        assert_eq!(
+            read_string(dir.join("governor")).as_deref(),
+            Some("schedutil")
+        );
+        assert_eq!(read_string(dir.join("missing")), None);
+    }
+
+    #[test]
+    fn test_read_u32() {
+        let dir = MockDir::new("sysfs-u32");
+        fs::write(dir.join("cur_freq"), "918000000\n").unwrap();
+        fs::write(dir.join("negative"), "-5\n").unwrap();
+        fs::write(dir.join("garbage"), "N/A\n").unwrap();
+
+        assert_eq!(read_u32(dir.join("cur_freq")), Some(918_000_000));
+        assert_eq!(read_u32(dir.join("negative")), None);
+        assert_eq!(read_u32(dir.join("garbage")), None);
+        assert_eq!(read_u32(dir.join("missing")), None);
+    }
+
+    #[test]
+    fn test_read_i32() {
+        let dir = MockDir::new("sysfs-i32");
+        fs::write(dir.join("curr1_input"), "-120\n").unwrap();
+        fs::write(dir.join("empty"), "").unwrap();
+
+        assert_eq!(read_i32(dir.join("curr1_input")), Some(-120));
+        assert_eq!(read_i32(dir.join("empty")), None);
+        assert_eq!(read_i32(dir.join("missing")), None);
+    }
+
+    #[test]
+    fn test_read_f32_milli() {
+        let dir = MockDir::new("sysfs-milli");
+        fs::write(dir.join("temp"), "45500\n").unwrap();
+        fs::write(dir.join("cold"), "-256000\n").unwrap();
+        fs::write(dir.join("float"), "45.5\n").unwrap();
+
+        assert_eq!(read_f32_milli(dir.join("temp")), Some(45.5));
+        assert_eq!(read_f32_milli(dir.join("cold")), Some(-256.0));
+        assert_eq!(read_f32_milli(dir.join("float")), None);
+        assert_eq!(read_f32_milli(dir.join("missing")), None);
+    }
+
+    #[test]
+    fn test_transient_errors() {
+        assert!(is_transient(&io::Error::from(io::ErrorKind::WouldBlock)));
+        assert!(is_transient(&io::Error::from(io::ErrorKind::ResourceBusy)));
+        assert!(is_transient(&io::Error::from_raw_os_error(5)));
+        assert!(!is_transient(&io::Error::from(io::ErrorKind::NotFound)));
+        assert!(!is_transient(&io::Error::from(
+            io::ErrorKind::PermissionDenied
+        )));
+    }
+
+    #[test]
+    fn test_mock_dir_is_removed_on_drop() {
+        let dir = MockDir::new("sysfs-drop");
+        fs::write(dir.join("cur_freq"), "0\n").unwrap();
+        let path = dir.to_path_buf();
+
+        drop(dir);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_write() {
+        let dir = MockDir::new("sysfs-write");
+
+        write(dir.join("governor"), "performance").unwrap();
+        assert_eq!(
+            read_string(dir.join("governor")).as_deref(),
+            Some("performance")
+        );
+
+        let err = write(dir.join("missing/governor"), "performance").unwrap_err();
+        assert!(err.to_string().starts_with("Failed to write"), "{}", err);
+    }
+}
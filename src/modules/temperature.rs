@@ -3,8 +3,15 @@
 
 //! Temperature monitoring module
 
-use std::fs;
+use crate::modules::collector::Collector;
+use crate::modules::sysfs::{self, Blocking, Reader, Tokio};
+use std::fmt;
 use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+const THERMAL_SYSFS: &str = "/sys/class/thermal";
+const CPU_SYSFS: &str = "/sys/devices/system/cpu";
 
 /// Temperature statistics
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -14,6 +21,12 @@ pub struct TemperatureStats {
     pub board: f32,
     pub pmic: f32,
     pub thermal_zones: Vec<ThermalZone>,
+    /// Whether any zone is past its passive trip point or a CPU is capped
+    #[serde(default)]
+    pub throttling: bool,
+    /// Zones past their passive trip point, plus `cpuN` for capped cores
+    #[serde(default)]
+    pub throttle_zones: Vec<String>,
 }
 
 /// Individual thermal zone
@@ -24,54 +37,309 @@ pub struct ThermalZone {
     pub current_temp: f32,
     pub max_temp: f32,
     pub critical_temp: f32,
+    /// All trip points of the zone, in sysfs order
+    #[serde(default)]
+    pub trip_points: Vec<TripPoint>,
+}
+
+/// Thermal zone trip point
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TripPoint {
+    /// Trip temperature in °C
+    pub temp: f32,
+    /// Trip type: `active`, `passive`, `hot` or `critical`
+    pub kind: String,
 }
 
 impl TemperatureStats {
     /// Get current temperature statistics
     pub fn get() -> Self {
-        let path = Path::new("/sys/class/thermal");
+        sysfs::block_on(Self::read(&Blocking))
+    }
+
+    /// Get current temperature statistics through `tokio::fs`
+    pub async fn get_async() -> Self {
+        Self::read(&Tokio).await
+    }
+
+    async fn read<R: Reader>(reader: &R) -> Self {
+        let path = Path::new(THERMAL_SYSFS);
 
-        if !path.exists() {
+        if !reader.exists(path).await {
             return TemperatureStats::default();
         }
 
         let mut stats = TemperatureStats {
-            thermal_zones: read_thermal_zones(path),
+            thermal_zones: read_thermal_zones(reader, path).await,
             ..Default::default()
         };
+        stats.throttle_zones =
+            throttle_zones(reader, &stats.thermal_zones, path, Path::new(CPU_SYSFS)).await;
+        stats.throttling = !stats.throttle_zones.is_empty();
 
         // Extract common temperatures (case-insensitive)
         for zone in &stats.thermal_zones {
-            let name_lower = zone.name.to_lowercase();
-            if name_lower.contains("cpu") || zone.name == "CPU-therm" || zone.name == "cpu-thermal"
-            {
-                stats.cpu = zone.current_temp;
-            } else if name_lower.contains("gpu")
-                || zone.name == "GPU-therm"
-                || zone.name == "gpu-thermal"
-            {
-                stats.gpu = zone.current_temp;
-            } else if name_lower.contains("pmic") {
-                stats.pmic = zone.current_temp;
-            } else if name_lower.contains("board")
-                || name_lower.contains("tboard")
-                || zone.name.contains("Tboard")
-            {
-                stats.board = zone.current_temp;
+            match zone_sensor(&zone.name) {
+                Some("cpu") => stats.cpu = zone.current_temp,
+                Some("gpu") => stats.gpu = zone.current_temp,
+                Some("pmic") => stats.pmic = zone.current_temp,
+                Some("board") => stats.board = zone.current_temp,
+                _ => {}
             }
         }
 
         stats
     }
+
+    /// Thermal zone backing one of the `cpu`, `gpu`, `board` or `pmic` readings
+    pub fn zone(&self, sensor: &str) -> Option<&ThermalZone> {
+        self.thermal_zones
+            .iter()
+            .rev()
+            .find(|z| zone_sensor(&z.name) == Some(sensor))
+    }
+
+    /// Copy of the statistics with every temperature converted to `unit`
+    pub fn to_unit(&self, unit: TemperatureUnit) -> Self {
+        let convert = |c| to_unit(c, unit);
+        // 0.0 marks an unknown limit and stays that way
+        let convert_limit = |c: f32| if c == 0.0 { 0.0 } else { convert(c) };
+        Self {
+            cpu: convert(self.cpu),
+            gpu: convert(self.gpu),
+            board: convert(self.board),
+            pmic: convert(self.pmic),
+            thermal_zones: self
+                .thermal_zones
+                .iter()
+                .map(|zone| ThermalZone {
+                    current_temp: convert(zone.current_temp),
+                    max_temp: convert_limit(zone.max_temp),
+                    critical_temp: convert_limit(zone.critical_temp),
+                    trip_points: zone
+                        .trip_points
+                        .iter()
+                        .map(|trip| TripPoint {
+                            temp: convert(trip.temp),
+                            kind: trip.kind.clone(),
+                        })
+                        .collect(),
+                    ..zone.clone()
+                })
+                .collect(),
+            throttle_zones: self.throttle_zones.clone(),
+            ..*self
+        }
+    }
+
+    /// Thermal zone with the highest current temperature
+    pub fn hottest_zone(&self) -> Option<&ThermalZone> {
+        self.thermal_zones
+            .iter()
+            .max_by(|a, b| a.current_temp.total_cmp(&b.current_temp))
+    }
+}
+
+impl ThermalZone {
+    /// Current temperature as a percentage of the critical trip point
+    ///
+    /// Returns `None` when the zone has no critical trip point.
+    pub fn percent_to_critical(&self) -> Option<f32> {
+        percent_to_critical(self.current_temp, self.critical_temp)
+    }
+
+    /// Lowest passive trip point, where the kernel starts throttling
+    pub fn throttle_temp(&self) -> Option<f32> {
+        self.trip_temps("passive").reduce(f32::min)
+    }
+
+    /// Whether the zone has reached its passive trip point
+    ///
+    /// Active trip points only switch fan levels and are not throttling.
+    pub fn is_throttling(&self) -> bool {
+        self.throttle_temp()
+            .is_some_and(|throttle| self.current_temp >= throttle)
+    }
+
+    /// Lowest critical trip point, falling back to `critical_temp`
+    pub fn critical_trip_temp(&self) -> Option<f32> {
+        self.trip_temps("critical")
+            .reduce(f32::min)
+            .or((self.critical_temp > 0.0).then_some(self.critical_temp))
+    }
+
+    fn trip_temps<'a>(&'a self, kind: &'a str) -> impl Iterator<Item = f32> + 'a {
+        self.trip_points
+            .iter()
+            .filter(move |t| t.kind == kind && t.temp > 0.0)
+            .map(|t| t.temp)
+    }
+}
+
+/// Unit temperatures are displayed in
+///
+/// Statistics are always collected in Celsius and only converted for display.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TemperatureUnit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+}
+
+impl TemperatureUnit {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            TemperatureUnit::Celsius => "°C",
+            TemperatureUnit::Fahrenheit => "°F",
+        }
+    }
+
+    /// Format a Celsius reading in this unit, e.g. "45.5°C"
+    pub fn format(self, celsius: f32) -> String {
+        format!("{:.1}{}", to_unit(celsius, self), self.symbol())
+    }
+}
+
+impl fmt::Display for TemperatureUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemperatureUnit::Celsius => write!(f, "celsius"),
+            TemperatureUnit::Fahrenheit => write!(f, "fahrenheit"),
+        }
+    }
+}
+
+impl FromStr for TemperatureUnit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "c" | "celsius" => Ok(TemperatureUnit::Celsius),
+            "f" | "fahrenheit" => Ok(TemperatureUnit::Fahrenheit),
+            _ => Err(format!(
+                "invalid temperature unit '{}': expected celsius (c) or fahrenheit (f)",
+                s
+            )),
+        }
+    }
+}
+
+/// Convert a Celsius temperature to `unit`
+pub fn to_unit(celsius: f32, unit: TemperatureUnit) -> f32 {
+    match unit {
+        TemperatureUnit::Celsius => celsius,
+        TemperatureUnit::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+    }
+}
+
+/// Temperature as a percentage of a critical trip point, `None` if unknown
+pub fn percent_to_critical(current_temp: f32, critical_temp: f32) -> Option<f32> {
+    if critical_temp > 0.0 {
+        Some(current_temp / critical_temp * 100.0)
+    } else {
+        None
+    }
+}
+
+/// Names of the throttling zones followed by the capped CPU cores
+async fn throttle_zones<R: Reader>(
+    reader: &R,
+    zones: &[ThermalZone],
+    thermal_root: &Path,
+    cpu_root: &Path,
+) -> Vec<String> {
+    let mut names: Vec<String> = zones
+        .iter()
+        .filter(|z| z.is_throttling())
+        .map(|z| z.name.clone())
+        .collect();
+    names.extend(capped_cpus(reader, thermal_root, cpu_root).await);
+    names
+}
+
+/// Cores capped by an active cpufreq cooling device
+///
+/// The thermal framework caps a cpufreq policy through a `cpufreq-cpuN`
+/// cooling device; `cur_state > 0` means it is lowering the ceiling. A
+/// lower `scaling_max_freq` alone is not throttling, as nvpmodel modes set
+/// it on purpose. Every core of the capped policy is listed.
+async fn capped_cpus<R: Reader>(reader: &R, thermal_root: &Path, cpu_root: &Path) -> Vec<String> {
+    let Ok(entries) = reader.read_dir(thermal_root).await else {
+        return Vec::new();
+    };
+
+    let mut cores: Vec<usize> = Vec::new();
+    for path in entries {
+        let is_cooling_device = path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with("cooling_device"));
+        if !is_cooling_device {
+            continue;
+        }
+        let Some(kind) = reader.read_string(&path.join("type")).await else {
+            continue;
+        };
+        let Some(first) = kind
+            .strip_prefix("cpufreq-cpu")
+            .and_then(|cpu| cpu.parse().ok())
+        else {
+            continue;
+        };
+        let state = reader.read_value::<u32>(&path.join("cur_state")).await;
+        if state.is_some_and(|state| state > 0) {
+            cores.extend(policy_cpus(reader, cpu_root, first).await);
+        }
+    }
+
+    cores.sort();
+    cores.dedup();
+    cores
+        .into_iter()
+        .map(|index| format!("cpu{}", index))
+        .collect()
+}
+
+/// Cores sharing the cpufreq policy of `cpu`, from `related_cpus`
+async fn policy_cpus<R: Reader>(reader: &R, cpu_root: &Path, cpu: usize) -> Vec<usize> {
+    let related = cpu_root.join(format!("cpu{}/cpufreq/related_cpus", cpu));
+    reader
+        .read_string(&related)
+        .await
+        .map(|list| {
+            list.split_whitespace()
+                .filter_map(|index| index.parse().ok())
+                .collect()
+        })
+        .filter(|cores: &Vec<usize>| !cores.is_empty())
+        .unwrap_or_else(|| vec![cpu])
+}
+
+/// Map a thermal zone type to the summary sensor it feeds
+///
+/// Returns `cpu`, `gpu`, `pmic` or `board`.
+pub fn zone_sensor(name: &str) -> Option<&'static str> {
+    let name_lower = name.to_lowercase();
+
+    if name_lower.contains("cpu") {
+        Some("cpu")
+    } else if name_lower.contains("gpu") {
+        Some("gpu")
+    } else if name_lower.contains("pmic") {
+        Some("pmic")
+    } else if name_lower.contains("board") {
+        Some("board")
+    } else {
+        None
+    }
 }
 
 /// Read all thermal zones
-fn read_thermal_zones(base_path: &Path) -> Vec<ThermalZone> {
+async fn read_thermal_zones<R: Reader>(reader: &R, base_path: &Path) -> Vec<ThermalZone> {
     let mut zones = Vec::new();
 
-    if let Ok(entries) = fs::read_dir(base_path) {
-        for entry in entries.flatten() {
-            let zone_path = entry.path();
+    if let Ok(entries) = reader.read_dir(base_path).await {
+        for zone_path in entries {
             let zone_name = zone_path
                 .file_name()
                 .and_then(|s| s.to_str())
@@ -90,33 +358,32 @@ fn read_thermal_zones(base_path: &Path) -> Vec<ThermalZone> {
                 .unwrap_or(0);
 
             let type_path = zone_path.join("type");
-            let zone_type = fs::read_to_string(type_path)
-                .ok()
-                .map(|s| s.trim().to_string())
+            let zone_type = reader
+                .read_string(&type_path)
+                .await
                 .unwrap_or_else(|| "unknown".to_string());
 
             // Read temperature (in millidegrees)
             let temp_path = zone_path.join("temp");
-            let current_temp = fs::read_to_string(temp_path)
-                .ok()
-                .and_then(|s| s.trim().parse::<i32>().ok())
-                .map(|milli| milli as f32 / 1000.0)
-                .unwrap_or(0.0);
+            let current_temp = reader.read_f32_milli(&temp_path).await.unwrap_or(0.0);
 
             // Read trip point temperatures
             let trip_path = zone_path.join("trip_point_0_temp");
-            let max_temp = fs::read_to_string(trip_path)
-                .ok()
-                .and_then(|s| s.trim().parse::<i32>().ok())
-                .map(|milli| milli as f32 / 1000.0)
-                .unwrap_or(0.0);
+            let max_temp = reader.read_f32_milli(&trip_path).await.unwrap_or(0.0);
+
+            let trip_points = read_trip_points(reader, &zone_path).await;
 
-            // Read critical temperature
+            // Read critical temperature, falling back to the critical trip
             let crit_path = zone_path.join("crit_temp");
-            let critical_temp = fs::read_to_string(crit_path)
-                .ok()
-                .and_then(|s| s.trim().parse::<i32>().ok())
-                .map(|milli| milli as f32 / 1000.0)
+            let critical_temp = reader
+                .read_f32_milli(&crit_path)
+                .await
+                .or_else(|| {
+                    trip_points
+                        .iter()
+                        .find(|t| t.kind == "critical")
+                        .map(|t| t.temp)
+                })
                 .unwrap_or(0.0);
 
             zones.push(ThermalZone {
@@ -125,6 +392,7 @@ fn read_thermal_zones(base_path: &Path) -> Vec<ThermalZone> {
                 current_temp,
                 max_temp,
                 critical_temp,
+                trip_points,
             });
         }
     }
@@ -132,9 +400,419 @@ fn read_thermal_zones(base_path: &Path) -> Vec<ThermalZone> {
     zones
 }
 
+/// Read the `trip_point_N_temp`/`trip_point_N_type` pairs of a zone
+///
+/// A trip point whose temperature cannot be parsed is skipped; reading
+/// stops at the first missing `trip_point_N_temp`.
+async fn read_trip_points<R: Reader>(reader: &R, zone_path: &Path) -> Vec<TripPoint> {
+    let mut trip_points = Vec::new();
+
+    for n in 0.. {
+        let temp_path = zone_path.join(format!("trip_point_{}_temp", n));
+        if !reader.exists(&temp_path).await {
+            break;
+        }
+        let Some(temp) = reader.read_f32_milli(&temp_path).await else {
+            continue;
+        };
+        let kind = reader
+            .read_string(&zone_path.join(format!("trip_point_{}_type", n)))
+            .await
+            .unwrap_or_default();
+
+        trip_points.push(TripPoint { temp, kind });
+    }
+
+    trip_points
+}
+
+/// Temperature collector for the monitoring loop
+#[derive(Debug, Default)]
+pub struct TemperatureMonitor;
+
+impl TemperatureMonitor {
+    /// Create a new temperature monitor
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Collector for TemperatureMonitor {
+    type Stats = TemperatureStats;
+
+    fn collect(&mut self) -> Self::Stats {
+        TemperatureStats::get()
+    }
+}
+
+/// Debounced over-temperature alert
+///
+/// Fires once the temperature has stayed at or above the threshold for the
+/// whole sustain period, so transient spikes are ignored. After firing it
+/// stays quiet until the temperature drops below the threshold again.
+#[derive(Debug, Clone)]
+pub struct TemperatureAlert {
+    threshold: f32,
+    sustain: Duration,
+    above_since: Option<Instant>,
+    fired: bool,
+}
+
+impl TemperatureAlert {
+    /// Create an alert for `threshold` °C sustained for `sustain`
+    pub fn new(threshold: f32, sustain: Duration) -> Self {
+        Self {
+            threshold,
+            sustain,
+            above_since: None,
+            fired: false,
+        }
+    }
+
+    /// Threshold in °C
+    pub fn threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    /// Feed a temperature sample taken at `now`
+    ///
+    /// Returns `true` when the alert fires on this sample.
+    pub fn update(&mut self, temp: f32, now: Instant) -> bool {
+        if temp < self.threshold {
+            self.above_since = None;
+            self.fired = false;
+            return false;
+        }
+
+        let since = *self.above_since.get_or_insert(now);
+        if !self.fired && now.duration_since(since) >= self.sustain {
+            self.fired = true;
+            return true;
+        }
+
+        false
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::modules::sysfs::MockDir;
+    use std::fs;
+
+    fn passive_zone(name: &str, current_temp: f32, passive: f32) -> ThermalZone {
+        ThermalZone {
+            name: name.to_string(),
+            current_temp,
+            trip_points: vec![
+                TripPoint {
+                    temp: 50.0,
+                    kind: "active".to_string(),
+                },
+                TripPoint {
+                    temp: passive,
+                    kind: "passive".to_string(),
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_zone_throttles_at_passive_trip() {
+        assert!(passive_zone("cpu-thermal", 99.5, 99.0).is_throttling());
+        assert!(passive_zone("cpu-thermal", 99.0, 99.0).is_throttling());
+        // Past the active (fan) trip only
+        assert!(!passive_zone("cpu-thermal", 60.0, 99.0).is_throttling());
+
+        let no_trips = ThermalZone {
+            current_temp: 120.0,
+            ..Default::default()
+        };
+        assert!(!no_trips.is_throttling());
+    }
+
+    async fn check_throttle_zones<R: Reader>(reader: &R) {
+        let root = MockDir::new("throttle");
+        let thermal = root.join("thermal");
+        let cpu = root.join("cpu");
+        for (device, kind, state) in [
+            ("cooling_device0", "cpufreq-cpu0", "2"),
+            ("cooling_device1", "cpufreq-cpu4", "0"),
+            ("cooling_device2", "pwm-fan", "3"),
+        ] {
+            fs::create_dir_all(thermal.join(device)).unwrap();
+            fs::write(thermal.join(device).join("type"), format!("{}\n", kind)).unwrap();
+            fs::write(
+                thermal.join(device).join("cur_state"),
+                format!("{}\n", state),
+            )
+            .unwrap();
+        }
+        // nvpmodel ceiling below the hardware maximum is not throttling
+        for index in 0..8 {
+            let cpufreq = cpu.join(format!("cpu{}", index)).join("cpufreq");
+            fs::create_dir_all(&cpufreq).unwrap();
+            let related = if index < 4 { "0 1 2 3\n" } else { "4 5 6 7\n" };
+            fs::write(cpufreq.join("related_cpus"), related).unwrap();
+            fs::write(cpufreq.join("scaling_max_freq"), "1510400\n").unwrap();
+            fs::write(cpufreq.join("cpuinfo_max_freq"), "2201600\n").unwrap();
+        }
+
+        let zones = vec![
+            passive_zone("cpu-thermal", 101.0, 99.0),
+            passive_zone("gpu-thermal", 60.0, 99.0),
+        ];
+        assert_eq!(
+            throttle_zones(reader, &zones, &thermal, &cpu).await,
+            vec!["cpu-thermal", "cpu0", "cpu1", "cpu2", "cpu3"]
+        );
+        assert!(
+            throttle_zones(reader, &zones[1..], &root.join("missing"), &cpu)
+                .await
+                .is_empty()
+        );
+
+        // Without related_cpus only the named core is reported
+        fs::remove_dir_all(&cpu).unwrap();
+        assert_eq!(capped_cpus(reader, &thermal, &cpu).await, vec!["cpu0"]);
+    }
+
+    #[test]
+    fn test_throttle_zones() {
+        sysfs::block_on(check_throttle_zones(&Blocking));
+    }
+
+    #[tokio::test]
+    async fn test_throttle_zones_async() {
+        check_throttle_zones(&Tokio).await;
+    }
+
+    #[test]
+    fn test_to_unit() {
+        assert_eq!(to_unit(100.0, TemperatureUnit::Fahrenheit), 212.0);
+        assert_eq!(to_unit(0.0, TemperatureUnit::Fahrenheit), 32.0);
+        assert_eq!(to_unit(-40.0, TemperatureUnit::Fahrenheit), -40.0);
+        assert_eq!(to_unit(45.5, TemperatureUnit::Celsius), 45.5);
+    }
+
+    #[test]
+    fn test_temperature_unit_format_and_parse() {
+        assert_eq!(TemperatureUnit::Celsius.format(45.5), "45.5°C");
+        assert_eq!(TemperatureUnit::Fahrenheit.format(100.0), "212.0°F");
+
+        assert_eq!("f".parse(), Ok(TemperatureUnit::Fahrenheit));
+        assert_eq!("Celsius".parse(), Ok(TemperatureUnit::Celsius));
+        assert!("kelvin".parse::<TemperatureUnit>().is_err());
+    }
+
+    #[test]
+    fn test_stats_to_unit() {
+        let stats = TemperatureStats {
+            cpu: 100.0,
+            thermal_zones: vec![ThermalZone {
+                name: "cpu-thermal".to_string(),
+                current_temp: 50.0,
+                critical_temp: 0.0,
+                trip_points: vec![TripPoint {
+                    temp: 100.0,
+                    kind: "critical".to_string(),
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let converted = stats.to_unit(TemperatureUnit::Fahrenheit);
+        assert_eq!(converted.cpu, 212.0);
+        assert_eq!(converted.gpu, 32.0);
+        let zone = &converted.thermal_zones[0];
+        assert_eq!(zone.name, "cpu-thermal");
+        assert_eq!(zone.current_temp, 122.0);
+        assert_eq!(zone.critical_temp, 0.0);
+        assert_eq!(zone.trip_points[0].temp, 212.0);
+    }
+
+    #[test]
+    fn test_alert_fires_only_after_sustained_duration() {
+        let mut alert = TemperatureAlert::new(90.0, Duration::from_secs(3));
+        let start = Instant::now();
+        // One sample per second
+        let series = [95.0, 80.0, 91.0, 92.0, 95.0, 93.0, 94.0, 96.0];
+
+        let fired: Vec<bool> = series
+            .iter()
+            .enumerate()
+            .map(|(i, &temp)| alert.update(temp, start + Duration::from_secs(i as u64)))
+            .collect();
+
+        // The spike at t=0 is reset at t=1, the run from t=2 fires at t=5
+        assert_eq!(
+            fired,
+            [false, false, false, false, false, true, false, false]
+        );
+    }
+
+    #[test]
+    fn test_alert_rearms_after_cooling_down() {
+        let mut alert = TemperatureAlert::new(90.0, Duration::from_secs(2));
+        let start = Instant::now();
+        let at = |secs| start + Duration::from_secs(secs);
+
+        assert!(!alert.update(95.0, at(0)));
+        assert!(alert.update(95.0, at(2)));
+        assert!(!alert.update(95.0, at(4)));
+
+        assert!(!alert.update(85.0, at(5)));
+        assert!(!alert.update(95.0, at(6)));
+        assert!(alert.update(95.0, at(8)));
+    }
+
+    async fn check_read_gpu_trip_points<R: Reader>(reader: &R) {
+        let base = MockDir::new("thermal");
+        let zone_dir = base.join("thermal_zone1");
+        fs::create_dir_all(&zone_dir).unwrap();
+        for (file, value) in [
+            ("type", "gpu-thermal"),
+            ("temp", "61500"),
+            ("trip_point_0_temp", "-40000"),
+            ("trip_point_0_type", "active"),
+            ("trip_point_1_temp", "99000"),
+            ("trip_point_1_type", "passive"),
+            ("trip_point_2_temp", "104500"),
+            ("trip_point_2_type", "critical"),
+        ] {
+            fs::write(zone_dir.join(file), format!("{}\n", value)).unwrap();
+        }
+
+        let zones = read_thermal_zones(reader, &base).await;
+        assert_eq!(zones.len(), 1);
+        let gpu = &zones[0];
+        assert_eq!(gpu.index, 1);
+        assert_eq!(gpu.current_temp, 61.5);
+        assert_eq!(gpu.trip_points.len(), 3);
+        assert_eq!(
+            gpu.trip_points[1],
+            TripPoint {
+                temp: 99.0,
+                kind: "passive".to_string()
+            }
+        );
+        assert_eq!(gpu.throttle_temp(), Some(99.0));
+        assert_eq!(gpu.critical_trip_temp(), Some(104.5));
+        // No crit_temp file, the critical trip point is used instead
+        assert_eq!(gpu.critical_temp, 104.5);
+    }
+
+    #[test]
+    fn test_read_gpu_trip_points() {
+        sysfs::block_on(check_read_gpu_trip_points(&Blocking));
+    }
+
+    #[tokio::test]
+    async fn test_read_gpu_trip_points_async() {
+        check_read_gpu_trip_points(&Tokio).await;
+    }
+
+    async fn check_read_trip_points_skips_unparsable_temp<R: Reader>(reader: &R) {
+        let zone_dir = MockDir::new("thermal-trips");
+        for (file, value) in [
+            ("trip_point_0_temp", "-40000"),
+            ("trip_point_0_type", "active"),
+            ("trip_point_1_temp", "garbage"),
+            ("trip_point_1_type", "passive"),
+            ("trip_point_2_temp", "104500"),
+            ("trip_point_2_type", "critical"),
+        ] {
+            fs::write(zone_dir.join(file), format!("{}\n", value)).unwrap();
+        }
+
+        let trips = read_trip_points(reader, &zone_dir).await;
+        assert_eq!(trips.len(), 2);
+        assert_eq!(trips[0].kind, "active");
+        assert_eq!(trips[1].temp, 104.5);
+        assert_eq!(trips[1].kind, "critical");
+    }
+
+    #[test]
+    fn test_read_trip_points_skips_unparsable_temp() {
+        sysfs::block_on(check_read_trip_points_skips_unparsable_temp(&Blocking));
+    }
+
+    #[tokio::test]
+    async fn test_read_trip_points_skips_unparsable_temp_async() {
+        check_read_trip_points_skips_unparsable_temp(&Tokio).await;
+    }
+
+    #[test]
+    fn test_trip_temps_without_trip_points() {
+        let zone = ThermalZone {
+            critical_temp: 105.0,
+            ..Default::default()
+        };
+
+        assert_eq!(zone.throttle_temp(), None);
+        assert_eq!(zone.critical_trip_temp(), Some(105.0));
+        assert_eq!(ThermalZone::default().critical_trip_temp(), None);
+    }
+
+    #[test]
+    fn test_hottest_zone() {
+        let stats = TemperatureStats {
+            thermal_zones: vec![
+                ThermalZone {
+                    name: "cpu-thermal".to_string(),
+                    current_temp: 55.0,
+                    ..Default::default()
+                },
+                ThermalZone {
+                    name: "gpu-thermal".to_string(),
+                    current_temp: 61.5,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(stats.hottest_zone().unwrap().name, "gpu-thermal");
+        assert!(TemperatureStats::default().hottest_zone().is_none());
+    }
+
+    fn zone(name: &str, current_temp: f32, critical_temp: f32) -> ThermalZone {
+        ThermalZone {
+            name: name.to_string(),
+            current_temp,
+            critical_temp,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_percent_to_critical() {
+        let cpu = zone("cpu-thermal", 52.5, 105.0);
+        assert_eq!(cpu.percent_to_critical(), Some(50.0));
+
+        let no_trip = zone("tj-thermal", 52.5, 0.0);
+        assert_eq!(no_trip.percent_to_critical(), None);
+    }
+
+    #[test]
+    fn test_zone_lookup_by_sensor() {
+        let stats = TemperatureStats {
+            thermal_zones: vec![
+                zone("cpu-thermal", 48.0, 105.0),
+                zone("GPU-therm", 45.0, 105.0),
+                zone("Tboard_tegra", 40.0, 107.0),
+                zone("soc0-thermal", 46.0, 105.0),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(stats.zone("cpu").unwrap().name, "cpu-thermal");
+        assert_eq!(stats.zone("gpu").unwrap().name, "GPU-therm");
+        assert_eq!(stats.zone("board").unwrap().critical_temp, 107.0);
+        assert!(stats.zone("pmic").is_none());
+    }
 
     #[test]
     fn test_temperature_stats_default() {
@@ -164,6 +842,7 @@ mod tests {
             current_temp: 45.5,
             max_temp: 85.0,
             critical_temp: 95.0,
+            trip_points: Vec::new(),
         };
 
         assert_eq!(zone.index, 1);
@@ -187,6 +866,7 @@ mod tests {
                     current_temp: 50.0,
                     max_temp: 85.0,
                     critical_temp: 95.0,
+                    trip_points: Vec::new(),
                 },
                 ThermalZone {
                     index: 1,
@@ -194,8 +874,11 @@ mod tests {
                     current_temp: 60.0,
                     max_temp: 87.0,
                     critical_temp: 97.0,
+                    trip_points: Vec::new(),
                 },
             ],
+            throttling: false,
+            throttle_zones: Vec::new(),
         };
 
         assert_eq!(stats.cpu, 50.0);
@@ -213,6 +896,7 @@ mod tests {
             current_temp: 45.0,
             max_temp: 85.0,
             critical_temp: 95.0,
+            trip_points: Vec::new(),
         };
 
         assert!(zone1.name.contains("CPU"));
@@ -223,6 +907,7 @@ mod tests {
             current_temp: 55.0,
             max_temp: 87.0,
             critical_temp: 97.0,
+            trip_points: Vec::new(),
         };
 
         assert!(zone2.name.contains("GPU"));
@@ -242,6 +927,7 @@ mod tests {
                     current_temp: 50.0,
                     max_temp: 85.0,
                     critical_temp: 95.0,
+                    trip_points: Vec::new(),
                 },
                 ThermalZone {
                     index: 1,
@@ -249,8 +935,11 @@ mod tests {
                     current_temp: 35.0,
                     max_temp: 70.0,
                     critical_temp: 80.0,
+                    trip_points: Vec::new(),
                 },
             ],
+            throttling: false,
+            throttle_zones: Vec::new(),
         };
 
         assert_eq!(stats.cpu, 50.0);
@@ -280,6 +969,7 @@ mod tests {
             current_temp: 45.0,
             max_temp: 85.0,
             critical_temp: 95.0,
+            trip_points: Vec::new(),
         };
 
         assert!(
@@ -292,6 +982,17 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_temperature_stats_get_async() {
+        let stats = TemperatureStats::get_async().await;
+        let sync = TemperatureStats::get();
+        assert_eq!(stats.thermal_zones.len(), sync.thermal_zones.len());
+        for (zone, sync_zone) in stats.thermal_zones.iter().zip(&sync.thermal_zones) {
+            assert_eq!(zone.name, sync_zone.name);
+            assert_eq!(zone.trip_points, sync_zone.trip_points);
+        }
+    }
+
     #[test]
     fn test_thermal_zone_sysfs_parsing() {
         let zone = ThermalZone {
@@ -300,6 +1001,7 @@ mod tests {
             current_temp: 38.5,
             max_temp: 70.0,
             critical_temp: 80.0,
+            trip_points: Vec::new(),
         };
 
         assert_eq!(zone.index, 10);
@@ -320,7 +1022,10 @@ mod tests {
                 current_temp: 45.5,
                 max_temp: 85.0,
                 critical_temp: 95.0,
+                trip_points: Vec::new(),
             }],
+            throttling: false,
+            throttle_zones: Vec::new(),
         };
 
         let json = serde_json::to_string(&stats);
@@ -341,6 +1046,7 @@ mod tests {
             current_temp: 60.0,
             max_temp: 87.0,
             critical_temp: 97.0,
+            trip_points: Vec::new(),
         };
 
         let json = serde_json::to_string(&zone);
@@ -390,6 +1096,7 @@ mod tests {
             current_temp: 25.0,
             max_temp: 80.0,
             critical_temp: 90.0,
+            trip_points: Vec::new(),
         };
 
         assert!(
@@ -0,0 +1,223 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Thermal watchdog module
+//!
+//! [`ThermalWatchdog`] runs an action once any thermal zone has stayed above
+//! a threshold for a whole window, for rigs that run unattended. It can be
+//! fed samples directly or attached to a [`JetsonMonitor`].
+//!
+//! [`JetsonMonitor`]: super::monitor::JetsonMonitor
+
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use super::temperature::{TemperatureAlert, TemperatureStats};
+
+/// Sustained over-temperature reported to the watchdog action
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThermalTrip {
+    /// Hottest zone when the watchdog tripped
+    pub zone: String,
+    /// Temperature of that zone in °C
+    pub temp: f32,
+    /// Threshold in °C
+    pub threshold: f32,
+    /// How long the temperature stayed above the threshold
+    pub window: Duration,
+}
+
+/// Action run when the watchdog trips
+pub type TripAction = Box<dyn FnMut(&ThermalTrip) + Send>;
+
+/// Runs an action when any zone stays above `threshold` for `window`
+///
+/// Spikes shorter than the window are ignored. After tripping the watchdog
+/// stays quiet until every zone has cooled below the threshold again.
+pub struct ThermalWatchdog {
+    alert: TemperatureAlert,
+    window: Duration,
+    on_trip: TripAction,
+}
+
+impl ThermalWatchdog {
+    /// Create a watchdog calling `on_trip` when it trips
+    pub fn new(
+        threshold: f32,
+        window: Duration,
+        on_trip: impl FnMut(&ThermalTrip) + Send + 'static,
+    ) -> Self {
+        Self {
+            alert: TemperatureAlert::new(threshold, window),
+            window,
+            on_trip: Box::new(on_trip),
+        }
+    }
+
+    /// Create a watchdog running [`default_action`] with `command`
+    pub fn with_command(threshold: f32, window: Duration, command: Option<String>) -> Self {
+        Self::new(threshold, window, default_action(command))
+    }
+
+    /// Threshold in °C
+    pub fn threshold(&self) -> f32 {
+        self.alert.threshold()
+    }
+
+    /// How long a zone must stay above the threshold
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Feed a temperature sample taken at `now`
+    ///
+    /// Returns `true` when the watchdog trips on this sample and the action
+    /// was run.
+    pub fn check(&mut self, stats: &TemperatureStats, now: Instant) -> bool {
+        let Some(zone) = stats.hottest_zone() else {
+            return false;
+        };
+        if !self.alert.update(zone.current_temp, now) {
+            return false;
+        }
+
+        let trip = ThermalTrip {
+            zone: zone.name.clone(),
+            temp: zone.current_temp,
+            threshold: self.threshold(),
+            window: self.window,
+        };
+        (self.on_trip)(&trip);
+        true
+    }
+}
+
+impl std::fmt::Debug for ThermalWatchdog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ThermalWatchdog")
+            .field("alert", &self.alert)
+            .field("window", &self.window)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Log the trip to stderr and run `command`, if any, through `sh -c`
+///
+/// The command gets `RJTOP_ALERT_ZONE` and `RJTOP_ALERT_TEMP` set to the
+/// triggering zone and temperature, and runs to completion before the next
+/// sample is taken.
+pub fn default_action(command: Option<String>) -> impl FnMut(&ThermalTrip) + Send {
+    move |trip| {
+        eprintln!(
+            "Alert: {} at {:.1}°C above {:.1}°C for {}s",
+            trip.zone,
+            trip.temp,
+            trip.threshold,
+            trip.window.as_secs_f64()
+        );
+
+        let Some(command) = &command else {
+            return;
+        };
+        eprintln!("Running '{}'", command);
+        let status = Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .env("RJTOP_ALERT_ZONE", &trip.zone)
+            .env("RJTOP_ALERT_TEMP", format!("{:.1}", trip.temp))
+            .status();
+        match status {
+            Ok(status) => eprintln!("Alert command finished: {}", status),
+            Err(e) => eprintln!("Error running alert command: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::temperature::ThermalZone;
+    use std::sync::{Arc, Mutex};
+
+    fn stats(temps: &[(&str, f32)]) -> TemperatureStats {
+        TemperatureStats {
+            thermal_zones: temps
+                .iter()
+                .map(|&(name, current_temp)| ThermalZone {
+                    name: name.to_string(),
+                    current_temp,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Watchdog recording every trip it reports
+    fn recording_watchdog(window: Duration) -> (ThermalWatchdog, Arc<Mutex<Vec<ThermalTrip>>>) {
+        let trips = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&trips);
+        let watchdog = ThermalWatchdog::new(90.0, window, move |trip| {
+            recorded.lock().unwrap().push(trip.clone());
+        });
+        (watchdog, trips)
+    }
+
+    #[test]
+    fn test_brief_spike_does_not_trip() {
+        let (mut watchdog, trips) = recording_watchdog(Duration::from_secs(5));
+        let start = Instant::now();
+        let at = |secs| start + Duration::from_secs(secs);
+
+        assert!(!watchdog.check(&stats(&[("cpu-thermal", 95.0)]), at(0)));
+        assert!(!watchdog.check(&stats(&[("cpu-thermal", 96.0)]), at(3)));
+        assert!(!watchdog.check(&stats(&[("cpu-thermal", 70.0)]), at(4)));
+        assert!(!watchdog.check(&stats(&[("cpu-thermal", 95.0)]), at(6)));
+        assert!(!watchdog.check(&stats(&[("cpu-thermal", 95.0)]), at(10)));
+
+        assert!(trips.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_sustained_heat_trips_once() {
+        let (mut watchdog, trips) = recording_watchdog(Duration::from_secs(5));
+        let start = Instant::now();
+        let at = |secs| start + Duration::from_secs(secs);
+
+        // The hottest zone changes, but some zone stays above the threshold
+        assert!(!watchdog.check(
+            &stats(&[("cpu-thermal", 95.0), ("gpu-thermal", 60.0)]),
+            at(0)
+        ));
+        assert!(!watchdog.check(
+            &stats(&[("cpu-thermal", 80.0), ("gpu-thermal", 92.0)]),
+            at(3)
+        ));
+        assert!(watchdog.check(
+            &stats(&[("cpu-thermal", 80.0), ("gpu-thermal", 93.0)]),
+            at(5)
+        ));
+        assert!(!watchdog.check(
+            &stats(&[("cpu-thermal", 80.0), ("gpu-thermal", 94.0)]),
+            at(8)
+        ));
+
+        let trips = trips.lock().unwrap();
+        assert_eq!(
+            *trips,
+            vec![ThermalTrip {
+                zone: "gpu-thermal".to_string(),
+                temp: 93.0,
+                threshold: 90.0,
+                window: Duration::from_secs(5),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_no_zones_never_trips() {
+        let (mut watchdog, trips) = recording_watchdog(Duration::ZERO);
+        assert!(!watchdog.check(&stats(&[]), Instant::now()));
+        assert!(trips.lock().unwrap().is_empty());
+    }
+}
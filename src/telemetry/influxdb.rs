@@ -0,0 +1,379 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! InfluxDB line protocol export
+//!
+//! Each export writes one `jetson` point to the InfluxDB v2 write API,
+//! tagged with the board model and serial number. Both `http://` and
+//! `https://` endpoints are supported; the token is only sent over plain
+//! HTTP to the local host.
+
+use std::fmt::Write;
+use std::net::IpAddr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+use reqwest::Url;
+
+use crate::{Error, JetsonStats};
+
+/// Path of the InfluxDB v2 write API
+const WRITE_PATH: &str = "/api/v2/write";
+
+/// Measurement every point is written to
+const MEASUREMENT: &str = "jetson";
+
+/// Time allowed for a single export round trip
+const EXPORT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Writes statistics to InfluxDB in line protocol
+pub struct InfluxExporter {
+    endpoint: String,
+    token: Option<String>,
+}
+
+impl InfluxExporter {
+    /// Create an exporter for `endpoint`, authenticating with `token` if set
+    ///
+    /// The bucket and organization go in the endpoint query, e.g.
+    /// `http://localhost:8086?org=acme&bucket=jetson`.
+    pub fn new(endpoint: String, token: Option<String>) -> Self {
+        Self { endpoint, token }
+    }
+
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// URL points are written to
+    ///
+    /// `/api/v2/write` is appended to the endpoint path unless it already
+    /// names it, and `precision=ns` is added unless a precision is given.
+    pub fn write_url(&self) -> String {
+        let (base, query) = match self.endpoint.split_once('?') {
+            Some((base, query)) => (base, query),
+            None => (self.endpoint.as_str(), ""),
+        };
+
+        let base = base.trim_end_matches('/');
+        let mut url = if base.ends_with(WRITE_PATH) {
+            base.to_string()
+        } else {
+            format!("{}{}", base, WRITE_PATH)
+        };
+
+        let mut params: Vec<&str> = query.split('&').filter(|p| !p.is_empty()).collect();
+        if !params.iter().any(|p| p.starts_with("precision=")) {
+            params.push("precision=ns");
+        }
+        url.push('?');
+        url.push_str(&params.join("&"));
+        url
+    }
+
+    /// Export one snapshot of statistics as a single point
+    pub async fn export(&self, stats: &JetsonStats) -> crate::Result<()> {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let body = line_protocol(stats, time);
+
+        let url = Url::parse(&self.write_url()).map_err(|e| {
+            Error::telemetry(
+                "InfluxDB",
+                format!("invalid endpoint '{}': {}", self.endpoint, e),
+            )
+        })?;
+        if self.token.is_some() && !token_allowed(&url) {
+            return Err(Error::telemetry(
+                "InfluxDB",
+                "refusing to send the token over plain http:// to a remote host, use https://",
+            ));
+        }
+
+        let mut request = reqwest::Client::new()
+            .post(url)
+            .header(CONTENT_TYPE, "text/plain; charset=utf-8")
+            .timeout(EXPORT_TIMEOUT)
+            .body(body);
+        if let Some(token) = &self.token {
+            request = request.header(AUTHORIZATION, format!("Token {}", token));
+        }
+
+        request
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| {
+                if e.is_timeout() {
+                    Error::telemetry("InfluxDB", "export timed out")
+                } else {
+                    Error::telemetry("InfluxDB", e)
+                }
+            })?;
+        Ok(())
+    }
+}
+
+/// Whether the API token may be sent to `url`
+///
+/// Always over `https://`; over plain `http://` only to the local host.
+fn token_allowed(url: &Url) -> bool {
+    if url.scheme() == "https" {
+        return true;
+    }
+
+    match url.host_str() {
+        Some("localhost") => true,
+        Some(host) => host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback()),
+        None => false,
+    }
+}
+
+/// Format a snapshot as one line protocol point at `timestamp_ns`
+///
+/// Per-core usage, thermal zones and power rails become `cpuN_usage`,
+/// `temp_<zone>` and `power_<rail>` (W) fields. Non-finite readings are left
+/// out since line protocol cannot represent them.
+pub fn line_protocol(stats: &JetsonStats, timestamp_ns: u128) -> String {
+    let mut line = MEASUREMENT.to_string();
+    for (key, value) in [
+        ("model", &stats.board.model),
+        ("serial", &stats.board.serial),
+    ] {
+        if !value.is_empty() {
+            let _ = write!(line, ",{}={}", key, escape(value));
+        }
+    }
+
+    let mut fields = Fields::default();
+    fields.float("cpu_usage", stats.cpu.usage);
+    for core in &stats.cpu.cores {
+        fields.float(&format!("cpu{}_usage", core.index), core.usage);
+    }
+    fields.float("gpu_usage", stats.gpu.usage);
+    fields.integer("gpu_frequency", stats.gpu.frequency.into());
+    fields.float("gpu_temperature", stats.gpu.temperature);
+    fields.integer("ram_used", stats.memory.ram_used);
+    fields.integer("ram_total", stats.memory.ram_total);
+    fields.integer("swap_used", stats.memory.swap_used);
+    fields.integer("swap_total", stats.memory.swap_total);
+    for zone in &stats.temperature.thermal_zones {
+        fields.float(&format!("temp_{}", zone.name), zone.current_temp);
+    }
+    fields.float("power_total", stats.power.total);
+    for rail in &stats.power.rails {
+        let watts = stats.power.rail_watts(rail);
+        fields.float(&format!("power_{}", rail.name), watts);
+    }
+    fields.integer("fan_speed", stats.fan.speed.into());
+
+    let _ = write!(line, " {} {}", fields.0.join(","), timestamp_ns);
+    line
+}
+
+/// `key=value` field pairs of a point
+#[derive(Default)]
+struct Fields(Vec<String>);
+
+impl Fields {
+    fn float(&mut self, key: &str, value: f32) {
+        if value.is_finite() {
+            self.0.push(format!("{}={}", escape(key), value));
+        }
+    }
+
+    fn integer(&mut self, key: &str, value: u64) {
+        self.0.push(format!("{}={}i", escape(key), value));
+    }
+}
+
+/// Escape a tag key, tag value or field key
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, ',' | '=' | ' ' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CpuCore, PowerRail, ThermalZone};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    fn sample_stats() -> JetsonStats {
+        let mut stats = JetsonStats::default();
+        stats.board.model = "NVIDIA Jetson AGX Orin, rev=2".to_string();
+        stats.board.serial = "1421222000123".to_string();
+        stats.cpu.usage = 12.5;
+        stats.cpu.cores = vec![CpuCore {
+            index: 0,
+            usage: 10.0,
+            ..Default::default()
+        }];
+        stats.gpu.usage = 30.0;
+        stats.gpu.temperature = 45.5;
+        stats.gpu.frequency = 1_300_500_000;
+        stats.memory.ram_used = 1024;
+        stats.memory.ram_total = 4096;
+        stats.temperature.thermal_zones = vec![ThermalZone {
+            name: "cpu-thermal".to_string(),
+            current_temp: 48.0,
+            ..Default::default()
+        }];
+        stats.power.total = 7.25;
+        stats.power.rails = vec![PowerRail {
+            name: "VDD_IN".to_string(),
+            power: 7250.0,
+            is_total: true,
+            ..Default::default()
+        }];
+        stats.fan.speed = 40;
+        stats
+    }
+
+    #[test]
+    fn test_line_protocol() {
+        let expected =
+            "jetson,model=NVIDIA\\ Jetson\\ AGX\\ Orin\\,\\ rev\\=2,serial=1421222000123 \
+            cpu_usage=12.5,cpu0_usage=10,gpu_usage=30,gpu_frequency=1300500000i,\
+            gpu_temperature=45.5,ram_used=1024i,ram_total=4096i,swap_used=0i,swap_total=0i,\
+            temp_cpu-thermal=48,power_total=7.25,power_VDD_IN=7.25,fan_speed=40i \
+            1700000000000000000";
+        assert_eq!(
+            line_protocol(&sample_stats(), 1_700_000_000_000_000_000),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_line_protocol_skips_non_finite_and_empty() {
+        let mut stats = sample_stats();
+        stats.board.serial = String::new();
+        stats.gpu.temperature = f32::NAN;
+
+        let line = line_protocol(&stats, 1);
+        assert!(!line.contains("serial="));
+        assert!(!line.contains("gpu_temperature"));
+        assert!(line.ends_with("fan_speed=40i 1"));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("a b,c=d\\e"), "a\\ b\\,c\\=d\\\\e");
+    }
+
+    #[test]
+    fn test_write_url() {
+        let url = |endpoint: &str| InfluxExporter::new(endpoint.to_string(), None).write_url();
+
+        assert_eq!(
+            url("http://localhost:8086/"),
+            "http://localhost:8086/api/v2/write?precision=ns"
+        );
+        assert_eq!(
+            url("http://influx:8086?org=acme&bucket=jetson"),
+            "http://influx:8086/api/v2/write?org=acme&bucket=jetson&precision=ns"
+        );
+        assert_eq!(
+            url("http://influx:8086/api/v2/write?bucket=jetson&precision=s"),
+            "http://influx:8086/api/v2/write?bucket=jetson&precision=s"
+        );
+    }
+
+    #[test]
+    fn test_token_allowed() {
+        let allowed = |url: &str| token_allowed(&Url::parse(url).unwrap());
+
+        assert!(allowed("https://eu-central-1-1.aws.cloud2.influxdata.com"));
+        assert!(allowed("http://localhost:8086"));
+        assert!(allowed("http://127.0.0.1:8086"));
+        assert!(allowed("http://[::1]:8086"));
+        assert!(!allowed("http://influx.lan:8086"));
+        assert!(!allowed("http://192.168.1.10:8086"));
+    }
+
+    #[tokio::test]
+    async fn test_export_refuses_token_over_remote_http() {
+        let exporter = InfluxExporter::new(
+            "http://influx.example:8086?bucket=jetson".to_string(),
+            Some("secret".to_string()),
+        );
+        let err = exporter.export(&sample_stats()).await.unwrap_err();
+        assert!(err.to_string().contains("https://"));
+    }
+
+    /// Accept one request and answer it with `status`, returning the
+    /// request head and body
+    async fn serve_once(listener: TcpListener, status: &'static str) -> (String, String) {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buf = [0u8; 4096];
+
+        // Read until the whole body announced by Content-Length arrived
+        let (head, body_start) = loop {
+            let n = socket.read(&mut buf).await.unwrap();
+            request.extend_from_slice(&buf[..n]);
+            if let Some(end) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                let head = String::from_utf8_lossy(&request[..end]).to_string();
+                let length = head
+                    .lines()
+                    .filter_map(|l| l.split_once(':'))
+                    .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                    .and_then(|(_, v)| v.trim().parse::<usize>().ok())
+                    .unwrap_or(0);
+                while request.len() < end + 4 + length {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                }
+                break (head, end + 4);
+            }
+        };
+
+        let response = format!("HTTP/1.1 {}\r\nContent-Length: 0\r\n\r\n", status);
+        socket.write_all(response.as_bytes()).await.unwrap();
+        (
+            head,
+            String::from_utf8_lossy(&request[body_start..]).to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_export_posts_line_with_token() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(serve_once(listener, "204 No Content"));
+
+        let exporter = InfluxExporter::new(
+            format!("http://{}?bucket=jetson", addr),
+            Some("secret".to_string()),
+        );
+        exporter.export(&sample_stats()).await.unwrap();
+
+        let (head, body) = server.await.unwrap();
+        assert!(head.starts_with("POST /api/v2/write?bucket=jetson&precision=ns HTTP/1.1"));
+        assert!(head.to_lowercase().contains("authorization: token secret"));
+        assert!(body.starts_with("jetson,model="));
+    }
+
+    #[tokio::test]
+    async fn test_export_rejects_error_status() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(serve_once(listener, "401 Unauthorized"));
+
+        let exporter = InfluxExporter::new(format!("http://{}", addr), None);
+        assert!(exporter.export(&sample_stats()).await.is_err());
+    }
+}
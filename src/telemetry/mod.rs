@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Telemetry exports for rusted-jetsons
+
+#[cfg(feature = "influxdb")]
+mod influxdb;
+#[cfg(feature = "telemetry")]
+mod otlp;
+#[cfg(feature = "prometheus")]
+mod prometheus;
+
+#[cfg(feature = "influxdb")]
+pub use influxdb::{line_protocol, InfluxExporter};
+
+#[cfg(feature = "telemetry")]
+pub use otlp::TelemetryExporter;
+#[cfg(feature = "prometheus")]
+pub use prometheus::PrometheusExporter;
@@ -0,0 +1,347 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! OTLP metrics export
+//!
+//! Metrics are posted as OTLP/HTTP protobuf to `<endpoint>/v1/metrics`
+//! through the `opentelemetry-otlp` exporter, over `http://` or `https://`.
+
+use std::borrow::Cow;
+use std::time::{Duration, SystemTime};
+
+use opentelemetry::metrics::Unit;
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::metrics::data::{self, DataPoint, Metric, ResourceMetrics, ScopeMetrics};
+use opentelemetry_sdk::metrics::exporter::PushMetricsExporter;
+use opentelemetry_sdk::metrics::reader::{DefaultAggregationSelector, DefaultTemporalitySelector};
+use opentelemetry_sdk::{AttributeSet, Resource, Scope};
+
+use crate::{Error, JetsonStats};
+
+/// Path of the OTLP/HTTP metrics signal
+const METRICS_PATH: &str = "/v1/metrics";
+
+/// Time allowed for a single export round trip
+const EXPORT_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct TelemetryExporter {
+    endpoint: String,
+}
+
+impl TelemetryExporter {
+    pub fn new(endpoint: String) -> Self {
+        Self { endpoint }
+    }
+
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// URL the metrics are posted to
+    ///
+    /// `/v1/metrics` is appended unless the endpoint already names it.
+    pub fn metrics_url(&self) -> String {
+        let endpoint = self.endpoint.trim_end_matches('/');
+        if endpoint.ends_with(METRICS_PATH) {
+            endpoint.to_string()
+        } else {
+            format!("{}{}", endpoint, METRICS_PATH)
+        }
+    }
+
+    /// Export one snapshot of statistics as OTLP gauges
+    pub async fn export(&self, stats: &JetsonStats) -> crate::Result<()> {
+        let exporter = opentelemetry_otlp::new_exporter()
+            .http()
+            .with_endpoint(self.metrics_url())
+            .with_timeout(EXPORT_TIMEOUT)
+            .build_metrics_exporter(
+                Box::new(DefaultAggregationSelector::new()),
+                Box::new(DefaultTemporalitySelector::new()),
+            )
+            .map_err(|e| Error::telemetry("OTLP", e))?;
+
+        let mut metrics = resource_metrics(stats, SystemTime::now());
+        let result = tokio::time::timeout(EXPORT_TIMEOUT, exporter.export(&mut metrics))
+            .await
+            .map_err(|_| Error::telemetry("OTLP", "export timed out"))?
+            .map_err(|e| Error::telemetry("OTLP", e));
+        let _ = exporter.shutdown();
+
+        result
+    }
+}
+
+/// Single gauge data point
+struct Gauge {
+    name: &'static str,
+    unit: &'static str,
+    value: f64,
+    attributes: Vec<(&'static str, String)>,
+}
+
+impl Gauge {
+    fn new(name: &'static str, unit: &'static str, value: f64) -> Self {
+        Self {
+            name,
+            unit,
+            value,
+            attributes: Vec::new(),
+        }
+    }
+}
+
+/// Map a statistics snapshot to gauge data points
+fn gauges(stats: &JetsonStats) -> Vec<Gauge> {
+    let mut gauges = vec![
+        Gauge::new("jetson.cpu.usage", "%", stats.cpu.usage as f64),
+        Gauge::new("jetson.gpu.usage", "%", stats.gpu.usage as f64),
+        Gauge::new(
+            "jetson.gpu.temperature",
+            "Cel",
+            stats.gpu.temperature as f64,
+        ),
+        Gauge::new("jetson.gpu.frequency", "Hz", stats.gpu.frequency as f64),
+        Gauge::new("jetson.memory.used", "By", stats.memory.ram_used as f64),
+        Gauge::new("jetson.memory.total", "By", stats.memory.ram_total as f64),
+        Gauge::new("jetson.power.total", "W", stats.power.total as f64),
+    ];
+
+    for rail in &stats.power.rails {
+        let watts = stats.power.rail_watts(rail) as f64;
+        let mut gauge = Gauge::new("jetson.power.rail", "W", watts);
+        gauge.attributes.push(("rail", rail.name.clone()));
+        gauges.push(gauge);
+    }
+
+    gauges.push(Gauge::new("jetson.fan.speed", "%", stats.fan.speed as f64));
+    gauges
+}
+
+/// Build the OTLP metrics of a snapshot taken at `time`
+fn resource_metrics(stats: &JetsonStats, time: SystemTime) -> ResourceMetrics {
+    let mut metrics: Vec<Metric> = Vec::new();
+
+    for gauge in gauges(stats) {
+        let attributes: Vec<KeyValue> = gauge
+            .attributes
+            .into_iter()
+            .map(|(key, value)| KeyValue::new(key, value))
+            .collect();
+        let point = DataPoint {
+            attributes: AttributeSet::from(attributes.as_slice()),
+            start_time: None,
+            time: Some(time),
+            value: gauge.value,
+            exemplars: Vec::new(),
+        };
+
+        // Data points of the same gauge, like per-rail power, share a metric
+        let existing = metrics
+            .iter_mut()
+            .find(|m| m.name == gauge.name)
+            .and_then(|m| {
+                data::Aggregation::as_mut(&mut *m.data).downcast_mut::<data::Gauge<f64>>()
+            });
+        match existing {
+            Some(data) => data.data_points.push(point),
+            None => metrics.push(Metric {
+                name: Cow::Borrowed(gauge.name),
+                description: Cow::Borrowed(""),
+                unit: Unit::new(gauge.unit),
+                data: Box::new(data::Gauge {
+                    data_points: vec![point],
+                }),
+            }),
+        }
+    }
+
+    ResourceMetrics {
+        resource: Resource::new([
+            KeyValue::new("service.name", env!("CARGO_PKG_NAME")),
+            KeyValue::new("jetson.board", stats.board.model.clone()),
+        ]),
+        scope_metrics: vec![ScopeMetrics {
+            scope: Scope::builder(env!("CARGO_PKG_NAME"))
+                .with_version(env!("CARGO_PKG_VERSION"))
+                .build(),
+            metrics,
+        }],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PowerRail;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    #[test]
+    fn test_telemetry_exporter_new() {
+        let exporter = TelemetryExporter::new("http://localhost:4318".to_string());
+        assert_eq!(exporter.endpoint(), "http://localhost:4318");
+    }
+
+    #[test]
+    fn test_telemetry_exporter_endpoint() {
+        let exporter = TelemetryExporter::new("http://grafana:4318/v1/metrics".to_string());
+        assert!(exporter.endpoint().starts_with("http://"));
+        assert!(exporter.endpoint().contains("4318"));
+    }
+
+    #[test]
+    fn test_metrics_url() {
+        let exporter = TelemetryExporter::new("http://localhost:4318/".to_string());
+        assert_eq!(exporter.metrics_url(), "http://localhost:4318/v1/metrics");
+
+        let exporter = TelemetryExporter::new("http://grafana:4318/v1/metrics".to_string());
+        assert_eq!(exporter.metrics_url(), "http://grafana:4318/v1/metrics");
+    }
+
+    fn sample_stats() -> JetsonStats {
+        let mut stats = JetsonStats::default();
+        stats.cpu.usage = 42.5;
+        stats.gpu.usage = 10.0;
+        stats.power.total = 7.5;
+        stats.power.rails = vec![
+            PowerRail {
+                name: "VDD_IN".to_string(),
+                power: 7500.0,
+                is_total: true,
+                ..Default::default()
+            },
+            PowerRail {
+                name: "VDD_CPU_GPU_CV".to_string(),
+                power: 2100.0,
+                ..Default::default()
+            },
+        ];
+        stats.fan.speed = 30;
+        stats
+    }
+
+    fn gauge_points(metric: &Metric) -> &[DataPoint<f64>] {
+        &metric
+            .data
+            .as_any()
+            .downcast_ref::<data::Gauge<f64>>()
+            .unwrap()
+            .data_points
+    }
+
+    #[test]
+    fn test_resource_metrics_groups_rails() {
+        let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1);
+        let metrics = resource_metrics(&sample_stats(), time);
+        let metrics = &metrics.scope_metrics[0].metrics;
+
+        let rails = metrics
+            .iter()
+            .find(|m| m.name == "jetson.power.rail")
+            .unwrap();
+        let points = gauge_points(rails);
+        assert_eq!(points.len(), 2);
+        let (key, value) = points[1].attributes.iter().next().unwrap();
+        assert_eq!(key.as_str(), "rail");
+        assert_eq!(value.as_str(), "VDD_CPU_GPU_CV");
+        assert_eq!(rails.unit.as_str(), "W");
+        assert!((points[1].value - 2.1).abs() < 1e-4);
+
+        let cpu = metrics
+            .iter()
+            .find(|m| m.name == "jetson.cpu.usage")
+            .unwrap();
+        assert_eq!(cpu.unit.as_str(), "%");
+        assert_eq!(gauge_points(cpu)[0].value, 42.5);
+        assert_eq!(gauge_points(cpu)[0].time, Some(time));
+    }
+
+    /// Accept one request and answer it with `status`, returning the
+    /// request head and body
+    async fn serve_once(listener: TcpListener, status: &'static str) -> (String, Vec<u8>) {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buf = [0u8; 4096];
+
+        // Read until the whole body announced by Content-Length arrived
+        let (head, body_start) = loop {
+            let n = socket.read(&mut buf).await.unwrap();
+            request.extend_from_slice(&buf[..n]);
+            if let Some(end) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                let head = String::from_utf8_lossy(&request[..end]).to_string();
+                let length = head
+                    .lines()
+                    .filter_map(|l| l.split_once(':'))
+                    .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                    .and_then(|(_, v)| v.trim().parse::<usize>().ok())
+                    .unwrap_or(0);
+                while request.len() < end + 4 + length {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                }
+                break (head, end + 4);
+            }
+        };
+
+        let response = format!("HTTP/1.1 {}\r\nContent-Length: 0\r\n\r\n", status);
+        socket.write_all(response.as_bytes()).await.unwrap();
+        (head, request[body_start..].to_vec())
+    }
+
+    #[tokio::test]
+    async fn test_export_posts_metrics() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(serve_once(listener, "200 OK"));
+
+        let exporter = TelemetryExporter::new(format!("http://{}", addr));
+        exporter.export(&sample_stats()).await.unwrap();
+
+        let (head, body) = server.await.unwrap();
+        assert!(head.starts_with("POST /v1/metrics HTTP/1.1"));
+        assert!(head
+            .to_lowercase()
+            .contains("content-type: application/x-protobuf"));
+
+        // Metric names are carried verbatim in the protobuf body
+        let body = String::from_utf8_lossy(&body);
+        for name in [
+            "jetson.cpu.usage",
+            "jetson.gpu.usage",
+            "jetson.gpu.temperature",
+            "jetson.gpu.frequency",
+            "jetson.memory.used",
+            "jetson.memory.total",
+            "jetson.power.total",
+            "jetson.power.rail",
+            "jetson.fan.speed",
+        ] {
+            assert!(body.contains(name), "missing metric {}", name);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_rejects_error_status() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(serve_once(listener, "500 Internal Server Error"));
+
+        let exporter = TelemetryExporter::new(format!("http://{}", addr));
+        assert!(exporter.export(&sample_stats()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_export_accepts_ipv6_endpoint() {
+        let Ok(listener) = TcpListener::bind("[::1]:0").await else {
+            return;
+        };
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(serve_once(listener, "200 OK"));
+
+        let exporter = TelemetryExporter::new(format!("http://[::1]:{}", port));
+        exporter.export(&sample_stats()).await.unwrap();
+        assert!(server.await.unwrap().0.starts_with("POST /v1/metrics"));
+    }
+}
@@ -0,0 +1,341 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Prometheus text exposition endpoint
+//!
+//! Renders statistics in the Prometheus text format and serves them at
+//! `/metrics` so Prometheus or Grafana Agent can scrape the board directly.
+//! CPU usage is the delta since the previous scrape and power samples go
+//! through the glitch filter of [`PowerMonitor`].
+
+use std::convert::Infallible;
+use std::fmt::Write;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use hyper::header::CONTENT_TYPE;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, Server, StatusCode};
+
+use crate::{CpuMonitor, Error, JetsonStats, PowerMonitor, PowerStats};
+
+/// Content type of the text exposition format
+const TEXT_FORMAT: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Renders and serves statistics in the Prometheus text format
+///
+/// Clones share the CPU and power monitors, so every connection samples
+/// against the same previous scrape.
+#[derive(Debug, Clone, Default)]
+pub struct PrometheusExporter {
+    cpu: Arc<tokio::sync::Mutex<CpuMonitor>>,
+    power: Arc<Mutex<PowerMonitor>>,
+}
+
+impl PrometheusExporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Render a snapshot as Prometheus gauges
+    ///
+    /// Every sample carries the board model as the `board` label.
+    pub fn render(&self, stats: &JetsonStats) -> String {
+        let board = stats.board.model.as_str();
+        let mut out = String::new();
+
+        let mut family = Family::new(
+            "jetson_cpu_usage_percent",
+            "CPU usage averaged over all cores",
+            board,
+        );
+        family.sample(&[], float(stats.cpu.usage));
+        family.write(&mut out);
+
+        let mut family = Family::new(
+            "jetson_cpu_core_usage_percent",
+            "Usage of a CPU core",
+            board,
+        );
+        for core in &stats.cpu.cores {
+            family.sample(&[("core", &core.index.to_string())], float(core.usage));
+        }
+        family.write(&mut out);
+
+        let mut family = Family::new("jetson_gpu_usage_percent", "GPU usage", board);
+        family.sample(&[], float(stats.gpu.usage));
+        family.write(&mut out);
+
+        let mut family = Family::new("jetson_gpu_temperature_celsius", "GPU temperature", board);
+        family.sample(&[], float(stats.gpu.temperature));
+        family.write(&mut out);
+
+        let mut family = Family::new("jetson_gpu_frequency_hertz", "GPU frequency", board);
+        family.sample(&[], stats.gpu.frequency.to_string());
+        family.write(&mut out);
+
+        let mut family = Family::new("jetson_memory_used_bytes", "Used RAM", board);
+        family.sample(&[], stats.memory.ram_used.to_string());
+        family.write(&mut out);
+
+        let mut family = Family::new("jetson_memory_total_bytes", "Total RAM", board);
+        family.sample(&[], stats.memory.ram_total.to_string());
+        family.write(&mut out);
+
+        let mut family = Family::new(
+            "jetson_temperature_celsius",
+            "Temperature of a thermal zone",
+            board,
+        );
+        for zone in &stats.temperature.thermal_zones {
+            family.sample(&[("zone", &zone.name)], float(zone.current_temp));
+        }
+        family.write(&mut out);
+
+        let mut family = Family::new("jetson_power_total_watts", "Total board power", board);
+        family.sample(&[], float(stats.power.total));
+        family.write(&mut out);
+
+        let mut family = Family::new("jetson_power_rail_watts", "Power of a rail", board);
+        for rail in &stats.power.rails {
+            family.sample(&[("rail", &rail.name)], float(stats.power.rail_watts(rail)));
+        }
+        family.write(&mut out);
+
+        let mut family = Family::new("jetson_fan_speed_percent", "Fan speed", board);
+        family.sample(&[], stats.fan.speed.to_string());
+        family.write(&mut out);
+
+        out
+    }
+
+    /// Serve freshly collected statistics at `/metrics` until the server fails
+    pub async fn serve(self, addr: SocketAddr) -> crate::Result<()> {
+        let make_service = make_service_fn(move |_conn| {
+            let exporter = self.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |req| {
+                    let exporter = exporter.clone();
+                    async move { Ok::<_, Infallible>(exporter.handle(req).await) }
+                }))
+            }
+        });
+
+        Server::try_bind(&addr)
+            .map_err(|e| {
+                Error::telemetry("Prometheus", format!("cannot listen on {}: {}", addr, e))
+            })?
+            .serve(make_service)
+            .await
+            .map_err(|e| Error::telemetry("Prometheus", e))
+    }
+
+    async fn handle(&self, req: Request<Body>) -> Response<Body> {
+        if req.uri().path() != "/metrics" {
+            let mut response = Response::new(Body::from("Not Found\n"));
+            *response.status_mut() = StatusCode::NOT_FOUND;
+            return response;
+        }
+
+        let stats = self.collect().await;
+        let mut response = Response::new(Body::from(self.render(&stats)));
+        response
+            .headers_mut()
+            .insert(CONTENT_TYPE, TEXT_FORMAT.parse().unwrap());
+        response
+    }
+}
+
+impl PrometheusExporter {
+    /// Collect statistics, sampling CPU and power through the monitors
+    async fn collect(&self) -> JetsonStats {
+        let mut cpu = self.cpu.lock().await;
+        let power = async {
+            let power = PowerStats::get_async().await;
+            match self.power.lock() {
+                Ok(mut monitor) => monitor.sample_at(power, Instant::now()),
+                Err(_) => power,
+            }
+        };
+
+        JetsonStats::collect_async_with(cpu.get_stats_async(), power).await
+    }
+}
+
+/// Samples of one metric sharing its HELP and TYPE lines
+struct Family<'a> {
+    name: &'static str,
+    help: &'static str,
+    board: &'a str,
+    samples: Vec<String>,
+}
+
+impl<'a> Family<'a> {
+    fn new(name: &'static str, help: &'static str, board: &'a str) -> Self {
+        Self {
+            name,
+            help,
+            board,
+            samples: Vec::new(),
+        }
+    }
+
+    fn sample(&mut self, labels: &[(&str, &str)], value: String) {
+        let mut line = format!("{}{{board=\"{}\"", self.name, escape_label(self.board));
+        for (key, label) in labels {
+            let _ = write!(line, ",{}=\"{}\"", key, escape_label(label));
+        }
+        let _ = write!(line, "}} {}", value);
+        self.samples.push(line);
+    }
+
+    /// Append the family, skipping it when there are no samples
+    fn write(self, out: &mut String) {
+        if self.samples.is_empty() {
+            return;
+        }
+
+        let _ = writeln!(out, "# HELP {} {}", self.name, self.help);
+        let _ = writeln!(out, "# TYPE {} gauge", self.name);
+        for sample in self.samples {
+            out.push_str(&sample);
+            out.push('\n');
+        }
+    }
+}
+
+/// Escape a label value as required by the text format
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Format a float sample, spelling non-finite values the Prometheus way
+fn float(value: f32) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CpuCore, PowerRail, ThermalZone};
+
+    fn sample_stats() -> JetsonStats {
+        let mut stats = JetsonStats::default();
+        stats.board.model = "NVIDIA Jetson AGX Orin".to_string();
+        stats.cpu.usage = 12.5;
+        stats.cpu.cores = vec![
+            CpuCore {
+                index: 0,
+                usage: 10.0,
+                ..Default::default()
+            },
+            CpuCore {
+                index: 1,
+                usage: 15.0,
+                ..Default::default()
+            },
+        ];
+        stats.gpu.usage = 30.0;
+        stats.gpu.temperature = 45.5;
+        stats.gpu.frequency = 1_300_500_000;
+        stats.memory.ram_used = 1024;
+        stats.memory.ram_total = 4096;
+        stats.temperature.thermal_zones = vec![ThermalZone {
+            index: 0,
+            name: "cpu-thermal".to_string(),
+            current_temp: 48.0,
+            ..Default::default()
+        }];
+        stats.power.total = 7.25;
+        stats.power.rails = vec![PowerRail {
+            name: "VDD_IN".to_string(),
+            power: 7250.0,
+            is_total: true,
+            ..Default::default()
+        }];
+        stats.fan.speed = 40;
+        stats
+    }
+
+    #[test]
+    fn test_render() {
+        let expected = "\
+# HELP jetson_cpu_usage_percent CPU usage averaged over all cores
+# TYPE jetson_cpu_usage_percent gauge
+jetson_cpu_usage_percent{board=\"NVIDIA Jetson AGX Orin\"} 12.5
+# HELP jetson_cpu_core_usage_percent Usage of a CPU core
+# TYPE jetson_cpu_core_usage_percent gauge
+jetson_cpu_core_usage_percent{board=\"NVIDIA Jetson AGX Orin\",core=\"0\"} 10
+jetson_cpu_core_usage_percent{board=\"NVIDIA Jetson AGX Orin\",core=\"1\"} 15
+# HELP jetson_gpu_usage_percent GPU usage
+# TYPE jetson_gpu_usage_percent gauge
+jetson_gpu_usage_percent{board=\"NVIDIA Jetson AGX Orin\"} 30
+# HELP jetson_gpu_temperature_celsius GPU temperature
+# TYPE jetson_gpu_temperature_celsius gauge
+jetson_gpu_temperature_celsius{board=\"NVIDIA Jetson AGX Orin\"} 45.5
+# HELP jetson_gpu_frequency_hertz GPU frequency
+# TYPE jetson_gpu_frequency_hertz gauge
+jetson_gpu_frequency_hertz{board=\"NVIDIA Jetson AGX Orin\"} 1300500000
+# HELP jetson_memory_used_bytes Used RAM
+# TYPE jetson_memory_used_bytes gauge
+jetson_memory_used_bytes{board=\"NVIDIA Jetson AGX Orin\"} 1024
+# HELP jetson_memory_total_bytes Total RAM
+# TYPE jetson_memory_total_bytes gauge
+jetson_memory_total_bytes{board=\"NVIDIA Jetson AGX Orin\"} 4096
+# HELP jetson_temperature_celsius Temperature of a thermal zone
+# TYPE jetson_temperature_celsius gauge
+jetson_temperature_celsius{board=\"NVIDIA Jetson AGX Orin\",zone=\"cpu-thermal\"} 48
+# HELP jetson_power_total_watts Total board power
+# TYPE jetson_power_total_watts gauge
+jetson_power_total_watts{board=\"NVIDIA Jetson AGX Orin\"} 7.25
+# HELP jetson_power_rail_watts Power of a rail
+# TYPE jetson_power_rail_watts gauge
+jetson_power_rail_watts{board=\"NVIDIA Jetson AGX Orin\",rail=\"VDD_IN\"} 7.25
+# HELP jetson_fan_speed_percent Fan speed
+# TYPE jetson_fan_speed_percent gauge
+jetson_fan_speed_percent{board=\"NVIDIA Jetson AGX Orin\"} 40
+";
+        assert_eq!(PrometheusExporter::new().render(&sample_stats()), expected);
+    }
+
+    #[test]
+    fn test_render_skips_empty_families() {
+        let output = PrometheusExporter::new().render(&JetsonStats::default());
+        assert!(!output.contains("jetson_cpu_core_usage_percent"));
+        assert!(!output.contains("jetson_power_rail_watts"));
+        assert!(output.contains("jetson_cpu_usage_percent{board=\"Unknown Jetson Board\"} 0"));
+    }
+
+    #[tokio::test]
+    async fn test_collect_samples_cpu_since_previous_scrape() {
+        let exporter = PrometheusExporter::new();
+        exporter.collect().await;
+        exporter.clone().collect().await;
+
+        // Both scrapes went through the shared monitors
+        assert_eq!(exporter.cpu.lock().await.usage_history().len(), 2);
+    }
+
+    #[test]
+    fn test_escape_label() {
+        assert_eq!(escape_label("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+
+    #[test]
+    fn test_float() {
+        assert_eq!(float(1.5), "1.5");
+        assert_eq!(float(f32::NAN), "NaN");
+        assert_eq!(float(f32::INFINITY), "+Inf");
+        assert_eq!(float(f32::NEG_INFINITY), "-Inf");
+    }
+}
@@ -3,12 +3,55 @@
 
 //! CLI integration tests
 
+use std::path::PathBuf;
 use std::process::Command;
 
+/// Path of the `rjtop-cli` binary built by cargo for the integration tests
+fn cli_binary() -> PathBuf {
+    PathBuf::from(env!("CARGO_BIN_EXE_rjtop-cli"))
+}
+
+/// Command running the already-built `rjtop-cli` binary
+///
+/// Points `XDG_CONFIG_HOME` at a missing directory so a user's config file
+/// does not change the results.
+fn rjtop_cli() -> Command {
+    let mut command = Command::new(cli_binary());
+    command.env(
+        "XDG_CONFIG_HOME",
+        std::env::temp_dir().join("rjtop-cli-no-config"),
+    );
+    command
+}
+
+/// `rjtop-cli` reading `config` as its configuration file
+fn rjtop_cli_with_config(name: &str, config: &str) -> Command {
+    let dir = scratch_dir(name);
+    std::fs::create_dir_all(dir.join("rusted-jetsons")).unwrap();
+    std::fs::write(dir.join("rusted-jetsons").join("config.toml"), config).unwrap();
+
+    let mut command = Command::new(cli_binary());
+    command.env("XDG_CONFIG_HOME", &dir);
+    command
+}
+
+#[test]
+fn test_cli_binary_path_resolution() {
+    let path = cli_binary();
+
+    assert!(path.is_absolute(), "Binary path should be absolute");
+    assert!(path.is_file(), "Binary should already be built");
+    assert_eq!(
+        path.file_stem().and_then(|s| s.to_str()),
+        Some("rjtop-cli"),
+        "Should resolve the rjtop-cli binary"
+    );
+}
+
 #[test]
 fn test_cli_stats_json_output() {
-    let output = Command::new("cargo")
-        .args(["run", "--bin", "rjtop-cli", "--", "--stats"])
+    let output = rjtop_cli()
+        .arg("--stats")
         .output()
         .expect("Failed to execute rjtop-cli");
 
@@ -22,10 +65,214 @@ fn test_cli_stats_json_output() {
     assert!(json.get("cpu").is_some(), "Should have CPU stats");
 }
 
+#[test]
+fn test_cli_stats_schema() {
+    let output = rjtop_cli()
+        .arg("--stats")
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(output.status.success(), "CLI should exit with success");
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+
+    assert_eq!(json["schema_version"], rusted_jetsons::SCHEMA_VERSION);
+    for key in [
+        "temperature_unit",
+        "hardware",
+        "cpu",
+        "gpu",
+        "memory",
+        "temperature",
+        "fan",
+        "power",
+        "pressure",
+        "disk",
+        "network",
+        "engine",
+        "processes",
+    ] {
+        assert!(json.get(key).is_some(), "Should have {} key", key);
+    }
+}
+
+#[test]
+fn test_cli_stats_reports_is_jetson() {
+    let output = rjtop_cli()
+        .arg("--stats")
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(output.status.success(), "CLI should degrade, not fail");
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    assert_eq!(
+        json["hardware"]["is_jetson"],
+        rusted_jetsons::modules::hardware::is_jetson()
+    );
+}
+
+#[test]
+fn test_cli_strict() {
+    let output = rjtop_cli()
+        .args(["--stats", "--strict"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    if rusted_jetsons::modules::hardware::is_jetson() {
+        assert!(output.status.success(), "CLI should exit with success");
+    } else {
+        assert!(
+            !output.status.success(),
+            "--strict should exit nonzero when not on a Jetson"
+        );
+        assert!(output.stdout.is_empty(), "Nothing should go to stdout");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("not running on a Jetson"));
+    }
+}
+
+#[test]
+fn test_cli_stats_camel_case() {
+    let output = rjtop_cli()
+        .args(["--stats", "--camel-case"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(output.status.success(), "CLI should exit with success");
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+
+    let memory = &json["memory"];
+    assert!(
+        memory.get("ramUsed").is_some(),
+        "ram_used should become ramUsed"
+    );
+    assert!(memory.get("ram_used").is_none(), "No snake_case keys left");
+}
+
+/// Empty scratch directory for a test
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rjtop-cli-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn test_cli_snapshot_json() {
+    let dir = scratch_dir("snapshot-json");
+    let path = dir.join("stats.json");
+
+    let output = rjtop_cli()
+        .arg("--snapshot")
+        .arg(&path)
+        .output()
+        .expect("Failed to execute rjtop-cli");
+    assert!(output.status.success(), "CLI should exit with success");
+
+    let report: rusted_jetsons::StatsReport =
+        serde_json::from_slice(&std::fs::read(&path).unwrap()).expect("Snapshot should parse");
+    assert_eq!(report.schema_version, rusted_jetsons::SCHEMA_VERSION);
+    // Only the snapshot is left behind, no temporary file
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_cli_snapshot_csv() {
+    let dir = scratch_dir("snapshot-csv");
+    let path = dir.join("stats.csv");
+
+    let output = rjtop_cli()
+        .arg("--snapshot")
+        .arg(&path)
+        .output()
+        .expect("Failed to execute rjtop-cli");
+    assert!(output.status.success(), "CLI should exit with success");
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    let lines: Vec<&str> = contents.lines().collect();
+    assert_eq!(lines.len(), 2, "Header and a single row");
+    assert!(lines[0].starts_with("timestamp,cpu_usage"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_cli_snapshot_missing_directory() {
+    let path = std::env::temp_dir()
+        .join(format!("rjtop-cli-missing-{}", std::process::id()))
+        .join("stats.json");
+
+    let output = rjtop_cli()
+        .arg("--snapshot")
+        .arg(&path)
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Error writing snapshot"));
+}
+
+#[test]
+fn test_cli_camel_case_requires_stats() {
+    let output = rjtop_cli()
+        .arg("--camel-case")
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(
+        !output.status.success(),
+        "--camel-case alone should be rejected"
+    );
+}
+
+#[test]
+fn test_cli_fan_auto_conflicts_with_fan() {
+    let output = rjtop_cli()
+        .args(["--fan", "50", "--fan-auto"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    // clap reports usage errors with exit code 2
+    assert_eq!(
+        output.status.code(),
+        Some(2),
+        "--fan and --fan-auto should be mutually exclusive"
+    );
+    assert!(String::from_utf8_lossy(&output.stderr).contains("cannot be used with"));
+}
+
+#[test]
+fn test_cli_fan_index_requires_fan() {
+    let output = rjtop_cli()
+        .args(["--fan-index", "1"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(
+        !output.status.success(),
+        "--fan-index without --fan should be rejected"
+    );
+}
+
+#[test]
+fn test_cli_fan_curve_rejects_invalid_curve() {
+    let output = rjtop_cli()
+        .args(["--fan-curve", "40:20,60:150"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid fan curve"));
+}
+
 #[test]
 fn test_cli_fan_speed_command() {
-    let output = Command::new("cargo")
-        .args(["run", "--bin", "rjtop-cli", "--", "--fan", "50"])
+    let output = rjtop_cli()
+        .args(["--fan", "50"])
         .output()
         .expect("Failed to execute rjtop-cli");
 
@@ -49,8 +296,8 @@ fn test_cli_fan_speed_command() {
 
 #[test]
 fn test_cli_fan_speed_validation() {
-    let output = Command::new("cargo")
-        .args(["run", "--bin", "rjtop-cli", "--", "--fan", "101"])
+    let output = rjtop_cli()
+        .args(["--fan", "101"])
         .output()
         .expect("Failed to execute rjtop-cli");
 
@@ -68,8 +315,8 @@ fn test_cli_fan_speed_validation() {
 
 #[test]
 fn test_cli_nvpmodel_command() {
-    let output = Command::new("cargo")
-        .args(["run", "--bin", "rjtop-cli", "--", "--nvpmodel", "0"])
+    let output = rjtop_cli()
+        .args(["--nvpmodel", "0"])
         .output()
         .expect("Failed to execute rjtop-cli");
 
@@ -91,8 +338,8 @@ fn test_cli_nvpmodel_command() {
 
 #[test]
 fn test_cli_nvpmodel_invalid_id() {
-    let output = Command::new("cargo")
-        .args(["run", "--bin", "rjtop-cli", "--", "--nvpmodel", "16"])
+    let output = rjtop_cli()
+        .args(["--nvpmodel", "16"])
         .output()
         .expect("Failed to execute rjtop-cli");
 
@@ -102,10 +349,45 @@ fn test_cli_nvpmodel_invalid_id() {
     );
 }
 
+#[test]
+fn test_cli_governor_rejects_unknown_governor() {
+    let output = rjtop_cli()
+        .args(["--governor", "no-such-governor"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Error setting CPU governor"));
+}
+
+#[test]
+fn test_cli_gpu_governor_rejects_unknown_governor() {
+    let output = rjtop_cli()
+        .args(["--gpu-governor", "no-such-governor"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Error setting GPU governor"));
+}
+
+#[test]
+fn test_cli_clocks_restore_rejects_missing_file() {
+    let output = rjtop_cli()
+        .args(["--clocks-restore", "/nonexistent/rjtop-clocks.conf"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("Error restoring jetson_clocks settings")
+    );
+}
+
 #[test]
 fn test_cli_jetson_clocks_toggle() {
-    let output = Command::new("cargo")
-        .args(["run", "--bin", "rjtop-cli", "--", "--jetson-clocks"])
+    let output = rjtop_cli()
+        .arg("--jetson-clocks")
         .output()
         .expect("Failed to execute rjtop-cli");
 
@@ -127,17 +409,8 @@ fn test_cli_jetson_clocks_toggle() {
 
 #[test]
 fn test_cli_export_otlp_endpoint() {
-    let output = Command::new("cargo")
-        .args([
-            "run",
-            "--bin",
-            "rjtop-cli",
-            "--",
-            "--export",
-            "otlp",
-            "--endpoint",
-            "http://localhost:4318",
-        ])
+    let output = rjtop_cli()
+        .args(["--export", "otlp", "--endpoint", "http://localhost:4318"])
         .output()
         .expect("Failed to execute rjtop-cli");
 
@@ -160,12 +433,46 @@ fn test_cli_export_otlp_endpoint() {
 }
 
 #[test]
-fn test_cli_no_arguments() {
-    let output = Command::new("cargo")
-        .args(["run", "--bin", "rjtop-cli"])
+fn test_cli_export_influxdb_unreachable() {
+    // Nothing listens on port 1, so the export fails with or without the feature
+    let output = rjtop_cli()
+        .args([
+            "--export",
+            "influxdb",
+            "--endpoint",
+            "http://127.0.0.1:1?bucket=jetson",
+            "--token",
+            "secret",
+        ])
         .output()
         .expect("Failed to execute rjtop-cli");
 
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stdout.contains("InfluxDB endpoint"));
+    assert!(stderr.contains("InfluxDB"));
+}
+
+#[test]
+fn test_cli_export_invalid_type() {
+    let output = rjtop_cli()
+        .args(["--export", "graphite"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(!output.status.success(), "Unknown export type should fail");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("otlp, prometheus, influxdb"),
+        "Should list supported export types"
+    );
+}
+
+#[test]
+fn test_cli_no_arguments() {
+    let output = rjtop_cli().output().expect("Failed to execute rjtop-cli");
+
     let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(
         stdout.contains("rjtop") || stdout.contains("help") || stdout.contains("usage"),
@@ -175,8 +482,8 @@ fn test_cli_no_arguments() {
 
 #[test]
 fn test_cli_help_flag() {
-    let output = Command::new("cargo")
-        .args(["run", "--bin", "rjtop-cli", "--", "--help"])
+    let output = rjtop_cli()
+        .arg("--help")
         .output()
         .expect("Failed to execute rjtop-cli");
 
@@ -195,3 +502,339 @@ fn test_cli_help_flag() {
         "Should mention --jetson-clocks flag"
     );
 }
+
+#[test]
+fn test_cli_stats_watch_ndjson() {
+    let output = rjtop_cli()
+        .args(["--stats", "--watch", "0", "--count", "2"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(output.status.success(), "CLI should exit with success");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2, "Should print one line per refresh");
+    for line in lines {
+        let json: serde_json::Value = serde_json::from_str(line).expect("Each line should be JSON");
+        assert!(
+            json.get("cpu").is_some(),
+            "Each object should have cpu stats"
+        );
+    }
+}
+
+#[test]
+fn test_cli_stats_csv() {
+    let output = rjtop_cli()
+        .args(["--stats", "--format", "csv"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(output.status.success(), "CLI should exit with success");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(
+        lines[0],
+        "timestamp,cpu_usage,gpu_usage,gpu_temp,ram_used,ram_total,power_total,fan_speed"
+    );
+    assert_eq!(lines.len(), 2, "Should print the header and one row");
+    assert_eq!(lines[1].split(',').count(), 8, "Row should match header");
+}
+
+#[test]
+fn test_cli_stats_watch_csv_single_header() {
+    let output = rjtop_cli()
+        .args(["--stats", "--watch", "0", "--count", "3", "--format", "csv"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(output.status.success(), "CLI should exit with success");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 4, "Should print the header and three rows");
+    assert!(lines[1..].iter().all(|l| !l.starts_with("timestamp")));
+}
+
+#[test]
+fn test_cli_watch_requires_stats() {
+    let output = rjtop_cli()
+        .args(["--watch", "1"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(
+        !output.status.success(),
+        "--watch without --stats should fail"
+    );
+}
+
+#[test]
+fn test_cli_gpu_only_single_refresh() {
+    let output = rjtop_cli()
+        .args([
+            "--gpu-only",
+            "--interval",
+            "0.1",
+            "--count",
+            "1",
+            "--no-color",
+        ])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(output.status.success(), "CLI should exit with success");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 1, "Should print exactly one refresh");
+    assert!(lines[0].contains("GPU"), "Line should contain GPU summary");
+    assert!(lines[0].contains("MHz"), "Line should contain GPU clock");
+    assert!(
+        !lines[0].contains('\x1b'),
+        "--no-color should disable ANSI codes"
+    );
+}
+
+#[test]
+fn test_cli_gpu_only_invalid_interval() {
+    let output = rjtop_cli()
+        .args(["--gpu-only", "--interval", "0"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(
+        !output.status.success(),
+        "CLI should reject a non-positive interval"
+    );
+}
+
+#[test]
+fn test_cli_board_only() {
+    let output = rjtop_cli()
+        .arg("--board-only")
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    if rusted_jetsons::modules::hardware::is_jetson() {
+        assert!(output.status.success(), "CLI should exit with success");
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert_eq!(stdout.lines().count(), 1, "Should print a single line");
+        assert!(!stdout.trim().is_empty(), "Board model should not be empty");
+    } else {
+        assert!(
+            !output.status.success(),
+            "CLI should exit nonzero when not on a Jetson"
+        );
+        assert!(output.stdout.is_empty(), "Nothing should go to stdout");
+    }
+}
+
+#[test]
+fn test_cli_model_id() {
+    let output = rjtop_cli()
+        .arg("--model-id")
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    if !rusted_jetsons::modules::hardware::is_jetson() {
+        assert!(
+            !output.status.success(),
+            "CLI should exit nonzero when not on a Jetson"
+        );
+        return;
+    }
+
+    if output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert_eq!(stdout.lines().count(), 1, "Should print a single line");
+        assert!(
+            stdout.trim().parse::<u8>().is_ok(),
+            "Model ID should be numeric"
+        );
+    }
+}
+
+#[test]
+fn test_cli_alert_temp_requires_cmd() {
+    let output = rjtop_cli()
+        .args(["--alert-temp", "90"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(
+        !output.status.success(),
+        "CLI should reject --alert-temp without --alert-cmd"
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--alert-cmd"),
+        "Should name the missing flag"
+    );
+}
+
+#[test]
+fn test_cli_alert_temp_not_reached() {
+    let output = rjtop_cli()
+        .args([
+            "--alert-temp",
+            "1000",
+            "--alert-cmd",
+            "echo fired",
+            "--count",
+            "1",
+        ])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(output.status.success(), "CLI should exit with success");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        !stdout.contains("fired"),
+        "Alert command should not run below the threshold"
+    );
+}
+
+#[test]
+fn test_cli_health_check_exit_code() {
+    let output = rjtop_cli()
+        .arg("--health-check")
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    let code = output.status.code().expect("CLI should exit normally");
+    assert!((0..=2).contains(&code), "Exit code should be 0, 1 or 2");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.lines().count(), 1, "Should print a single line");
+    let expected = ["OK: ", "WARNING: ", "CRITICAL: "][code as usize];
+    assert!(
+        stdout.starts_with(expected),
+        "Status should match exit code"
+    );
+}
+
+#[test]
+fn test_cli_health_check_inconsistent_thresholds() {
+    let output = rjtop_cli()
+        .args(["--health-check", "--warn-temp", "90", "--crit-temp", "80"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert_eq!(output.status.code(), Some(3));
+}
+
+#[test]
+fn test_cli_config_thresholds() {
+    let config = "warn_temp = 90.0\ncrit_temp = 80.0\n";
+
+    let output = rjtop_cli_with_config("config-thresholds", config)
+        .arg("--health-check")
+        .output()
+        .expect("Failed to execute rjtop-cli");
+    assert_eq!(
+        output.status.code(),
+        Some(3),
+        "Thresholds should come from the config file"
+    );
+}
+
+#[test]
+fn test_cli_flags_override_config() {
+    let config = "warn_temp = 90.0\ncrit_temp = 80.0\n";
+
+    let output = rjtop_cli_with_config("config-override", config)
+        .args(["--health-check", "--warn-temp", "70"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+    let code = output.status.code().expect("CLI should exit normally");
+    assert!(
+        (0..=2).contains(&code),
+        "--warn-temp should override the config file"
+    );
+}
+
+#[test]
+fn test_cli_invalid_config() {
+    let output = rjtop_cli_with_config("config-invalid", "tick_ms = \"fast\"\n")
+        .args(["--fan-curve", "--count", "1"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(
+        !output.status.success(),
+        "Invalid config should be an error"
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("config.toml"), "Error should name the file");
+}
+
+#[test]
+fn test_cli_invalid_config_only_fails_commands_using_it() {
+    let config = "tick_ms = \"fast\"\n";
+
+    let output = rjtop_cli_with_config("config-invalid-stats", config)
+        .arg("--stats")
+        .output()
+        .expect("Failed to execute rjtop-cli");
+    assert!(output.status.success(), "--stats does not read the config");
+
+    let output = rjtop_cli_with_config("config-invalid-health", config)
+        .arg("--health-check")
+        .output()
+        .expect("Failed to execute rjtop-cli");
+    assert_eq!(output.status.code(), Some(3));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("UNKNOWN"), "Got: {}", stdout);
+
+    let output = rjtop_cli_with_config("config-invalid-board", config)
+        .arg("--board-only")
+        .output()
+        .expect("Failed to execute rjtop-cli");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!stderr.contains("config.toml"), "Got: {}", stderr);
+}
+
+#[test]
+fn test_cli_fan_curve_without_value_needs_config() {
+    let output = rjtop_cli()
+        .args(["--fan-curve", "--count", "1"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("fan_curve"));
+}
+
+#[test]
+fn test_cli_swaps() {
+    let output = rjtop_cli()
+        .arg("--swaps")
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(output.status.success(), "CLI should exit with success");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let devices: serde_json::Value =
+        serde_json::from_str(&stdout).expect("--swaps should print JSON");
+    assert!(devices.is_array(), "--swaps should print a JSON array");
+}
+
+#[test]
+fn test_cli_swap_create_rejects_zero() {
+    let output = rjtop_cli()
+        .args(["--swap-create", "0"])
+        .output()
+        .expect("Failed to execute rjtop-cli");
+
+    assert!(
+        !output.status.success(),
+        "--swap-create 0 should be rejected"
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--swap-create"));
+}
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Health check integration tests
+//!
+//! Fixtures under `tests/fixtures/health` hold captured temperature and
+//! memory statistics for each condition.
+
+use std::path::Path;
+
+use rusted_jetsons::{
+    health_summary, HealthStatus, HealthSummary, HealthThresholds, MemoryStats, TemperatureStats,
+};
+
+#[derive(serde::Deserialize)]
+struct Fixture {
+    temperature: TemperatureStats,
+    memory: MemoryStats,
+}
+
+fn summary_for(name: &str, thresholds: &HealthThresholds) -> HealthSummary {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/health")
+        .join(format!("{}.json", name));
+    let content = std::fs::read_to_string(&path).expect("Failed to read fixture");
+    let fixture: Fixture = serde_json::from_str(&content).expect("Invalid fixture");
+
+    health_summary(&fixture.temperature, &fixture.memory, thresholds)
+}
+
+#[test]
+fn test_health_ok_exit_code() {
+    let summary = summary_for("ok", &HealthThresholds::default());
+
+    assert_eq!(summary.status, HealthStatus::Ok);
+    assert_eq!(summary.status.exit_code(), 0);
+}
+
+#[test]
+fn test_health_warn_temperature_exit_code() {
+    let summary = summary_for("warn_temperature", &HealthThresholds::default());
+
+    assert_eq!(summary.status.exit_code(), 1);
+    assert_eq!(summary.to_string(), "WARNING: gpu-thermal at 86.5°C");
+}
+
+#[test]
+fn test_health_critical_temperature_exit_code() {
+    let summary = summary_for("critical_temperature", &HealthThresholds::default());
+
+    assert_eq!(summary.status.exit_code(), 2);
+    assert!(summary.reason.contains("cpu-thermal"));
+}
+
+#[test]
+fn test_health_critical_memory_exit_code() {
+    let summary = summary_for("critical_memory", &HealthThresholds::default());
+
+    assert_eq!(summary.status.exit_code(), 2);
+    assert!(summary.reason.starts_with("RAM at"));
+}
+
+#[test]
+fn test_health_temperature_overrides() {
+    let thresholds = HealthThresholds {
+        warn_temp: 45.0,
+        crit_temp: 60.0,
+        ..Default::default()
+    };
+
+    assert_eq!(summary_for("ok", &thresholds).status, HealthStatus::Warn);
+    assert_eq!(
+        summary_for("warn_temperature", &thresholds).status,
+        HealthStatus::Critical
+    );
+}
@@ -3,30 +3,248 @@
 
 //! TUI application structure
 
-use std::io;
-use std::sync::mpsc;
-use std::time::{Duration, Instant};
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+use std::sync::{mpsc, Arc, Once};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use crossterm::{
     event::{
         self, DisableMouseCapture, EnableMouseCapture, Event as CEvent, KeyCode, KeyEventKind,
+        MouseButton, MouseEvent, MouseEventKind,
     },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{
     backend::{Backend, CrosstermBackend},
-    Terminal,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame, Terminal,
 };
 
 use crate::tui::screens::{
-    AllScreen, ControlScreen, CpuScreen, GpuScreen, GpuScreenStats, InfoScreen, JetsonStats,
-    MemoryScreen, PowerScreen, SimpleBoardInfo, SimpleCpuStats, SimpleFanStats, SimpleGpuStats,
-    SimpleMemoryStats, SimplePowerStats, SimpleTemperatureStats, TemperatureScreen,
+    AllScreen, ControlScreen, CpuScreen, EngineScreen, GpuScreen, GpuScreenStats, GpuThermalLimits,
+    InfoScreen, JetsonStats, MemoryScreen, PowerScreen, ProcessScreen, SimpleBoardInfo,
+    SimpleCpuStats, SimpleFanStats, SimpleGpuStats, SimpleMemoryStats, SimplePowerStats,
+    SimpleTemperatureStats, TemperatureScreen,
 };
 use crate::tui::state::{ScreenState, StateMessage};
+use crate::tui::widgets::tabs::TabBar;
+
+use crate::modules::collector::Collector;
+use crate::modules::config::Config;
+use crate::modules::hardware::{detect_board, BoardInfo};
+use crate::modules::jetson_clocks::{JetsonClocksStats, JetsonClocksStatus};
+use crate::modules::TemperatureUnit;
+use crate::modules::{cpu, engine, fan, gpu, memory, nvpmodel, power, processes, temperature};
+
+/// How long a status message stays in the footer
+const STATUS_MESSAGE_DURATION: Duration = Duration::from_secs(5);
+
+/// Banner shown when the board is not detected as a Jetson
+const NOT_JETSON_BANNER: &str = "Not a Jetson — values may be unavailable";
+
+/// Default refresh interval in milliseconds
+pub const DEFAULT_TICK_RATE_MS: u64 = crate::modules::config::DEFAULT_TICK_MS;
+/// Fastest refresh interval in milliseconds
+pub const MIN_TICK_RATE_MS: u64 = 100;
+/// Slowest refresh interval in milliseconds
+pub const MAX_TICK_RATE_MS: u64 = 5000;
+
+/// Clamp a refresh interval to the supported range
+///
+/// A zero interval would turn the event poll into a busy loop.
+fn clamp_tick_rate(ms: u64) -> Duration {
+    Duration::from_millis(ms.clamp(MIN_TICK_RATE_MS, MAX_TICK_RATE_MS))
+}
+
+/// Next refresh interval for the `+` (slower) and `-` (faster) keys
+///
+/// Steps by 100ms up to one second and by 500ms above it.
+fn step_tick_rate(current: Duration, slower: bool) -> Duration {
+    let ms = current.as_millis() as u64;
+    let next = if slower {
+        ms + if ms < 1000 { 100 } else { 500 }
+    } else {
+        ms.saturating_sub(if ms <= 1000 { 100 } else { 500 })
+    };
+    clamp_tick_rate(next)
+}
 
-use crate::modules::{cpu, fan, gpu, memory, power, temperature};
+/// Transient message shown over the footer (e.g. snapshot confirmation)
+struct StatusMessage {
+    text: String,
+    is_error: bool,
+    shown_at: Instant,
+}
+
+/// Build the GPU screen stats, including the GPU zone trip points
+fn gpu_screen_stats(
+    full_gpu: &gpu::GpuStats,
+    temperatures: &temperature::TemperatureStats,
+    power: &power::PowerStats,
+    cores: Option<gpu::GpuCores>,
+) -> GpuScreenStats {
+    GpuScreenStats {
+        gpu: SimpleGpuStats {
+            usage: full_gpu.usage,
+            frequency: full_gpu.frequency,
+        },
+        temperature: SimpleTemperatureStats {
+            gpu: full_gpu.temperature,
+            ..SimpleTemperatureStats::from_stats(temperatures)
+        },
+        gpu_name: gpu::GpuStats::name().to_string(),
+        gpu_arch: gpu::GpuStats::arch().to_string(),
+        memory_used: full_gpu.memory_used,
+        memory_total: full_gpu.memory_total,
+        state: full_gpu.state.clone(),
+        governor: full_gpu.governor.clone(),
+        functions: full_gpu.functions.clone(),
+        cores,
+        thermal_limits: GpuThermalLimits::from_stats(temperatures),
+        power: power.gpu_power(),
+    }
+}
+
+/// Module readers used once per tick
+///
+/// Lets tests count reads without touching sysfs.
+trait StatsSource {
+    fn cpu(&mut self) -> cpu::CpuStats;
+    fn core_count(&mut self) -> usize;
+    fn gpu(&mut self) -> Arc<gpu::GpuStats>;
+    fn memory(&mut self) -> memory::MemoryStats;
+    fn fan(&mut self, temperatures: &temperature::TemperatureStats) -> fan::FanStats;
+    fn temperature(&mut self) -> temperature::TemperatureStats;
+    fn power(&mut self) -> power::PowerStats;
+    fn power_supply(&mut self) -> (power::PowerSource, Option<u8>);
+    fn engines(&mut self) -> engine::EngineStats;
+    fn processes(&mut self) -> processes::ProcessStats;
+    fn clocks(&mut self) -> JetsonClocksStatus;
+    fn nvpmodel(&mut self) -> Option<nvpmodel::NVPModelQuery>;
+    fn board(&mut self) -> BoardInfo;
+}
+
+/// Reads the hardware, keeping CPU and power deltas in the app's monitors
+struct LiveSource<'a> {
+    cpu_monitor: &'a mut cpu::CpuMonitor,
+    power_monitor: &'a mut power::PowerMonitor,
+}
+
+impl StatsSource for LiveSource<'_> {
+    fn cpu(&mut self) -> cpu::CpuStats {
+        self.cpu_monitor.get_stats()
+    }
+
+    fn core_count(&mut self) -> usize {
+        cpu::get_core_count()
+    }
+
+    fn gpu(&mut self) -> Arc<gpu::GpuStats> {
+        gpu::GpuStats::get_cached()
+    }
+
+    fn memory(&mut self) -> memory::MemoryStats {
+        memory::MemoryStats::get()
+    }
+
+    fn fan(&mut self, temperatures: &temperature::TemperatureStats) -> fan::FanStats {
+        fan::FanStats::with_temperature(temperatures)
+    }
+
+    fn temperature(&mut self) -> temperature::TemperatureStats {
+        temperature::TemperatureStats::get()
+    }
+
+    fn power(&mut self) -> power::PowerStats {
+        self.power_monitor.collect()
+    }
+
+    fn power_supply(&mut self) -> (power::PowerSource, Option<u8>) {
+        power::power_supply()
+    }
+
+    fn engines(&mut self) -> engine::EngineStats {
+        engine::EngineStats::get()
+    }
+
+    fn processes(&mut self) -> processes::ProcessStats {
+        processes::ProcessStats::get()
+    }
+
+    fn clocks(&mut self) -> JetsonClocksStatus {
+        JetsonClocksStats::status()
+    }
+
+    fn nvpmodel(&mut self) -> Option<nvpmodel::NVPModelQuery> {
+        nvpmodel::NVPModelStats::query()
+    }
+
+    fn board(&mut self) -> BoardInfo {
+        detect_board()
+    }
+}
+
+/// Everything one tick displays, read exactly once so a frame is consistent
+struct TickReadings {
+    cpu: cpu::CpuStats,
+    core_count: usize,
+    gpu: Arc<gpu::GpuStats>,
+    memory: memory::MemoryStats,
+    fan: fan::FanStats,
+    temperature: temperature::TemperatureStats,
+    power: power::PowerStats,
+    power_source: power::PowerSource,
+    battery_capacity: Option<u8>,
+    engines: engine::EngineStats,
+    processes: processes::ProcessStats,
+    clocks: JetsonClocksStatus,
+    nvpmodel: Option<nvpmodel::NVPModelQuery>,
+    board: BoardInfo,
+}
+
+impl TickReadings {
+    fn collect(source: &mut impl StatsSource) -> Self {
+        let temperature = source.temperature();
+        let (power_source, battery_capacity) = source.power_supply();
+
+        Self {
+            cpu: source.cpu(),
+            core_count: source.core_count(),
+            gpu: source.gpu(),
+            memory: source.memory(),
+            fan: source.fan(&temperature),
+            temperature,
+            power: source.power(),
+            power_source,
+            battery_capacity,
+            engines: source.engines(),
+            processes: source.processes(),
+            clocks: source.clocks(),
+            nvpmodel: source.nvpmodel(),
+            board: source.board(),
+        }
+    }
+}
+
+/// Serialize the current stats, engines and processes into a snapshot
+fn snapshot_json(
+    stats: &JetsonStats,
+    engines: &engine::EngineStats,
+    processes: &processes::ProcessStats,
+    timestamp: u64,
+) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(&serde_json::json!({
+        "timestamp": timestamp,
+        "stats": stats,
+        "engine": engines,
+        "processes": processes,
+    }))
+}
 
 /// Main TUI application
 pub struct TuiApp {
@@ -42,15 +260,55 @@ pub struct TuiApp {
     memory_screen: MemoryScreen,
     power_screen: PowerScreen,
     temperature_screen: TemperatureScreen,
+    engine_screen: EngineScreen,
+    process_screen: ProcessScreen,
     stats: Option<JetsonStats>,
     should_exit: bool,
     tick_rate: Duration,
     screen_changed: bool,
     cpu_monitor: cpu::CpuMonitor,
+    power_monitor: power::PowerMonitor,
+    status_message: Option<StatusMessage>,
+    show_help: bool,
+    mouse: bool,
+    /// Whether the board was detected as a Jetson; a banner warns otherwise
+    jetson: bool,
 }
 
 impl TuiApp {
-    pub fn new() -> anyhow::Result<Self> {
+    /// Create the app with the preferences from the configuration file
+    pub fn new() -> crate::Result<Self> {
+        Self::with_config(&Config::load()?)
+    }
+
+    /// Create the app with the refresh interval, temperature unit and
+    /// startup screen of `config`
+    pub fn with_config(config: &Config) -> crate::Result<Self> {
+        let screen = ScreenState::from_name(&config.default_screen).ok_or_else(|| {
+            crate::Error::InvalidArgument(format!(
+                "Unknown default_screen '{}'",
+                config.default_screen
+            ))
+        })?;
+
+        let mut app = Self::with_interval(config.tick_ms)?;
+        app.current_screen = screen;
+        app.set_temperature_unit(config.temp_unit);
+        Ok(app)
+    }
+
+    /// Create the app refreshing every `ms` milliseconds
+    ///
+    /// The interval is clamped to 100ms-5s.
+    ///
+    /// Fails with [`Error::NotATerminal`] when stdin or stdout is not a
+    /// terminal, e.g. when the output is piped.
+    ///
+    /// [`Error::NotATerminal`]: crate::Error::NotATerminal
+    pub fn with_interval(ms: u64) -> crate::Result<Self> {
+        check_terminal(io::stdin().is_terminal(), io::stdout().is_terminal())?;
+        install_sigint_handler();
+
         let (tx, rx) = mpsc::channel();
 
         // Enable raw mode and alternate screen
@@ -76,15 +334,39 @@ impl TuiApp {
             memory_screen: MemoryScreen::new(),
             power_screen: PowerScreen::new(),
             temperature_screen: TemperatureScreen::new(),
+            engine_screen: EngineScreen::new(),
+            process_screen: ProcessScreen::new(),
             stats: None,
             should_exit: false,
-            tick_rate: Duration::from_millis(250),
+            tick_rate: clamp_tick_rate(ms),
             screen_changed: false,
             cpu_monitor: cpu::CpuMonitor::new(),
+            power_monitor: power::PowerMonitor::new(),
+            status_message: None,
+            show_help: false,
+            mouse: false,
+            jetson: crate::modules::hardware::is_jetson(),
         })
     }
 
-    pub fn run(&mut self) -> anyhow::Result<()> {
+    /// Capture the mouse so tabs can be clicked
+    ///
+    /// Off by default since capturing breaks terminal copy/paste.
+    pub fn enable_mouse(&mut self) -> crate::Result<()> {
+        execute!(io::stdout(), EnableMouseCapture)?;
+        self.mouse = true;
+        Ok(())
+    }
+
+    /// Display temperatures in `unit` on every screen that shows them
+    pub fn set_temperature_unit(&mut self, unit: TemperatureUnit) {
+        self.all_screen.set_temperature_unit(unit);
+        self.cpu_screen.set_temperature_unit(unit);
+        self.gpu_screen.set_temperature_unit(unit);
+        self.temperature_screen.set_temperature_unit(unit);
+    }
+
+    pub fn run(&mut self) -> crate::Result<()> {
         let mut last_tick = Instant::now();
 
         // Initial draw (loading screen)
@@ -125,8 +407,13 @@ impl TuiApp {
                 .unwrap_or_else(|| Duration::from_secs(0));
 
             if event::poll(timeout)? {
-                if let CEvent::Key(key) = event::read()? {
-                    self.handle_key(key)?;
+                match event::read()? {
+                    CEvent::Key(key) => self.handle_key(key)?,
+                    CEvent::Mouse(mouse) if self.mouse => self.handle_mouse(mouse)?,
+                    // Reflow right away instead of waiting for the next tick;
+                    // every screen recomputes its layout from the frame size
+                    CEvent::Resize(_, _) => self.screen_changed = true,
+                    _ => {}
                 }
             }
 
@@ -153,49 +440,119 @@ impl TuiApp {
     }
 
     fn tick(&mut self) {
-        // Get CPU stats once using the monitor (for delta-based usage calculation)
-        let full_cpu = self.cpu_monitor.get_stats();
+        let readings = TickReadings::collect(&mut LiveSource {
+            cpu_monitor: &mut self.cpu_monitor,
+            power_monitor: &mut self.power_monitor,
+        });
+        self.apply(readings);
+    }
 
-        // Collect real stats from modules (passing cpu_stats to avoid double-reading)
-        let stats = self.collect_stats_with_cpu(full_cpu.clone());
+    /// Update every screen from one tick's readings, without reading sysfs
+    fn apply(&mut self, readings: TickReadings) {
+        let TickReadings {
+            cpu: full_cpu,
+            core_count,
+            gpu: full_gpu,
+            memory: full_memory,
+            fan: full_fan,
+            temperature: full_temperature,
+            power: full_power,
+            power_source,
+            battery_capacity,
+            engines,
+            processes,
+            clocks,
+            nvpmodel,
+            board,
+        } = readings;
+
+        let stats = JetsonStats {
+            cpu: SimpleCpuStats {
+                usage: full_cpu.usage,
+                frequency: full_cpu.frequency,
+                governor: full_cpu
+                    .cores
+                    .first()
+                    .map(|c| c.governor.clone())
+                    .unwrap_or_else(|| "unknown".to_string()),
+            },
+            gpu: SimpleGpuStats {
+                usage: full_gpu.usage,
+                frequency: full_gpu.frequency,
+            },
+            memory: SimpleMemoryStats {
+                ram_used: full_memory.ram_used,
+                ram_total: full_memory.ram_total,
+                swap_used: full_memory.swap_used,
+                swap_total: full_memory.swap_total,
+            },
+            fan: SimpleFanStats {
+                speed: full_fan.speed,
+            },
+            temperature: SimpleTemperatureStats::from_stats(&full_temperature),
+            power: SimplePowerStats {
+                total: full_power.total,
+            },
+            board: SimpleBoardInfo {
+                model: board.model,
+                jetpack: board.jetpack,
+                l4t: board.l4t,
+                serial: board.serial,
+                part_number: board.part_number,
+                board_ids: board.board_ids,
+            },
+        };
         self.stats = Some(stats.clone());
 
         // Update all screens with current stats
         self.all_screen.update(stats.clone());
 
         // Update control screen with control-specific stats
+        let nvpmodel = nvpmodel.unwrap_or_default();
         let control_stats = crate::tui::screens::ControlStats {
-            fan_speed: fan::FanStats::get().speed,
-            fan_mode: "Auto".to_string(),
-            jetson_clocks: false,
-            jetson_clocks_status: "inactive".to_string(),
-            nvpmodel_id: 0,
-            nvpmodel_name: "MAXN".to_string(),
+            fan_speed: full_fan.speed,
+            fan_mode: full_fan.mode.to_string(),
+            jetson_clocks: clocks.is_active().unwrap_or(false),
+            jetson_clocks_status: clocks.to_string(),
+            nvpmodel_id: nvpmodel.id,
+            nvpmodel_name: nvpmodel.name,
         };
         self.control_screen.update(control_stats);
 
         // Update info screen with hardware info
-        let cpu_cores = cpu::get_core_count();
-        let cpu_governor = full_cpu
-            .cores
-            .first()
-            .map(|c| c.governor.clone())
-            .unwrap_or_else(|| "unknown".to_string());
+        let cpu_clusters = full_cpu.cluster_frequencies();
+        let gpu_cores = gpu::nominal_gpu_cores(&stats.board.model);
 
         let info_stats = crate::tui::screens::InfoStats {
             board: stats.board,
-            cpu_cores,
-            cpu_governor,
-            gpu_name: "NVIDIA GPU".to_string(),
+            cpu_cores: core_count,
+            cpu_governor: stats.cpu.governor.clone(),
+            cpu_clusters: cpu_clusters.clone(),
+            gpu_name: gpu::GpuStats::name().to_string(),
+            gpu_arch: gpu::GpuStats::arch().to_string(),
+            gpu_cores,
         };
         self.info_screen.update(info_stats);
 
-        // Update CPU screen with detailed stats (using full_cpu from cpu_monitor above)
+        // Update engine screen with accelerator stats
+        self.engine_screen.update(engines);
+
+        // Update process screen with GPU processes
+        self.process_screen.update(processes);
+
+        // Update CPU screen with detailed stats
         let cpu_screen_stats = crate::tui::screens::CpuScreenStats {
             overall: SimpleCpuStats {
                 usage: full_cpu.usage,
-                frequency: full_cpu.cores.first().map(|c| c.frequency).unwrap_or(0),
+                frequency: full_cpu.frequency,
+                governor: stats.cpu.governor.clone(),
             },
+            usage_history: self
+                .cpu_monitor
+                .usage_history()
+                .iter()
+                .map(|&usage| usage as u64)
+                .collect(),
             cores: full_cpu
                 .cores
                 .into_iter()
@@ -204,56 +561,36 @@ impl TuiApp {
                     usage: c.usage,
                     frequency: c.frequency,
                     governor: c.governor.clone(),
+                    online: c.online,
+                    temperature: c.temperature,
                 })
                 .collect(),
+            clusters: cpu_clusters,
             fan: SimpleFanStats {
-                speed: fan::FanStats::get().speed,
+                speed: full_fan.speed,
             },
             temperature: SimpleTemperatureStats {
-                cpu: temperature::TemperatureStats::get().cpu,
-                gpu: temperature::TemperatureStats::get().gpu,
-                board: temperature::TemperatureStats::get().board,
+                cpu: full_temperature.cpu,
+                gpu: full_temperature.gpu,
+                board: full_temperature.board,
+                ..Default::default()
             },
         };
         self.cpu_screen.update(cpu_screen_stats);
 
         // Update GPU screen with detailed stats
-        let full_gpu = gpu::GpuStats::get();
-        let gpu_screen_stats = crate::tui::screens::GpuScreenStats {
-            gpu: SimpleGpuStats {
-                usage: full_gpu.usage,
-                frequency: full_gpu.frequency,
-            },
-            temperature: SimpleTemperatureStats {
-                cpu: temperature::TemperatureStats::get().cpu,
-                gpu: full_gpu.temperature,
-                board: temperature::TemperatureStats::get().board,
-            },
-            gpu_name: "NVIDIA GPU".to_string(),
-            gpu_arch: "Unknown".to_string(),
-            memory_used: full_gpu.memory_used,
-            memory_total: full_gpu.memory_total,
-            state: full_gpu.state.clone(),
-            governor: full_gpu.governor.clone(),
-            active_functions: full_gpu.active_functions.clone(),
-        };
+        let gpu_screen_stats =
+            gpu_screen_stats(&full_gpu, &full_temperature, &full_power, gpu_cores);
         self.gpu_screen.update(gpu_screen_stats);
 
         // Update Memory screen with detailed stats
-        let full_memory = memory::MemoryStats::get();
         let memory_screen_stats = crate::tui::screens::MemoryScreenStats {
-            memory: SimpleMemoryStats {
-                ram_used: full_memory.ram_used,
-                ram_total: full_memory.ram_total,
-                swap_used: full_memory.swap_used,
-                swap_total: full_memory.swap_total,
-            },
+            memory: stats.memory,
             full_memory,
         };
         self.memory_screen.update(memory_screen_stats);
 
         // Update Power screen with detailed stats
-        let full_power = power::PowerStats::get();
         let power_screen_stats = crate::tui::screens::PowerScreenStats {
             power: SimplePowerStats {
                 total: full_power.total,
@@ -266,19 +603,18 @@ impl TuiApp {
                     current: r.current,
                     voltage: r.voltage,
                     power: r.power,
+                    power_avg: r.power_avg,
+                    is_total: r.is_total,
                 })
                 .collect(),
+            source: power_source,
+            battery_capacity,
         };
         self.power_screen.update(power_screen_stats);
 
         // Update Temperature screen with detailed stats
-        let full_temperature = temperature::TemperatureStats::get();
         let temp_screen_stats = crate::tui::screens::TemperatureScreenStats {
-            temperature: SimpleTemperatureStats {
-                cpu: full_temperature.cpu,
-                gpu: full_temperature.gpu,
-                board: full_temperature.board,
-            },
+            temperature: SimpleTemperatureStats::from_stats(&full_temperature),
             zones: full_temperature
                 .thermal_zones
                 .into_iter()
@@ -287,70 +623,30 @@ impl TuiApp {
                     current_temp: z.current_temp,
                     max_temp: z.max_temp,
                     critical_temp: z.critical_temp,
-                    usage_percent: if z.critical_temp > 0.0 {
-                        ((z.current_temp / z.critical_temp) * 100.0) as u16
-                    } else {
-                        0
-                    },
+                    usage_percent: z.percent_to_critical().unwrap_or(0.0) as u16,
                 })
                 .collect(),
         };
         self.temperature_screen.update(temp_screen_stats);
     }
 
-    fn collect_stats_with_cpu(&self, cpu_stats: cpu::CpuStats) -> JetsonStats {
-        // Collect stats from hardware modules
-        use crate::modules::{fan, gpu, hardware, memory, power, temperature};
+    fn handle_key(&mut self, key: event::KeyEvent) -> crate::Result<()> {
+        if key.kind != KeyEventKind::Press {
+            return Ok(());
+        }
 
-        JetsonStats {
-            cpu: SimpleCpuStats {
-                usage: cpu_stats.usage,
-                frequency: cpu_stats
-                    .cores
-                    .first()
-                    .map(|c| c.frequency)
-                    .unwrap_or(0),
-            },
-            gpu: SimpleGpuStats {
-                usage: gpu::GpuStats::get().usage,
-                frequency: gpu::GpuStats::get().frequency,
-            },
-            memory: {
-                let mem = memory::MemoryStats::get();
-                SimpleMemoryStats {
-                    ram_used: mem.ram_used,
-                    ram_total: mem.ram_total,
-                    swap_used: mem.swap_used,
-                    swap_total: mem.swap_total,
-                }
-            },
-            fan: SimpleFanStats {
-                speed: fan::FanStats::get().speed,
-            },
-            temperature: {
-                let temp = temperature::TemperatureStats::get();
-                SimpleTemperatureStats {
-                    cpu: temp.cpu,
-                    gpu: temp.gpu,
-                    board: temp.board,
+        // The help overlay swallows keys until it is dismissed
+        if self.show_help {
+            match key.code {
+                KeyCode::Char('h') | KeyCode::Char('H') | KeyCode::Esc => {
+                    self.show_help = false;
+                    self.screen_changed = true;
                 }
-            },
-            power: SimplePowerStats {
-                total: power::PowerStats::get().total,
-            },
-            board: {
-                let hw = hardware::detect_board();
-                SimpleBoardInfo {
-                    model: hw.model,
-                    jetpack: hw.jetpack,
-                    l4t: hw.l4t,
+                KeyCode::Char('q') | KeyCode::Char('Q') => {
+                    self.should_exit = true;
                 }
-            },
-        }
-    }
-
-    fn handle_key(&mut self, key: event::KeyEvent) -> anyhow::Result<()> {
-        if key.kind != KeyEventKind::Press {
+                _ => {}
+            }
             return Ok(());
         }
 
@@ -390,37 +686,153 @@ impl TuiApp {
                 self.current_screen = ScreenState::Info;
                 self.screen_changed = true;
             }
+            KeyCode::Char('9') => {
+                self.current_screen = ScreenState::Engine;
+                self.screen_changed = true;
+            }
+            KeyCode::Char('0') => {
+                self.current_screen = ScreenState::Processes;
+                self.screen_changed = true;
+            }
+            KeyCode::Up if self.current_screen == ScreenState::Processes => {
+                self.process_screen.select_previous();
+                self.screen_changed = true;
+            }
+            KeyCode::Down if self.current_screen == ScreenState::Processes => {
+                self.process_screen.select_next();
+                self.screen_changed = true;
+            }
+            KeyCode::Char('h') | KeyCode::Char('H') => {
+                self.show_help = true;
+                self.screen_changed = true;
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.tick_rate = step_tick_rate(self.tick_rate, true);
+                self.screen_changed = true;
+            }
+            KeyCode::Char('-') => {
+                self.tick_rate = step_tick_rate(self.tick_rate, false);
+                self.screen_changed = true;
+            }
+            KeyCode::Char('s') | KeyCode::Char('S') => {
+                self.save_snapshot();
+                self.screen_changed = true;
+            }
             _ => {}
         }
 
         Ok(())
     }
 
-    fn draw(&mut self) -> anyhow::Result<()> {
-        self.terminal.draw(|f| match self.current_screen {
-            ScreenState::All => {
-                self.all_screen.draw(f);
-            }
-            ScreenState::Cpu => {
-                self.cpu_screen.draw(f);
-            }
-            ScreenState::Gpu => {
-                self.gpu_screen.draw(f);
-            }
-            ScreenState::Memory => {
-                self.memory_screen.draw(f);
-            }
-            ScreenState::Power => {
-                self.power_screen.draw(f);
+    fn handle_mouse(&mut self, mouse: MouseEvent) -> crate::Result<()> {
+        if self.show_help || mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+            return Ok(());
+        }
+
+        let size = self.terminal.size()?;
+        if let Some(screen) = TabBar::screen_at(size, mouse.column, mouse.row) {
+            self.current_screen = screen;
+            self.screen_changed = true;
+        }
+
+        Ok(())
+    }
+
+    /// Write the current stats to `./rjtop-snapshot-<ts>.json`
+    fn save_snapshot(&mut self) {
+        let Some(stats) = self.stats.as_ref() else {
+            self.set_status("Snapshot failed: no stats collected yet".to_string(), true);
+            return;
+        };
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let path = PathBuf::from(format!("rjtop-snapshot-{}.json", timestamp));
+
+        let (engines, processes) = (
+            self.engine_screen.stats().cloned().unwrap_or_default(),
+            self.process_screen.stats().cloned().unwrap_or_default(),
+        );
+        let result = snapshot_json(stats, &engines, &processes, timestamp)
+            .map_err(io::Error::from)
+            .and_then(|json| std::fs::write(&path, json));
+
+        match result {
+            Ok(()) => self.set_status(format!("Snapshot saved to {}", path.display()), false),
+            Err(e) => self.set_status(format!("Snapshot failed: {}", e), true),
+        }
+    }
+
+    fn set_status(&mut self, text: String, is_error: bool) {
+        self.status_message = Some(StatusMessage {
+            text,
+            is_error,
+            shown_at: Instant::now(),
+        });
+    }
+
+    fn draw(&mut self) -> crate::Result<()> {
+        if self
+            .status_message
+            .as_ref()
+            .is_some_and(|m| m.shown_at.elapsed() >= STATUS_MESSAGE_DURATION)
+        {
+            self.status_message = None;
+        }
+        let status = self.status_message.as_ref();
+        let show_help = self.show_help;
+        let tick_rate = self.tick_rate;
+        let jetson = self.jetson;
+        let tabs = TabBar::new(self.current_screen);
+
+        self.terminal.draw(|f| {
+            match self.current_screen {
+                ScreenState::All => {
+                    self.all_screen.draw(f);
+                }
+                ScreenState::Cpu => {
+                    self.cpu_screen.draw(f);
+                }
+                ScreenState::Gpu => {
+                    self.gpu_screen.draw(f);
+                }
+                ScreenState::Memory => {
+                    self.memory_screen.draw(f);
+                }
+                ScreenState::Power => {
+                    self.power_screen.draw(f);
+                }
+                ScreenState::Temperature => {
+                    self.temperature_screen.draw(f);
+                }
+                ScreenState::Control => {
+                    self.control_screen.draw(f);
+                }
+                ScreenState::Info => {
+                    self.info_screen.draw(f);
+                }
+                ScreenState::Engine => {
+                    self.engine_screen.draw(f);
+                }
+                ScreenState::Processes => {
+                    self.process_screen.draw(f);
+                }
             }
-            ScreenState::Temperature => {
-                self.temperature_screen.draw(f);
+
+            tabs.draw(f);
+            draw_interval(f, tick_rate);
+            if !jetson {
+                draw_not_jetson_banner(f);
             }
-            ScreenState::Control => {
-                self.control_screen.draw(f);
+
+            if let Some(status) = status {
+                draw_status_message(f, status);
             }
-            ScreenState::Info => {
-                self.info_screen.draw(f);
+
+            if show_help {
+                draw_help(f);
             }
         })?;
 
@@ -428,15 +840,167 @@ impl TuiApp {
     }
 }
 
+/// Draw a status message over the footer of the current screen
+fn draw_status_message(f: &mut Frame, status: &StatusMessage) {
+    let size = f.size();
+    let height = size.height.min(3);
+    let area = Rect::new(size.x, size.y + size.height - height, size.width, height);
+    let color = if status.is_error {
+        Color::Red
+    } else {
+        Color::Yellow
+    };
+
+    let paragraph = Paragraph::new(status.text.as_str())
+        .style(Style::default().fg(color))
+        .block(Block::default().borders(Borders::ALL))
+        .alignment(Alignment::Center);
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+/// Show the refresh interval on the bottom border of the footer
+fn draw_interval(f: &mut Frame, tick_rate: Duration) {
+    let size = f.size();
+    let text = format!(" {}ms ", tick_rate.as_millis());
+    let width = (text.chars().count() as u16).min(size.width.saturating_sub(2));
+    if size.height == 0 || width == 0 {
+        return;
+    }
+
+    let area = Rect::new(
+        size.x + size.width - width - 1,
+        size.y + size.height - 1,
+        width,
+        1,
+    );
+    let paragraph = Paragraph::new(text).style(Style::default().fg(Color::DarkGray));
+    f.render_widget(paragraph, area);
+}
+
+/// Warn under the tab bar that readings may be missing off a Jetson
+fn draw_not_jetson_banner(f: &mut Frame) {
+    let size = f.size();
+    if size.height < 4 {
+        return;
+    }
+
+    let area = Rect::new(size.x, size.y + 2, size.width, 1);
+    let paragraph = Paragraph::new(NOT_JETSON_BANNER)
+        .style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .alignment(Alignment::Center);
+    f.render_widget(paragraph, area);
+}
+
+/// Centered popup area taking the given percentage of `area`
+fn popup_area(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
+    let width = area.width * percent_x.min(100) / 100;
+    let height = area.height * percent_y.min(100) / 100;
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+/// Key bindings shown in the help overlay
+fn help_lines() -> Vec<Line<'static>> {
+    let key_style = Style::default().fg(Color::Cyan);
+    let binding = |key: String, action: String| {
+        Line::from(vec![
+            Span::styled(format!("{:>6}  ", key), key_style),
+            Span::raw(action),
+        ])
+    };
+
+    let mut lines = vec![Line::from(Span::styled(
+        "Screens",
+        Style::default().add_modifier(Modifier::BOLD),
+    ))];
+    for idx in 0..ScreenState::COUNT {
+        if let Some(state) = ScreenState::from_index(idx) {
+            // Screen 10 sits on the 0 key
+            lines.push(binding(
+                (state.index() % 10).to_string(),
+                state.name().to_string(),
+            ));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Keys",
+        Style::default().add_modifier(Modifier::BOLD),
+    )));
+    for (key, action) in [
+        ("↑/↓", "Scroll the process table"),
+        ("+/-", "Slower/faster refresh (100ms-5s)"),
+        ("s", "Save a JSON snapshot"),
+        ("h", "Toggle this help"),
+        ("q/Esc", "Quit (Esc closes help first)"),
+    ] {
+        lines.push(binding(key.to_string(), action.to_string()));
+    }
+
+    lines
+}
+
+/// Draw the help overlay on top of the current screen
+fn draw_help(f: &mut Frame) {
+    let area = popup_area(f.size(), 60, 90);
+    let paragraph = Paragraph::new(help_lines()).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Help (h/Esc to close)")
+            .border_style(Style::default().fg(Color::Yellow)),
+    );
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+/// Refuse to start the TUI unless both stdin and stdout are terminals
+fn check_terminal(stdin_tty: bool, stdout_tty: bool) -> crate::Result<()> {
+    if stdin_tty && stdout_tty {
+        Ok(())
+    } else {
+        Err(crate::Error::NotATerminal)
+    }
+}
+
+/// Restore the terminal and exit when SIGINT arrives
+///
+/// Raw mode turns Ctrl+C into a key event, so this covers signals sent from
+/// elsewhere, e.g. `kill -INT`. The handler runs on its own thread and so
+/// still works while the event loop is blocked.
+fn install_sigint_handler() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let _ = ctrlc::set_handler(|| {
+            restore_terminal();
+            std::process::exit(130);
+        });
+    });
+}
+
+/// Leave raw mode and the alternate screen and show the cursor again
+fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(
+        io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        crossterm::cursor::Show
+    );
+}
+
 impl Drop for TuiApp {
     fn drop(&mut self) {
-        let _ = disable_raw_mode();
-        let _ = execute!(
-            io::stdout(),
-            LeaveAlternateScreen,
-            DisableMouseCapture,
-            crossterm::cursor::Show
-        );
+        restore_terminal();
     }
 }
 
@@ -444,6 +1008,292 @@ impl Drop for TuiApp {
 mod tests {
     use super::*;
     use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+    use ratatui::backend::TestBackend;
+
+    fn sample_jetson_stats() -> JetsonStats {
+        JetsonStats {
+            cpu: SimpleCpuStats {
+                usage: 42.0,
+                frequency: 1_420_000,
+                governor: "schedutil".to_string(),
+            },
+            gpu: SimpleGpuStats {
+                usage: 63.0,
+                frequency: 918_000_000,
+            },
+            memory: SimpleMemoryStats {
+                ram_used: 3_000_000,
+                ram_total: 7_600_000,
+                swap_used: 100_000,
+                swap_total: 3_800_000,
+            },
+            fan: SimpleFanStats { speed: 40 },
+            temperature: SimpleTemperatureStats {
+                cpu: 48.5,
+                gpu: 47.0,
+                board: 41.0,
+                ..Default::default()
+            },
+            power: SimplePowerStats { total: 9.8 },
+            board: SimpleBoardInfo {
+                model: "NVIDIA Jetson Orin Nano".to_string(),
+                jetpack: "6.0".to_string(),
+                l4t: "36.3.0".to_string(),
+                serial: "1421222000123".to_string(),
+                part_number: "699-13767-0005-300 N.1".to_string(),
+                board_ids: vec!["3767-0005-300-N.1".to_string()],
+            },
+        }
+    }
+
+    /// Draw every screen, loading and with stats, on the given terminal
+    fn draw_all_screens(terminal: &mut Terminal<TestBackend>) {
+        let stats = sample_jetson_stats();
+
+        let mut all = AllScreen::new();
+        terminal.draw(|f| all.draw(f)).unwrap();
+        all.update(stats.clone());
+        terminal.draw(|f| all.draw(f)).unwrap();
+
+        let cores: Vec<cpu::CpuCore> = (0..6)
+            .map(|index| cpu::CpuCore {
+                index,
+                cluster: index / 4,
+                frequency: 1_420_000,
+                min_frequency: 115_200,
+                max_frequency: 1_510_400,
+                ..Default::default()
+            })
+            .collect();
+        let clusters = cpu::cluster_frequencies(&cores);
+
+        let mut cpu_screen = CpuScreen::new();
+        cpu_screen.update(crate::tui::screens::CpuScreenStats {
+            overall: stats.cpu.clone(),
+            usage_history: vec![10, 20, 35, 30, 25],
+            cores: (0..6)
+                .map(|index| crate::tui::screens::CoreStats {
+                    index,
+                    usage: 10.0 * index as f32,
+                    frequency: 1_420_000,
+                    governor: "schedutil".to_string(),
+                    online: index != 2,
+                    temperature: Some(45.0 + index as f32),
+                })
+                .collect(),
+            clusters: clusters.clone(),
+            fan: stats.fan.clone(),
+            temperature: stats.temperature.clone(),
+        });
+        terminal.draw(|f| cpu_screen.draw(f)).unwrap();
+
+        let mut gpu_screen = GpuScreen::new();
+        gpu_screen.update(GpuScreenStats {
+            gpu: stats.gpu.clone(),
+            temperature: stats.temperature.clone(),
+            gpu_name: "NVIDIA Orin".to_string(),
+            gpu_arch: "Ampere (Orin)".to_string(),
+            memory_used: 512,
+            memory_total: 2048,
+            state: "active".to_string(),
+            governor: "nvhost_podgov".to_string(),
+            functions: vec![gpu::GpuFunction {
+                name: "CUDA".to_string(),
+                usage: 63,
+            }],
+            cores: gpu::nominal_gpu_cores(&stats.board.model),
+            thermal_limits: GpuThermalLimits {
+                throttle: Some(99.0),
+                critical: Some(104.5),
+            },
+            power: Some(4.2),
+        });
+        terminal.draw(|f| gpu_screen.draw(f)).unwrap();
+
+        let mut memory_screen = MemoryScreen::new();
+        memory_screen.update(crate::tui::screens::MemoryScreenStats {
+            memory: stats.memory.clone(),
+            full_memory: memory::MemoryStats::default(),
+        });
+        terminal.draw(|f| memory_screen.draw(f)).unwrap();
+
+        let mut power_screen = PowerScreen::new();
+        power_screen.update(crate::tui::screens::PowerScreenStats {
+            power: stats.power.clone(),
+            rails: vec![crate::tui::screens::PowerRail {
+                name: "VDD_IN".to_string(),
+                current: 1960.0,
+                voltage: 5000.0,
+                power: 9.8,
+                power_avg: Some(9.6),
+                is_total: true,
+            }],
+            source: power::PowerSource::AC,
+            battery_capacity: None,
+        });
+        terminal.draw(|f| power_screen.draw(f)).unwrap();
+
+        let mut temperature_screen = TemperatureScreen::new();
+        temperature_screen.update(crate::tui::screens::TemperatureScreenStats {
+            temperature: stats.temperature.clone(),
+            zones: vec![crate::tui::screens::ThermalZone {
+                name: "cpu-thermal".to_string(),
+                current_temp: 48.5,
+                max_temp: 99.0,
+                critical_temp: 105.0,
+                usage_percent: 46,
+            }],
+        });
+        terminal.draw(|f| temperature_screen.draw(f)).unwrap();
+
+        let mut control_screen = ControlScreen::new();
+        control_screen.update(crate::tui::screens::ControlStats {
+            fan_speed: 40,
+            fan_mode: "Auto".to_string(),
+            jetson_clocks: false,
+            jetson_clocks_status: "inactive".to_string(),
+            nvpmodel_id: Some(0),
+            nvpmodel_name: Some("MAXN".to_string()),
+        });
+        terminal.draw(|f| control_screen.draw(f)).unwrap();
+
+        let mut info_screen = InfoScreen::new();
+        info_screen.update(crate::tui::screens::InfoStats {
+            board: stats.board.clone(),
+            cpu_cores: 6,
+            cpu_governor: "schedutil".to_string(),
+            cpu_clusters: clusters,
+            gpu_name: "NVIDIA Orin".to_string(),
+            gpu_arch: "Ampere (Orin)".to_string(),
+            gpu_cores: gpu::nominal_gpu_cores(&stats.board.model),
+        });
+        terminal.draw(|f| info_screen.draw(f)).unwrap();
+
+        let mut engine_screen = EngineScreen::new();
+        engine_screen.update(engine::EngineStats::default());
+        terminal.draw(|f| engine_screen.draw(f)).unwrap();
+
+        let mut process_screen = ProcessScreen::new();
+        process_screen.update(processes::ProcessStats::default());
+        terminal.draw(|f| process_screen.draw(f)).unwrap();
+    }
+
+    #[test]
+    fn test_gpu_screen_stats_include_gpu_trip_points() {
+        let temperatures = temperature::TemperatureStats {
+            thermal_zones: vec![
+                temperature::ThermalZone {
+                    name: "cpu-thermal".to_string(),
+                    current_temp: 50.0,
+                    critical_temp: 100.0,
+                    ..Default::default()
+                },
+                temperature::ThermalZone {
+                    name: "gpu-thermal".to_string(),
+                    current_temp: 61.5,
+                    trip_points: vec![
+                        temperature::TripPoint {
+                            temp: 99.0,
+                            kind: "passive".to_string(),
+                        },
+                        temperature::TripPoint {
+                            temp: 104.5,
+                            kind: "critical".to_string(),
+                        },
+                    ],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let stats = gpu_screen_stats(
+            &gpu::GpuStats::default(),
+            &temperatures,
+            &power::PowerStats::default(),
+            None,
+        );
+        assert_eq!(stats.thermal_limits.critical, Some(104.5));
+        assert_eq!(stats.thermal_limits.throttle, Some(99.0));
+    }
+
+    #[test]
+    fn test_snapshot_json_is_valid() {
+        let stats = sample_jetson_stats();
+        let json = snapshot_json(
+            &stats,
+            &engine::EngineStats::default(),
+            &processes::ProcessStats::default(),
+            1_700_000_000,
+        )
+        .unwrap();
+
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["timestamp"], 1_700_000_000u64);
+        assert_eq!(value["stats"]["board"]["model"], "NVIDIA Jetson Orin Nano");
+        assert_eq!(
+            value["stats"]["power"]["total"].as_f64().unwrap() as f32,
+            9.8
+        );
+        assert!(value["engine"]["nvjpg"].is_object());
+        assert!(value["processes"]["gpu_processes"].is_array());
+    }
+
+    #[test]
+    fn test_status_message_draws_over_footer() {
+        let mut terminal = Terminal::new(TestBackend::new(60, 10)).unwrap();
+        let status = StatusMessage {
+            text: "Snapshot saved to rjtop-snapshot-1.json".to_string(),
+            is_error: false,
+            shown_at: Instant::now(),
+        };
+
+        terminal
+            .draw(|f| {
+                AllScreen::new().draw(f);
+                draw_status_message(f, &status);
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let footer: String = (0..60).map(|x| buffer.get(x, 8).symbol()).collect();
+        assert!(footer.contains("Snapshot saved"));
+    }
+
+    #[test]
+    fn test_not_jetson_banner_draws_under_tabs() {
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal
+            .draw(|f| {
+                AllScreen::new().draw(f);
+                draw_not_jetson_banner(f);
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let row: String = (0..80).map(|x| buffer.get(x, 2).symbol()).collect();
+        assert!(row.contains("Not a Jetson"));
+    }
+
+    #[test]
+    fn test_not_jetson_banner_skipped_on_tiny_terminal() {
+        let mut terminal = Terminal::new(TestBackend::new(40, 3)).unwrap();
+        terminal.draw(draw_not_jetson_banner).unwrap();
+    }
+
+    #[test]
+    fn test_resize_between_draws_does_not_panic() {
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+        draw_all_screens(&mut terminal);
+
+        // Shrink to a tiny terminal, then grow past the original size
+        for (width, height) in [(20, 6), (1, 1), (200, 60)] {
+            terminal.backend_mut().resize(width, height);
+            draw_all_screens(&mut terminal);
+            assert_eq!(terminal.size().unwrap().width, width);
+            assert_eq!(terminal.size().unwrap().height, height);
+        }
+    }
 
     #[test]
     fn test_screen_state_index_consistency() {
@@ -457,11 +1307,41 @@ mod tests {
         assert_eq!(gpu_screen.index(), 3);
     }
 
+    #[test]
+    fn test_popup_area_is_centered() {
+        let area = popup_area(Rect::new(0, 0, 100, 40), 60, 50);
+        assert_eq!(area, Rect::new(20, 10, 60, 20));
+
+        let area = popup_area(Rect::new(0, 0, 10, 10), 150, 100);
+        assert_eq!(area, Rect::new(0, 0, 10, 10));
+    }
+
+    #[test]
+    fn test_help_overlay_lists_screens_and_keys() {
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal.draw(draw_help).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let text: String = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer.get(x, y).symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(text.contains("Help (h/Esc to close)"));
+        assert!(text.contains("1  All"));
+        assert!(text.contains("0  Processes"));
+        assert!(text.contains("Save a JSON snapshot"));
+    }
+
     #[test]
     fn test_screen_state_from_index_roundtrip() {
         for idx in 0..ScreenState::COUNT {
             if let Some(state) = ScreenState::from_index(idx) {
-                assert!(state.index() >= 1 && state.index() <= 8);
+                assert!(state.index() >= 1 && state.index() <= 10);
             }
         }
     }
@@ -501,6 +1381,8 @@ mod tests {
             ScreenState::Temperature,
             ScreenState::Control,
             ScreenState::Info,
+            ScreenState::Engine,
+            ScreenState::Processes,
         ];
 
         for (i, state1) in states.iter().enumerate() {
@@ -544,6 +1426,8 @@ mod tests {
         let key_6 = KeyCode::Char('6');
         let key_7 = KeyCode::Char('7');
         let key_8 = KeyCode::Char('8');
+        let key_9 = KeyCode::Char('9');
+        let key_0 = KeyCode::Char('0');
 
         let mut event_q = KeyEvent::new(key_q, KeyModifiers::NONE);
         event_q.kind = KeyEventKind::Press;
@@ -567,6 +1451,10 @@ mod tests {
         event_7.kind = KeyEventKind::Press;
         let mut event_8 = KeyEvent::new(key_8, KeyModifiers::NONE);
         event_8.kind = KeyEventKind::Press;
+        let mut event_9 = KeyEvent::new(key_9, KeyModifiers::NONE);
+        event_9.kind = KeyEventKind::Press;
+        let mut event_0 = KeyEvent::new(key_0, KeyModifiers::NONE);
+        event_0.kind = KeyEventKind::Press;
 
         assert_eq!(event_q.code, key_q);
         assert_eq!(event_q_upper.code, key_q_upper);
@@ -579,6 +1467,8 @@ mod tests {
         assert_eq!(event_6.code, key_6);
         assert_eq!(event_7.code, key_7);
         assert_eq!(event_8.code, key_8);
+        assert_eq!(event_9.code, key_9);
+        assert_eq!(event_0.code, key_0);
     }
 
     #[test]
@@ -634,6 +1524,44 @@ mod tests {
         assert_eq!(current_screen, ScreenState::All);
     }
 
+    #[test]
+    fn test_clamp_tick_rate() {
+        assert_eq!(clamp_tick_rate(0), Duration::from_millis(MIN_TICK_RATE_MS));
+        assert_eq!(clamp_tick_rate(750), Duration::from_millis(750));
+        assert_eq!(
+            clamp_tick_rate(60_000),
+            Duration::from_millis(MAX_TICK_RATE_MS)
+        );
+    }
+
+    #[test]
+    fn test_step_tick_rate() {
+        let ms = |ms| Duration::from_millis(ms);
+        assert_eq!(step_tick_rate(ms(250), true), ms(350));
+        assert_eq!(step_tick_rate(ms(250), false), ms(150));
+        assert_eq!(step_tick_rate(ms(1000), true), ms(1500));
+        assert_eq!(step_tick_rate(ms(1000), false), ms(900));
+        assert_eq!(step_tick_rate(ms(1500), false), ms(1000));
+
+        // Never leaves the supported range
+        assert_eq!(step_tick_rate(ms(100), false), ms(100));
+        assert_eq!(step_tick_rate(ms(5000), true), ms(5000));
+    }
+
+    #[test]
+    fn test_interval_drawn_on_footer_border() {
+        let mut terminal = Terminal::new(TestBackend::new(40, 5)).unwrap();
+        terminal
+            .draw(|f| draw_interval(f, Duration::from_millis(250)))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let last_row: String = (0..buffer.area.width)
+            .map(|x| buffer.get(x, 4).symbol())
+            .collect();
+        assert!(last_row.ends_with(" 250ms  "));
+    }
+
     #[test]
     fn test_tick_rate_duration() {
         let tick_rate = Duration::from_millis(250);
@@ -675,4 +1603,110 @@ mod tests {
         assert!(matches!(messages[3], StateMessage::Update));
         assert!(matches!(messages[4], StateMessage::Exit));
     }
+
+    #[test]
+    fn test_check_terminal() {
+        assert!(check_terminal(true, true).is_ok());
+        for (stdin_tty, stdout_tty) in [(true, false), (false, true), (false, false)] {
+            assert!(matches!(
+                check_terminal(stdin_tty, stdout_tty),
+                Err(crate::Error::NotATerminal)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_new_without_terminal_fails() {
+        // Only meaningful when the test output is captured or piped
+        if io::stdin().is_terminal() && io::stdout().is_terminal() {
+            return;
+        }
+
+        let err = TuiApp::with_interval(DEFAULT_TICK_RATE_MS).err().unwrap();
+        assert!(matches!(err, crate::Error::NotATerminal));
+    }
+
+    /// Source counting how often each module is read
+    #[derive(Default)]
+    struct CountingSource {
+        reads: std::collections::HashMap<&'static str, usize>,
+    }
+
+    impl CountingSource {
+        fn read<T: Default>(&mut self, module: &'static str) -> T {
+            *self.reads.entry(module).or_default() += 1;
+            T::default()
+        }
+    }
+
+    impl StatsSource for CountingSource {
+        fn cpu(&mut self) -> cpu::CpuStats {
+            self.read("cpu")
+        }
+
+        fn core_count(&mut self) -> usize {
+            self.read("core_count")
+        }
+
+        fn gpu(&mut self) -> Arc<gpu::GpuStats> {
+            self.read("gpu")
+        }
+
+        fn memory(&mut self) -> memory::MemoryStats {
+            self.read("memory")
+        }
+
+        fn fan(&mut self, _temperatures: &temperature::TemperatureStats) -> fan::FanStats {
+            self.read("fan")
+        }
+
+        fn temperature(&mut self) -> temperature::TemperatureStats {
+            self.read("temperature")
+        }
+
+        fn power(&mut self) -> power::PowerStats {
+            self.read("power")
+        }
+
+        fn power_supply(&mut self) -> (power::PowerSource, Option<u8>) {
+            self.read("power_supply")
+        }
+
+        fn engines(&mut self) -> engine::EngineStats {
+            self.read("engines")
+        }
+
+        fn processes(&mut self) -> processes::ProcessStats {
+            self.read("processes")
+        }
+
+        fn clocks(&mut self) -> JetsonClocksStatus {
+            self.read("clocks")
+        }
+
+        fn nvpmodel(&mut self) -> Option<nvpmodel::NVPModelQuery> {
+            self.read("nvpmodel")
+        }
+
+        fn board(&mut self) -> BoardInfo {
+            self.read("board")
+        }
+    }
+
+    #[test]
+    fn test_tick_reads_each_module_once() {
+        let mut source = CountingSource::default();
+
+        for tick in 1..=3 {
+            TickReadings::collect(&mut source);
+            assert_eq!(source.reads.len(), 13);
+            for (module, count) in &source.reads {
+                assert_eq!(
+                    *count, tick,
+                    "{} read {} times in {} ticks",
+                    module, count, tick
+                );
+            }
+        }
+    }
 }
@@ -12,6 +12,9 @@ use ratatui::{
     Frame,
 };
 
+use crate::modules::temperature::{percent_to_critical, TemperatureUnit};
+use crate::tui::widgets::color::{gauge_color, usage_color, TEMP_CRIT_RATIO, TEMP_WARN_RATIO};
+
 use super::{
     JetsonStats, SimpleBoardInfo, SimpleCpuStats, SimpleFanStats, SimpleGpuStats,
     SimpleMemoryStats, SimplePowerStats, SimpleTemperatureStats,
@@ -20,11 +23,13 @@ use super::{
 /// All screen - main dashboard with all stats
 pub struct AllScreen {
     stats: Option<JetsonStats>,
+    unit: TemperatureUnit,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
 
     #[test]
     fn test_all_screen_initialization() {
@@ -32,13 +37,12 @@ mod tests {
         assert!(screen.stats.is_none());
     }
 
-    #[test]
-    fn test_all_screen_update() {
-        let mut screen = AllScreen::new();
-        let test_stats = JetsonStats {
+    fn sample_stats() -> JetsonStats {
+        JetsonStats {
             cpu: SimpleCpuStats {
                 usage: 50.0,
                 frequency: 2000,
+                governor: "powersave".to_string(),
             },
             gpu: SimpleGpuStats {
                 usage: 60.0,
@@ -55,29 +59,142 @@ mod tests {
                 cpu: 45.0,
                 gpu: 50.0,
                 board: 40.0,
+                ..Default::default()
             },
             power: SimplePowerStats { total: 10.5 },
             board: SimpleBoardInfo {
                 model: "Jetson Orin".to_string(),
                 jetpack: "6.0".to_string(),
                 l4t: "36.3".to_string(),
+                serial: "Unknown".to_string(),
+                part_number: "Unknown".to_string(),
+                board_ids: Vec::new(),
             },
-        };
+        }
+    }
 
-        screen.update(test_stats);
+    #[test]
+    fn test_all_screen_update() {
+        let mut screen = AllScreen::new();
+        screen.update(sample_stats());
         assert!(screen.stats.is_some());
     }
+
+    #[test]
+    fn test_cpu_section_shows_governor() {
+        let mut screen = AllScreen::new();
+        screen.update(sample_stats());
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 40)).unwrap();
+        terminal.draw(|f| screen.draw(f)).unwrap();
+
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains("governor: powersave"));
+    }
+
+    #[test]
+    fn test_temperature_section_shows_board_temperature() {
+        let mut screen = AllScreen::new();
+        screen.update(sample_stats());
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 40)).unwrap();
+        terminal.draw(|f| screen.draw(f)).unwrap();
+
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains("Board: 40.0°C"));
+    }
+
+    #[test]
+    fn test_temperature_section_in_fahrenheit() {
+        let mut screen = AllScreen::new();
+        screen.set_temperature_unit(TemperatureUnit::Fahrenheit);
+        screen.update(sample_stats());
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 40)).unwrap();
+        terminal.draw(|f| screen.draw(f)).unwrap();
+
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains("CPU: 113.0°F"));
+        assert!(rendered.contains("Board: 104.0°F"));
+    }
+
+    #[test]
+    fn test_temperature_section_flags_throttling() {
+        let mut stats = sample_stats();
+        stats.temperature.throttle_zones = vec!["cpu-thermal".to_string()];
+        let mut screen = AllScreen::new();
+        screen.update(stats);
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 40)).unwrap();
+        terminal.draw(|f| screen.draw(f)).unwrap();
+
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains("THROTTLING"));
+    }
+
+    #[test]
+    fn test_temperature_near_critical_is_red() {
+        // 100°C against a 105°C critical trip
+        let style = temperature_style(percent_to_critical(100.0, 105.0));
+        assert_eq!(style.fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn test_temperature_style_thresholds() {
+        assert_eq!(
+            temperature_style(percent_to_critical(45.0, 105.0)).fg,
+            Some(Color::Green)
+        );
+        assert_eq!(
+            temperature_style(percent_to_critical(80.0, 105.0)).fg,
+            Some(Color::Yellow)
+        );
+        // Without a critical trip point the value stays uncolored
+        assert_eq!(temperature_style(percent_to_critical(80.0, 0.0)).fg, None);
+    }
 }
 
 impl AllScreen {
     pub fn new() -> Self {
-        Self { stats: None }
+        Self {
+            stats: None,
+            unit: TemperatureUnit::default(),
+        }
     }
 
     pub fn update(&mut self, stats: JetsonStats) {
         self.stats = Some(stats);
     }
 
+    /// Show the summary temperatures in `unit`
+    pub fn set_temperature_unit(&mut self, unit: TemperatureUnit) {
+        self.unit = unit;
+    }
+
     pub fn draw(&mut self, f: &mut Frame) {
         if let Some(stats) = &self.stats {
             self.draw_content(f, stats);
@@ -149,8 +266,12 @@ impl AllScreen {
 
     fn draw_cpu(&self, f: &mut Frame, stats: &JetsonStats, area: Rect) {
         let gauge = Gauge::default()
-            .block(Block::default().borders(Borders::ALL).title("CPU Usage"))
-            .gauge_style(Style::default().fg(Color::Green))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("CPU Usage | governor: {}", stats.cpu.governor)),
+            )
+            .gauge_style(Style::default().fg(usage_color(stats.cpu.usage)))
             .percent(stats.cpu.usage as u16)
             .label(format!("{}%", stats.cpu.usage));
         f.render_widget(gauge, area);
@@ -159,7 +280,7 @@ impl AllScreen {
     fn draw_gpu(&self, f: &mut Frame, stats: &JetsonStats, area: Rect) {
         let gauge = Gauge::default()
             .block(Block::default().borders(Borders::ALL).title("GPU Usage"))
-            .gauge_style(Style::default().fg(Color::Blue))
+            .gauge_style(Style::default().fg(usage_color(stats.gpu.usage)))
             .percent(stats.gpu.usage as u16)
             .label(format!("{}%", stats.gpu.usage));
         f.render_widget(gauge, area);
@@ -182,20 +303,38 @@ impl AllScreen {
                 "Memory: {:.1}{} / {:.1}{}",
                 ram_used_val, ram_used_unit, ram_total_val, ram_total_unit
             )))
-            .gauge_style(Style::default().fg(Color::Yellow))
+            .gauge_style(Style::default().fg(usage_color(ram_percent as f32)))
             .percent(ram_percent)
             .label(format!("{}%", ram_percent));
         f.render_widget(gauge, area);
     }
 
     fn draw_temperature(&self, f: &mut Frame, stats: &JetsonStats, area: Rect) {
-        let board_temp = stats.temperature.board;
-        let text = format!(
-            "CPU: {:.1}°C | GPU: {:.1}°C | Board: {:.1}°C",
-            stats.temperature.cpu, stats.temperature.gpu, board_temp
-        );
+        let temp = &stats.temperature;
+        let reading = |label: &str, current: f32, critical: f32| {
+            Span::styled(
+                format!("{}: {}", label, self.unit.format(current)),
+                temperature_style(percent_to_critical(current, critical)),
+            )
+        };
 
-        let paragraph = Paragraph::new(text.as_str())
+        let mut spans = vec![
+            reading("CPU", temp.cpu, temp.cpu_critical),
+            Span::raw(" | "),
+            reading("GPU", temp.gpu, temp.gpu_critical),
+            Span::raw(" | "),
+            reading("Board", temp.board, temp.board_critical),
+        ];
+        if !temp.throttle_zones.is_empty() {
+            spans.push(Span::raw(" | "));
+            spans.push(Span::styled(
+                "THROTTLING",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ));
+        }
+        let line = Line::from(spans);
+
+        let paragraph = Paragraph::new(line)
             .block(Block::default().borders(Borders::ALL).title("Temperature"))
             .alignment(Alignment::Center);
         f.render_widget(paragraph, area);
@@ -215,7 +354,7 @@ impl AllScreen {
     }
 
     fn draw_footer(&self, f: &mut Frame, area: Rect) {
-        let footer_text = "q: quit | 1-8: screens | h: help";
+        let footer_text = "q: quit | 0-9: screens | h: help";
         let paragraph = Paragraph::new(footer_text)
             .block(Block::default().borders(Borders::ALL))
             .alignment(Alignment::Center);
@@ -223,6 +362,17 @@ impl AllScreen {
     }
 }
 
+/// Color a temperature by its proximity to the critical trip point
+fn temperature_style(percent_to_critical: Option<f32>) -> Style {
+    percent_to_critical.map_or(Style::default(), |p| {
+        Style::default().fg(gauge_color(
+            p,
+            TEMP_WARN_RATIO * 100.0,
+            TEMP_CRIT_RATIO * 100.0,
+        ))
+    })
+}
+
 impl Default for AllScreen {
     fn default() -> Self {
         Self::new()
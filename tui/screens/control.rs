@@ -31,8 +31,9 @@ pub struct ControlStats {
     pub fan_mode: String,
     pub jetson_clocks: bool,
     pub jetson_clocks_status: String,
-    pub nvpmodel_id: u8,
-    pub nvpmodel_name: String,
+    /// Active power mode from `nvpmodel -q`, `None` when it is unavailable
+    pub nvpmodel_id: Option<u8>,
+    pub nvpmodel_name: Option<String>,
 }
 
 impl ControlScreen {
@@ -105,8 +106,8 @@ impl ControlScreen {
                 stats.jetson_clocks_status
             )),
             ListItem::new(format!(
-                "NVP Model: {} ({})",
-                stats.nvpmodel_id, stats.nvpmodel_name
+                "NVP Model: {}",
+                format_nvpmodel(stats.nvpmodel_id, stats.nvpmodel_name.as_deref())
             )),
         ];
 
@@ -123,14 +124,14 @@ impl ControlScreen {
     }
 
     fn draw_footer(&self, f: &mut Frame, area: Rect) {
-        let footer_text = "q: quit | ↑↓: navigate | Enter: select | 1-8: screens | h: help";
+        let footer_text = "q: quit | ↑↓: navigate | Enter: select | 0-9: screens | h: help";
         let paragraph = Paragraph::new(footer_text)
             .block(Block::default().borders(Borders::ALL))
             .alignment(Alignment::Center);
         f.render_widget(paragraph, area);
     }
 
-    pub fn handle_key(&mut self, key: crossterm::event::KeyEvent) -> anyhow::Result<()> {
+    pub fn handle_key(&mut self, key: crossterm::event::KeyEvent) -> crate::Result<()> {
         use crossterm::event::{KeyCode, KeyEventKind};
 
         if key.kind != KeyEventKind::Press {
@@ -157,7 +158,7 @@ impl ControlScreen {
         Ok(())
     }
 
-    fn handle_select(&mut self) -> anyhow::Result<()> {
+    fn handle_select(&mut self) -> crate::Result<()> {
         match self.selected_item {
             0 => {
                 // Fan speed control
@@ -177,6 +178,16 @@ impl ControlScreen {
     }
 }
 
+/// Format the power mode as `0 (MAXN)`, or `N/A` when nvpmodel is unavailable
+pub fn format_nvpmodel(id: Option<u8>, name: Option<&str>) -> String {
+    match (id, name) {
+        (Some(id), Some(name)) => format!("{} ({})", id, name),
+        (Some(id), None) => id.to_string(),
+        (None, Some(name)) => name.to_string(),
+        (None, None) => "N/A".to_string(),
+    }
+}
+
 impl Default for ControlScreen {
     fn default() -> Self {
         Self::new()
@@ -202,14 +213,22 @@ mod tests {
             fan_mode: "Auto".to_string(),
             jetson_clocks: false,
             jetson_clocks_status: "inactive".to_string(),
-            nvpmodel_id: 0,
-            nvpmodel_name: "MAXN".to_string(),
+            nvpmodel_id: Some(0),
+            nvpmodel_name: Some("MAXN".to_string()),
         };
 
         screen.update(test_stats);
         assert!(screen.stats.is_some());
     }
 
+    #[test]
+    fn test_format_nvpmodel() {
+        assert_eq!(format_nvpmodel(Some(0), Some("MAXN")), "0 (MAXN)");
+        assert_eq!(format_nvpmodel(Some(2), None), "2");
+        assert_eq!(format_nvpmodel(None, Some("15W")), "15W");
+        assert_eq!(format_nvpmodel(None, None), "N/A");
+    }
+
     #[test]
     fn test_default() {
         let screen = ControlScreen::default();
@@ -8,18 +8,21 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Gauge, List, ListItem, Paragraph},
+    widgets::{Block, Borders, Gauge, List, ListItem, Paragraph, Sparkline},
     Frame,
 };
 
-use crate::modules::{CpuStats, FanStats, TemperatureStats};
+use crate::modules::{CpuCluster, CpuStats, FanStats, TemperatureStats, TemperatureUnit};
 
 use super::SimpleTemperatureStats;
+use crate::tui::widgets::color::usage_color;
 
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct SimpleCpuStats {
     pub usage: f32,
     pub frequency: u32,
+    /// Scaling governor of the first online core
+    pub governor: String,
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -30,7 +33,10 @@ pub struct SimpleFanStats {
 #[derive(Debug, Clone)]
 pub struct CpuScreenStats {
     pub overall: SimpleCpuStats,
+    /// Recent overall usage in percent, oldest first
+    pub usage_history: Vec<u64>,
     pub cores: Vec<CoreStats>,
+    pub clusters: Vec<CpuCluster>,
     pub fan: SimpleFanStats,
     pub temperature: SimpleTemperatureStats,
 }
@@ -41,12 +47,16 @@ pub struct CoreStats {
     pub usage: f32,
     pub frequency: u32,
     pub governor: String,
+    pub online: bool,
+    /// Core temperature in °C, when the board has per-core thermal zones
+    pub temperature: Option<f32>,
 }
 
 /// CPU screen - detailed CPU monitoring
 pub struct CpuScreen {
     stats: Option<CpuScreenStats>,
     selected_core: usize,
+    unit: TemperatureUnit,
 }
 
 impl CpuScreen {
@@ -54,6 +64,7 @@ impl CpuScreen {
         Self {
             stats: None,
             selected_core: 0,
+            unit: TemperatureUnit::default(),
         }
     }
 
@@ -61,6 +72,11 @@ impl CpuScreen {
         self.stats = Some(stats);
     }
 
+    /// Show per-core and CPU zone temperatures in `unit`
+    pub fn set_temperature_unit(&mut self, unit: TemperatureUnit) {
+        self.unit = unit;
+    }
+
     pub fn draw(&mut self, f: &mut Frame) {
         if let Some(stats) = &self.stats {
             self.draw_content(f, stats);
@@ -111,7 +127,7 @@ impl CpuScreen {
         let body_chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
-                Constraint::Length(20), // Core list
+                Constraint::Length(26), // Overall and clusters
                 Constraint::Min(0),     // Core details
             ])
             .split(area);
@@ -121,12 +137,40 @@ impl CpuScreen {
     }
 
     fn draw_core_list(&self, f: &mut Frame, stats: &CpuScreenStats, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Overall gauge
+                Constraint::Length(5), // Usage history
+                Constraint::Min(0),    // Clusters
+            ])
+            .split(area);
+
         let overall_gauge = Gauge::default()
             .block(Block::default().borders(Borders::ALL).title("Overall CPU"))
-            .gauge_style(Style::default().fg(Color::Green))
+            .gauge_style(Style::default().fg(usage_color(stats.overall.usage)))
             .percent(stats.overall.usage as u16)
             .label(format!("{}%", stats.overall.usage));
-        f.render_widget(overall_gauge, area);
+        f.render_widget(overall_gauge, chunks[0]);
+
+        // Keep the newest samples when the history is wider than the widget
+        let width = chunks[1].width.saturating_sub(2) as usize;
+        let history = &stats.usage_history[stats.usage_history.len().saturating_sub(width)..];
+        let sparkline = Sparkline::default()
+            .block(Block::default().borders(Borders::ALL).title("History"))
+            .style(Style::default().fg(Color::Green))
+            .data(history)
+            .max(100);
+        f.render_widget(sparkline, chunks[1]);
+
+        let items: Vec<ListItem> = stats
+            .clusters
+            .iter()
+            .map(|cluster| ListItem::new(format_cluster_frequency(cluster)))
+            .collect();
+
+        let list = List::new(items).block(Block::default().borders(Borders::ALL).title("Clusters"));
+        f.render_widget(list, chunks[2]);
     }
 
     fn draw_core_details(&self, f: &mut Frame, stats: &CpuScreenStats, area: Rect) {
@@ -134,13 +178,22 @@ impl CpuScreen {
             .cores
             .iter()
             .map(|core| {
-                ListItem::new(format!(
+                if !core.online {
+                    return ListItem::new(format!("Core {}: offline", core.index))
+                        .style(Style::default().fg(Color::DarkGray));
+                }
+
+                let mut line = format!(
                     "Core {}: {}% @ {}MHz ({})",
                     core.index,
                     core.usage as u32,
                     core.frequency / 1_000_000,
                     core.governor
-                ))
+                );
+                if let Some(temp) = core.temperature {
+                    line.push_str(&format!(" {}", self.unit.format(temp)));
+                }
+                ListItem::new(line)
             })
             .collect();
 
@@ -155,14 +208,15 @@ impl CpuScreen {
     fn draw_footer(&self, f: &mut Frame, area: Rect) {
         let fan_temp = if let Some(stats) = &self.stats {
             format!(
-                "Fan: {}% | CPU: {:.1}°C",
-                stats.fan.speed, stats.temperature.cpu
+                "Fan: {}% | CPU: {}",
+                stats.fan.speed,
+                self.unit.format(stats.temperature.cpu)
             )
         } else {
             "Loading...".to_string()
         };
 
-        let footer_text = format!("q: quit | 1-8: screens | h: help | {}", fan_temp);
+        let footer_text = format!("q: quit | 0-9: screens | h: help | {}", fan_temp);
         let paragraph = Paragraph::new(footer_text)
             .block(Block::default().borders(Borders::ALL))
             .alignment(Alignment::Center);
@@ -170,6 +224,16 @@ impl CpuScreen {
     }
 }
 
+/// Format a cluster as `Cluster 0: 1.2/2.2 GHz` (current/max)
+pub fn format_cluster_frequency(cluster: &CpuCluster) -> String {
+    format!(
+        "Cluster {}: {:.1}/{:.1} GHz",
+        cluster.id,
+        cluster.current as f32 / 1_000_000.0,
+        cluster.max as f32 / 1_000_000.0
+    )
+}
+
 impl Default for CpuScreen {
     fn default() -> Self {
         Self::new()
@@ -194,13 +258,17 @@ mod tests {
             overall: SimpleCpuStats {
                 usage: 50.0,
                 frequency: 2000,
+                governor: "schedutil".to_string(),
             },
+            usage_history: vec![40, 45, 50],
             cores: vec![],
+            clusters: vec![],
             fan: SimpleFanStats { speed: 50 },
             temperature: SimpleTemperatureStats {
                 cpu: 45.0,
                 gpu: 50.0,
                 board: 40.0,
+                ..Default::default()
             },
         };
 
@@ -208,6 +276,65 @@ mod tests {
         assert!(screen.stats.is_some());
     }
 
+    #[test]
+    fn test_offline_core_is_greyed_out() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let mut screen = CpuScreen::new();
+        screen.update(CpuScreenStats {
+            overall: SimpleCpuStats {
+                usage: 20.0,
+                frequency: 1_420_000,
+                governor: "schedutil".to_string(),
+            },
+            usage_history: vec![],
+            cores: (0..4)
+                .map(|index| CoreStats {
+                    index,
+                    usage: 20.0,
+                    frequency: 1_420_000,
+                    governor: "schedutil".to_string(),
+                    online: index != 2,
+                    temperature: None,
+                })
+                .collect(),
+            clusters: vec![],
+            fan: SimpleFanStats { speed: 0 },
+            temperature: SimpleTemperatureStats::default(),
+        });
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+        terminal.draw(|f| screen.draw(f)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let (x, y) = (0..buffer.area.height)
+            .find_map(|y| {
+                let cells: Vec<&str> = (0..buffer.area.width)
+                    .map(|x| buffer.get(x, y).symbol())
+                    .collect();
+                let x = cells
+                    .windows(15)
+                    .position(|w| w.concat() == "Core 2: offline")?;
+                Some((x as u16, y))
+            })
+            .expect("Offline core should stay listed under its own index");
+        assert_eq!(buffer.get(x, y).fg, Color::DarkGray);
+    }
+
+    #[test]
+    fn test_format_cluster_frequency() {
+        let cluster = CpuCluster {
+            id: 1,
+            core_indices: vec![4, 5, 6, 7],
+            min: 115_200,
+            current: 1_190_400,
+            max: 2_201_600,
+            ..Default::default()
+        };
+
+        assert_eq!(format_cluster_frequency(&cluster), "Cluster 1: 1.2/2.2 GHz");
+    }
+
     #[test]
     fn test_default() {
         let screen = CpuScreen::default();
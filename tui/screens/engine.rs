@@ -0,0 +1,210 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Engine screen - hardware accelerator monitoring
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, LineGauge, Paragraph},
+    Frame,
+};
+
+use crate::modules::engine::{EngineStats, EngineStatus};
+use crate::tui::widgets::color::usage_color;
+
+/// Engine screen - hardware accelerator monitoring
+pub struct EngineScreen {
+    stats: Option<EngineStats>,
+}
+
+impl EngineScreen {
+    pub fn new() -> Self {
+        Self { stats: None }
+    }
+
+    pub fn update(&mut self, stats: EngineStats) {
+        self.stats = Some(stats);
+    }
+
+    /// Stats from the last update
+    pub fn stats(&self) -> Option<&EngineStats> {
+        self.stats.as_ref()
+    }
+
+    pub fn draw(&mut self, f: &mut Frame) {
+        if let Some(stats) = &self.stats {
+            self.draw_content(f, stats);
+        } else {
+            self.draw_loading(f);
+        }
+    }
+
+    fn draw_loading(&self, f: &mut Frame) {
+        let size = f.size();
+        let paragraph = Paragraph::new("Loading...")
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).title("Engines"));
+        f.render_widget(paragraph, size);
+    }
+
+    fn draw_content(&self, f: &mut Frame, stats: &EngineStats) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Header
+                Constraint::Min(0),    // Content
+                Constraint::Length(3), // Footer
+            ])
+            .split(f.size());
+
+        self.draw_header(f, chunks[0]);
+        self.draw_engines(f, stats, chunks[1]);
+        self.draw_footer(f, stats, chunks[2]);
+    }
+
+    fn draw_header(&self, f: &mut Frame, area: Rect) {
+        let header = Paragraph::new(vec![Line::from(vec![
+            Span::styled(
+                "rusted-jetsons",
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" | "),
+            Span::styled("Engines", Style::default().fg(Color::Gray)),
+        ])])
+        .alignment(Alignment::Center);
+        f.render_widget(header, area);
+    }
+
+    /// One line per engine: a usage gauge with the clock, or "off"
+    fn draw_engines(&self, f: &mut Frame, stats: &EngineStats, area: Rect) {
+        let block = Block::default().borders(Borders::ALL).title("Engines");
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let engines = engine_list(stats);
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(1); engines.len()])
+            .split(inner);
+
+        for ((label, engine), row) in engines.iter().zip(rows.iter()) {
+            if engine.enabled {
+                let gauge = LineGauge::default()
+                    .label(format!(
+                        "{:<6} {:>8} {:>3}%",
+                        label,
+                        format_clock(engine.clock),
+                        engine.usage
+                    ))
+                    .gauge_style(Style::default().fg(usage_color(engine.usage as f32)))
+                    .ratio(engine.usage.min(100) as f64 / 100.0);
+                f.render_widget(gauge, *row);
+            } else {
+                let line = Paragraph::new(format!("{:<6} off", label))
+                    .style(Style::default().fg(Color::DarkGray));
+                f.render_widget(line, *row);
+            }
+        }
+    }
+
+    fn draw_footer(&self, f: &mut Frame, stats: &EngineStats, area: Rect) {
+        let active = engine_list(stats)
+            .iter()
+            .filter(|(_, engine)| engine.enabled)
+            .count();
+        let footer_text = format!("q: quit | 0-9: screens | h: help | Enabled: {}", active);
+        let paragraph = Paragraph::new(footer_text)
+            .block(Block::default().borders(Borders::ALL))
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+    }
+}
+
+impl Default for EngineScreen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Engines in display order with their labels
+fn engine_list(stats: &EngineStats) -> [(&'static str, &EngineStatus); 9] {
+    [
+        ("APE", &stats.ape),
+        ("DLA0", &stats.dla0),
+        ("DLA1", &stats.dla1),
+        ("NVDEC", &stats.nvdec),
+        ("NVENC", &stats.nvenc),
+        ("NVJPG", &stats.nvjpg),
+        ("PVA0", &stats.pva0),
+        ("PVA1", &stats.pva1),
+        ("OFA", &stats.ofa),
+    ]
+}
+
+/// Format an engine clock in Hz as MHz
+fn format_clock(clock: u32) -> String {
+    if clock == 0 {
+        "-".to_string()
+    } else {
+        format!("{:.0}MHz", clock as f64 / 1_000_000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_engine_screen_update() {
+        let mut screen = EngineScreen::new();
+        assert!(screen.stats.is_none());
+
+        screen.update(EngineStats::default());
+        assert!(screen.stats.is_some());
+    }
+
+    #[test]
+    fn test_format_clock() {
+        assert_eq!(format_clock(0), "-");
+        assert_eq!(format_clock(716_800_000), "717MHz");
+    }
+
+    #[test]
+    fn test_engine_screen_renders_engines() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let mut screen = EngineScreen::new();
+        screen.update(EngineStats {
+            nvdec: EngineStatus {
+                name: "nvdec".to_string(),
+                enabled: true,
+                usage: 42,
+                clock: 729_600_000,
+            },
+            ..Default::default()
+        });
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+        terminal.draw(|f| screen.draw(f)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let text: String = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer.get(x, y).symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(text.contains("NVDEC"));
+        assert!(text.contains("730MHz"));
+        assert!(text.contains("42%"));
+        assert!(text.contains("OFA    off"));
+        assert!(text.contains("Enabled: 1"));
+    }
+}
@@ -7,11 +7,14 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Gauge, Paragraph},
+    widgets::{Block, Borders, Gauge, Paragraph, Sparkline},
     Frame,
 };
 
-use crate::modules::{GpuStats, TemperatureStats};
+use crate::modules::gpu::GpuFunction;
+use crate::modules::history::{self, History};
+use crate::modules::{GpuCores, GpuStats, TemperatureStats, TemperatureUnit};
+use crate::tui::widgets::color::usage_color;
 
 #[derive(Debug, Clone, serde::Serialize, Default)]
 pub struct SimpleGpuStats {
@@ -29,39 +32,132 @@ pub struct GpuScreenStats {
     pub memory_total: u64,
     pub state: String,
     pub governor: String,
-    pub active_functions: Vec<String>,
+    pub functions: Vec<GpuFunction>,
+    pub cores: Option<GpuCores>,
+    pub thermal_limits: GpuThermalLimits,
+    /// Power of the GPU-only rails in W, `None` on combined-rail boards
+    pub power: Option<f32>,
+}
+
+/// Trip points of the GPU thermal zone in °C
+#[derive(Debug, Clone, Copy, Default, PartialEq, serde::Serialize)]
+pub struct GpuThermalLimits {
+    pub throttle: Option<f32>,
+    pub critical: Option<f32>,
+}
+
+impl GpuThermalLimits {
+    /// Limits of the zone backing the GPU temperature
+    pub fn from_stats(stats: &TemperatureStats) -> Self {
+        match stats.zone("gpu") {
+            Some(zone) => Self {
+                throttle: zone.throttle_temp(),
+                critical: zone.critical_trip_temp(),
+            },
+            None => Self::default(),
+        }
+    }
+}
+
+/// Margin in °C below the throttle point where the reading turns yellow
+const THROTTLE_MARGIN: f32 = 10.0;
+
+/// Color a GPU temperature by the headroom left to its trip points
+fn headroom_style(temp: f32, limits: &GpuThermalLimits) -> Style {
+    let Some(limit) = limits.throttle.or(limits.critical) else {
+        return Style::default();
+    };
+
+    if temp >= limit {
+        Style::default().fg(Color::Red)
+    } else if temp >= limit - THROTTLE_MARGIN {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::Green)
+    }
 }
 
 impl Default for GpuScreenStats {
     fn default() -> Self {
         Self {
             gpu: SimpleGpuStats::default(),
-            temperature: crate::tui::screens::SimpleTemperatureStats { cpu: 0.0, gpu: 0.0, board: 0.0 },
+            temperature: crate::tui::screens::SimpleTemperatureStats::default(),
             gpu_name: "NVIDIA GPU".to_string(),
             gpu_arch: "Unknown".to_string(),
             memory_used: 0,
             memory_total: 0,
             state: String::new(),
             governor: String::new(),
-            active_functions: Vec::new(),
+            functions: Vec::new(),
+            cores: None,
+            thermal_limits: GpuThermalLimits::default(),
+            power: None,
         }
     }
 }
 
+/// Format GPU power as `4.2 W`, or `N/A` without a GPU-only rail
+pub fn format_gpu_power(power: Option<f32>) -> String {
+    match power {
+        Some(watts) => format!("{:.1} W", watts),
+        None => "N/A".to_string(),
+    }
+}
+
+/// Format busy functions as `CUDA 63%, NVDEC 35%`
+pub fn format_functions(functions: &[GpuFunction]) -> String {
+    functions
+        .iter()
+        .map(|f| format!("{} {}%", f.name, f.usage))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Format nominal GPU cores as `1024 (8 SM, nominal)`
+pub fn format_gpu_cores(cores: Option<GpuCores>) -> String {
+    match cores {
+        Some(cores) => format!("{} ({} SM, nominal)", cores.cuda_cores, cores.sm_count),
+        None => "N/A".to_string(),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GpuScreen {
     stats: Option<GpuScreenStats>,
+    /// Kept across screen switches since the app updates every screen
+    usage_history: History<f32>,
+    unit: TemperatureUnit,
 }
 
 impl GpuScreen {
     pub fn new() -> Self {
-        Self { stats: None }
+        Self::with_history_capacity(history::DEFAULT_CAPACITY)
+    }
+
+    /// Create a GPU screen graphing the last `capacity` usage samples
+    pub fn with_history_capacity(capacity: usize) -> Self {
+        Self {
+            stats: None,
+            usage_history: History::new(capacity),
+            unit: TemperatureUnit::default(),
+        }
     }
 
     pub fn update(&mut self, stats: GpuScreenStats) {
+        self.usage_history.push(stats.gpu.usage);
         self.stats = Some(stats);
     }
 
+    /// Show the GPU temperature and its throttle limits in `unit`
+    pub fn set_temperature_unit(&mut self, unit: TemperatureUnit) {
+        self.unit = unit;
+    }
+
+    /// GPU usage of the previous updates in percent, oldest first
+    pub fn usage_history(&self) -> &[f32] {
+        self.usage_history.as_slice()
+    }
+
     pub fn draw(&mut self, f: &mut Frame) {
         if let Some(stats) = &self.stats {
             self.draw_content(f, stats);
@@ -141,7 +237,7 @@ impl GpuScreen {
     fn draw_usage_gauge(&self, f: &mut Frame, stats: &GpuScreenStats, area: Rect) {
         let gauge = Gauge::default()
             .block(Block::default().borders(Borders::ALL).title("GPU Usage"))
-            .gauge_style(Style::default().fg(Color::Blue))
+            .gauge_style(Style::default().fg(usage_color(stats.gpu.usage)))
             .percent(stats.gpu.usage as u16)
             .label(format!("{}%", stats.gpu.usage));
         f.render_widget(gauge, area);
@@ -194,10 +290,10 @@ impl GpuScreen {
             ]),
             Line::from(vec![
                 Span::styled("Functions: ", Style::default().fg(Color::Cyan)),
-                Span::raw(if stats.active_functions.is_empty() {
+                Span::raw(if stats.functions.is_empty() {
                     "None".to_string()
                 } else {
-                    stats.active_functions.join(", ")
+                    format_functions(&stats.functions)
                 }),
             ]),
         ];
@@ -208,19 +304,24 @@ impl GpuScreen {
     }
 
     fn draw_temperature(&self, f: &mut Frame, stats: &GpuScreenStats, area: Rect) {
-        let text = vec![
-            Line::from(Span::styled(
-                "GPU Temperature",
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
-            )),
-            Line::from(""),
-            Line::from(vec![
-                Span::styled("GPU: ", Style::default().fg(Color::Cyan)),
-                Span::raw(format!("{:.1}°C", stats.temperature.gpu)),
-            ]),
+        let limits = &stats.thermal_limits;
+        let mut spans = vec![
+            Span::styled("GPU: ", Style::default().fg(Color::Cyan)),
+            Span::styled(
+                self.unit.format(stats.temperature.gpu),
+                headroom_style(stats.temperature.gpu, limits),
+            ),
         ];
+        if let Some(throttle) = limits.throttle {
+            spans.push(Span::raw(format!(
+                " | throttle {}",
+                self.unit.format(throttle)
+            )));
+        }
+        if let Some(critical) = limits.critical {
+            spans.push(Span::raw(format!(" | crit {}", self.unit.format(critical))));
+        }
+        let text = vec![Line::from(spans)];
 
         let paragraph =
             Paragraph::new(text).block(Block::default().borders(Borders::ALL).title("Temperature"));
@@ -244,6 +345,14 @@ impl GpuScreen {
                 Span::styled("Governor: ", Style::default().fg(Color::Cyan)),
                 Span::raw(stats.governor.as_str()),
             ]),
+            Line::from(vec![
+                Span::styled("CUDA cores: ", Style::default().fg(Color::Cyan)),
+                Span::raw(format_gpu_cores(stats.cores)),
+            ]),
+            Line::from(vec![
+                Span::styled("GPU Power: ", Style::default().fg(Color::Cyan)),
+                Span::raw(format_gpu_power(stats.power)),
+            ]),
         ];
 
         let paragraph =
@@ -252,27 +361,30 @@ impl GpuScreen {
     }
 
     fn draw_usage_graph(&self, f: &mut Frame, _stats: &GpuScreenStats, area: Rect) {
-        let text = vec![
-            Line::from(Span::styled(
-                "GPU Usage History",
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
-            )),
-            Line::from(""),
-            Line::from("Usage history not implemented yet"),
-        ];
+        // Keep the newest samples when the history is wider than the widget
+        let samples = self.usage_history();
+        let width = area.width.saturating_sub(2) as usize;
+        let data: Vec<u64> = samples[samples.len().saturating_sub(width)..]
+            .iter()
+            .map(|&usage| usage as u64)
+            .collect();
 
-        let paragraph = Paragraph::new(text)
-            .block(Block::default().borders(Borders::ALL).title("History"))
-            .alignment(Alignment::Center);
-        f.render_widget(paragraph, area);
+        let sparkline = Sparkline::default()
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("GPU Usage History"),
+            )
+            .style(Style::default().fg(Color::Yellow))
+            .data(&data)
+            .max(100);
+        f.render_widget(sparkline, area);
     }
 
     fn draw_footer(&self, f: &mut Frame, stats: &GpuScreenStats, area: Rect) {
         let footer_text = format!(
-            "q: quit | 1-8: screens | h: help | GPU: {:.1}°C",
-            stats.temperature.gpu
+            "q: quit | 0-9: screens | h: help | GPU: {}",
+            self.unit.format(stats.temperature.gpu)
         );
         let paragraph = Paragraph::new(footer_text.as_str())
             .block(Block::default().borders(Borders::ALL))
@@ -286,3 +398,78 @@ impl Default for GpuScreen {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_gpu_power() {
+        assert_eq!(format_gpu_power(Some(4.25)), "4.2 W");
+        assert_eq!(format_gpu_power(Some(12.0)), "12.0 W");
+        assert_eq!(format_gpu_power(None), "N/A");
+    }
+
+    fn stats_with_usage(usage: f32) -> GpuScreenStats {
+        GpuScreenStats {
+            gpu: SimpleGpuStats {
+                usage,
+                frequency: 918_000_000,
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_usage_history_keeps_newest_samples() {
+        let mut screen = GpuScreen::with_history_capacity(60);
+        for sample in 0..100 {
+            screen.update(stats_with_usage(sample as f32));
+        }
+
+        let history = screen.usage_history();
+        assert_eq!(history.len(), 60);
+        assert_eq!(history.first(), Some(&40.0));
+        assert_eq!(history.last(), Some(&99.0));
+    }
+
+    #[test]
+    fn test_format_functions_with_usage() {
+        let functions = [
+            GpuFunction {
+                name: "CUDA".to_string(),
+                usage: 63,
+            },
+            GpuFunction {
+                name: "NVDEC".to_string(),
+                usage: 35,
+            },
+        ];
+        assert_eq!(format_functions(&functions), "CUDA 63%, NVDEC 35%");
+        assert_eq!(format_functions(&[]), "");
+    }
+
+    #[test]
+    fn test_usage_graph_renders_history() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let mut screen = GpuScreen::new();
+        for _ in 0..10 {
+            screen.update(stats_with_usage(100.0));
+        }
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal.draw(|f| screen.draw(f)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let has_bar = (0..buffer.area.height)
+            .flat_map(|y| (0..buffer.area.width).map(move |x| (x, y)))
+            .any(|(x, y)| buffer.get(x, y).symbol() == "█");
+        assert!(has_bar, "Full usage should draw full sparkline bars");
+        let text: String = (0..buffer.area.height)
+            .flat_map(|y| (0..buffer.area.width).map(move |x| (x, y)))
+            .map(|(x, y)| buffer.get(x, y).symbol())
+            .collect();
+        assert!(!text.contains("not implemented"));
+    }
+}
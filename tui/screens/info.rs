@@ -17,8 +17,17 @@ pub struct SimpleBoardInfo {
     pub model: String,
     pub jetpack: String,
     pub l4t: String,
+    pub serial: String,
+    /// 699-level part number of the module
+    pub part_number: String,
+    /// EEPROM board IDs, module first
+    pub board_ids: Vec<String>,
 }
 
+use super::cpu_screen::format_cluster_frequency;
+use super::gpu::format_gpu_cores;
+use crate::modules::{CpuCluster, GpuCores};
+
 use super::{
     SimpleCpuStats, SimpleFanStats, SimpleGpuStats, SimpleMemoryStats, SimplePowerStats,
     SimpleTemperatureStats,
@@ -34,7 +43,20 @@ pub struct InfoStats {
     pub board: SimpleBoardInfo,
     pub cpu_cores: usize,
     pub cpu_governor: String,
+    pub cpu_clusters: Vec<CpuCluster>,
     pub gpu_name: String,
+    /// Architecture with its SoC, e.g. `Ampere (Orin)`
+    pub gpu_arch: String,
+    pub gpu_cores: Option<GpuCores>,
+}
+
+/// Board IDs joined for display, "Unknown" when none were found
+fn format_board_ids(ids: &[String]) -> String {
+    if ids.is_empty() {
+        "Unknown".to_string()
+    } else {
+        ids.join(", ")
+    }
 }
 
 impl InfoScreen {
@@ -129,6 +151,18 @@ impl InfoScreen {
                 Span::styled("L4T: ", Style::default().fg(Color::Cyan)),
                 Span::raw(stats.board.l4t.as_str()),
             ]),
+            Line::from(vec![
+                Span::styled("Serial: ", Style::default().fg(Color::Cyan)),
+                Span::raw(stats.board.serial.as_str()),
+            ]),
+            Line::from(vec![
+                Span::styled("Part number: ", Style::default().fg(Color::Cyan)),
+                Span::raw(stats.board.part_number.as_str()),
+            ]),
+            Line::from(vec![
+                Span::styled("Board IDs: ", Style::default().fg(Color::Cyan)),
+                Span::raw(format_board_ids(&stats.board.board_ids)),
+            ]),
         ];
 
         let paragraph =
@@ -137,7 +171,7 @@ impl InfoScreen {
     }
 
     fn draw_cpu_info(&self, f: &mut Frame, stats: &InfoStats, area: Rect) {
-        let text = vec![
+        let mut text = vec![
             Line::from(Span::styled(
                 "CPU Information",
                 Style::default()
@@ -154,6 +188,15 @@ impl InfoScreen {
                 Span::raw(stats.cpu_governor.as_str()),
             ]),
         ];
+        text.extend(stats.cpu_clusters.iter().map(|cluster| {
+            Line::from(vec![
+                Span::raw(format_cluster_frequency(cluster)),
+                Span::styled(
+                    format!(" (min {:.1} GHz)", cluster.min as f32 / 1_000_000.0),
+                    Style::default().fg(Color::Gray),
+                ),
+            ])
+        }));
 
         let paragraph =
             Paragraph::new(text).block(Block::default().borders(Borders::ALL).title("CPU"));
@@ -173,6 +216,14 @@ impl InfoScreen {
                 Span::styled("Device: ", Style::default().fg(Color::Cyan)),
                 Span::raw(stats.gpu_name.as_str()),
             ]),
+            Line::from(vec![
+                Span::styled("Architecture: ", Style::default().fg(Color::Cyan)),
+                Span::raw(stats.gpu_arch.as_str()),
+            ]),
+            Line::from(vec![
+                Span::styled("CUDA cores: ", Style::default().fg(Color::Cyan)),
+                Span::raw(format_gpu_cores(stats.gpu_cores)),
+            ]),
         ];
 
         let paragraph =
@@ -181,7 +232,7 @@ impl InfoScreen {
     }
 
     fn draw_footer(&self, f: &mut Frame, area: Rect) {
-        let footer_text = "q: quit | 1-8: screens | h: help";
+        let footer_text = "q: quit | 0-9: screens | h: help";
         let paragraph = Paragraph::new(footer_text)
             .block(Block::default().borders(Borders::ALL))
             .alignment(Alignment::Center);
@@ -213,16 +264,34 @@ mod tests {
                 model: "Jetson Orin".to_string(),
                 jetpack: "6.0".to_string(),
                 l4t: "36.3".to_string(),
+                serial: "1421222000123".to_string(),
+                part_number: "699-13767-0000-300 N.1".to_string(),
+                board_ids: vec!["3767-0000-300-N.1".to_string()],
             },
             cpu_cores: 8,
             cpu_governor: "schedutil".to_string(),
-            gpu_name: "NVIDIA Orin GPU".to_string(),
+            cpu_clusters: vec![],
+            gpu_name: "NVIDIA Orin".to_string(),
+            gpu_arch: "Ampere (Orin)".to_string(),
+            gpu_cores: None,
         };
 
         screen.update(test_stats);
         assert!(screen.stats.is_some());
     }
 
+    #[test]
+    fn test_format_board_ids() {
+        assert_eq!(format_board_ids(&[]), "Unknown");
+        assert_eq!(
+            format_board_ids(&[
+                "3701-0005-500-M.0".to_string(),
+                "3737-0000-500-N.1".to_string()
+            ]),
+            "3701-0005-500-M.0, 3737-0000-500-N.1"
+        );
+    }
+
     #[test]
     fn test_default() {
         let screen = InfoScreen::default();
@@ -13,6 +13,7 @@ use ratatui::{
 };
 
 use crate::modules::MemoryStats;
+use crate::tui::widgets::color::usage_color;
 
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct SimpleMemoryStats {
@@ -22,6 +23,50 @@ pub struct SimpleMemoryStats {
     pub swap_total: u64,
 }
 
+/// Labels of the stacked RAM bar segments, in bar order
+const SEGMENT_LABELS: [&str; 4] = ["used", "cache", "buf", "free"];
+const SEGMENT_COLORS: [Color; 4] = [Color::Green, Color::Yellow, Color::Blue, Color::DarkGray];
+
+/// Split RAM into used, cached, buffers and free bytes
+///
+/// Reclaimable slab memory counts as cache, so the used segment only
+/// covers memory the kernel cannot give back under pressure.
+pub fn ram_segments(memory: &MemoryStats) -> [u64; 4] {
+    let cached = memory.ram_cached + memory.ram_reclaimable;
+    let used = memory
+        .ram_total
+        .saturating_sub(cached + memory.ram_buffers + memory.ram_free);
+    [used, cached, memory.ram_buffers, memory.ram_free]
+}
+
+/// Split `width` cells between `values` proportionally
+///
+/// Uses the largest remainder method so the widths always add up to
+/// `width`. When every value is zero the last segment takes the whole bar.
+pub fn segment_widths(values: &[u64], width: u16) -> Vec<u16> {
+    let total: u128 = values.iter().map(|&v| v as u128).sum();
+    if values.is_empty() {
+        return Vec::new();
+    }
+    if total == 0 {
+        let mut widths = vec![0; values.len()];
+        widths[values.len() - 1] = width;
+        return widths;
+    }
+
+    let exact: Vec<u128> = values.iter().map(|&v| v as u128 * width as u128).collect();
+    let mut widths: Vec<u16> = exact.iter().map(|e| (e / total) as u16).collect();
+
+    let mut by_remainder: Vec<usize> = (0..values.len()).collect();
+    by_remainder.sort_by_key(|&i| std::cmp::Reverse(exact[i] % total));
+    let assigned: u16 = widths.iter().sum();
+    for &i in by_remainder.iter().take((width - assigned) as usize) {
+        widths[i] += 1;
+    }
+
+    widths
+}
+
 /// Memory screen - detailed memory monitoring
 pub struct MemoryScreen {
     stats: Option<MemoryScreenStats>,
@@ -105,34 +150,49 @@ impl MemoryScreen {
         let mem_chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Percentage(35), // RAM
-                Constraint::Percentage(35), // SWAP
-                Constraint::Percentage(25), // IRAM
+                Constraint::Percentage(30), // RAM
+                Constraint::Percentage(25), // SWAP
+                Constraint::Percentage(20), // IRAM
+                Constraint::Percentage(20), // EMC
                 Constraint::Min(0),         // Spacer
             ])
             .split(area);
 
-        // RAM gauge
-        let ram_percent = if stats.memory.ram_total > 0 {
-            (stats.memory.ram_used * 100 / stats.memory.ram_total) as u16
-        } else {
-            0
-        };
-
+        // RAM breakdown
         let (ram_used_val, ram_used_unit) =
             crate::modules::memory::format_memory_bytes(stats.memory.ram_used);
         let (ram_total_val, ram_total_unit) =
             crate::modules::memory::format_memory_bytes(stats.memory.ram_total);
 
-        let ram_gauge = Gauge::default()
-            .block(Block::default().borders(Borders::ALL).title("RAM"))
-            .gauge_style(Style::default().fg(Color::Green))
-            .percent(ram_percent)
-            .label(format!(
-                "{:.1}{} / {:.1}{}",
-                ram_used_val, ram_used_unit, ram_total_val, ram_total_unit
-            ));
-        f.render_widget(ram_gauge, mem_chunks[0]);
+        let ram_block = Block::default().borders(Borders::ALL).title(format!(
+            "RAM {:.1}{} / {:.1}{}",
+            ram_used_val, ram_used_unit, ram_total_val, ram_total_unit
+        ));
+        let bar_width = ram_block.inner(mem_chunks[0]).width;
+        let widths = segment_widths(&ram_segments(&stats.full_memory), bar_width);
+
+        let bar = Line::from(
+            widths
+                .iter()
+                .zip(SEGMENT_COLORS)
+                .map(|(&width, color)| {
+                    Span::styled("█".repeat(width as usize), Style::default().fg(color))
+                })
+                .collect::<Vec<_>>(),
+        );
+        let legend = Line::from(
+            SEGMENT_LABELS
+                .iter()
+                .zip(SEGMENT_COLORS)
+                .map(|(label, color)| {
+                    Span::styled(format!("■ {} ", label), Style::default().fg(color))
+                })
+                .collect::<Vec<_>>(),
+        );
+        f.render_widget(
+            Paragraph::new(vec![bar, legend]).block(ram_block),
+            mem_chunks[0],
+        );
 
         // SWAP gauge
         let swap_percent = if stats.memory.swap_total > 0 {
@@ -156,7 +216,8 @@ impl MemoryScreen {
             ));
         f.render_widget(swap_gauge, mem_chunks[1]);
 
-        // IRAM gauge
+        // IRAM gauge, with the EMC gauge taking its place on boards without IRAM
+        let mut next_chunk = 2;
         let iram_total = stats.full_memory.iram_total;
         if iram_total > 0 {
             let iram_used = stats.full_memory.iram_used;
@@ -176,6 +237,23 @@ impl MemoryScreen {
                     iram_used_val, iram_used_unit, iram_total_val, iram_total_unit
                 ));
             f.render_widget(iram_gauge, mem_chunks[2]);
+            next_chunk = 3;
+        }
+
+        // EMC gauge
+        let emc_frequency = stats.full_memory.emc_frequency;
+        if emc_frequency > 0 {
+            let emc_usage = stats.full_memory.emc_usage;
+            let emc_gauge = Gauge::default()
+                .block(Block::default().borders(Borders::ALL).title("EMC"))
+                .gauge_style(Style::default().fg(usage_color(emc_usage)))
+                .percent(emc_usage.clamp(0.0, 100.0) as u16)
+                .label(format!(
+                    "{:.0}% @ {}MHz",
+                    emc_usage,
+                    emc_frequency / 1_000_000
+                ));
+            f.render_widget(emc_gauge, mem_chunks[next_chunk]);
         }
     }
 
@@ -198,6 +276,8 @@ impl MemoryScreen {
             crate::modules::memory::format_memory_bytes(stats.full_memory.iram_total);
         let (iram_lfb_val, iram_lfb_unit) =
             crate::modules::memory::format_memory_bytes(stats.full_memory.iram_lfb);
+        let (nv_used_val, nv_used_unit) =
+            crate::modules::memory::format_memory_bytes(stats.full_memory.nv_used);
 
         let items = vec![
             ListItem::new(format!(
@@ -221,6 +301,7 @@ impl MemoryScreen {
                 iram_used_val, iram_used_unit, iram_total_val, iram_total_unit
             )),
             ListItem::new(format!("IRAM LFB: {:.1}{}", iram_lfb_val, iram_lfb_unit)),
+            ListItem::new(format!("NvMap: {:.1}{}", nv_used_val, nv_used_unit)),
         ];
 
         let list = List::new(items)
@@ -236,7 +317,7 @@ impl MemoryScreen {
     }
 
     fn draw_footer(&self, f: &mut Frame, stats: &MemoryScreenStats, area: Rect) {
-        let footer_text = "q: quit | 1-8: screens | h: help";
+        let footer_text = "q: quit | 0-9: screens | h: help";
         let paragraph = Paragraph::new(footer_text)
             .block(Block::default().borders(Borders::ALL))
             .alignment(Alignment::Center);
@@ -249,3 +330,77 @@ impl Default for MemoryScreen {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment_widths_sum_to_bar_width() {
+        for (values, width) in [
+            ([3_000, 2_500, 300, 1_800], 28),
+            ([1, 1, 1, 0], 28),
+            ([7_600_000, 0, 0, 0], 80),
+            ([10, 20, 30, 40], 7),
+        ] {
+            let widths = segment_widths(&values, width);
+            assert_eq!(widths.iter().sum::<u16>(), width, "{:?}", values);
+        }
+    }
+
+    #[test]
+    fn test_segment_widths_proportional() {
+        assert_eq!(segment_widths(&[50, 25, 0, 25], 20), vec![10, 5, 0, 5]);
+        assert_eq!(segment_widths(&[0, 0, 0, 0], 10), vec![0, 0, 0, 10]);
+    }
+
+    #[test]
+    fn test_ram_segments_count_reclaimable_as_cache() {
+        let memory = MemoryStats {
+            ram_total: 8_000,
+            ram_cached: 2_000,
+            ram_reclaimable: 500,
+            ram_buffers: 300,
+            ram_free: 1_200,
+            ..Default::default()
+        };
+
+        let segments = ram_segments(&memory);
+        assert_eq!(segments, [4_000, 2_500, 300, 1_200]);
+        assert_eq!(segments.iter().sum::<u64>(), memory.ram_total);
+    }
+
+    #[test]
+    fn test_memory_screen_shows_emc_gauge() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let mut screen = MemoryScreen::new();
+        screen.update(MemoryScreenStats {
+            memory: SimpleMemoryStats {
+                ram_used: 4_000,
+                ram_total: 8_000,
+                swap_used: 0,
+                swap_total: 0,
+            },
+            full_memory: MemoryStats {
+                ram_total: 8_000,
+                emc_frequency: 3_199_000_000,
+                emc_usage: 42.0,
+                ..Default::default()
+            },
+        });
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+        terminal.draw(|f| screen.draw(f)).unwrap();
+
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains("EMC"));
+        assert!(rendered.contains("42% @ 3199MHz"));
+    }
+}
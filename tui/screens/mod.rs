@@ -6,26 +6,30 @@
 pub mod all;
 pub mod control;
 pub mod cpu_screen;
+pub mod engine;
 pub mod gpu;
 pub mod info;
 pub mod memory;
 pub mod power;
+pub mod processes;
 pub mod temperature;
 
 pub use all::AllScreen;
 pub use control::{ControlScreen, ControlStats};
 pub use cpu::CpuScreen;
 pub use cpu_screen as cpu;
+pub use engine::EngineScreen;
 pub use gpu::GpuScreen;
 pub use info::{InfoScreen, InfoStats};
 
 pub use memory::MemoryScreen;
 pub use power::PowerScreen;
+pub use processes::ProcessScreen;
 pub use temperature::TemperatureScreen;
 
 // Re-export Simple*Stats and ScreenStats from individual modules
 pub use cpu_screen::{CoreStats, CpuScreenStats, SimpleCpuStats, SimpleFanStats};
-pub use gpu::{GpuScreenStats, SimpleGpuStats};
+pub use gpu::{GpuScreenStats, GpuThermalLimits, SimpleGpuStats};
 pub use info::SimpleBoardInfo;
 pub use memory::{MemoryScreenStats, SimpleMemoryStats};
 pub use power::{PowerRail, PowerScreenStats, SimplePowerStats};
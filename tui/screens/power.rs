@@ -3,15 +3,22 @@
 
 //! Power screen - detailed power monitoring
 
+use std::collections::HashMap;
+
 use ratatui::{
     backend::Backend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
+    symbols::Marker,
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Sparkline},
+    widgets::{
+        Axis, Block, Borders, Chart, Dataset, GraphType, List, ListItem, Paragraph, Sparkline,
+    },
     Frame,
 };
 
+use crate::modules::history::{self, History};
+use crate::modules::power::PowerSource;
 use crate::modules::PowerStats as FullPowerStats;
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -19,15 +26,34 @@ pub struct SimplePowerStats {
     pub total: f32,
 }
 
+/// Colors of the stacked rails, reused in order when there are more rails
+const RAIL_COLORS: [Color; 6] = [
+    Color::Cyan,
+    Color::Green,
+    Color::Yellow,
+    Color::Magenta,
+    Color::Blue,
+    Color::Red,
+];
+
+/// Rail name, color and `(x, watts)` points of one chart line
+type RailSeries<'a> = (&'a str, Color, Vec<(f64, f64)>);
+
 /// Power screen - detailed power monitoring
 pub struct PowerScreen {
     stats: Option<PowerScreenStats>,
+    /// Recent power of each rail, keyed by rail name so rails can come and go
+    histories: HashMap<String, History<f32>>,
+    /// Recent total power in W
+    total_history: History<f32>,
 }
 
 #[derive(Debug, Clone)]
 pub struct PowerScreenStats {
     pub power: SimplePowerStats,
     pub rails: Vec<PowerRail>,
+    pub source: PowerSource,
+    pub battery_capacity: Option<u8>,
 }
 
 #[derive(Debug, Clone)]
@@ -36,17 +62,48 @@ pub struct PowerRail {
     pub current: f32,
     pub voltage: f32,
     pub power: f32,
+    pub power_avg: Option<f32>,
+    /// Rail measuring the whole board input, left out of the stacked chart
+    pub is_total: bool,
 }
 
 impl PowerScreen {
     pub fn new() -> Self {
-        Self { stats: None }
+        Self {
+            stats: None,
+            histories: HashMap::new(),
+            total_history: History::new(history::DEFAULT_CAPACITY),
+        }
     }
 
     pub fn update(&mut self, stats: PowerScreenStats) {
+        for rail in &stats.rails {
+            self.histories
+                .entry(rail.name.clone())
+                .or_insert_with(|| History::new(history::DEFAULT_CAPACITY))
+                .push(rail.power);
+        }
+        // A rail missing from this sample drew nothing, recording 0 keeps
+        // every history aligned with the total on the newest sample
+        for (name, samples) in &mut self.histories {
+            if !stats.rails.iter().any(|rail| &rail.name == name) {
+                samples.push(0.0);
+            }
+        }
+        self.total_history.push(stats.power.total);
         self.stats = Some(stats);
     }
 
+    /// Power of a rail, oldest first, `None` until it was first seen
+    pub fn rail_history(&self, name: &str) -> Option<&[f32]> {
+        self.histories.get(name).map(History::as_slice)
+    }
+
+    /// Total power in W, oldest first
+    pub fn total_history(&self) -> &[f32] {
+        self.total_history.as_slice()
+    }
+
     pub fn draw(&mut self, f: &mut Frame) {
         if let Some(stats) = &self.stats {
             self.draw_content(f, stats);
@@ -73,12 +130,22 @@ impl PowerScreen {
             ])
             .split(f.size());
 
-        self.draw_header(f, chunks[0]);
+        self.draw_header(f, stats, chunks[0]);
         self.draw_body(f, stats, chunks[1]);
         self.draw_footer(f, stats, chunks[2]);
     }
 
-    fn draw_header(&self, f: &mut Frame, area: Rect) {
+    fn draw_header(&self, f: &mut Frame, stats: &PowerScreenStats, area: Rect) {
+        let source = match (stats.source, stats.battery_capacity) {
+            (PowerSource::Battery, Some(capacity)) => format!("Battery {}%", capacity),
+            (source, _) => source.to_string(),
+        };
+        let source_color = match stats.source {
+            PowerSource::AC => Color::Green,
+            PowerSource::Battery => Color::Yellow,
+            PowerSource::Unknown => Color::Gray,
+        };
+
         let header = Paragraph::new(vec![Line::from(vec![
             Span::styled(
                 "rusted-jetsons",
@@ -88,30 +155,124 @@ impl PowerScreen {
             ),
             Span::raw(" | "),
             Span::styled("Power Details", Style::default().fg(Color::Gray)),
+            Span::raw(" | "),
+            Span::styled(source, Style::default().fg(source_color)),
         ])])
         .alignment(Alignment::Center);
         f.render_widget(header, area);
     }
 
     fn draw_body(&self, f: &mut Frame, stats: &PowerScreenStats, area: Rect) {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(50), // Readings
+                Constraint::Percentage(50), // History chart
+            ])
+            .split(area);
+
         let body_chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
                 Constraint::Length(20), // Total power
                 Constraint::Min(0),     // Power rails list
             ])
-            .split(area);
+            .split(rows[0]);
 
         self.draw_total_power(f, stats, body_chunks[0]);
         self.draw_power_rails(f, stats, body_chunks[1]);
+        self.draw_history_chart(f, stats, rows[1]);
+    }
+
+    /// Rails stacked on top of each other with the total drawn over them
+    ///
+    /// Rail power is in mW and plotted in W next to the total. Each rail is
+    /// drawn at the running sum of the rails below it, so the gap between
+    /// two lines is the power of the upper rail.
+    fn draw_history_chart(&self, f: &mut Frame, stats: &PowerScreenStats, area: Rect) {
+        let capacity = history::DEFAULT_CAPACITY;
+        let x_of = |len: usize, i: usize| (capacity.saturating_sub(len) + i) as f64;
+
+        let mut rails: Vec<&str> = stats
+            .rails
+            .iter()
+            .filter(|rail| !rail.is_total)
+            .map(|rail| rail.name.as_str())
+            .collect();
+        rails.sort_unstable();
+
+        let mut stacked = vec![0.0f64; capacity];
+        let mut series: Vec<RailSeries> = Vec::new();
+        for (idx, name) in rails.iter().enumerate() {
+            let Some(samples) = self.rail_history(name) else {
+                continue;
+            };
+            let samples = &samples[samples.len().saturating_sub(capacity)..];
+            let points = samples
+                .iter()
+                .enumerate()
+                .map(|(i, &mw)| {
+                    let x = x_of(samples.len(), i);
+                    stacked[x as usize] += mw as f64 / 1000.0;
+                    (x, stacked[x as usize])
+                })
+                .collect();
+            series.push((name, RAIL_COLORS[idx % RAIL_COLORS.len()], points));
+        }
+
+        let total = self.total_history();
+        let total_points: Vec<(f64, f64)> = total
+            .iter()
+            .enumerate()
+            .map(|(i, &w)| (x_of(total.len(), i), w as f64))
+            .collect();
+
+        let max = series
+            .iter()
+            .flat_map(|(_, _, points)| points.iter())
+            .chain(total_points.iter())
+            .fold(0.0f64, |max, &(_, w)| max.max(w));
+        // Leave headroom so the total line doesn't sit on the border
+        let max = if max > 0.0 { (max * 1.2).ceil() } else { 1.0 };
+
+        let mut datasets: Vec<Dataset> = series
+            .iter()
+            .map(|(name, color, points)| {
+                Dataset::default()
+                    .name(*name)
+                    .marker(Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(*color))
+                    .data(points)
+            })
+            .collect();
+        datasets.push(
+            Dataset::default()
+                .name("Total")
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::White))
+                .data(&total_points),
+        );
+
+        let chart = Chart::new(datasets)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Power History"),
+            )
+            .x_axis(Axis::default().bounds([0.0, capacity.saturating_sub(1) as f64]))
+            .y_axis(
+                Axis::default()
+                    .style(Style::default().fg(Color::Gray))
+                    .bounds([0.0, max])
+                    .labels(vec![Span::raw("0W"), Span::raw(format!("{:.0}W", max))]),
+            );
+        f.render_widget(chart, area);
     }
 
     fn draw_total_power(&self, f: &mut Frame, stats: &PowerScreenStats, area: Rect) {
-        let items = vec![
-            ListItem::new(format!("Total: {:.2}W", stats.power.total)),
-            ListItem::new(""),
-            ListItem::new("Power usage graph not implemented yet"),
-        ];
+        let items = vec![ListItem::new(format!("Total: {:.2}W", stats.power.total))];
 
         let list = List::new(items)
             .block(Block::default().borders(Borders::ALL).title("Total Power"))
@@ -126,10 +287,14 @@ impl PowerScreen {
             .rails
             .iter()
             .map(|rail| {
-                ListItem::new(format!(
+                let mut line = format!(
                     "{:12} {:.2}mA {:.2}mV {:.2}mW",
                     rail.name, rail.current, rail.voltage, rail.power
-                ))
+                );
+                if let Some(avg) = rail.power_avg {
+                    line.push_str(&format!(" (avg {:.2}mW)", avg));
+                }
+                ListItem::new(line)
             })
             .collect();
 
@@ -143,7 +308,7 @@ impl PowerScreen {
 
     fn draw_footer(&self, f: &mut Frame, stats: &PowerScreenStats, area: Rect) {
         let footer_text = format!(
-            "q: quit | 1-8: screens | h: help | Total: {:.2}W",
+            "q: quit | 0-9: screens | h: help | Total: {:.2}W",
             stats.power.total
         );
         let paragraph = Paragraph::new(footer_text.as_str())
@@ -158,3 +323,104 @@ impl Default for PowerScreen {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats_with(total: f32, rails: &[(&str, f32)]) -> PowerScreenStats {
+        PowerScreenStats {
+            power: SimplePowerStats { total },
+            rails: rails
+                .iter()
+                .map(|&(name, power)| PowerRail {
+                    name: name.to_string(),
+                    current: 0.0,
+                    voltage: 0.0,
+                    power,
+                    power_avg: None,
+                    is_total: name == "VDD_IN",
+                })
+                .collect(),
+            source: PowerSource::AC,
+            battery_capacity: None,
+        }
+    }
+
+    fn render(screen: &mut PowerScreen) -> String {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 30)).unwrap();
+        terminal.draw(|f| screen.draw(f)).unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_rail_histories_accumulate_and_trim() {
+        let mut screen = PowerScreen::new();
+        assert_eq!(screen.rail_history("VDD_CPU_GPU_CV"), None);
+
+        let extra = 5;
+        for i in 0..history::DEFAULT_CAPACITY + extra {
+            screen.update(stats_with(
+                i as f32,
+                &[("VDD_IN", 5000.0), ("VDD_CPU_GPU_CV", i as f32)],
+            ));
+        }
+
+        let rail = screen.rail_history("VDD_CPU_GPU_CV").unwrap();
+        assert_eq!(rail.len(), history::DEFAULT_CAPACITY);
+        assert_eq!(rail[0], extra as f32);
+        assert_eq!(
+            rail.last(),
+            Some(&((history::DEFAULT_CAPACITY + extra - 1) as f32))
+        );
+        assert_eq!(
+            screen.rail_history("VDD_IN").map(<[f32]>::len),
+            Some(history::DEFAULT_CAPACITY)
+        );
+        assert_eq!(screen.total_history().len(), history::DEFAULT_CAPACITY);
+    }
+
+    #[test]
+    fn test_missing_rails_stay_aligned() {
+        let mut screen = PowerScreen::new();
+        screen.update(stats_with(5.0, &[("VDD_CPU_GPU_CV", 1000.0)]));
+        screen.update(stats_with(6.0, &[("VDD_SOC", 2000.0)]));
+        screen.update(stats_with(
+            7.0,
+            &[("VDD_CPU_GPU_CV", 3000.0), ("VDD_SOC", 2500.0)],
+        ));
+
+        assert_eq!(
+            screen.rail_history("VDD_CPU_GPU_CV"),
+            Some(&[1000.0, 0.0, 3000.0][..])
+        );
+        assert_eq!(screen.rail_history("VDD_SOC"), Some(&[2000.0, 2500.0][..]));
+        assert_eq!(screen.total_history(), &[5.0, 6.0, 7.0]);
+    }
+
+    #[test]
+    fn test_history_chart_renders_changing_rails() {
+        let mut screen = PowerScreen::new();
+        screen.update(stats_with(
+            9.0,
+            &[("VDD_IN", 9000.0), ("VDD_CPU_GPU_CV", 3000.0)],
+        ));
+        assert!(render(&mut screen).contains("Power History"));
+
+        screen.update(stats_with(0.0, &[]));
+        render(&mut screen);
+
+        screen.update(stats_with(8.0, &[("VDD_IN", 8000.0), ("VDD_SOC", 2000.0)]));
+        let rendered = render(&mut screen);
+        assert!(rendered.contains("Power History"));
+        assert!(rendered.contains("VDD_SOC"));
+    }
+}
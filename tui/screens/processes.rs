@@ -0,0 +1,281 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Process screen - GPU process table
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::modules::memory::format_memory_bytes;
+use crate::modules::processes::{ProcessStats, COMMAND_COLUMN_WIDTH};
+
+/// Process screen - GPU process table
+pub struct ProcessScreen {
+    stats: Option<ProcessStats>,
+    table_state: TableState,
+}
+
+impl ProcessScreen {
+    pub fn new() -> Self {
+        Self {
+            stats: None,
+            table_state: TableState::default(),
+        }
+    }
+
+    pub fn update(&mut self, stats: ProcessStats) {
+        // Keep the selection on screen when the list shrinks
+        let len = stats.gpu_processes.len();
+        let selected = match self.table_state.selected() {
+            _ if len == 0 => None,
+            Some(idx) => Some(idx.min(len - 1)),
+            None => Some(0),
+        };
+        self.table_state.select(selected);
+        self.stats = Some(stats);
+    }
+
+    /// Stats from the last update
+    pub fn stats(&self) -> Option<&ProcessStats> {
+        self.stats.as_ref()
+    }
+
+    /// Move the selection one row down
+    pub fn select_next(&mut self) {
+        let len = self.process_count();
+        if len == 0 {
+            return;
+        }
+        let next = self
+            .table_state
+            .selected()
+            .map_or(0, |idx| (idx + 1).min(len - 1));
+        self.table_state.select(Some(next));
+    }
+
+    /// Move the selection one row up
+    pub fn select_previous(&mut self) {
+        if self.process_count() == 0 {
+            return;
+        }
+        let previous = self
+            .table_state
+            .selected()
+            .map_or(0, |idx| idx.saturating_sub(1));
+        self.table_state.select(Some(previous));
+    }
+
+    pub fn selected(&self) -> Option<usize> {
+        self.table_state.selected()
+    }
+
+    fn process_count(&self) -> usize {
+        self.stats.as_ref().map_or(0, |s| s.gpu_processes.len())
+    }
+
+    pub fn draw(&mut self, f: &mut Frame) {
+        if let Some(stats) = self.stats.take() {
+            self.draw_content(f, &stats);
+            self.stats = Some(stats);
+        } else {
+            self.draw_loading(f);
+        }
+    }
+
+    fn draw_loading(&self, f: &mut Frame) {
+        let size = f.size();
+        let paragraph = Paragraph::new("Loading...")
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).title("Processes"));
+        f.render_widget(paragraph, size);
+    }
+
+    fn draw_content(&mut self, f: &mut Frame, stats: &ProcessStats) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Header
+                Constraint::Min(0),    // Content
+                Constraint::Length(3), // Footer
+            ])
+            .split(f.size());
+
+        self.draw_header(f, chunks[0]);
+        self.draw_table(f, stats, chunks[1]);
+        self.draw_footer(f, stats, chunks[2]);
+    }
+
+    fn draw_header(&self, f: &mut Frame, area: Rect) {
+        let header = Paragraph::new(vec![Line::from(vec![
+            Span::styled(
+                "rusted-jetsons",
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" | "),
+            Span::styled("Processes", Style::default().fg(Color::Gray)),
+        ])])
+        .alignment(Alignment::Center);
+        f.render_widget(header, area);
+    }
+
+    /// GPU process table; the table scrolls to keep the selected row visible
+    fn draw_table(&mut self, f: &mut Frame, stats: &ProcessStats, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("GPU Processes");
+
+        if stats.gpu_processes.is_empty() {
+            let paragraph = Paragraph::new("No GPU processes")
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(Color::DarkGray))
+                .block(block);
+            f.render_widget(paragraph, area);
+            return;
+        }
+
+        let header = Row::new(["PID", "Name", "SM%", "FB Mem", "Command"]).style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let rows = stats.gpu_processes.iter().map(|process| {
+            Row::new(vec![
+                Cell::from(process.pid.to_string()),
+                Cell::from(process.name.clone()),
+                Cell::from(format!("{:.0}", process.gpu_usage)),
+                Cell::from(format_fb_memory(process.memory)),
+                Cell::from(process.display_command(COMMAND_COLUMN_WIDTH)),
+            ])
+        });
+
+        let widths = [
+            Constraint::Length(8),
+            Constraint::Length(16),
+            Constraint::Length(5),
+            Constraint::Length(10),
+            Constraint::Min(10),
+        ];
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(block)
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+        f.render_stateful_widget(table, area, &mut self.table_state);
+    }
+
+    fn draw_footer(&self, f: &mut Frame, stats: &ProcessStats, area: Rect) {
+        let footer_text = format!(
+            "q: quit | 0-9: screens | ↑/↓: scroll | GPU: {} | Total: {}",
+            stats.gpu_processes.len(),
+            stats.total_processes
+        );
+        let paragraph = Paragraph::new(footer_text)
+            .block(Block::default().borders(Borders::ALL))
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+    }
+}
+
+impl Default for ProcessScreen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Format framebuffer memory in bytes, or "-" when it wasn't reported
+fn format_fb_memory(bytes: u64) -> String {
+    if bytes == 0 {
+        return "-".to_string();
+    }
+    let (value, unit) = format_memory_bytes(bytes);
+    format!("{:.1}{}", value, unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::processes::ProcessInfo;
+
+    fn stats_with(count: u32) -> ProcessStats {
+        ProcessStats {
+            total_processes: 200,
+            gpu_processes: (0..count)
+                .map(|i| ProcessInfo {
+                    pid: 1000 + i,
+                    name: format!("proc{}", i),
+                    gpu_usage: 10.0,
+                    memory: 0,
+                    command: format!("/usr/bin/proc{}", i),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_process_screen_selection() {
+        let mut screen = ProcessScreen::new();
+        screen.select_next();
+        assert_eq!(screen.selected(), None);
+
+        screen.update(stats_with(3));
+        assert_eq!(screen.selected(), Some(0));
+
+        screen.select_previous();
+        assert_eq!(screen.selected(), Some(0));
+        screen.select_next();
+        screen.select_next();
+        screen.select_next();
+        assert_eq!(screen.selected(), Some(2));
+
+        // Selection is clamped when the list shrinks
+        screen.update(stats_with(1));
+        assert_eq!(screen.selected(), Some(0));
+
+        screen.update(stats_with(0));
+        assert_eq!(screen.selected(), None);
+    }
+
+    #[test]
+    fn test_format_fb_memory() {
+        assert_eq!(format_fb_memory(0), "-");
+        assert_eq!(format_fb_memory(512 * 1_048_576), "512.0MB");
+    }
+
+    #[test]
+    fn test_process_screen_scrolls_to_selection() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let mut screen = ProcessScreen::new();
+        screen.update(stats_with(30));
+        for _ in 0..29 {
+            screen.select_next();
+        }
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 16)).unwrap();
+        terminal.draw(|f| screen.draw(f)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let text: String = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer.get(x, y).symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(text.contains("PID"));
+        assert!(text.contains("1029"));
+        assert!(!text.contains("1000 "));
+        assert!(text.contains("GPU: 30 | Total: 200"));
+    }
+}
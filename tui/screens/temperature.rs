@@ -3,22 +3,54 @@
 
 //! Temperature screen - detailed temperature monitoring
 
+use std::collections::HashMap;
+
 use ratatui::{
     backend::Backend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
+    symbols::Marker,
     text::{Line, Span},
-    widgets::{Block, Borders, Gauge, List, ListItem, Paragraph, Sparkline},
+    widgets::{
+        Axis, Block, Borders, Chart, Dataset, Gauge, GraphType, List, ListItem, Paragraph,
+        Sparkline,
+    },
     Frame,
 };
 
-use crate::modules::TemperatureStats;
+use crate::modules::history::{self, History};
+use crate::modules::temperature::{to_unit, zone_sensor};
+use crate::modules::{TemperatureStats, TemperatureUnit};
+use crate::tui::widgets::color::temperature_color;
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, Default, serde::Serialize)]
 pub struct SimpleTemperatureStats {
     pub cpu: f32,
     pub gpu: f32,
     pub board: f32,
+    /// Critical trip points of the zones above, 0.0 when unknown
+    pub cpu_critical: f32,
+    pub gpu_critical: f32,
+    pub board_critical: f32,
+    /// Zones and cores being throttled, empty when running at full speed
+    pub throttle_zones: Vec<String>,
+}
+
+impl SimpleTemperatureStats {
+    /// Build the summary, including critical trip points, from full stats
+    pub fn from_stats(stats: &TemperatureStats) -> Self {
+        let critical = |sensor| stats.zone(sensor).map_or(0.0, |z| z.critical_temp);
+
+        Self {
+            cpu: stats.cpu,
+            gpu: stats.gpu,
+            board: stats.board,
+            cpu_critical: critical("cpu"),
+            gpu_critical: critical("gpu"),
+            board_critical: critical("board"),
+            throttle_zones: stats.throttle_zones.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -36,20 +68,46 @@ pub struct ThermalZone {
     pub usage_percent: u16,
 }
 
+/// Zone label, color and `(x, temperature)` points of one chart line
+type ZoneSeries<'a> = (&'a str, Color, Vec<(f64, f64)>);
+
 /// Temperature screen - detailed temperature monitoring
 pub struct TemperatureScreen {
     stats: Option<TemperatureScreenStats>,
+    unit: TemperatureUnit,
+    /// Recent readings in °C, keyed by zone name so zones can come and go
+    histories: HashMap<String, History<f32>>,
 }
 
 impl TemperatureScreen {
     pub fn new() -> Self {
-        Self { stats: None }
+        Self {
+            stats: None,
+            unit: TemperatureUnit::default(),
+            histories: HashMap::new(),
+        }
     }
 
     pub fn update(&mut self, stats: TemperatureScreenStats) {
+        for zone in &stats.zones {
+            self.histories
+                .entry(zone.name.clone())
+                .or_insert_with(|| History::new(history::DEFAULT_CAPACITY))
+                .push(zone.current_temp);
+        }
         self.stats = Some(stats);
     }
 
+    /// Readings of a zone in °C, oldest first, `None` until it was first seen
+    pub fn zone_history(&self, name: &str) -> Option<&[f32]> {
+        self.histories.get(name).map(History::as_slice)
+    }
+
+    /// Show zone readings, limits and the history chart in `unit`
+    pub fn set_temperature_unit(&mut self, unit: TemperatureUnit) {
+        self.unit = unit;
+    }
+
     pub fn draw(&mut self, f: &mut Frame) {
         if let Some(stats) = &self.stats {
             let chunks = Layout::default()
@@ -105,25 +163,110 @@ impl TemperatureScreen {
     }
 
     fn draw_body(&self, f: &mut Frame, stats: &TemperatureScreenStats, area: Rect) {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(50), // Readings
+                Constraint::Percentage(50), // History chart
+            ])
+            .split(area);
+
         let body_chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
                 Constraint::Length(25), // Main temps
                 Constraint::Min(0),     // All zones
             ])
-            .split(area);
+            .split(rows[0]);
 
         self.draw_main_temps(f, stats, body_chunks[0]);
         self.draw_all_zones(f, stats, body_chunks[1]);
+        self.draw_history_chart(f, stats, rows[1]);
+    }
+
+    /// Line chart of the CPU, GPU and board zones, newest readings on the right
+    fn draw_history_chart(&self, f: &mut Frame, stats: &TemperatureScreenStats, area: Rect) {
+        let capacity = history::DEFAULT_CAPACITY;
+        let series: Vec<ZoneSeries> = stats
+            .zones
+            .iter()
+            .filter_map(|zone| {
+                let color = match zone_sensor(&zone.name)? {
+                    "cpu" => Color::Cyan,
+                    "gpu" => Color::Green,
+                    "board" => Color::Yellow,
+                    _ => return None,
+                };
+                let samples = self.zone_history(&zone.name)?;
+                let offset = capacity.saturating_sub(samples.len());
+                let points = samples
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &temp)| ((offset + i) as f64, to_unit(temp, self.unit) as f64))
+                    .collect();
+                Some((zone.name.as_str(), color, points))
+            })
+            .collect();
+
+        let (min, max) = series
+            .iter()
+            .flat_map(|(_, _, points)| points.iter().map(|&(_, temp)| temp))
+            .fold((f64::MAX, f64::MIN), |(min, max), temp| {
+                (min.min(temp), max.max(temp))
+            });
+        // Pad the range so flat lines don't sit on the border
+        let (min, max) = if min > max {
+            (0.0, 100.0)
+        } else {
+            ((min - 5.0).floor(), (max + 5.0).ceil())
+        };
+
+        let datasets = series
+            .iter()
+            .map(|(name, color, points)| {
+                Dataset::default()
+                    .name(*name)
+                    .marker(Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(*color))
+                    .data(points)
+            })
+            .collect();
+
+        let symbol = self.unit.symbol();
+        let chart = Chart::new(datasets)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Temperature History"),
+            )
+            .x_axis(Axis::default().bounds([0.0, capacity.saturating_sub(1) as f64]))
+            .y_axis(
+                Axis::default()
+                    .style(Style::default().fg(Color::Gray))
+                    .bounds([min, max])
+                    .labels(vec![
+                        Span::raw(format!("{:.0}{}", min, symbol)),
+                        Span::raw(format!("{:.0}{}", max, symbol)),
+                    ]),
+            );
+        f.render_widget(chart, area);
     }
 
     fn draw_main_temps(&self, f: &mut Frame, stats: &TemperatureScreenStats, area: Rect) {
-        let items = vec![
-            ListItem::new(format!("CPU: {:.1}°C", stats.temperature.cpu)),
-            ListItem::new(format!("GPU: {:.1}°C", stats.temperature.gpu)),
-            ListItem::new(""),
-            ListItem::new("Temperature graph not implemented yet"),
+        let temp = &stats.temperature;
+        let mut items = vec![
+            ListItem::new(format!("CPU: {}", self.unit.format(temp.cpu)))
+                .style(temperature_style(temp.cpu, temp.cpu_critical)),
+            ListItem::new(format!("GPU: {}", self.unit.format(temp.gpu)))
+                .style(temperature_style(temp.gpu, temp.gpu_critical)),
         ];
+        if !temp.throttle_zones.is_empty() {
+            items.push(
+                ListItem::new(format!("THROTTLING: {}", temp.throttle_zones.join(", ")))
+                    .style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+            );
+        }
 
         let list = List::new(items)
             .block(
@@ -143,9 +286,13 @@ impl TemperatureScreen {
             .iter()
             .map(|zone| {
                 ListItem::new(format!(
-                    "{:18} {:.1}°C / {:.1}°C ({}%)",
-                    zone.name, zone.current_temp, zone.max_temp, zone.usage_percent
+                    "{:18} {} / {} ({}%)",
+                    zone.name,
+                    self.unit.format(zone.current_temp),
+                    self.unit.format(zone.max_temp),
+                    zone.usage_percent
                 ))
+                .style(temperature_style(zone.current_temp, zone.critical_temp))
             })
             .collect();
 
@@ -163,8 +310,9 @@ impl TemperatureScreen {
 
     fn draw_footer(&self, f: &mut Frame, stats: &TemperatureScreenStats, area: Rect) {
         let footer_text = format!(
-            "q: quit | 1-8: screens | h: help | CPU: {:.1}°C | GPU: {:.1}°C",
-            stats.temperature.cpu, stats.temperature.gpu
+            "q: quit | 0-9: screens | h: help | CPU: {} | GPU: {}",
+            self.unit.format(stats.temperature.cpu),
+            self.unit.format(stats.temperature.gpu)
         );
         let paragraph = Paragraph::new(footer_text.as_str())
             .block(Block::default().borders(Borders::ALL))
@@ -173,8 +321,84 @@ impl TemperatureScreen {
     }
 }
 
+/// Color a reading against its zone's critical trip point
+fn temperature_style(current: f32, critical: f32) -> Style {
+    temperature_color(current, critical).map_or(Style::default(), |c| Style::default().fg(c))
+}
+
 impl Default for TemperatureScreen {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats_with(zones: &[(&str, f32)]) -> TemperatureScreenStats {
+        TemperatureScreenStats {
+            temperature: SimpleTemperatureStats::default(),
+            zones: zones
+                .iter()
+                .map(|&(name, current_temp)| ThermalZone {
+                    name: name.to_string(),
+                    current_temp,
+                    max_temp: 0.0,
+                    critical_temp: 0.0,
+                    usage_percent: 0,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_zone_histories_are_created_on_first_sighting() {
+        let mut screen = TemperatureScreen::new();
+        assert_eq!(screen.zone_history("cpu-thermal"), None);
+
+        screen.update(stats_with(&[("cpu-thermal", 45.0)]));
+        assert_eq!(screen.zone_history("cpu-thermal"), Some(&[45.0][..]));
+        assert_eq!(screen.zone_history("gpu-thermal"), None);
+
+        screen.update(stats_with(&[("cpu-thermal", 46.0), ("gpu-thermal", 50.0)]));
+        assert_eq!(screen.zone_history("cpu-thermal"), Some(&[45.0, 46.0][..]));
+        assert_eq!(screen.zone_history("gpu-thermal"), Some(&[50.0][..]));
+    }
+
+    #[test]
+    fn test_zone_histories_survive_missing_zones() {
+        let mut screen = TemperatureScreen::new();
+        screen.update(stats_with(&[("cpu-thermal", 45.0), ("gpu-thermal", 50.0)]));
+        screen.update(stats_with(&[("gpu-thermal", 51.0)]));
+        screen.update(stats_with(&[("cpu-thermal", 47.0), ("gpu-thermal", 52.0)]));
+
+        assert_eq!(screen.zone_history("cpu-thermal"), Some(&[45.0, 47.0][..]));
+        assert_eq!(
+            screen.zone_history("gpu-thermal"),
+            Some(&[50.0, 51.0, 52.0][..])
+        );
+    }
+
+    #[test]
+    fn test_history_chart_renders() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let mut screen = TemperatureScreen::new();
+        screen.update(stats_with(&[("cpu-thermal", 45.0), ("tj-thermal", 48.0)]));
+        screen.update(stats_with(&[("cpu-thermal", 46.0), ("tj-thermal", 49.0)]));
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 30)).unwrap();
+        terminal.draw(|f| screen.draw(f)).unwrap();
+
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains("Temperature History"));
+        assert!(rendered.contains("cpu-thermal"));
+    }
+}
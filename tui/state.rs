@@ -14,10 +14,12 @@ pub enum ScreenState {
     Temperature,
     Control,
     Info,
+    Engine,
+    Processes,
 }
 
 impl ScreenState {
-    pub const COUNT: usize = 8;
+    pub const COUNT: usize = 10;
 
     pub fn from_index(idx: usize) -> Option<Self> {
         match idx {
@@ -29,6 +31,8 @@ impl ScreenState {
             5 => Some(ScreenState::Temperature),
             6 => Some(ScreenState::Control),
             7 => Some(ScreenState::Info),
+            8 => Some(ScreenState::Engine),
+            9 => Some(ScreenState::Processes),
             _ => None,
         }
     }
@@ -43,9 +47,18 @@ impl ScreenState {
             ScreenState::Temperature => 6,
             ScreenState::Control => 7,
             ScreenState::Info => 8,
+            ScreenState::Engine => 9,
+            ScreenState::Processes => 10,
         }
     }
 
+    /// Screen whose name matches `name`, ignoring case
+    pub fn from_name(name: &str) -> Option<Self> {
+        (0..Self::COUNT)
+            .filter_map(Self::from_index)
+            .find(|screen| screen.name().eq_ignore_ascii_case(name))
+    }
+
     pub fn name(&self) -> &'static str {
         match self {
             ScreenState::All => "All",
@@ -56,6 +69,8 @@ impl ScreenState {
             ScreenState::Temperature => "Temperature",
             ScreenState::Control => "Control",
             ScreenState::Info => "Info",
+            ScreenState::Engine => "Engine",
+            ScreenState::Processes => "Processes",
         }
     }
 }
@@ -79,7 +94,7 @@ mod tests {
 
     #[test]
     fn test_screen_state_count() {
-        assert_eq!(ScreenState::COUNT, 8, "Should have 8 screen states");
+        assert_eq!(ScreenState::COUNT, 10, "Should have 10 screen states");
     }
 
     #[test]
@@ -92,10 +107,12 @@ mod tests {
         assert_eq!(ScreenState::from_index(5), Some(ScreenState::Temperature));
         assert_eq!(ScreenState::from_index(6), Some(ScreenState::Control));
         assert_eq!(ScreenState::from_index(7), Some(ScreenState::Info));
+        assert_eq!(ScreenState::from_index(8), Some(ScreenState::Engine));
+        assert_eq!(ScreenState::from_index(9), Some(ScreenState::Processes));
         assert_eq!(
-            ScreenState::from_index(8),
+            ScreenState::from_index(10),
             None,
-            "Index 8 should be out of range"
+            "Index 10 should be out of range"
         );
         assert_eq!(
             ScreenState::from_index(999),
@@ -114,6 +131,8 @@ mod tests {
         assert_eq!(ScreenState::Temperature.index(), 6);
         assert_eq!(ScreenState::Control.index(), 7);
         assert_eq!(ScreenState::Info.index(), 8);
+        assert_eq!(ScreenState::Engine.index(), 9);
+        assert_eq!(ScreenState::Processes.index(), 10);
     }
 
     #[test]
@@ -126,6 +145,22 @@ mod tests {
         assert_eq!(ScreenState::Temperature.name(), "Temperature");
         assert_eq!(ScreenState::Control.name(), "Control");
         assert_eq!(ScreenState::Info.name(), "Info");
+        assert_eq!(ScreenState::Engine.name(), "Engine");
+        assert_eq!(ScreenState::Processes.name(), "Processes");
+    }
+
+    #[test]
+    fn test_screen_state_from_name() {
+        for idx in 0..ScreenState::COUNT {
+            let screen = ScreenState::from_index(idx).unwrap();
+            assert_eq!(ScreenState::from_name(screen.name()), Some(screen));
+        }
+        assert_eq!(ScreenState::from_name("gpu"), Some(ScreenState::Gpu));
+        assert_eq!(
+            ScreenState::from_name("TEMPERATURE"),
+            Some(ScreenState::Temperature)
+        );
+        assert_eq!(ScreenState::from_name("fans"), None);
     }
 
     #[test]
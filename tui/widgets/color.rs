@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Threshold colors shared by the gauges of every screen
+
+use ratatui::style::Color;
+
+/// Usage percentage where gauges turn yellow
+pub const USAGE_WARN: f32 = 70.0;
+/// Usage percentage where gauges turn red
+pub const USAGE_CRIT: f32 = 90.0;
+
+/// Fraction of a zone's critical trip point where temperatures turn yellow
+pub const TEMP_WARN_RATIO: f32 = 0.70;
+/// Fraction of a zone's critical trip point where temperatures turn red
+pub const TEMP_CRIT_RATIO: f32 = 0.85;
+
+/// Green below `warn`, yellow from `warn`, red from `crit`
+pub fn gauge_color(value: f32, warn: f32, crit: f32) -> Color {
+    if value >= crit {
+        Color::Red
+    } else if value >= warn {
+        Color::Yellow
+    } else {
+        Color::Green
+    }
+}
+
+/// Color of a usage percentage
+pub fn usage_color(usage: f32) -> Color {
+    gauge_color(usage, USAGE_WARN, USAGE_CRIT)
+}
+
+/// Color of a temperature, with thresholds derived from the critical trip point
+///
+/// Returns `None` when the zone has no critical trip point.
+pub fn temperature_color(current: f32, critical: f32) -> Option<Color> {
+    (critical > 0.0).then(|| {
+        gauge_color(
+            current,
+            critical * TEMP_WARN_RATIO,
+            critical * TEMP_CRIT_RATIO,
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gauge_color_boundaries() {
+        assert_eq!(gauge_color(69.9, 70.0, 90.0), Color::Green);
+        assert_eq!(gauge_color(70.0, 70.0, 90.0), Color::Yellow);
+        assert_eq!(gauge_color(89.9, 70.0, 90.0), Color::Yellow);
+        assert_eq!(gauge_color(90.0, 70.0, 90.0), Color::Red);
+    }
+
+    #[test]
+    fn test_usage_color() {
+        assert_eq!(usage_color(0.0), Color::Green);
+        assert_eq!(usage_color(70.0), Color::Yellow);
+        assert_eq!(usage_color(100.0), Color::Red);
+    }
+
+    #[test]
+    fn test_temperature_color_follows_critical_trip() {
+        // 70% and 85% of a 100°C critical trip point
+        assert_eq!(temperature_color(69.0, 100.0), Some(Color::Green));
+        assert_eq!(temperature_color(70.0, 100.0), Some(Color::Yellow));
+        assert_eq!(temperature_color(85.0, 100.0), Some(Color::Red));
+        // The same reading is fine against a hotter trip point
+        assert_eq!(temperature_color(85.0, 125.0), Some(Color::Green));
+    }
+
+    #[test]
+    fn test_temperature_color_without_critical_trip() {
+        assert_eq!(temperature_color(95.0, 0.0), None);
+    }
+}
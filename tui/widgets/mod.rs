@@ -1,8 +1,10 @@
 // SPDX-License-Identifier: LGPL-3.0
 // Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
 
+pub mod color;
 pub mod control;
 pub mod cpu;
 pub mod gpu;
 pub mod memory;
 pub mod power;
+pub mod tabs;
@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: LGPL-3.0
+// Copyright (C) 2026 Mateusz Krawczuk with work <m.krawczuk@cybrixsystems.com>
+
+//! Screen tab bar drawn in the header of every screen
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::tui::state::ScreenState;
+
+const SEPARATOR: &str = "│";
+
+/// Tab bar listing every screen, highlighting the current one
+pub struct TabBar {
+    current: ScreenState,
+}
+
+impl TabBar {
+    pub fn new(current: ScreenState) -> Self {
+        Self { current }
+    }
+
+    /// Row of the tab bar: the blank line under the screen header
+    pub fn area(size: Rect) -> Option<Rect> {
+        if size.height < 3 {
+            return None;
+        }
+        Some(Rect::new(size.x, size.y + 1, size.width, 1))
+    }
+
+    pub fn draw(&self, f: &mut Frame) {
+        let Some(area) = Self::area(f.size()) else {
+            return;
+        };
+
+        let mut spans = Vec::new();
+        for (i, state) in screens().enumerate() {
+            if i > 0 {
+                spans.push(Span::styled(
+                    SEPARATOR,
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            let style = if state == self.current {
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::REVERSED)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            spans.push(Span::styled(state.name(), style));
+        }
+
+        let x = area.x + area.width.saturating_sub(total_width()) / 2;
+        let area = Rect::new(x, area.y, area.width - (x - area.x), 1);
+        f.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+
+    /// Screen whose tab covers the given terminal cell, if any
+    pub fn screen_at(size: Rect, column: u16, row: u16) -> Option<ScreenState> {
+        let area = Self::area(size)?;
+        if row != area.y {
+            return None;
+        }
+
+        let mut x = area.x + area.width.saturating_sub(total_width()) / 2;
+        for state in screens() {
+            let width = state.name().chars().count() as u16;
+            if column >= x && column < x + width {
+                return Some(state);
+            }
+            x += width + SEPARATOR.chars().count() as u16;
+        }
+        None
+    }
+}
+
+/// Every screen in key order
+fn screens() -> impl Iterator<Item = ScreenState> {
+    (0..ScreenState::COUNT).filter_map(ScreenState::from_index)
+}
+
+/// Width of all tab names and the separators between them
+fn total_width() -> u16 {
+    let names: usize = screens().map(|s| s.name().chars().count()).sum();
+    (names + ScreenState::COUNT.saturating_sub(1) * SEPARATOR.chars().count()) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_screen_at_maps_columns_to_tabs() {
+        let size = Rect::new(0, 0, 80, 24);
+        // The tabs are centered: (80 - 66) / 2 = 7
+        assert_eq!(total_width(), 66);
+        assert_eq!(TabBar::screen_at(size, 7, 1), Some(ScreenState::All));
+        assert_eq!(TabBar::screen_at(size, 9, 1), Some(ScreenState::All));
+        // Separator between "All" and "CPU"
+        assert_eq!(TabBar::screen_at(size, 10, 1), None);
+        assert_eq!(TabBar::screen_at(size, 11, 1), Some(ScreenState::Cpu));
+        assert_eq!(TabBar::screen_at(size, 72, 1), Some(ScreenState::Processes));
+        assert_eq!(TabBar::screen_at(size, 73, 1), None);
+        assert_eq!(TabBar::screen_at(size, 6, 1), None);
+    }
+
+    #[test]
+    fn test_screen_at_ignores_other_rows() {
+        let size = Rect::new(0, 0, 80, 24);
+        assert_eq!(TabBar::screen_at(size, 8, 0), None);
+        assert_eq!(TabBar::screen_at(size, 8, 2), None);
+        assert_eq!(TabBar::screen_at(Rect::new(0, 0, 80, 2), 8, 1), None);
+    }
+
+    #[test]
+    fn test_tab_bar_renders_every_screen() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 5)).unwrap();
+        terminal
+            .draw(|f| TabBar::new(ScreenState::Gpu).draw(f))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let row: String = (0..buffer.area.width)
+            .map(|x| buffer.get(x, 1).symbol())
+            .collect();
+        assert_eq!(
+            row.trim(),
+            "All│CPU│GPU│Memory│Power│Temperature│Control│Info│Engine│Processes"
+        );
+        assert!(buffer.get(15, 1).modifier.contains(Modifier::REVERSED));
+    }
+}